@@ -1,11 +1,14 @@
 import 'dart:math';
 import 'dart:ui';
 
-import 'package:fast_immutable_collections/fast_immutable_collections.dart';
 import 'package:flutter/foundation.dart';
 import 'package:flutter/material.dart';
 import 'package:flutter/scheduler.dart';
+import 'package:x_compute/src/rust/api/hit_test.dart';
+import 'package:x_compute/src/rust/api/point_set.dart';
+import 'package:x_compute/src/rust/api/projection.dart';
 import 'package:x_compute/src/rust/api/simple.dart';
+import 'package:x_compute/src/rust/api/transform.dart';
 import 'package:x_compute/src/rust/frb_generated.dart';
 
 Future<void> main() async {
@@ -21,23 +24,44 @@ class MyApp extends StatefulWidget {
 }
 
 class _MyAppState extends State<MyApp> {
-  final pointsInside = ValueNotifier<Iterable<F32Array2>>([]);
-  final rect = ValueNotifier(ComputeRect(
-    min: F32Array2(Float32List.fromList([0.0, 0.0])),
-    max: F32Array2(Float32List.fromList([0.0, 0.0])),
-  ));
+  // How far from a point, in logical pixels, the cursor still counts as over it.
+  static const hitTolerance = 8.0;
+  // Radius the points are drawn with, so the ones at the edges are not culled while still visible.
+  static const pointRadius = 2.5;
+
+  // Index of the point under the cursor.
+  final hovered = ValueNotifier<int?>(null);
+  // Coordinates of the points one after another, as `x0, y0, x1, y1, ...`.
+  Float32List? points;
+  Future<int>? pointSet;
+  Future<FlatScreenPoints?>? visible;
 
   @override
   Widget build(BuildContext context) {
-    final points = List.generate(50000, (index) {
-      final size = MediaQuery.sizeOf(context);
-      return [
-        Random().nextInt(size.width.toInt()),
-        Random().nextInt(
-          size.height.toInt(),
-        )
-      ].map((e) => e.toDouble()).toList();
-    }).map((e) => F32Array2(Float32List.fromList(e))).toList();
+    final size = MediaQuery.sizeOf(context);
+    final points = this.points ??= Float32List.fromList(
+      List.generate(50000, (index) {
+        return [
+          Random().nextInt(size.width.toInt()),
+          Random().nextInt(size.height.toInt()),
+        ];
+      }).expand((point) => point).map((e) => e.toDouble()).toList(),
+    );
+    final pointSet = this.pointSet ??=
+        createPointSetFlat(points: points).then((pointSet) => pointSet!);
+    final visible = this.visible ??= pointSet.then(
+      (pointSet) => projectPointSetFlat(
+        pointSet: pointSet,
+        camera: Camera(
+          view: AffineTransform.identity(),
+          viewport: ComputeRect(
+            min: F32Array2(Float32List.fromList([0.0, 0.0])),
+            max: F32Array2(Float32List.fromList([size.width, size.height])),
+          ),
+          margin: pointRadius,
+        ),
+      ),
+    );
 
     var isComputing = false;
 
@@ -46,59 +70,53 @@ class _MyAppState extends State<MyApp> {
         backgroundColor: Colors.grey,
         body: Stack(
           children: [
-            // CustomPaint(
-            //   painter: PointPainter(points, Colors.blue),
-            // ),
-            ValueListenableBuilder(
-                valueListenable: pointsInside,
-                builder: (context, value, child) {
+            FutureBuilder(
+                future: visible,
+                builder: (context, snapshot) {
                   return CustomPaint(
-                    painter: PointPainter(value, Colors.blueGrey),
+                    painter: PointPainter(
+                      snapshot.data?.positions ?? Float32List(0),
+                      Colors.blueGrey,
+                    ),
                   );
                 }),
             ValueListenableBuilder(
-                valueListenable: rect,
-                builder: (context, value, child) {
+                valueListenable: hovered,
+                builder: (context, index, child) {
                   return CustomPaint(
-                    painter: RectPainter(value),
+                    painter: PointPainter(
+                      index == null
+                          ? Float32List(0)
+                          : Float32List.sublistView(
+                              points, index * 2, index * 2 + 2),
+                      Colors.redAccent,
+                    ),
                   );
                 }),
             MouseRegion(
               onHover: (event) async {
-                final minRect = [0.0, 0.0];
-                final maxRect = [event.position.dx, event.position.dy];
-                // print(maxRect);
-                rect.value = ComputeRect(
-                  min: F32Array2(Float32List.fromList(minRect)),
-                  max: F32Array2(
-                    Float32List.fromList(maxRect),
-                  ),
-                );
                 if (isComputing == true) return;
                 isComputing = true;
-                // final now = DateTime.now();
+                final position = F32Array2(Float32List.fromList(
+                    [event.position.dx, event.position.dy]));
                 SchedulerBinding.instance
                     .scheduleTask(
-                  () => runCompute(
-                    points: points.take(points.length).toList(),
-                    rect: rect.value,
+                  () async => hitTest(
+                    pointSet: await pointSet,
+                    position: position,
+                    tolerance: hitTolerance,
                   ),
                   Priority.animation,
                 )
                     .then(
-                  (value) {
+                  (index) {
                     isComputing = false;
-                    // print('Elapsed: ${DateTime.now().difference(now)}');
-                    // pointsInside.value.clear().flush;
-
-                    if (value != null) {
-                      pointsInside.value = value;
-                    }
+                    hovered.value = index;
                   },
                 );
               },
               child: const Center(
-                  child: Text('Hover to calculate points inside rect')),
+                  child: Text('Hover to highlight the point under the cursor')),
             ),
           ],
         ),
@@ -109,24 +127,23 @@ class _MyAppState extends State<MyApp> {
 
 class PointPainter extends CustomPainter {
   PointPainter(this.points, this.color);
-  final Iterable<F32Array2> points;
+  // Coordinates of the points one after another, as `drawRawPoints` takes them.
+  final Float32List points;
   final Color color;
 
   @override
   void paint(Canvas canvas, Size size) {
-    final view =
-        points.map((point) => Offset(point[0], point[1])).toIList().unlockView;
-    canvas.drawPoints(
+    canvas.drawRawPoints(
       PointMode.points,
-      view,
+      points,
       Paint()
         ..color = color
-        ..strokeWidth = 5
+        ..strokeWidth = _MyAppState.pointRadius * 2
         ..strokeCap = StrokeCap.round,
     );
-    canvas.drawPoints(
+    canvas.drawRawPoints(
       PointMode.points,
-      view,
+      points,
       Paint()
         ..color = Colors.white
         ..strokeWidth = 1,
@@ -134,29 +151,6 @@ class PointPainter extends CustomPainter {
   }
 
   @override
-  bool shouldRepaint(covariant CustomPainter oldDelegate) => true;
-}
-
-class RectPainter extends CustomPainter {
-  RectPainter(this.rect);
-  final ComputeRect rect;
-
-  @override
-  void paint(Canvas canvas, Size size) {
-    final paint = Paint()
-          //
-          ..color = Colors.redAccent.withOpacity(.2)
-        // ..strokeWidth = 1
-        // ..style = PaintingStyle.stroke
-        //
-        ;
-
-    canvas.drawRect(
-        Rect.fromPoints(
-            Offset(rect.min[0], rect.min[1]), Offset(rect.max[0], rect.max[1])),
-        paint);
-  }
-
-  @override
-  bool shouldRepaint(covariant CustomPainter oldDelegate) => true;
+  bool shouldRepaint(covariant PointPainter oldDelegate) =>
+      oldDelegate.points != points || oldDelegate.color != color;
 }
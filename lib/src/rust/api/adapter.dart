@@ -0,0 +1,165 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+Future<AdapterReport?> getAdapterReport({dynamic hint}) =>
+    RustLib.instance.api.getAdapterReport(hint: hint);
+
+/// What the selected adapter is and what it can run. Meant for bug reports and for gating GPU only features.
+class AdapterReport {
+  final String name;
+  final int vendor;
+  final int device;
+  /// `DiscreteGpu`, `IntegratedGpu`, `VirtualGpu`, `Cpu` or `Other`.
+  final String deviceType;
+  /// `Vulkan`, `Metal`, `Dx12`, `Gl` or `BrowserWebGpu`.
+  final String backend;
+  final String driver;
+  final String driverInfo;
+  final bool computeShaders;
+  /// Features the adapter supports.
+  final List<String> adapterFeatures;
+  /// Features enabled on the device the compute thread uses.
+  final List<String> deviceFeatures;
+  final ComputeLimits adapterLimits;
+  final ComputeLimits deviceLimits;
+  final List<KernelSupport> kernels;
+
+  const AdapterReport({
+    required this.name,
+    required this.vendor,
+    required this.device,
+    required this.deviceType,
+    required this.backend,
+    required this.driver,
+    required this.driverInfo,
+    required this.computeShaders,
+    required this.adapterFeatures,
+    required this.deviceFeatures,
+    required this.adapterLimits,
+    required this.deviceLimits,
+    required this.kernels,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      vendor.hashCode ^
+      device.hashCode ^
+      deviceType.hashCode ^
+      backend.hashCode ^
+      driver.hashCode ^
+      driverInfo.hashCode ^
+      computeShaders.hashCode ^
+      adapterFeatures.hashCode ^
+      deviceFeatures.hashCode ^
+      adapterLimits.hashCode ^
+      deviceLimits.hashCode ^
+      kernels.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AdapterReport &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          vendor == other.vendor &&
+          device == other.device &&
+          deviceType == other.deviceType &&
+          backend == other.backend &&
+          driver == other.driver &&
+          driverInfo == other.driverInfo &&
+          computeShaders == other.computeShaders &&
+          adapterFeatures == other.adapterFeatures &&
+          deviceFeatures == other.deviceFeatures &&
+          adapterLimits == other.adapterLimits &&
+          deviceLimits == other.deviceLimits &&
+          kernels == other.kernels;
+}
+
+/// The subset of `wgpu::Limits` that bounds compute workloads.
+class ComputeLimits {
+  final int maxBufferSize;
+  final int maxStorageBufferBindingSize;
+  final int maxStorageBuffersPerShaderStage;
+  final int maxComputeWorkgroupStorageSize;
+  final int maxComputeInvocationsPerWorkgroup;
+  final int maxComputeWorkgroupSizeX;
+  final int maxComputeWorkgroupsPerDimension;
+
+  const ComputeLimits({
+    required this.maxBufferSize,
+    required this.maxStorageBufferBindingSize,
+    required this.maxStorageBuffersPerShaderStage,
+    required this.maxComputeWorkgroupStorageSize,
+    required this.maxComputeInvocationsPerWorkgroup,
+    required this.maxComputeWorkgroupSizeX,
+    required this.maxComputeWorkgroupsPerDimension,
+  });
+
+  @override
+  int get hashCode =>
+      maxBufferSize.hashCode ^
+      maxStorageBufferBindingSize.hashCode ^
+      maxStorageBuffersPerShaderStage.hashCode ^
+      maxComputeWorkgroupStorageSize.hashCode ^
+      maxComputeInvocationsPerWorkgroup.hashCode ^
+      maxComputeWorkgroupSizeX.hashCode ^
+      maxComputeWorkgroupsPerDimension.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ComputeLimits &&
+          runtimeType == other.runtimeType &&
+          maxBufferSize == other.maxBufferSize &&
+          maxStorageBufferBindingSize == other.maxStorageBufferBindingSize &&
+          maxStorageBuffersPerShaderStage ==
+              other.maxStorageBuffersPerShaderStage &&
+          maxComputeWorkgroupStorageSize ==
+              other.maxComputeWorkgroupStorageSize &&
+          maxComputeInvocationsPerWorkgroup ==
+              other.maxComputeInvocationsPerWorkgroup &&
+          maxComputeWorkgroupSizeX == other.maxComputeWorkgroupSizeX &&
+          maxComputeWorkgroupsPerDimension ==
+              other.maxComputeWorkgroupsPerDimension;
+}
+
+class KernelSupport {
+  final String name;
+  final bool available;
+  /// Features the device is missing for this kernel, empty when available.
+  final List<String> missingFeatures;
+  /// Storage buffers the kernel binds at once. It is unavailable when the adapter's
+  /// `max_storage_buffers_per_shader_stage` is lower.
+  final int storageBuffers;
+
+  const KernelSupport({
+    required this.name,
+    required this.available,
+    required this.missingFeatures,
+    required this.storageBuffers,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      available.hashCode ^
+      missingFeatures.hashCode ^
+      storageBuffers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KernelSupport &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          available == other.available &&
+          missingFeatures == other.missingFeatures &&
+          storageBuffers == other.storageBuffers;
+}
@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Outline of `point_set` as an alpha shape: the union of the Delaunay triangles whose circumcircle has a
+/// radius of at most `alpha`, as polygons with holes. Smaller values follow the points more tightly and
+/// split sparse areas apart, points no kept triangle touches are left out. Coincident points resolve to the
+/// lowest index. `None` if the point set does not exist.
+Future<List<ShapePolygon>?> alphaShape(
+        {required int pointSet, required double alpha, dynamic hint}) =>
+    RustLib.instance.api.alphaShape(
+        pointSet: pointSet, alpha: alpha, hint: hint);
+
+/// Like `alpha_shape`, over only the points of `point_set` within `radius` of `center`.
+Future<List<ShapePolygon>?> alphaShapeInCircle(
+        {required int pointSet,
+        required double alpha,
+        required F32Array2 center,
+        required double radius,
+        dynamic hint}) =>
+    RustLib.instance.api.alphaShapeInCircle(
+        pointSet: pointSet,
+        alpha: alpha,
+        center: center,
+        radius: radius,
+        hint: hint);
+
+/// Like `alpha_shape`, over only the points of `point_set` inside `polygon` (even-odd rule).
+Future<List<ShapePolygon>?> alphaShapeInPolygon(
+        {required int pointSet,
+        required double alpha,
+        required List<F32Array2> polygon,
+        dynamic hint}) =>
+    RustLib.instance.api.alphaShapeInPolygon(
+        pointSet: pointSet, alpha: alpha, polygon: polygon, hint: hint);
+
+/// Like `alpha_shape`, over only the points of `point_set` inside `rect`.
+Future<List<ShapePolygon>?> alphaShapeInRect(
+        {required int pointSet,
+        required double alpha,
+        required ComputeRect rect,
+        dynamic hint}) =>
+    RustLib.instance.api.alphaShapeInRect(
+        pointSet: pointSet, alpha: alpha, rect: rect, hint: hint);
+
+/// One connected piece of an alpha shape, as point indices.
+class ShapePolygon {
+  /// Outer ring, counter-clockwise with y pointing up (clockwise on screen).
+  final Uint32List exterior;
+  /// Rings around the holes, clockwise with y pointing up (counter-clockwise on screen).
+  final List<Uint32List> holes;
+
+  const ShapePolygon({
+    required this.exterior,
+    required this.holes,
+  });
+
+  @override
+  int get hashCode => exterior.hashCode ^ holes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ShapePolygon &&
+          runtimeType == other.runtimeType &&
+          exterior == other.exterior &&
+          holes == other.holes;
+}
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'attributes.freezed.dart';
+
+/// Removes the attribute channel `name` of `point_set`. Returns false if the set or channel does not exist.
+Future<bool> removePointAttribute(
+        {required int pointSet, required String name, dynamic hint}) =>
+    RustLib.instance.api.removePointAttribute(
+        pointSet: pointSet, name: name, hint: hint);
+
+/// Adds an attribute channel named `name` to `point_set`, or replaces it, with one value per point. Channels
+/// stay on the device next to the points and are dropped when `update_point_set` changes the number of
+/// points. Returns false if the set does not exist or `values` does not have one value per point.
+Future<bool> setPointAttribute(
+        {required int pointSet,
+        required String name,
+        required AttributeValues values,
+        dynamic hint}) =>
+    RustLib.instance.api.setPointAttribute(
+        pointSet: pointSet, name: name, values: values, hint: hint);
+
+/// A condition on an attribute channel. Queries keep the points meeting all of their filters.
+@freezed
+sealed class AttributeFilter with _$AttributeFilter {
+  /// Values from `min` to `max`, both included. NaN never matches.
+  const factory AttributeFilter.range({
+    required String attribute,
+    required double min,
+    required double max,
+  }) = AttributeFilter_Range;
+  /// Values equal to one of `values`.
+  const factory AttributeFilter.oneOf({
+    required String attribute,
+    required Float64List values,
+  }) = AttributeFilter_OneOf;
+  /// Values sharing any bit with `mask`, or all of its bits with `all`. Only for `U32` channels.
+  const factory AttributeFilter.bitmask({
+    required String attribute,
+    required int mask,
+    required bool all,
+  }) = AttributeFilter_Bitmask;
+}
+
+/// Values of one attribute channel, one per point.
+@freezed
+sealed class AttributeValues with _$AttributeValues {
+  const factory AttributeValues.f32(
+    Float32List field0,
+  ) = AttributeValues_F32;
+  const factory AttributeValues.u32(
+    Uint32List field0,
+  ) = AttributeValues_U32;
+}
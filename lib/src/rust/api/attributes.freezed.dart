@@ -0,0 +1,931 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'attributes.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$AttributeFilter {
+  String get attribute => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String attribute, double min, double max) range,
+    required TResult Function(String attribute, Float64List values) oneOf,
+    required TResult Function(String attribute, int mask, bool all) bitmask,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String attribute, double min, double max)? range,
+    TResult? Function(String attribute, Float64List values)? oneOf,
+    TResult? Function(String attribute, int mask, bool all)? bitmask,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String attribute, double min, double max)? range,
+    TResult Function(String attribute, Float64List values)? oneOf,
+    TResult Function(String attribute, int mask, bool all)? bitmask,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AttributeFilter_Range value) range,
+    required TResult Function(AttributeFilter_OneOf value) oneOf,
+    required TResult Function(AttributeFilter_Bitmask value) bitmask,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AttributeFilter_Range value)? range,
+    TResult? Function(AttributeFilter_OneOf value)? oneOf,
+    TResult? Function(AttributeFilter_Bitmask value)? bitmask,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AttributeFilter_Range value)? range,
+    TResult Function(AttributeFilter_OneOf value)? oneOf,
+    TResult Function(AttributeFilter_Bitmask value)? bitmask,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @JsonKey(ignore: true)
+  $AttributeFilterCopyWith<AttributeFilter> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $AttributeFilterCopyWith<$Res> {
+  factory $AttributeFilterCopyWith(
+          AttributeFilter value, $Res Function(AttributeFilter) then) =
+      _$AttributeFilterCopyWithImpl<$Res, AttributeFilter>;
+  @useResult
+  $Res call({String attribute});
+}
+
+/// @nodoc
+class _$AttributeFilterCopyWithImpl<$Res, $Val extends AttributeFilter>
+    implements $AttributeFilterCopyWith<$Res> {
+  _$AttributeFilterCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? attribute = null,
+  }) {
+    return _then(_value.copyWith(
+      attribute: null == attribute
+          ? _value.attribute
+          : attribute // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$AttributeFilter_RangeImplCopyWith<$Res>
+    implements $AttributeFilterCopyWith<$Res> {
+  factory _$$AttributeFilter_RangeImplCopyWith(
+          _$AttributeFilter_RangeImpl value,
+          $Res Function(_$AttributeFilter_RangeImpl) then) =
+      __$$AttributeFilter_RangeImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String attribute, double min, double max});
+}
+
+/// @nodoc
+class __$$AttributeFilter_RangeImplCopyWithImpl<$Res>
+    extends _$AttributeFilterCopyWithImpl<$Res, _$AttributeFilter_RangeImpl>
+    implements _$$AttributeFilter_RangeImplCopyWith<$Res> {
+  __$$AttributeFilter_RangeImplCopyWithImpl(_$AttributeFilter_RangeImpl _value,
+      $Res Function(_$AttributeFilter_RangeImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? attribute = null,
+    Object? min = null,
+    Object? max = null,
+  }) {
+    return _then(_$AttributeFilter_RangeImpl(
+      attribute: null == attribute
+          ? _value.attribute
+          : attribute // ignore: cast_nullable_to_non_nullable
+              as String,
+      min: null == min
+          ? _value.min
+          : min // ignore: cast_nullable_to_non_nullable
+              as double,
+      max: null == max
+          ? _value.max
+          : max // ignore: cast_nullable_to_non_nullable
+              as double,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$AttributeFilter_RangeImpl implements AttributeFilter_Range {
+  const _$AttributeFilter_RangeImpl(
+      {required this.attribute, required this.min, required this.max});
+
+  @override
+  final String attribute;
+  @override
+  final double min;
+  @override
+  final double max;
+
+  @override
+  String toString() {
+    return 'AttributeFilter.range(attribute: $attribute, min: $min, max: $max)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AttributeFilter_RangeImpl &&
+            (identical(other.attribute, attribute) ||
+                other.attribute == attribute) &&
+            (identical(other.min, min) || other.min == min) &&
+            (identical(other.max, max) || other.max == max));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, attribute, min, max);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AttributeFilter_RangeImplCopyWith<_$AttributeFilter_RangeImpl>
+      get copyWith => __$$AttributeFilter_RangeImplCopyWithImpl<
+          _$AttributeFilter_RangeImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String attribute, double min, double max) range,
+    required TResult Function(String attribute, Float64List values) oneOf,
+    required TResult Function(String attribute, int mask, bool all) bitmask,
+  }) {
+    return range(attribute, min, max);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String attribute, double min, double max)? range,
+    TResult? Function(String attribute, Float64List values)? oneOf,
+    TResult? Function(String attribute, int mask, bool all)? bitmask,
+  }) {
+    return range?.call(attribute, min, max);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String attribute, double min, double max)? range,
+    TResult Function(String attribute, Float64List values)? oneOf,
+    TResult Function(String attribute, int mask, bool all)? bitmask,
+    required TResult orElse(),
+  }) {
+    if (range != null) {
+      return range(attribute, min, max);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AttributeFilter_Range value) range,
+    required TResult Function(AttributeFilter_OneOf value) oneOf,
+    required TResult Function(AttributeFilter_Bitmask value) bitmask,
+  }) {
+    return range(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AttributeFilter_Range value)? range,
+    TResult? Function(AttributeFilter_OneOf value)? oneOf,
+    TResult? Function(AttributeFilter_Bitmask value)? bitmask,
+  }) {
+    return range?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AttributeFilter_Range value)? range,
+    TResult Function(AttributeFilter_OneOf value)? oneOf,
+    TResult Function(AttributeFilter_Bitmask value)? bitmask,
+    required TResult orElse(),
+  }) {
+    if (range != null) {
+      return range(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AttributeFilter_Range implements AttributeFilter {
+  const factory AttributeFilter_Range(
+      {required final String attribute,
+      required final double min,
+      required final double max}) = _$AttributeFilter_RangeImpl;
+
+  @override
+  String get attribute;
+  double get min;
+  double get max;
+  @JsonKey(ignore: true)
+  _$$AttributeFilter_RangeImplCopyWith<_$AttributeFilter_RangeImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$AttributeFilter_OneOfImplCopyWith<$Res>
+    implements $AttributeFilterCopyWith<$Res> {
+  factory _$$AttributeFilter_OneOfImplCopyWith(
+          _$AttributeFilter_OneOfImpl value,
+          $Res Function(_$AttributeFilter_OneOfImpl) then) =
+      __$$AttributeFilter_OneOfImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String attribute, Float64List values});
+}
+
+/// @nodoc
+class __$$AttributeFilter_OneOfImplCopyWithImpl<$Res>
+    extends _$AttributeFilterCopyWithImpl<$Res, _$AttributeFilter_OneOfImpl>
+    implements _$$AttributeFilter_OneOfImplCopyWith<$Res> {
+  __$$AttributeFilter_OneOfImplCopyWithImpl(_$AttributeFilter_OneOfImpl _value,
+      $Res Function(_$AttributeFilter_OneOfImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? attribute = null,
+    Object? values = null,
+  }) {
+    return _then(_$AttributeFilter_OneOfImpl(
+      attribute: null == attribute
+          ? _value.attribute
+          : attribute // ignore: cast_nullable_to_non_nullable
+              as String,
+      values: null == values
+          ? _value.values
+          : values // ignore: cast_nullable_to_non_nullable
+              as Float64List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$AttributeFilter_OneOfImpl implements AttributeFilter_OneOf {
+  const _$AttributeFilter_OneOfImpl(
+      {required this.attribute, required this.values});
+
+  @override
+  final String attribute;
+  @override
+  final Float64List values;
+
+  @override
+  String toString() {
+    return 'AttributeFilter.oneOf(attribute: $attribute, values: $values)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AttributeFilter_OneOfImpl &&
+            (identical(other.attribute, attribute) ||
+                other.attribute == attribute) &&
+            const DeepCollectionEquality().equals(other.values, values));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, attribute, const DeepCollectionEquality().hash(values));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AttributeFilter_OneOfImplCopyWith<_$AttributeFilter_OneOfImpl>
+      get copyWith => __$$AttributeFilter_OneOfImplCopyWithImpl<
+          _$AttributeFilter_OneOfImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String attribute, double min, double max) range,
+    required TResult Function(String attribute, Float64List values) oneOf,
+    required TResult Function(String attribute, int mask, bool all) bitmask,
+  }) {
+    return oneOf(attribute, values);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String attribute, double min, double max)? range,
+    TResult? Function(String attribute, Float64List values)? oneOf,
+    TResult? Function(String attribute, int mask, bool all)? bitmask,
+  }) {
+    return oneOf?.call(attribute, values);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String attribute, double min, double max)? range,
+    TResult Function(String attribute, Float64List values)? oneOf,
+    TResult Function(String attribute, int mask, bool all)? bitmask,
+    required TResult orElse(),
+  }) {
+    if (oneOf != null) {
+      return oneOf(attribute, values);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AttributeFilter_Range value) range,
+    required TResult Function(AttributeFilter_OneOf value) oneOf,
+    required TResult Function(AttributeFilter_Bitmask value) bitmask,
+  }) {
+    return oneOf(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AttributeFilter_Range value)? range,
+    TResult? Function(AttributeFilter_OneOf value)? oneOf,
+    TResult? Function(AttributeFilter_Bitmask value)? bitmask,
+  }) {
+    return oneOf?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AttributeFilter_Range value)? range,
+    TResult Function(AttributeFilter_OneOf value)? oneOf,
+    TResult Function(AttributeFilter_Bitmask value)? bitmask,
+    required TResult orElse(),
+  }) {
+    if (oneOf != null) {
+      return oneOf(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AttributeFilter_OneOf implements AttributeFilter {
+  const factory AttributeFilter_OneOf(
+      {required final String attribute,
+      required final Float64List values}) = _$AttributeFilter_OneOfImpl;
+
+  @override
+  String get attribute;
+  Float64List get values;
+  @JsonKey(ignore: true)
+  _$$AttributeFilter_OneOfImplCopyWith<_$AttributeFilter_OneOfImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$AttributeFilter_BitmaskImplCopyWith<$Res>
+    implements $AttributeFilterCopyWith<$Res> {
+  factory _$$AttributeFilter_BitmaskImplCopyWith(
+          _$AttributeFilter_BitmaskImpl value,
+          $Res Function(_$AttributeFilter_BitmaskImpl) then) =
+      __$$AttributeFilter_BitmaskImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String attribute, int mask, bool all});
+}
+
+/// @nodoc
+class __$$AttributeFilter_BitmaskImplCopyWithImpl<$Res>
+    extends _$AttributeFilterCopyWithImpl<$Res, _$AttributeFilter_BitmaskImpl>
+    implements _$$AttributeFilter_BitmaskImplCopyWith<$Res> {
+  __$$AttributeFilter_BitmaskImplCopyWithImpl(
+      _$AttributeFilter_BitmaskImpl _value,
+      $Res Function(_$AttributeFilter_BitmaskImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? attribute = null,
+    Object? mask = null,
+    Object? all = null,
+  }) {
+    return _then(_$AttributeFilter_BitmaskImpl(
+      attribute: null == attribute
+          ? _value.attribute
+          : attribute // ignore: cast_nullable_to_non_nullable
+              as String,
+      mask: null == mask
+          ? _value.mask
+          : mask // ignore: cast_nullable_to_non_nullable
+              as int,
+      all: null == all
+          ? _value.all
+          : all // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$AttributeFilter_BitmaskImpl implements AttributeFilter_Bitmask {
+  const _$AttributeFilter_BitmaskImpl(
+      {required this.attribute, required this.mask, required this.all});
+
+  @override
+  final String attribute;
+  @override
+  final int mask;
+  @override
+  final bool all;
+
+  @override
+  String toString() {
+    return 'AttributeFilter.bitmask(attribute: $attribute, mask: $mask, all: $all)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AttributeFilter_BitmaskImpl &&
+            (identical(other.attribute, attribute) ||
+                other.attribute == attribute) &&
+            (identical(other.mask, mask) || other.mask == mask) &&
+            (identical(other.all, all) || other.all == all));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, attribute, mask, all);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AttributeFilter_BitmaskImplCopyWith<_$AttributeFilter_BitmaskImpl>
+      get copyWith => __$$AttributeFilter_BitmaskImplCopyWithImpl<
+          _$AttributeFilter_BitmaskImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String attribute, double min, double max) range,
+    required TResult Function(String attribute, Float64List values) oneOf,
+    required TResult Function(String attribute, int mask, bool all) bitmask,
+  }) {
+    return bitmask(attribute, mask, all);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String attribute, double min, double max)? range,
+    TResult? Function(String attribute, Float64List values)? oneOf,
+    TResult? Function(String attribute, int mask, bool all)? bitmask,
+  }) {
+    return bitmask?.call(attribute, mask, all);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String attribute, double min, double max)? range,
+    TResult Function(String attribute, Float64List values)? oneOf,
+    TResult Function(String attribute, int mask, bool all)? bitmask,
+    required TResult orElse(),
+  }) {
+    if (bitmask != null) {
+      return bitmask(attribute, mask, all);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AttributeFilter_Range value) range,
+    required TResult Function(AttributeFilter_OneOf value) oneOf,
+    required TResult Function(AttributeFilter_Bitmask value) bitmask,
+  }) {
+    return bitmask(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AttributeFilter_Range value)? range,
+    TResult? Function(AttributeFilter_OneOf value)? oneOf,
+    TResult? Function(AttributeFilter_Bitmask value)? bitmask,
+  }) {
+    return bitmask?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AttributeFilter_Range value)? range,
+    TResult Function(AttributeFilter_OneOf value)? oneOf,
+    TResult Function(AttributeFilter_Bitmask value)? bitmask,
+    required TResult orElse(),
+  }) {
+    if (bitmask != null) {
+      return bitmask(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AttributeFilter_Bitmask implements AttributeFilter {
+  const factory AttributeFilter_Bitmask(
+      {required final String attribute,
+      required final int mask,
+      required final bool all}) = _$AttributeFilter_BitmaskImpl;
+
+  @override
+  String get attribute;
+  int get mask;
+  bool get all;
+  @JsonKey(ignore: true)
+  _$$AttributeFilter_BitmaskImplCopyWith<_$AttributeFilter_BitmaskImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$AttributeValues {
+  Object get field0 => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Float32List field0) f32,
+    required TResult Function(Uint32List field0) u32,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Float32List field0)? f32,
+    TResult? Function(Uint32List field0)? u32,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Float32List field0)? f32,
+    TResult Function(Uint32List field0)? u32,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AttributeValues_F32 value) f32,
+    required TResult Function(AttributeValues_U32 value) u32,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AttributeValues_F32 value)? f32,
+    TResult? Function(AttributeValues_U32 value)? u32,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AttributeValues_F32 value)? f32,
+    TResult Function(AttributeValues_U32 value)? u32,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $AttributeValuesCopyWith<$Res> {
+  factory $AttributeValuesCopyWith(
+          AttributeValues value, $Res Function(AttributeValues) then) =
+      _$AttributeValuesCopyWithImpl<$Res, AttributeValues>;
+}
+
+/// @nodoc
+class _$AttributeValuesCopyWithImpl<$Res, $Val extends AttributeValues>
+    implements $AttributeValuesCopyWith<$Res> {
+  _$AttributeValuesCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$AttributeValues_F32ImplCopyWith<$Res> {
+  factory _$$AttributeValues_F32ImplCopyWith(
+          _$AttributeValues_F32Impl value,
+          $Res Function(_$AttributeValues_F32Impl) then) =
+      __$$AttributeValues_F32ImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Float32List field0});
+}
+
+/// @nodoc
+class __$$AttributeValues_F32ImplCopyWithImpl<$Res>
+    extends _$AttributeValuesCopyWithImpl<$Res, _$AttributeValues_F32Impl>
+    implements _$$AttributeValues_F32ImplCopyWith<$Res> {
+  __$$AttributeValues_F32ImplCopyWithImpl(_$AttributeValues_F32Impl _value,
+      $Res Function(_$AttributeValues_F32Impl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$AttributeValues_F32Impl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as Float32List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$AttributeValues_F32Impl implements AttributeValues_F32 {
+  const _$AttributeValues_F32Impl(this.field0);
+
+  @override
+  final Float32List field0;
+
+  @override
+  String toString() {
+    return 'AttributeValues.f32(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AttributeValues_F32Impl &&
+            const DeepCollectionEquality().equals(other.field0, field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, const DeepCollectionEquality().hash(field0));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AttributeValues_F32ImplCopyWith<_$AttributeValues_F32Impl> get copyWith =>
+      __$$AttributeValues_F32ImplCopyWithImpl<_$AttributeValues_F32Impl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Float32List field0) f32,
+    required TResult Function(Uint32List field0) u32,
+  }) {
+    return f32(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Float32List field0)? f32,
+    TResult? Function(Uint32List field0)? u32,
+  }) {
+    return f32?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Float32List field0)? f32,
+    TResult Function(Uint32List field0)? u32,
+    required TResult orElse(),
+  }) {
+    if (f32 != null) {
+      return f32(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AttributeValues_F32 value) f32,
+    required TResult Function(AttributeValues_U32 value) u32,
+  }) {
+    return f32(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AttributeValues_F32 value)? f32,
+    TResult? Function(AttributeValues_U32 value)? u32,
+  }) {
+    return f32?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AttributeValues_F32 value)? f32,
+    TResult Function(AttributeValues_U32 value)? u32,
+    required TResult orElse(),
+  }) {
+    if (f32 != null) {
+      return f32(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AttributeValues_F32 implements AttributeValues {
+  const factory AttributeValues_F32(final Float32List field0) =
+      _$AttributeValues_F32Impl;
+
+  @override
+  Float32List get field0;
+  @JsonKey(ignore: true)
+  _$$AttributeValues_F32ImplCopyWith<_$AttributeValues_F32Impl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$AttributeValues_U32ImplCopyWith<$Res> {
+  factory _$$AttributeValues_U32ImplCopyWith(
+          _$AttributeValues_U32Impl value,
+          $Res Function(_$AttributeValues_U32Impl) then) =
+      __$$AttributeValues_U32ImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint32List field0});
+}
+
+/// @nodoc
+class __$$AttributeValues_U32ImplCopyWithImpl<$Res>
+    extends _$AttributeValuesCopyWithImpl<$Res, _$AttributeValues_U32Impl>
+    implements _$$AttributeValues_U32ImplCopyWith<$Res> {
+  __$$AttributeValues_U32ImplCopyWithImpl(_$AttributeValues_U32Impl _value,
+      $Res Function(_$AttributeValues_U32Impl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$AttributeValues_U32Impl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as Uint32List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$AttributeValues_U32Impl implements AttributeValues_U32 {
+  const _$AttributeValues_U32Impl(this.field0);
+
+  @override
+  final Uint32List field0;
+
+  @override
+  String toString() {
+    return 'AttributeValues.u32(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$AttributeValues_U32Impl &&
+            const DeepCollectionEquality().equals(other.field0, field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, const DeepCollectionEquality().hash(field0));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$AttributeValues_U32ImplCopyWith<_$AttributeValues_U32Impl> get copyWith =>
+      __$$AttributeValues_U32ImplCopyWithImpl<_$AttributeValues_U32Impl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Float32List field0) f32,
+    required TResult Function(Uint32List field0) u32,
+  }) {
+    return u32(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Float32List field0)? f32,
+    TResult? Function(Uint32List field0)? u32,
+  }) {
+    return u32?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Float32List field0)? f32,
+    TResult Function(Uint32List field0)? u32,
+    required TResult orElse(),
+  }) {
+    if (u32 != null) {
+      return u32(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(AttributeValues_F32 value) f32,
+    required TResult Function(AttributeValues_U32 value) u32,
+  }) {
+    return u32(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(AttributeValues_F32 value)? f32,
+    TResult? Function(AttributeValues_U32 value)? u32,
+  }) {
+    return u32?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(AttributeValues_F32 value)? f32,
+    TResult Function(AttributeValues_U32 value)? u32,
+    required TResult orElse(),
+  }) {
+    if (u32 != null) {
+      return u32(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class AttributeValues_U32 implements AttributeValues {
+  const factory AttributeValues_U32(final Uint32List field0) =
+      _$AttributeValues_U32Impl;
+
+  @override
+  Uint32List get field0;
+  @JsonKey(ignore: true)
+  _$$AttributeValues_U32ImplCopyWith<_$AttributeValues_U32Impl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Density based clustering of `point_set`. A point with at least `min_points` points within `eps`
+/// (itself included) is a core point; core points within `eps` of each other share a cluster, and other
+/// points within `eps` of a core point join the cluster of the lowest indexed one. Points that are not
+/// finite are always noise. `None` if the point set does not exist, `eps` is negative or not finite, or
+/// the points have more than `u32::MAX` neighbours in total.
+Future<Clustering?> dbscan(
+        {required int pointSet,
+        required double eps,
+        required int minPoints,
+        dynamic hint}) =>
+    RustLib.instance.api.dbscan(
+        pointSet: pointSet, eps: eps, minPoints: minPoints, hint: hint);
+
+/// Cluster label per point, `-1` for noise. Clusters are numbered from 0 in the order their first
+/// point appears in the set, so the GPU and CPU paths label the same input identically.
+class Clustering {
+  final Int32List labels;
+  final int clusterCount;
+
+  const Clustering({
+    required this.labels,
+    required this.clusterCount,
+  });
+
+  @override
+  int get hashCode => labels.hashCode ^ clusterCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Clustering &&
+          runtimeType == other.runtimeType &&
+          labels == other.labels &&
+          clusterCount == other.clusterCount;
+}
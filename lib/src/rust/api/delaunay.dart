@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Delaunay triangulation of `point_set`, using exact orientation and in-circle tests so nearly degenerate
+/// input still triangulates consistently. Coincident points are triangulated once, under the lowest index,
+/// and non-finite points are left out. `None` if the point set does not exist.
+Future<DelaunayTriangulation?> delaunay(
+        {required int pointSet, dynamic hint}) =>
+    RustLib.instance.api.delaunay(pointSet: pointSet, hint: hint);
+
+/// Delaunay triangulation of a point set, as point indices.
+class DelaunayTriangulation {
+  /// Three indices per triangle, counter-clockwise with y pointing up (clockwise on screen).
+  final Uint32List triangles;
+  /// Convex hull in the same winding, including points lying on its edges. Points in order along their
+  /// line when they are all collinear.
+  final Uint32List hull;
+
+  const DelaunayTriangulation({
+    required this.triangles,
+    required this.hull,
+  });
+
+  @override
+  int get hashCode => triangles.hashCode ^ hull.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DelaunayTriangulation &&
+          runtimeType == other.runtimeType &&
+          triangles == other.triangles &&
+          hull == other.hull;
+}
@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Re-measures the CPU and GPU cost model, e.g. after the app returns from the background. `None` if the
+/// adapter has no compute shader support, in which case every query runs on the CPU.
+Future<DispatchCalibration?> calibrateDispatch({dynamic hint}) =>
+    RustLib.instance.api.calibrateDispatch(hint: hint);
+
+ComputeBackend getComputeBackend({dynamic hint}) =>
+    RustLib.instance.api.getComputeBackend(hint: hint);
+
+/// Forces queries onto the CPU or the GPU, or hands the choice back to the scheduler with `Auto`.
+void setComputeBackend({required ComputeBackend backend, dynamic hint}) =>
+    RustLib.instance.api.setComputeBackend(backend: backend, hint: hint);
+
+/// Where queries are executed. `Auto` lets the scheduler decide per query.
+enum ComputeBackend {
+  auto,
+  cpu,
+  gpu,
+}
+
+/// Cost model measured on the current device, used by `ComputeBackend::Auto`.
+class DispatchCalibration {
+  /// Cost paid by every GPU query regardless of size: buffer creation, submission and mapping.
+  final double gpuFixedMicros;
+  /// Per point cost of a GPU query once the points are on the device.
+  final double gpuPerPointNanos;
+  /// Per point cost of uploading points that are not resident on the device.
+  final double uploadPerPointNanos;
+  final double cpuPerPointNanos;
+
+  const DispatchCalibration({
+    required this.gpuFixedMicros,
+    required this.gpuPerPointNanos,
+    required this.uploadPerPointNanos,
+    required this.cpuPerPointNanos,
+  });
+
+  /// Smallest point count for which the GPU is expected to win, `None` if it never does.
+  int? crossoverPoints({required bool resident, dynamic hint}) =>
+      RustLib.instance.api.dispatchCalibrationCrossoverPoints(
+          that: this, resident: resident, hint: hint);
+
+  @override
+  int get hashCode =>
+      gpuFixedMicros.hashCode ^
+      gpuPerPointNanos.hashCode ^
+      uploadPerPointNanos.hashCode ^
+      cpuPerPointNanos.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DispatchCalibration &&
+          runtimeType == other.runtimeType &&
+          gpuFixedMicros == other.gpuFixedMicros &&
+          gpuPerPointNanos == other.gpuPerPointNanos &&
+          uploadPerPointNanos == other.uploadPerPointNanos &&
+          cpuPerPointNanos == other.cpuPerPointNanos;
+}
@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'attributes.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'simple.dart';
+part 'expression.freezed.dart';
+
+/// Indices of the points of `point_set` for which `predicate` holds, in ascending order. `None` if the
+/// point set does not exist, or an attribute filter names a missing attribute or uses a bitmask on an
+/// `F32` attribute.
+Future<Uint32List?> queryPredicate(
+        {required int pointSet, required Predicate predicate, dynamic hint}) =>
+    RustLib.instance.api.queryPredicate(
+        pointSet: pointSet, predicate: predicate, hint: hint);
+
+/// A condition on the points of a set, composed of shapes and attribute filters. Compiled to a WGSL kernel
+/// that is cached by the structure of the predicate, so changing only its rects, radii, vertices or filter
+/// values reuses the kernel.
+@freezed
+sealed class Predicate with _$Predicate {
+  const factory Predicate.inRect(
+    ComputeRect field0,
+  ) = Predicate_InRect;
+  const factory Predicate.inCircle({
+    required F32Array2 center,
+    required double radius,
+  }) = Predicate_InCircle;
+  /// Even-odd rule.
+  const factory Predicate.inPolygon(
+    List<ComputePoint> field0,
+  ) = Predicate_InPolygon;
+  const factory Predicate.attribute(
+    AttributeFilter field0,
+  ) = Predicate_Attribute;
+  /// Holds when all of the predicates hold, so also for an empty list.
+  const factory Predicate.and(
+    List<Predicate> field0,
+  ) = Predicate_And;
+  /// Holds when any of the predicates holds, so never for an empty list.
+  const factory Predicate.or(
+    List<Predicate> field0,
+  ) = Predicate_Or;
+  const factory Predicate.not(
+    Predicate field0,
+  ) = Predicate_Not;
+}
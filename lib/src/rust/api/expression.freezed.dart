@@ -0,0 +1,1289 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'expression.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$Predicate {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ComputeRect field0) inRect,
+    required TResult Function(F32Array2 center, double radius) inCircle,
+    required TResult Function(List<ComputePoint> field0) inPolygon,
+    required TResult Function(AttributeFilter field0) attribute,
+    required TResult Function(List<Predicate> field0) and,
+    required TResult Function(List<Predicate> field0) or,
+    required TResult Function(Predicate field0) not,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ComputeRect field0)? inRect,
+    TResult? Function(F32Array2 center, double radius)? inCircle,
+    TResult? Function(List<ComputePoint> field0)? inPolygon,
+    TResult? Function(AttributeFilter field0)? attribute,
+    TResult? Function(List<Predicate> field0)? and,
+    TResult? Function(List<Predicate> field0)? or,
+    TResult? Function(Predicate field0)? not,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ComputeRect field0)? inRect,
+    TResult Function(F32Array2 center, double radius)? inCircle,
+    TResult Function(List<ComputePoint> field0)? inPolygon,
+    TResult Function(AttributeFilter field0)? attribute,
+    TResult Function(List<Predicate> field0)? and,
+    TResult Function(List<Predicate> field0)? or,
+    TResult Function(Predicate field0)? not,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Predicate_InRect value) inRect,
+    required TResult Function(Predicate_InCircle value) inCircle,
+    required TResult Function(Predicate_InPolygon value) inPolygon,
+    required TResult Function(Predicate_Attribute value) attribute,
+    required TResult Function(Predicate_And value) and,
+    required TResult Function(Predicate_Or value) or,
+    required TResult Function(Predicate_Not value) not,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Predicate_InRect value)? inRect,
+    TResult? Function(Predicate_InCircle value)? inCircle,
+    TResult? Function(Predicate_InPolygon value)? inPolygon,
+    TResult? Function(Predicate_Attribute value)? attribute,
+    TResult? Function(Predicate_And value)? and,
+    TResult? Function(Predicate_Or value)? or,
+    TResult? Function(Predicate_Not value)? not,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Predicate_InRect value)? inRect,
+    TResult Function(Predicate_InCircle value)? inCircle,
+    TResult Function(Predicate_InPolygon value)? inPolygon,
+    TResult Function(Predicate_Attribute value)? attribute,
+    TResult Function(Predicate_And value)? and,
+    TResult Function(Predicate_Or value)? or,
+    TResult Function(Predicate_Not value)? not,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $PredicateCopyWith<$Res> {
+  factory $PredicateCopyWith(Predicate value, $Res Function(Predicate) then) =
+      _$PredicateCopyWithImpl<$Res, Predicate>;
+}
+
+/// @nodoc
+class _$PredicateCopyWithImpl<$Res, $Val extends Predicate>
+    implements $PredicateCopyWith<$Res> {
+  _$PredicateCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$Predicate_InRectImplCopyWith<$Res> {
+  factory _$$Predicate_InRectImplCopyWith(
+          _$Predicate_InRectImpl value,
+          $Res Function(_$Predicate_InRectImpl) then) =
+      __$$Predicate_InRectImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ComputeRect field0});
+}
+
+/// @nodoc
+class __$$Predicate_InRectImplCopyWithImpl<$Res>
+    extends _$PredicateCopyWithImpl<$Res, _$Predicate_InRectImpl>
+    implements _$$Predicate_InRectImplCopyWith<$Res> {
+  __$$Predicate_InRectImplCopyWithImpl(_$Predicate_InRectImpl _value,
+      $Res Function(_$Predicate_InRectImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$Predicate_InRectImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ComputeRect,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$Predicate_InRectImpl implements Predicate_InRect {
+  const _$Predicate_InRectImpl(this.field0);
+
+  @override
+  final ComputeRect field0;
+
+  @override
+  String toString() {
+    return 'Predicate.inRect(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Predicate_InRectImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Predicate_InRectImplCopyWith<_$Predicate_InRectImpl> get copyWith =>
+      __$$Predicate_InRectImplCopyWithImpl<_$Predicate_InRectImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ComputeRect field0) inRect,
+    required TResult Function(F32Array2 center, double radius) inCircle,
+    required TResult Function(List<ComputePoint> field0) inPolygon,
+    required TResult Function(AttributeFilter field0) attribute,
+    required TResult Function(List<Predicate> field0) and,
+    required TResult Function(List<Predicate> field0) or,
+    required TResult Function(Predicate field0) not,
+  }) {
+    return inRect(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ComputeRect field0)? inRect,
+    TResult? Function(F32Array2 center, double radius)? inCircle,
+    TResult? Function(List<ComputePoint> field0)? inPolygon,
+    TResult? Function(AttributeFilter field0)? attribute,
+    TResult? Function(List<Predicate> field0)? and,
+    TResult? Function(List<Predicate> field0)? or,
+    TResult? Function(Predicate field0)? not,
+  }) {
+    return inRect?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ComputeRect field0)? inRect,
+    TResult Function(F32Array2 center, double radius)? inCircle,
+    TResult Function(List<ComputePoint> field0)? inPolygon,
+    TResult Function(AttributeFilter field0)? attribute,
+    TResult Function(List<Predicate> field0)? and,
+    TResult Function(List<Predicate> field0)? or,
+    TResult Function(Predicate field0)? not,
+    required TResult orElse(),
+  }) {
+    if (inRect != null) {
+      return inRect(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Predicate_InRect value) inRect,
+    required TResult Function(Predicate_InCircle value) inCircle,
+    required TResult Function(Predicate_InPolygon value) inPolygon,
+    required TResult Function(Predicate_Attribute value) attribute,
+    required TResult Function(Predicate_And value) and,
+    required TResult Function(Predicate_Or value) or,
+    required TResult Function(Predicate_Not value) not,
+  }) {
+    return inRect(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Predicate_InRect value)? inRect,
+    TResult? Function(Predicate_InCircle value)? inCircle,
+    TResult? Function(Predicate_InPolygon value)? inPolygon,
+    TResult? Function(Predicate_Attribute value)? attribute,
+    TResult? Function(Predicate_And value)? and,
+    TResult? Function(Predicate_Or value)? or,
+    TResult? Function(Predicate_Not value)? not,
+  }) {
+    return inRect?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Predicate_InRect value)? inRect,
+    TResult Function(Predicate_InCircle value)? inCircle,
+    TResult Function(Predicate_InPolygon value)? inPolygon,
+    TResult Function(Predicate_Attribute value)? attribute,
+    TResult Function(Predicate_And value)? and,
+    TResult Function(Predicate_Or value)? or,
+    TResult Function(Predicate_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (inRect != null) {
+      return inRect(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Predicate_InRect implements Predicate {
+  const factory Predicate_InRect(final ComputeRect field0) =
+      _$Predicate_InRectImpl;
+
+  ComputeRect get field0;
+  @JsonKey(ignore: true)
+  _$$Predicate_InRectImplCopyWith<_$Predicate_InRectImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$Predicate_InCircleImplCopyWith<$Res> {
+  factory _$$Predicate_InCircleImplCopyWith(
+          _$Predicate_InCircleImpl value,
+          $Res Function(_$Predicate_InCircleImpl) then) =
+      __$$Predicate_InCircleImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({F32Array2 center, double radius});
+}
+
+/// @nodoc
+class __$$Predicate_InCircleImplCopyWithImpl<$Res>
+    extends _$PredicateCopyWithImpl<$Res, _$Predicate_InCircleImpl>
+    implements _$$Predicate_InCircleImplCopyWith<$Res> {
+  __$$Predicate_InCircleImplCopyWithImpl(_$Predicate_InCircleImpl _value,
+      $Res Function(_$Predicate_InCircleImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? center = null,
+    Object? radius = null,
+  }) {
+    return _then(_$Predicate_InCircleImpl(
+      center: null == center
+          ? _value.center
+          : center // ignore: cast_nullable_to_non_nullable
+              as F32Array2,
+      radius: null == radius
+          ? _value.radius
+          : radius // ignore: cast_nullable_to_non_nullable
+              as double,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$Predicate_InCircleImpl implements Predicate_InCircle {
+  const _$Predicate_InCircleImpl({required this.center, required this.radius});
+
+  @override
+  final F32Array2 center;
+  @override
+  final double radius;
+
+  @override
+  String toString() {
+    return 'Predicate.inCircle(center: $center, radius: $radius)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Predicate_InCircleImpl &&
+            (identical(other.center, center) || other.center == center) &&
+            (identical(other.radius, radius) || other.radius == radius));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, center, radius);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Predicate_InCircleImplCopyWith<_$Predicate_InCircleImpl> get copyWith =>
+      __$$Predicate_InCircleImplCopyWithImpl<_$Predicate_InCircleImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ComputeRect field0) inRect,
+    required TResult Function(F32Array2 center, double radius) inCircle,
+    required TResult Function(List<ComputePoint> field0) inPolygon,
+    required TResult Function(AttributeFilter field0) attribute,
+    required TResult Function(List<Predicate> field0) and,
+    required TResult Function(List<Predicate> field0) or,
+    required TResult Function(Predicate field0) not,
+  }) {
+    return inCircle(center, radius);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ComputeRect field0)? inRect,
+    TResult? Function(F32Array2 center, double radius)? inCircle,
+    TResult? Function(List<ComputePoint> field0)? inPolygon,
+    TResult? Function(AttributeFilter field0)? attribute,
+    TResult? Function(List<Predicate> field0)? and,
+    TResult? Function(List<Predicate> field0)? or,
+    TResult? Function(Predicate field0)? not,
+  }) {
+    return inCircle?.call(center, radius);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ComputeRect field0)? inRect,
+    TResult Function(F32Array2 center, double radius)? inCircle,
+    TResult Function(List<ComputePoint> field0)? inPolygon,
+    TResult Function(AttributeFilter field0)? attribute,
+    TResult Function(List<Predicate> field0)? and,
+    TResult Function(List<Predicate> field0)? or,
+    TResult Function(Predicate field0)? not,
+    required TResult orElse(),
+  }) {
+    if (inCircle != null) {
+      return inCircle(center, radius);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Predicate_InRect value) inRect,
+    required TResult Function(Predicate_InCircle value) inCircle,
+    required TResult Function(Predicate_InPolygon value) inPolygon,
+    required TResult Function(Predicate_Attribute value) attribute,
+    required TResult Function(Predicate_And value) and,
+    required TResult Function(Predicate_Or value) or,
+    required TResult Function(Predicate_Not value) not,
+  }) {
+    return inCircle(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Predicate_InRect value)? inRect,
+    TResult? Function(Predicate_InCircle value)? inCircle,
+    TResult? Function(Predicate_InPolygon value)? inPolygon,
+    TResult? Function(Predicate_Attribute value)? attribute,
+    TResult? Function(Predicate_And value)? and,
+    TResult? Function(Predicate_Or value)? or,
+    TResult? Function(Predicate_Not value)? not,
+  }) {
+    return inCircle?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Predicate_InRect value)? inRect,
+    TResult Function(Predicate_InCircle value)? inCircle,
+    TResult Function(Predicate_InPolygon value)? inPolygon,
+    TResult Function(Predicate_Attribute value)? attribute,
+    TResult Function(Predicate_And value)? and,
+    TResult Function(Predicate_Or value)? or,
+    TResult Function(Predicate_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (inCircle != null) {
+      return inCircle(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Predicate_InCircle implements Predicate {
+  const factory Predicate_InCircle(
+      {required final F32Array2 center,
+      required final double radius}) = _$Predicate_InCircleImpl;
+
+  F32Array2 get center;
+  double get radius;
+  @JsonKey(ignore: true)
+  _$$Predicate_InCircleImplCopyWith<_$Predicate_InCircleImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$Predicate_InPolygonImplCopyWith<$Res> {
+  factory _$$Predicate_InPolygonImplCopyWith(
+          _$Predicate_InPolygonImpl value,
+          $Res Function(_$Predicate_InPolygonImpl) then) =
+      __$$Predicate_InPolygonImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<ComputePoint> field0});
+}
+
+/// @nodoc
+class __$$Predicate_InPolygonImplCopyWithImpl<$Res>
+    extends _$PredicateCopyWithImpl<$Res, _$Predicate_InPolygonImpl>
+    implements _$$Predicate_InPolygonImplCopyWith<$Res> {
+  __$$Predicate_InPolygonImplCopyWithImpl(_$Predicate_InPolygonImpl _value,
+      $Res Function(_$Predicate_InPolygonImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$Predicate_InPolygonImpl(
+      null == field0
+          ? _value._field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as List<ComputePoint>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$Predicate_InPolygonImpl implements Predicate_InPolygon {
+  const _$Predicate_InPolygonImpl(final List<ComputePoint> field0)
+      : _field0 = field0;
+
+  final List<ComputePoint> _field0;
+  @override
+  List<ComputePoint> get field0 {
+    if (_field0 is EqualUnmodifiableListView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_field0);
+  }
+
+  @override
+  String toString() {
+    return 'Predicate.inPolygon(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Predicate_InPolygonImpl &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Predicate_InPolygonImplCopyWith<_$Predicate_InPolygonImpl> get copyWith =>
+      __$$Predicate_InPolygonImplCopyWithImpl<_$Predicate_InPolygonImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ComputeRect field0) inRect,
+    required TResult Function(F32Array2 center, double radius) inCircle,
+    required TResult Function(List<ComputePoint> field0) inPolygon,
+    required TResult Function(AttributeFilter field0) attribute,
+    required TResult Function(List<Predicate> field0) and,
+    required TResult Function(List<Predicate> field0) or,
+    required TResult Function(Predicate field0) not,
+  }) {
+    return inPolygon(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ComputeRect field0)? inRect,
+    TResult? Function(F32Array2 center, double radius)? inCircle,
+    TResult? Function(List<ComputePoint> field0)? inPolygon,
+    TResult? Function(AttributeFilter field0)? attribute,
+    TResult? Function(List<Predicate> field0)? and,
+    TResult? Function(List<Predicate> field0)? or,
+    TResult? Function(Predicate field0)? not,
+  }) {
+    return inPolygon?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ComputeRect field0)? inRect,
+    TResult Function(F32Array2 center, double radius)? inCircle,
+    TResult Function(List<ComputePoint> field0)? inPolygon,
+    TResult Function(AttributeFilter field0)? attribute,
+    TResult Function(List<Predicate> field0)? and,
+    TResult Function(List<Predicate> field0)? or,
+    TResult Function(Predicate field0)? not,
+    required TResult orElse(),
+  }) {
+    if (inPolygon != null) {
+      return inPolygon(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Predicate_InRect value) inRect,
+    required TResult Function(Predicate_InCircle value) inCircle,
+    required TResult Function(Predicate_InPolygon value) inPolygon,
+    required TResult Function(Predicate_Attribute value) attribute,
+    required TResult Function(Predicate_And value) and,
+    required TResult Function(Predicate_Or value) or,
+    required TResult Function(Predicate_Not value) not,
+  }) {
+    return inPolygon(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Predicate_InRect value)? inRect,
+    TResult? Function(Predicate_InCircle value)? inCircle,
+    TResult? Function(Predicate_InPolygon value)? inPolygon,
+    TResult? Function(Predicate_Attribute value)? attribute,
+    TResult? Function(Predicate_And value)? and,
+    TResult? Function(Predicate_Or value)? or,
+    TResult? Function(Predicate_Not value)? not,
+  }) {
+    return inPolygon?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Predicate_InRect value)? inRect,
+    TResult Function(Predicate_InCircle value)? inCircle,
+    TResult Function(Predicate_InPolygon value)? inPolygon,
+    TResult Function(Predicate_Attribute value)? attribute,
+    TResult Function(Predicate_And value)? and,
+    TResult Function(Predicate_Or value)? or,
+    TResult Function(Predicate_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (inPolygon != null) {
+      return inPolygon(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Predicate_InPolygon implements Predicate {
+  const factory Predicate_InPolygon(final List<ComputePoint> field0) =
+      _$Predicate_InPolygonImpl;
+
+  List<ComputePoint> get field0;
+  @JsonKey(ignore: true)
+  _$$Predicate_InPolygonImplCopyWith<_$Predicate_InPolygonImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$Predicate_AttributeImplCopyWith<$Res> {
+  factory _$$Predicate_AttributeImplCopyWith(
+          _$Predicate_AttributeImpl value,
+          $Res Function(_$Predicate_AttributeImpl) then) =
+      __$$Predicate_AttributeImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({AttributeFilter field0});
+}
+
+/// @nodoc
+class __$$Predicate_AttributeImplCopyWithImpl<$Res>
+    extends _$PredicateCopyWithImpl<$Res, _$Predicate_AttributeImpl>
+    implements _$$Predicate_AttributeImplCopyWith<$Res> {
+  __$$Predicate_AttributeImplCopyWithImpl(_$Predicate_AttributeImpl _value,
+      $Res Function(_$Predicate_AttributeImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$Predicate_AttributeImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as AttributeFilter,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$Predicate_AttributeImpl implements Predicate_Attribute {
+  const _$Predicate_AttributeImpl(this.field0);
+
+  @override
+  final AttributeFilter field0;
+
+  @override
+  String toString() {
+    return 'Predicate.attribute(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Predicate_AttributeImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Predicate_AttributeImplCopyWith<_$Predicate_AttributeImpl> get copyWith =>
+      __$$Predicate_AttributeImplCopyWithImpl<_$Predicate_AttributeImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ComputeRect field0) inRect,
+    required TResult Function(F32Array2 center, double radius) inCircle,
+    required TResult Function(List<ComputePoint> field0) inPolygon,
+    required TResult Function(AttributeFilter field0) attribute,
+    required TResult Function(List<Predicate> field0) and,
+    required TResult Function(List<Predicate> field0) or,
+    required TResult Function(Predicate field0) not,
+  }) {
+    return attribute(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ComputeRect field0)? inRect,
+    TResult? Function(F32Array2 center, double radius)? inCircle,
+    TResult? Function(List<ComputePoint> field0)? inPolygon,
+    TResult? Function(AttributeFilter field0)? attribute,
+    TResult? Function(List<Predicate> field0)? and,
+    TResult? Function(List<Predicate> field0)? or,
+    TResult? Function(Predicate field0)? not,
+  }) {
+    return attribute?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ComputeRect field0)? inRect,
+    TResult Function(F32Array2 center, double radius)? inCircle,
+    TResult Function(List<ComputePoint> field0)? inPolygon,
+    TResult Function(AttributeFilter field0)? attribute,
+    TResult Function(List<Predicate> field0)? and,
+    TResult Function(List<Predicate> field0)? or,
+    TResult Function(Predicate field0)? not,
+    required TResult orElse(),
+  }) {
+    if (attribute != null) {
+      return attribute(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Predicate_InRect value) inRect,
+    required TResult Function(Predicate_InCircle value) inCircle,
+    required TResult Function(Predicate_InPolygon value) inPolygon,
+    required TResult Function(Predicate_Attribute value) attribute,
+    required TResult Function(Predicate_And value) and,
+    required TResult Function(Predicate_Or value) or,
+    required TResult Function(Predicate_Not value) not,
+  }) {
+    return attribute(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Predicate_InRect value)? inRect,
+    TResult? Function(Predicate_InCircle value)? inCircle,
+    TResult? Function(Predicate_InPolygon value)? inPolygon,
+    TResult? Function(Predicate_Attribute value)? attribute,
+    TResult? Function(Predicate_And value)? and,
+    TResult? Function(Predicate_Or value)? or,
+    TResult? Function(Predicate_Not value)? not,
+  }) {
+    return attribute?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Predicate_InRect value)? inRect,
+    TResult Function(Predicate_InCircle value)? inCircle,
+    TResult Function(Predicate_InPolygon value)? inPolygon,
+    TResult Function(Predicate_Attribute value)? attribute,
+    TResult Function(Predicate_And value)? and,
+    TResult Function(Predicate_Or value)? or,
+    TResult Function(Predicate_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (attribute != null) {
+      return attribute(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Predicate_Attribute implements Predicate {
+  const factory Predicate_Attribute(final AttributeFilter field0) =
+      _$Predicate_AttributeImpl;
+
+  AttributeFilter get field0;
+  @JsonKey(ignore: true)
+  _$$Predicate_AttributeImplCopyWith<_$Predicate_AttributeImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$Predicate_AndImplCopyWith<$Res> {
+  factory _$$Predicate_AndImplCopyWith(
+          _$Predicate_AndImpl value, $Res Function(_$Predicate_AndImpl) then) =
+      __$$Predicate_AndImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<Predicate> field0});
+}
+
+/// @nodoc
+class __$$Predicate_AndImplCopyWithImpl<$Res>
+    extends _$PredicateCopyWithImpl<$Res, _$Predicate_AndImpl>
+    implements _$$Predicate_AndImplCopyWith<$Res> {
+  __$$Predicate_AndImplCopyWithImpl(_$Predicate_AndImpl _value,
+      $Res Function(_$Predicate_AndImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$Predicate_AndImpl(
+      null == field0
+          ? _value._field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as List<Predicate>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$Predicate_AndImpl implements Predicate_And {
+  const _$Predicate_AndImpl(final List<Predicate> field0)
+      : _field0 = field0;
+
+  final List<Predicate> _field0;
+  @override
+  List<Predicate> get field0 {
+    if (_field0 is EqualUnmodifiableListView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_field0);
+  }
+
+  @override
+  String toString() {
+    return 'Predicate.and(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Predicate_AndImpl &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Predicate_AndImplCopyWith<_$Predicate_AndImpl> get copyWith =>
+      __$$Predicate_AndImplCopyWithImpl<_$Predicate_AndImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ComputeRect field0) inRect,
+    required TResult Function(F32Array2 center, double radius) inCircle,
+    required TResult Function(List<ComputePoint> field0) inPolygon,
+    required TResult Function(AttributeFilter field0) attribute,
+    required TResult Function(List<Predicate> field0) and,
+    required TResult Function(List<Predicate> field0) or,
+    required TResult Function(Predicate field0) not,
+  }) {
+    return and(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ComputeRect field0)? inRect,
+    TResult? Function(F32Array2 center, double radius)? inCircle,
+    TResult? Function(List<ComputePoint> field0)? inPolygon,
+    TResult? Function(AttributeFilter field0)? attribute,
+    TResult? Function(List<Predicate> field0)? and,
+    TResult? Function(List<Predicate> field0)? or,
+    TResult? Function(Predicate field0)? not,
+  }) {
+    return and?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ComputeRect field0)? inRect,
+    TResult Function(F32Array2 center, double radius)? inCircle,
+    TResult Function(List<ComputePoint> field0)? inPolygon,
+    TResult Function(AttributeFilter field0)? attribute,
+    TResult Function(List<Predicate> field0)? and,
+    TResult Function(List<Predicate> field0)? or,
+    TResult Function(Predicate field0)? not,
+    required TResult orElse(),
+  }) {
+    if (and != null) {
+      return and(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Predicate_InRect value) inRect,
+    required TResult Function(Predicate_InCircle value) inCircle,
+    required TResult Function(Predicate_InPolygon value) inPolygon,
+    required TResult Function(Predicate_Attribute value) attribute,
+    required TResult Function(Predicate_And value) and,
+    required TResult Function(Predicate_Or value) or,
+    required TResult Function(Predicate_Not value) not,
+  }) {
+    return and(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Predicate_InRect value)? inRect,
+    TResult? Function(Predicate_InCircle value)? inCircle,
+    TResult? Function(Predicate_InPolygon value)? inPolygon,
+    TResult? Function(Predicate_Attribute value)? attribute,
+    TResult? Function(Predicate_And value)? and,
+    TResult? Function(Predicate_Or value)? or,
+    TResult? Function(Predicate_Not value)? not,
+  }) {
+    return and?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Predicate_InRect value)? inRect,
+    TResult Function(Predicate_InCircle value)? inCircle,
+    TResult Function(Predicate_InPolygon value)? inPolygon,
+    TResult Function(Predicate_Attribute value)? attribute,
+    TResult Function(Predicate_And value)? and,
+    TResult Function(Predicate_Or value)? or,
+    TResult Function(Predicate_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (and != null) {
+      return and(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Predicate_And implements Predicate {
+  const factory Predicate_And(final List<Predicate> field0) =
+      _$Predicate_AndImpl;
+
+  List<Predicate> get field0;
+  @JsonKey(ignore: true)
+  _$$Predicate_AndImplCopyWith<_$Predicate_AndImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$Predicate_OrImplCopyWith<$Res> {
+  factory _$$Predicate_OrImplCopyWith(
+          _$Predicate_OrImpl value, $Res Function(_$Predicate_OrImpl) then) =
+      __$$Predicate_OrImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({List<Predicate> field0});
+}
+
+/// @nodoc
+class __$$Predicate_OrImplCopyWithImpl<$Res>
+    extends _$PredicateCopyWithImpl<$Res, _$Predicate_OrImpl>
+    implements _$$Predicate_OrImplCopyWith<$Res> {
+  __$$Predicate_OrImplCopyWithImpl(_$Predicate_OrImpl _value,
+      $Res Function(_$Predicate_OrImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$Predicate_OrImpl(
+      null == field0
+          ? _value._field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as List<Predicate>,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$Predicate_OrImpl implements Predicate_Or {
+  const _$Predicate_OrImpl(final List<Predicate> field0)
+      : _field0 = field0;
+
+  final List<Predicate> _field0;
+  @override
+  List<Predicate> get field0 {
+    if (_field0 is EqualUnmodifiableListView) return _field0;
+    // ignore: implicit_dynamic_type
+    return EqualUnmodifiableListView(_field0);
+  }
+
+  @override
+  String toString() {
+    return 'Predicate.or(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Predicate_OrImpl &&
+            const DeepCollectionEquality().equals(other._field0, _field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, const DeepCollectionEquality().hash(_field0));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Predicate_OrImplCopyWith<_$Predicate_OrImpl> get copyWith =>
+      __$$Predicate_OrImplCopyWithImpl<_$Predicate_OrImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ComputeRect field0) inRect,
+    required TResult Function(F32Array2 center, double radius) inCircle,
+    required TResult Function(List<ComputePoint> field0) inPolygon,
+    required TResult Function(AttributeFilter field0) attribute,
+    required TResult Function(List<Predicate> field0) and,
+    required TResult Function(List<Predicate> field0) or,
+    required TResult Function(Predicate field0) not,
+  }) {
+    return or(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ComputeRect field0)? inRect,
+    TResult? Function(F32Array2 center, double radius)? inCircle,
+    TResult? Function(List<ComputePoint> field0)? inPolygon,
+    TResult? Function(AttributeFilter field0)? attribute,
+    TResult? Function(List<Predicate> field0)? and,
+    TResult? Function(List<Predicate> field0)? or,
+    TResult? Function(Predicate field0)? not,
+  }) {
+    return or?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ComputeRect field0)? inRect,
+    TResult Function(F32Array2 center, double radius)? inCircle,
+    TResult Function(List<ComputePoint> field0)? inPolygon,
+    TResult Function(AttributeFilter field0)? attribute,
+    TResult Function(List<Predicate> field0)? and,
+    TResult Function(List<Predicate> field0)? or,
+    TResult Function(Predicate field0)? not,
+    required TResult orElse(),
+  }) {
+    if (or != null) {
+      return or(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Predicate_InRect value) inRect,
+    required TResult Function(Predicate_InCircle value) inCircle,
+    required TResult Function(Predicate_InPolygon value) inPolygon,
+    required TResult Function(Predicate_Attribute value) attribute,
+    required TResult Function(Predicate_And value) and,
+    required TResult Function(Predicate_Or value) or,
+    required TResult Function(Predicate_Not value) not,
+  }) {
+    return or(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Predicate_InRect value)? inRect,
+    TResult? Function(Predicate_InCircle value)? inCircle,
+    TResult? Function(Predicate_InPolygon value)? inPolygon,
+    TResult? Function(Predicate_Attribute value)? attribute,
+    TResult? Function(Predicate_And value)? and,
+    TResult? Function(Predicate_Or value)? or,
+    TResult? Function(Predicate_Not value)? not,
+  }) {
+    return or?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Predicate_InRect value)? inRect,
+    TResult Function(Predicate_InCircle value)? inCircle,
+    TResult Function(Predicate_InPolygon value)? inPolygon,
+    TResult Function(Predicate_Attribute value)? attribute,
+    TResult Function(Predicate_And value)? and,
+    TResult Function(Predicate_Or value)? or,
+    TResult Function(Predicate_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (or != null) {
+      return or(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Predicate_Or implements Predicate {
+  const factory Predicate_Or(final List<Predicate> field0) = _$Predicate_OrImpl;
+
+  List<Predicate> get field0;
+  @JsonKey(ignore: true)
+  _$$Predicate_OrImplCopyWith<_$Predicate_OrImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$Predicate_NotImplCopyWith<$Res> {
+  factory _$$Predicate_NotImplCopyWith(
+          _$Predicate_NotImpl value, $Res Function(_$Predicate_NotImpl) then) =
+      __$$Predicate_NotImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Predicate field0});
+}
+
+/// @nodoc
+class __$$Predicate_NotImplCopyWithImpl<$Res>
+    extends _$PredicateCopyWithImpl<$Res, _$Predicate_NotImpl>
+    implements _$$Predicate_NotImplCopyWith<$Res> {
+  __$$Predicate_NotImplCopyWithImpl(_$Predicate_NotImpl _value,
+      $Res Function(_$Predicate_NotImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$Predicate_NotImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as Predicate,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$Predicate_NotImpl implements Predicate_Not {
+  const _$Predicate_NotImpl(this.field0);
+
+  @override
+  final Predicate field0;
+
+  @override
+  String toString() {
+    return 'Predicate.not(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Predicate_NotImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Predicate_NotImplCopyWith<_$Predicate_NotImpl> get copyWith =>
+      __$$Predicate_NotImplCopyWithImpl<_$Predicate_NotImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(ComputeRect field0) inRect,
+    required TResult Function(F32Array2 center, double radius) inCircle,
+    required TResult Function(List<ComputePoint> field0) inPolygon,
+    required TResult Function(AttributeFilter field0) attribute,
+    required TResult Function(List<Predicate> field0) and,
+    required TResult Function(List<Predicate> field0) or,
+    required TResult Function(Predicate field0) not,
+  }) {
+    return not(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(ComputeRect field0)? inRect,
+    TResult? Function(F32Array2 center, double radius)? inCircle,
+    TResult? Function(List<ComputePoint> field0)? inPolygon,
+    TResult? Function(AttributeFilter field0)? attribute,
+    TResult? Function(List<Predicate> field0)? and,
+    TResult? Function(List<Predicate> field0)? or,
+    TResult? Function(Predicate field0)? not,
+  }) {
+    return not?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(ComputeRect field0)? inRect,
+    TResult Function(F32Array2 center, double radius)? inCircle,
+    TResult Function(List<ComputePoint> field0)? inPolygon,
+    TResult Function(AttributeFilter field0)? attribute,
+    TResult Function(List<Predicate> field0)? and,
+    TResult Function(List<Predicate> field0)? or,
+    TResult Function(Predicate field0)? not,
+    required TResult orElse(),
+  }) {
+    if (not != null) {
+      return not(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Predicate_InRect value) inRect,
+    required TResult Function(Predicate_InCircle value) inCircle,
+    required TResult Function(Predicate_InPolygon value) inPolygon,
+    required TResult Function(Predicate_Attribute value) attribute,
+    required TResult Function(Predicate_And value) and,
+    required TResult Function(Predicate_Or value) or,
+    required TResult Function(Predicate_Not value) not,
+  }) {
+    return not(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Predicate_InRect value)? inRect,
+    TResult? Function(Predicate_InCircle value)? inCircle,
+    TResult? Function(Predicate_InPolygon value)? inPolygon,
+    TResult? Function(Predicate_Attribute value)? attribute,
+    TResult? Function(Predicate_And value)? and,
+    TResult? Function(Predicate_Or value)? or,
+    TResult? Function(Predicate_Not value)? not,
+  }) {
+    return not?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Predicate_InRect value)? inRect,
+    TResult Function(Predicate_InCircle value)? inCircle,
+    TResult Function(Predicate_InPolygon value)? inPolygon,
+    TResult Function(Predicate_Attribute value)? attribute,
+    TResult Function(Predicate_And value)? and,
+    TResult Function(Predicate_Or value)? or,
+    TResult Function(Predicate_Not value)? not,
+    required TResult orElse(),
+  }) {
+    if (not != null) {
+      return not(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Predicate_Not implements Predicate {
+  const factory Predicate_Not(final Predicate field0) = _$Predicate_NotImpl;
+
+  Predicate get field0;
+  @JsonKey(ignore: true)
+  _$$Predicate_NotImplCopyWith<_$Predicate_NotImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
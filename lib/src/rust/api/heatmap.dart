@@ -0,0 +1,142 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Kernel density estimate of `point_set` over `viewport` at `width` by `height` pixels. Every point adds
+/// its weight, 1 without `weights`, spread by a kernel of `shape` with `bandwidth` in point units; points
+/// are binned to pixels first, so the result is exact up to half a pixel. Points outside the viewport
+/// still count near its edges. `None` if the point set does not exist, `weights` does not have one finite
+/// value per point, the grid or viewport is empty, or `bandwidth` is not positive. Also `None` when the
+/// kernel reaches more than 64 pixels (a third of that for `Gaussian`), when the grid takes more than
+/// 256 MiB, or when pixels times kernel size passes 2³⁰ and the GPU cannot take it; use a coarser grid for
+/// wide kernels.
+Future<DensityGrid?> densityGrid(
+        {required int pointSet,
+        required ComputeRect viewport,
+        required int width,
+        required int height,
+        required double bandwidth,
+        required KernelShape shape,
+        required Float32List? weights,
+        dynamic hint}) =>
+    RustLib.instance.api.densityGrid(
+        pointSet: pointSet,
+        viewport: viewport,
+        width: width,
+        height: height,
+        bandwidth: bandwidth,
+        shape: shape,
+        weights: weights,
+        hint: hint);
+
+/// `density_grid` drawn with `colormap`, scaled so `max` gets the top color, or the densest pixel without
+/// it. Useful to keep colors stable while panning.
+Future<HeatmapImage?> densityImage(
+        {required int pointSet,
+        required ComputeRect viewport,
+        required int width,
+        required int height,
+        required double bandwidth,
+        required KernelShape shape,
+        required Float32List? weights,
+        required Colormap colormap,
+        required double? max,
+        dynamic hint}) =>
+    RustLib.instance.api.densityImage(
+        pointSet: pointSet,
+        viewport: viewport,
+        width: width,
+        height: height,
+        bandwidth: bandwidth,
+        shape: shape,
+        weights: weights,
+        colormap: colormap,
+        max: max,
+        hint: hint);
+
+/// Colormaps for `density_image`, from low to high density.
+enum Colormap {
+  viridis,
+  magma,
+  inferno,
+  grayscale,
+}
+
+/// Density of a point set over a grid of pixels, in weight per unit area, row by row starting at the `min`
+/// corner of the viewport.
+class DensityGrid {
+  final int width;
+  final int height;
+  final Float32List values;
+  final double max;
+
+  const DensityGrid({
+    required this.width,
+    required this.height,
+    required this.values,
+    required this.max,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^ height.hashCode ^ values.hashCode ^ max.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DensityGrid &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          values == other.values &&
+          max == other.max;
+}
+
+/// A `DensityGrid` colored with a colormap, 4 RGBA bytes per pixel in the same order. Pixels without any
+/// density are transparent.
+class HeatmapImage {
+  final int width;
+  final int height;
+  final Uint8List pixels;
+  /// The density drawn with the top color of the colormap.
+  final double max;
+
+  const HeatmapImage({
+    required this.width,
+    required this.height,
+    required this.pixels,
+    required this.max,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^ height.hashCode ^ pixels.hashCode ^ max.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HeatmapImage &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          pixels == other.pixels &&
+          max == other.max;
+}
+
+/// Shape of the kernel spread around every point. All of them integrate to the weight of the point.
+enum KernelShape {
+  /// Bandwidth is the standard deviation, cut off at three of them.
+  gaussian,
+  /// `1 - (d / bandwidth)²` inside the bandwidth.
+  epanechnikov,
+  /// `(1 - (d / bandwidth)²)²` inside the bandwidth, smoother at the edge than Epanechnikov.
+  quartic,
+  /// Constant inside the bandwidth.
+  uniform,
+}
@@ -0,0 +1,68 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Bins the points of `point_set` into `bins_x` by `bins_y` equal bins over `extent`, counting them and,
+/// with `values` holding one value per point, summing and averaging those. Points on the `max` edge of
+/// the extent go into the last bins, points outside it are left out. On the GPU the sums are accurate to
+/// about 2⁻³⁰ of the bin count times the largest magnitude. `None` if the point set does not exist,
+/// `values` does not have one finite value per point, the grid or extent is empty, or the bins take more
+/// than 256 MiB.
+Future<Histogram2d?> histogram2d(
+        {required int pointSet,
+        required ComputeRect extent,
+        required int binsX,
+        required int binsY,
+        required Float32List? values,
+        dynamic hint}) =>
+    RustLib.instance.api.histogram2d(
+        pointSet: pointSet,
+        extent: extent,
+        binsX: binsX,
+        binsY: binsY,
+        values: values,
+        hint: hint);
+
+/// Points binned into a grid over an extent, row by row starting at the `min` corner of the extent.
+class Histogram2d {
+  final int binsX;
+  final int binsY;
+  final Uint32List counts;
+  /// Sum of the values of the points in every bin, empty without values.
+  final Float32List sums;
+  /// Mean of the values of the points in every bin, 0 for empty bins, empty without values.
+  final Float32List means;
+
+  const Histogram2d({
+    required this.binsX,
+    required this.binsY,
+    required this.counts,
+    required this.sums,
+    required this.means,
+  });
+
+  @override
+  int get hashCode =>
+      binsX.hashCode ^
+      binsY.hashCode ^
+      counts.hashCode ^
+      sums.hashCode ^
+      means.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Histogram2d &&
+          runtimeType == other.runtimeType &&
+          binsX == other.binsX &&
+          binsY == other.binsY &&
+          counts == other.counts &&
+          sums == other.sums &&
+          means == other.means;
+}
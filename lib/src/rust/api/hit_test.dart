@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Index of the point of `point_set` closest to `position`, if any lies within `tolerance` of it.
+/// Equally close points resolve to the lowest index. `None` as well if the point set does not exist or
+/// `tolerance` is negative or not finite.
+Future<int?> hitTest(
+        {required int pointSet,
+        required F32Array2 position,
+        required double tolerance,
+        dynamic hint}) =>
+    RustLib.instance.api.hitTest(
+        pointSet: pointSet,
+        position: position,
+        tolerance: tolerance,
+        hint: hint);
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Indices of the points on the convex hull of `point_set`, counter-clockwise with y pointing up (so
+/// clockwise on screen), starting from the point with the lowest x. Collinear points on hull edges are left
+/// out, coincident points resolve to the lowest index and points that are not finite are ignored. `None`
+/// if the point set does not exist.
+Future<Uint32List?> convexHull({required int pointSet, dynamic hint}) =>
+    RustLib.instance.api.convexHull(pointSet: pointSet, hint: hint);
+
+/// Like `convex_hull`, over only the points of `point_set` within `radius` of `center`.
+Future<Uint32List?> convexHullInCircle(
+        {required int pointSet,
+        required F32Array2 center,
+        required double radius,
+        dynamic hint}) =>
+    RustLib.instance.api.convexHullInCircle(
+        pointSet: pointSet, center: center, radius: radius, hint: hint);
+
+/// Like `convex_hull`, over only the points of `point_set` inside `polygon` (even-odd rule).
+Future<Uint32List?> convexHullInPolygon(
+        {required int pointSet,
+        required List<F32Array2> polygon,
+        dynamic hint}) =>
+    RustLib.instance.api.convexHullInPolygon(
+        pointSet: pointSet, polygon: polygon, hint: hint);
+
+/// Like `convex_hull`, over only the points of `point_set` inside `rect`.
+Future<Uint32List?> convexHullInRect(
+        {required int pointSet, required ComputeRect rect, dynamic hint}) =>
+    RustLib.instance.api.convexHullInRect(
+        pointSet: pointSet, rect: rect, hint: hint);
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Rasterizes the Voronoi regions of `point_set` over `bounds` into `width` by `height` pixels: which point
+/// is closest to each pixel, and how far it is. The GPU path uses jump flooding, which keeps one point per
+/// pixel and can mislabel a few pixels near region borders, so distances may be off by up to a pixel; the
+/// CPU path is exact. `None` if the point set does not exist, the size is zero or the maps take more than
+/// 256 MiB.
+Future<VoronoiRaster?> rasterizeVoronoi(
+        {required int pointSet,
+        required ComputeRect bounds,
+        required int width,
+        required int height,
+        dynamic hint}) =>
+    RustLib.instance.api.rasterizeVoronoi(
+        pointSet: pointSet,
+        bounds: bounds,
+        width: width,
+        height: height,
+        hint: hint);
+
+/// Voronoi regions of a point set rasterized over a rectangle. Both maps hold one little-endian 4 byte
+/// value per pixel, row by row starting at the `min` corner of the rectangle.
+class VoronoiRaster {
+  final int width;
+  final int height;
+  /// `u32` index of the point closest to the pixel center, `u32::MAX` when the set is empty.
+  final Uint8List labels;
+  /// `f32` distance from the pixel center to that point, in point coordinates, `f32::MAX` when the set is
+  /// empty.
+  final Uint8List distances;
+
+  const VoronoiRaster({
+    required this.width,
+    required this.height,
+    required this.labels,
+    required this.distances,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^ height.hashCode ^ labels.hashCode ^ distances.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VoronoiRaster &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          labels == other.labels &&
+          distances == other.distances;
+}
@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Partitions `point_set` into `k` clusters (fewer if the set has fewer finite points). Stops after `max_iterations`
+/// or once no centroid moves further than `tolerance` in an iteration. The same `seed` always picks the
+/// same initial centroids. `None` if the point set does not exist.
+Future<KMeans?> kmeans(
+        {required int pointSet,
+        required int k,
+        required int maxIterations,
+        required double tolerance,
+        required int seed,
+        dynamic hint}) =>
+    RustLib.instance.api.kmeans(
+        pointSet: pointSet,
+        k: k,
+        maxIterations: maxIterations,
+        tolerance: tolerance,
+        seed: seed,
+        hint: hint);
+
+/// Result of k-means clustering.
+class KMeans {
+  /// Index into `centroids` for every point. Points that are not finite are assigned to the first centroid
+  /// without counting towards it.
+  final Uint32List assignments;
+  final List<ComputePoint> centroids;
+  /// Lloyd iterations run before converging or reaching the limit.
+  final int iterations;
+
+  const KMeans({
+    required this.assignments,
+    required this.centroids,
+    required this.iterations,
+  });
+
+  @override
+  int get hashCode =>
+      assignments.hashCode ^ centroids.hashCode ^ iterations.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KMeans &&
+          runtimeType == other.runtimeType &&
+          assignments == other.assignments &&
+          centroids == other.centroids &&
+          iterations == other.iterations;
+}
@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// The `k` points of `point_set` nearest to each of `query_points`. `None` if the point set does not exist or
+/// a query point is not finite. Uses the set's spatial index when it has one.
+Future<Neighbours?> knn(
+        {required int pointSet,
+        required List<F32Array2> queryPoints,
+        required int k,
+        dynamic hint}) =>
+    RustLib.instance.api.knn(
+        pointSet: pointSet, queryPoints: queryPoints, k: k, hint: hint);
+
+/// Nearest neighbours of a batch of query points, nearest first. The neighbours of query `i` are
+/// `indices[i * k..(i + 1) * k]`, with their Euclidean distances at the same positions in `distances`.
+/// Equally distant points are ordered by index.
+class Neighbours {
+  /// The requested `k`, capped at the number of finite points in the set. Points that are not finite are
+  /// never neighbours.
+  final int k;
+  final Uint32List indices;
+  final Float32List distances;
+
+  const Neighbours({
+    required this.k,
+    required this.indices,
+    required this.distances,
+  });
+
+  @override
+  int get hashCode => k.hashCode ^ indices.hashCode ^ distances.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Neighbours &&
+          runtimeType == other.runtimeType &&
+          k == other.k &&
+          indices == other.indices &&
+          distances == other.distances;
+}
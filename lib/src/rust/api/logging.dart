@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Stops forwarding log events to Dart.
+void closeLogStream({dynamic hint}) =>
+    RustLib.instance.api.closeLogStream(hint: hint);
+
+/// Forwards log events at `max_level` and above to Dart. A new stream replaces the previous one.
+Stream<LogEntry> createLogStream({required LogLevel maxLevel, dynamic hint}) =>
+    RustLib.instance.api.createLogStream(maxLevel: maxLevel, hint: hint);
+
+/// A log event forwarded to Dart.
+class LogEntry {
+  final LogLevel level;
+  final String target;
+  final String message;
+  /// Event fields other than the message, as `key=value` pairs.
+  final String fields;
+  /// Enclosing spans from the outermost in, e.g. `compute_request{request_id=3 command=rect}:upload{bytes=8000}`.
+  final String spans;
+
+  const LogEntry({
+    required this.level,
+    required this.target,
+    required this.message,
+    required this.fields,
+    required this.spans,
+  });
+
+  @override
+  int get hashCode =>
+      level.hashCode ^
+      target.hashCode ^
+      message.hashCode ^
+      fields.hashCode ^
+      spans.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogEntry &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          target == other.target &&
+          message == other.message &&
+          fields == other.fields &&
+          spans == other.spans;
+}
+
+enum LogLevel {
+  trace,
+  debug,
+  info,
+  warn,
+  error,
+}
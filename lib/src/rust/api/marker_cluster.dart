@@ -0,0 +1,66 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Precomputes marker clusters of `point_set` for every zoom level from `min_zoom` to `max_zoom`.
+/// At zoom 0 points sharing a `radius` sized grid cell (in point coordinates) form one cluster, and each
+/// zoom level halves the cell size. The clusters are rebuilt automatically when the points are updated.
+/// Returns false if the point set does not exist, `radius` is not positive, `min_zoom` is above `max_zoom`
+/// or `max_zoom` is above 30.
+Future<bool> buildMarkerClusters(
+        {required int pointSet,
+        required double radius,
+        required int minZoom,
+        required int maxZoom,
+        dynamic hint}) =>
+    RustLib.instance.api.buildMarkerClusters(
+        pointSet: pointSet,
+        radius: radius,
+        minZoom: minZoom,
+        maxZoom: maxZoom,
+        hint: hint);
+
+/// Marker clusters of `point_set` at `zoom` whose position is inside `viewport`. Zooms below `min_zoom`
+/// use the `min_zoom` clusters, zooms above `max_zoom` return every point as a cluster of one.
+/// `None` if the point set does not exist or has no marker clusters.
+Future<List<MarkerCluster>?> getMarkerClusters(
+        {required int pointSet,
+        required ComputeRect viewport,
+        required int zoom,
+        dynamic hint}) =>
+    RustLib.instance.api.getMarkerClusters(
+        pointSet: pointSet, viewport: viewport, zoom: zoom, hint: hint);
+
+/// A group of nearby points shown as one marker at some zoom level.
+class MarkerCluster {
+  /// Mean position of the points in the cluster.
+  final F32Array2 position;
+  final int count;
+  /// Lowest index of the points in the cluster, to label or style the marker with.
+  final int representative;
+
+  const MarkerCluster({
+    required this.position,
+    required this.count,
+    required this.representative,
+  });
+
+  @override
+  int get hashCode =>
+      position.hashCode ^ count.hashCode ^ representative.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MarkerCluster &&
+          runtimeType == other.runtimeType &&
+          position == other.position &&
+          count == other.count &&
+          representative == other.representative;
+}
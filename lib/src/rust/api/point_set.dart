@@ -0,0 +1,122 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Uploads `points` and keeps them on the device. Returns the id used by queries on this set.
+Future<int> createPointSet({required List<F32Array2> points, dynamic hint}) =>
+    RustLib.instance.api.createPointSet(points: points, hint: hint);
+
+/// Same as `create_point_set`, with the coordinates of the points one after another as `x0, y0, x1, y1, ...`,
+/// so a `Float32List` is uploaded without wrapping every point. `None` if `points` has an odd length.
+Future<int?> createPointSetFlat({required Float32List points, dynamic hint}) =>
+    RustLib.instance.api.createPointSetFlat(points: points, hint: hint);
+
+/// Frees the device memory of a resident set. Returns false if the set does not exist.
+Future<bool> disposePointSet({required int pointSet, dynamic hint}) =>
+    RustLib.instance.api.disposePointSet(pointSet: pointSet, hint: hint);
+
+/// Timing of the last build or refit of the index of a resident set, including the automatic ones done by
+/// `update_point_set`. `None` if the set does not exist or never had an index.
+Future<IndexStats?> getPointSetIndexStats(
+        {required int pointSet, dynamic hint}) =>
+    RustLib.instance.api.getPointSetIndexStats(pointSet: pointSet, hint: hint);
+
+/// Adds `points` to a resident set without changing the ids of the points already in it. Slots left by
+/// `remove_points` are filled first, lowest id first, then the set grows. Returns the ids of the new points
+/// in order, `None` if the set does not exist. Attribute channels are kept, with zero for the new points.
+Future<Uint32List?> insertPoints(
+        {required int pointSet,
+        required List<F32Array2> points,
+        dynamic hint}) =>
+    RustLib.instance.api.insertPoints(
+        pointSet: pointSet, points: points, hint: hint);
+
+/// Moves the point at `indices[k]` of a resident set to `points[k]`, leaving the others and the attribute
+/// channels as they are. Only the moved points are written to the device, and an R-tree index inserts and
+/// removes just those. Returns false if the set does not exist, the lists differ in length or an index is
+/// out of range or was removed by `remove_points`.
+Future<bool> movePoints(
+        {required int pointSet,
+        required Uint32List indices,
+        required List<F32Array2> points,
+        dynamic hint}) =>
+    RustLib.instance.api.movePoints(
+        pointSet: pointSet, indices: indices, points: points, hint: hint);
+
+/// Removes the points with the given ids from a resident set. The other points keep their ids, and the
+/// removed ones leave vacant slots that no query returns until `insert_points` fills them. Returns false if
+/// the set does not exist, or an id is out of range, repeated or already removed.
+Future<bool> removePoints(
+        {required int pointSet, required Uint32List ids, dynamic hint}) =>
+    RustLib.instance.api.removePoints(pointSet: pointSet, ids: ids, hint: hint);
+
+/// Builds (or drops, with `SpatialIndexKind::None`) the spatial index of a resident set.
+/// The index is rebuilt or refitted automatically when the points are updated. On adapters without compute
+/// shader support `Grid` and `Bvh` fall back to an `RTree`, see the `kind` of the returned stats.
+Future<IndexStats?> setPointSetIndex(
+        {required int pointSet,
+        required SpatialIndexKind kind,
+        dynamic hint}) =>
+    RustLib.instance.api.setPointSetIndex(
+        pointSet: pointSet, kind: kind, hint: hint);
+
+/// Replaces the points of a resident set. Its attribute channels are kept if the number of points stays the
+/// same. Returns false if the set does not exist. When only some points move, `move_points` avoids sending
+/// and comparing the whole set, and `insert_points` and `remove_points` keep the ids of the other points.
+Future<bool> updatePointSet(
+        {required int pointSet,
+        required List<F32Array2> points,
+        dynamic hint}) =>
+    RustLib.instance.api.updatePointSet(
+        pointSet: pointSet, points: points, hint: hint);
+
+class IndexStats {
+  final SpatialIndexKind kind;
+  final double buildMicros;
+  /// Cells for a grid, nodes for a BVH, points for an R-tree.
+  final int size;
+  /// Whether the last update adjusted the existing index (BVH refit, R-tree insert and remove)
+  /// instead of rebuilding it.
+  final bool refitted;
+
+  const IndexStats({
+    required this.kind,
+    required this.buildMicros,
+    required this.size,
+    required this.refitted,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ buildMicros.hashCode ^ size.hashCode ^ refitted.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IndexStats &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          buildMicros == other.buildMicros &&
+          size == other.size &&
+          refitted == other.refitted;
+}
+
+/// Spatial index kept alongside a resident point set to speed up queries.
+enum SpatialIndexKind {
+  /// Every query scans all points.
+  none,
+  /// Uniform grid built on the GPU. Best for evenly spread points.
+  grid,
+  /// Linear BVH over Morton ordered points, built on the GPU. Best for clustered points.
+  /// Refitted instead of rebuilt when points move without changing count.
+  bvh,
+  /// R-tree kept in CPU memory, updated by inserting and removing the points that changed.
+  /// Queries on it always run on the CPU, which suits small sets and the CPU backend.
+  rTree,
+}
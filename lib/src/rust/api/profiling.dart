@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+bool isProfilingEnabled({dynamic hint}) =>
+    RustLib.instance.api.isProfilingEnabled(hint: hint);
+
+/// Turns per request timing on or off. Profiling adds a timestamp query and extra synchronisation, keep it to debug builds.
+void setProfilingEnabled({required bool enabled, dynamic hint}) =>
+    RustLib.instance.api.setProfilingEnabled(enabled: enabled, hint: hint);
+
+/// Returns the metrics recorded since the last call, oldest first. At most the last 256 requests are kept.
+List<ComputeMetrics> takeComputeMetrics({dynamic hint}) =>
+    RustLib.instance.api.takeComputeMetrics(hint: hint);
+
+/// Timings of a single request handled by the compute thread. Durations are in microseconds.
+class ComputeMetrics {
+  final int requestId;
+  final String kernel;
+  final int pointCount;
+  final bool onGpu;
+  /// Creating and filling the device buffers.
+  final double uploadMicros;
+  /// Submission until the device is idle, as seen from the CPU.
+  final double dispatchMicros;
+  /// Time spent inside the compute pass, from `TIMESTAMP_QUERY`. `None` when the adapter lacks the feature.
+  final double? gpuDispatchMicros;
+  /// Mapping the staging buffers and copying the results out.
+  final double readbackMicros;
+  final double totalMicros;
+
+  const ComputeMetrics({
+    required this.requestId,
+    required this.kernel,
+    required this.pointCount,
+    required this.onGpu,
+    required this.uploadMicros,
+    required this.dispatchMicros,
+    required this.gpuDispatchMicros,
+    required this.readbackMicros,
+    required this.totalMicros,
+  });
+
+  @override
+  int get hashCode =>
+      requestId.hashCode ^
+      kernel.hashCode ^
+      pointCount.hashCode ^
+      onGpu.hashCode ^
+      uploadMicros.hashCode ^
+      dispatchMicros.hashCode ^
+      gpuDispatchMicros.hashCode ^
+      readbackMicros.hashCode ^
+      totalMicros.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ComputeMetrics &&
+          runtimeType == other.runtimeType &&
+          requestId == other.requestId &&
+          kernel == other.kernel &&
+          pointCount == other.pointCount &&
+          onGpu == other.onGpu &&
+          uploadMicros == other.uploadMicros &&
+          dispatchMicros == other.dispatchMicros &&
+          gpuDispatchMicros == other.gpuDispatchMicros &&
+          readbackMicros == other.readbackMicros &&
+          totalMicros == other.totalMicros;
+}
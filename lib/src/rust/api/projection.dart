@@ -0,0 +1,100 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+import 'transform.dart';
+
+/// Maps the world space points of `point_set` through `camera` and keeps those inside its viewport, all on
+/// the device in a single request: the positions and indices to draw this frame. `None` if the point set
+/// does not exist, the camera is not finite or its margin is negative.
+Future<ScreenPoints?> projectPointSet(
+        {required int pointSet, required Camera camera, dynamic hint}) =>
+    RustLib.instance.api.projectPointSet(
+        pointSet: pointSet, camera: camera, hint: hint);
+
+/// Same as `project_point_set`, with the positions flattened for `Canvas.drawRawPoints`.
+Future<FlatScreenPoints?> projectPointSetFlat(
+        {required int pointSet, required Camera camera, dynamic hint}) =>
+    RustLib.instance.api.projectPointSetFlat(
+        pointSet: pointSet, camera: camera, hint: hint);
+
+/// How world space points end up on the screen.
+class Camera {
+  /// World to screen transform, for example the pan and zoom of the view.
+  final AffineTransform view;
+  /// Visible part of the screen, in the space `view` maps to.
+  final ComputeRect viewport;
+  /// Distance around the viewport within which points are still kept, usually the radius they are
+  /// drawn with, so they do not pop in and out at the edges.
+  final double margin;
+
+  const Camera({
+    required this.view,
+    required this.viewport,
+    required this.margin,
+  });
+
+  @override
+  int get hashCode => view.hashCode ^ viewport.hashCode ^ margin.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Camera &&
+          runtimeType == other.runtimeType &&
+          view == other.view &&
+          viewport == other.viewport &&
+          margin == other.margin;
+}
+
+/// `ScreenPoints` with the positions one after another as `x0, y0, x1, y1, ...`. Both lists reach Dart as
+/// typed data without wrapping every point, so `positions` goes straight to `Canvas.drawRawPoints`.
+class FlatScreenPoints {
+  final Float32List positions;
+  final Uint32List indices;
+
+  const FlatScreenPoints({
+    required this.positions,
+    required this.indices,
+  });
+
+  @override
+  int get hashCode => positions.hashCode ^ indices.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FlatScreenPoints &&
+          runtimeType == other.runtimeType &&
+          positions == other.positions &&
+          indices == other.indices;
+}
+
+/// Visible points of a set, in index order.
+class ScreenPoints {
+  /// Screen position of every visible point.
+  final List<ComputePoint> positions;
+  /// Index in the point set of every visible point.
+  final Uint32List indices;
+
+  const ScreenPoints({
+    required this.positions,
+    required this.indices,
+  });
+
+  @override
+  int get hashCode => positions.hashCode ^ indices.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScreenPoints &&
+          runtimeType == other.runtimeType &&
+          positions == other.positions &&
+          indices == other.indices;
+}
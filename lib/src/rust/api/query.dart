@@ -0,0 +1,68 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'attributes.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Indices of the points of `point_set` within `radius` of `center`, in ascending order. `None` if the
+/// point set does not exist or `radius` is negative or not finite.
+Future<Uint32List?> queryCircle(
+        {required int pointSet,
+        required F32Array2 center,
+        required double radius,
+        dynamic hint}) =>
+    RustLib.instance.api.queryCircle(
+        pointSet: pointSet, center: center, radius: radius, hint: hint);
+
+/// `query_circle` keeping only the points that pass all of `filters`, like `query_rect_filtered`. `None` as
+/// well for a negative or not finite `radius`.
+Future<Uint32List?> queryCircleFiltered(
+        {required int pointSet,
+        required F32Array2 center,
+        required double radius,
+        required List<AttributeFilter> filters,
+        dynamic hint}) =>
+    RustLib.instance.api.queryCircleFiltered(
+        pointSet: pointSet,
+        center: center,
+        radius: radius,
+        filters: filters,
+        hint: hint);
+
+/// Indices of the points of `point_set` inside `polygon` (even-odd rule), in ascending order.
+Future<Uint32List?> queryPolygon(
+        {required int pointSet,
+        required List<F32Array2> polygon,
+        dynamic hint}) =>
+    RustLib.instance.api.queryPolygon(
+        pointSet: pointSet, polygon: polygon, hint: hint);
+
+/// `query_polygon` keeping only the points that pass all of `filters`, like `query_rect_filtered`.
+Future<Uint32List?> queryPolygonFiltered(
+        {required int pointSet,
+        required List<F32Array2> polygon,
+        required List<AttributeFilter> filters,
+        dynamic hint}) =>
+    RustLib.instance.api.queryPolygonFiltered(
+        pointSet: pointSet, polygon: polygon, filters: filters, hint: hint);
+
+/// Indices of the points of `point_set` inside `rect`, in ascending order. `None` if the point set does not exist.
+Future<Uint32List?> queryRect(
+        {required int pointSet, required ComputeRect rect, dynamic hint}) =>
+    RustLib.instance.api.queryRect(pointSet: pointSet, rect: rect, hint: hint);
+
+/// `query_rect` keeping only the points that pass all of `filters`, the same as `query_predicate` with the
+/// rect and the filters under an `And`. `None` if the point set does not exist, a filter names a missing
+/// attribute, or a bitmask filter is used on an `F32` attribute.
+Future<Uint32List?> queryRectFiltered(
+        {required int pointSet,
+        required ComputeRect rect,
+        required List<AttributeFilter> filters,
+        dynamic hint}) =>
+    RustLib.instance.api.queryRectFiltered(
+        pointSet: pointSet, rect: rect, filters: filters, hint: hint);
@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Links every point of `point_set` to all other points within `radius` of it. `None` if the point set
+/// does not exist, `radius` is negative or not finite, or the graph has more than `u32::MAX` edges.
+Future<ProximityGraph?> radiusGraph(
+        {required int pointSet, required double radius, dynamic hint}) =>
+    RustLib.instance.api.radiusGraph(
+        pointSet: pointSet, radius: radius, hint: hint);
+
+/// Proximity graph of a point set in compressed sparse row form. The neighbours of point `i` are
+/// `neighbours[offsets[i]..offsets[i + 1]]`, in ascending order. A point is never its own neighbour,
+/// so every edge appears once in each direction.
+class ProximityGraph {
+  /// One entry per point plus a final one holding the edge count.
+  final Uint32List offsets;
+  final Uint32List neighbours;
+
+  const ProximityGraph({
+    required this.offsets,
+    required this.neighbours,
+  });
+
+  @override
+  int get hashCode => offsets.hashCode ^ neighbours.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProximityGraph &&
+          runtimeType == other.runtimeType &&
+          offsets == other.offsets &&
+          neighbours == other.neighbours;
+}
@@ -13,6 +13,38 @@ Future<List<F32Array2>?> runCompute(
         dynamic hint}) =>
     RustLib.instance.api.runCompute(points: points, rect: rect, hint: hint);
 
+/// Same as `run_compute`, with the coordinates of the points one after another as `x0, y0, x1, y1, ...`
+/// both ways. Crosses the bridge as a `Float32List` without wrapping every point, and the result is ready
+/// for `Canvas.drawRawPoints`. `None` if `points` has an odd length.
+Future<Float32List?> runComputeFlat(
+        {required Float32List points,
+        required ComputeRect rect,
+        dynamic hint}) =>
+    RustLib.instance.api.runComputeFlat(points: points, rect: rect, hint: hint);
+
+/// A point inside a result struct or enum. The bridge encodes `[f32; 2]` only as a parameter or return
+/// value, so points nested in other types cross it as `ComputePoint`.
+class ComputePoint {
+  final double x;
+  final double y;
+
+  const ComputePoint({
+    required this.x,
+    required this.y,
+  });
+
+  @override
+  int get hashCode => x.hashCode ^ y.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ComputePoint &&
+          runtimeType == other.runtimeType &&
+          x == other.x &&
+          y == other.y;
+}
+
 class ComputeRect {
   final F32Array2 min;
   final F32Array2 max;
@@ -0,0 +1,120 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Same as `transform_point_set`, but leaves `point_set` as is and returns the id of a new set holding the
+/// transformed points and copies of its attribute channels, without a spatial index.
+Future<int?> createTransformedPointSet(
+        {required int pointSet,
+        required AffineTransform transform,
+        dynamic hint}) =>
+    RustLib.instance.api.createTransformedPointSet(
+        pointSet: pointSet, transform: transform, hint: hint);
+
+/// Same as `transform_point_set_groups`, but into a new set like `create_transformed_point_set`.
+Future<int?> createTransformedPointSetGroups(
+        {required int pointSet,
+        required String groupAttribute,
+        required List<AffineTransform> transforms,
+        dynamic hint}) =>
+    RustLib.instance.api.createTransformedPointSetGroups(
+        pointSet: pointSet,
+        groupAttribute: groupAttribute,
+        transforms: transforms,
+        hint: hint);
+
+/// Applies `transform` to every point of `point_set` where it lives, so pan, zoom and layout changes do not
+/// send the points again. The spatial index and marker clusters follow as with `update_point_set`, and
+/// attribute channels are kept. Returns false if the set does not exist or the transform is not finite.
+Future<bool> transformPointSet(
+        {required int pointSet,
+        required AffineTransform transform,
+        dynamic hint}) =>
+    RustLib.instance.api.transformPointSet(
+        pointSet: pointSet, transform: transform, hint: hint);
+
+/// Applies `transforms[g]` to the points of `point_set` whose `group_attribute` is `g`, in one pass. Points
+/// of groups without a transform stay where they are. Returns false if the set does not exist,
+/// `group_attribute` is not a `U32` channel of it or a transform is not finite.
+Future<bool> transformPointSetGroups(
+        {required int pointSet,
+        required String groupAttribute,
+        required List<AffineTransform> transforms,
+        dynamic hint}) =>
+    RustLib.instance.api.transformPointSetGroups(
+        pointSet: pointSet,
+        groupAttribute: groupAttribute,
+        transforms: transforms,
+        hint: hint);
+
+/// 2D affine transform mapping `(x, y)` to `(xx * x + xy * y + tx, yx * x + yy * y + ty)`.
+class AffineTransform {
+  final double xx;
+  final double xy;
+  final double yx;
+  final double yy;
+  final double tx;
+  final double ty;
+
+  const AffineTransform({
+    required this.xx,
+    required this.xy,
+    required this.yx,
+    required this.yy,
+    required this.tx,
+    required this.ty,
+  });
+
+  static AffineTransform identity({dynamic hint}) =>
+      RustLib.instance.api.affineTransformIdentity(hint: hint);
+
+  /// Rotation about the origin, from the x axis towards the y axis, so clockwise on screen.
+  static AffineTransform rotation({required double radians, dynamic hint}) =>
+      RustLib.instance.api.affineTransformRotation(
+          radians: radians, hint: hint);
+
+  static AffineTransform scaling(
+          {required double x, required double y, dynamic hint}) =>
+      RustLib.instance.api.affineTransformScaling(x: x, y: y, hint: hint);
+
+  /// Shifts x by `tan(x_radians) * y` and y by `tan(y_radians) * x`, like `Canvas.skew` with tangents.
+  static AffineTransform skew(
+          {required double xRadians, required double yRadians, dynamic hint}) =>
+      RustLib.instance.api.affineTransformSkew(
+          xRadians: xRadians, yRadians: yRadians, hint: hint);
+
+  /// This transform followed by `next`.
+  AffineTransform then({required AffineTransform next, dynamic hint}) =>
+      RustLib.instance.api.affineTransformThen(
+          that: this, next: next, hint: hint);
+
+  static AffineTransform translation(
+          {required double x, required double y, dynamic hint}) =>
+      RustLib.instance.api.affineTransformTranslation(x: x, y: y, hint: hint);
+
+  @override
+  int get hashCode =>
+      xx.hashCode ^
+      xy.hashCode ^
+      yx.hashCode ^
+      yy.hashCode ^
+      tx.hashCode ^
+      ty.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AffineTransform &&
+          runtimeType == other.runtimeType &&
+          xx == other.xx &&
+          xy == other.xy &&
+          yx == other.yx &&
+          yy == other.yy &&
+          tx == other.tx &&
+          ty == other.ty;
+}
@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.23.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Voronoi diagram of `point_set`, the dual of its Delaunay triangulation, with every cell clipped to
+/// `bounds`. Cells of points outside `bounds` may still reach into it. Of coincident points only the lowest
+/// index gets a cell, the others get an empty one. `None` if the point set does not exist.
+Future<VoronoiDiagram?> voronoi(
+        {required int pointSet, required ComputeRect bounds, dynamic hint}) =>
+    RustLib.instance.api.voronoi(
+        pointSet: pointSet, bounds: bounds, hint: hint);
+
+/// Voronoi cells of a point set clipped to a rectangle, laid out like `ProximityGraph`.
+class VoronoiDiagram {
+  /// The cell of point `i` is `vertices[offsets[i]..offsets[i + 1]]`, so there is one more offset than
+  /// points.
+  final Uint32List offsets;
+  /// Cell polygons, counter-clockwise with y pointing up (clockwise on screen).
+  final List<ComputePoint> vertices;
+
+  const VoronoiDiagram({
+    required this.offsets,
+    required this.vertices,
+  });
+
+  @override
+  int get hashCode => offsets.hashCode ^ vertices.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VoronoiDiagram &&
+          runtimeType == other.runtimeType &&
+          offsets == other.offsets &&
+          vertices == other.vertices;
+}
@@ -3,11 +3,35 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/adapter.dart';
+import 'api/alpha_shape.dart';
+import 'api/attributes.dart';
+import 'api/dbscan.dart';
+import 'api/delaunay.dart';
+import 'api/dispatch.dart';
+import 'api/expression.dart';
+import 'api/heatmap.dart';
+import 'api/histogram.dart';
+import 'api/hit_test.dart';
+import 'api/hull.dart';
+import 'api/jump_flood.dart';
+import 'api/kmeans.dart';
+import 'api/knn.dart';
+import 'api/logging.dart';
+import 'api/marker_cluster.dart';
+import 'api/point_set.dart';
+import 'api/profiling.dart';
+import 'api/projection.dart';
+import 'api/query.dart';
+import 'api/radius_graph.dart';
 import 'api/simple.dart';
+import 'api/transform.dart';
+import 'api/voronoi.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.io.dart' if (dart.library.html) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 
 /// Main entrypoint of the Rust API
 class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
@@ -64,12 +88,276 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<AdapterReport?> getAdapterReport({dynamic hint});
+
+  Future<List<ShapePolygon>?> alphaShape(
+      {required int pointSet, required double alpha, dynamic hint});
+
+  Future<List<ShapePolygon>?> alphaShapeInCircle(
+      {required int pointSet,
+      required double alpha,
+      required F32Array2 center,
+      required double radius,
+      dynamic hint});
+
+  Future<List<ShapePolygon>?> alphaShapeInPolygon(
+      {required int pointSet,
+      required double alpha,
+      required List<F32Array2> polygon,
+      dynamic hint});
+
+  Future<List<ShapePolygon>?> alphaShapeInRect(
+      {required int pointSet,
+      required double alpha,
+      required ComputeRect rect,
+      dynamic hint});
+
+  Future<bool> removePointAttribute(
+      {required int pointSet, required String name, dynamic hint});
+
+  Future<bool> setPointAttribute(
+      {required int pointSet,
+      required String name,
+      required AttributeValues values,
+      dynamic hint});
+
+  Future<Clustering?> dbscan(
+      {required int pointSet,
+      required double eps,
+      required int minPoints,
+      dynamic hint});
+
+  Future<DelaunayTriangulation?> delaunay(
+      {required int pointSet, dynamic hint});
+
+  int? dispatchCalibrationCrossoverPoints(
+      {required DispatchCalibration that,
+      required bool resident,
+      dynamic hint});
+
+  Future<DispatchCalibration?> calibrateDispatch({dynamic hint});
+
+  ComputeBackend getComputeBackend({dynamic hint});
+
+  void setComputeBackend({required ComputeBackend backend, dynamic hint});
+
+  Future<Uint32List?> queryPredicate(
+      {required int pointSet, required Predicate predicate, dynamic hint});
+
+  Future<DensityGrid?> densityGrid(
+      {required int pointSet,
+      required ComputeRect viewport,
+      required int width,
+      required int height,
+      required double bandwidth,
+      required KernelShape shape,
+      required Float32List? weights,
+      dynamic hint});
+
+  Future<HeatmapImage?> densityImage(
+      {required int pointSet,
+      required ComputeRect viewport,
+      required int width,
+      required int height,
+      required double bandwidth,
+      required KernelShape shape,
+      required Float32List? weights,
+      required Colormap colormap,
+      required double? max,
+      dynamic hint});
+
+  Future<Histogram2d?> histogram2d(
+      {required int pointSet,
+      required ComputeRect extent,
+      required int binsX,
+      required int binsY,
+      required Float32List? values,
+      dynamic hint});
+
+  Future<int?> hitTest(
+      {required int pointSet,
+      required F32Array2 position,
+      required double tolerance,
+      dynamic hint});
+
+  Future<Uint32List?> convexHull({required int pointSet, dynamic hint});
+
+  Future<Uint32List?> convexHullInCircle(
+      {required int pointSet,
+      required F32Array2 center,
+      required double radius,
+      dynamic hint});
+
+  Future<Uint32List?> convexHullInPolygon(
+      {required int pointSet, required List<F32Array2> polygon, dynamic hint});
+
+  Future<Uint32List?> convexHullInRect(
+      {required int pointSet, required ComputeRect rect, dynamic hint});
+
+  Future<VoronoiRaster?> rasterizeVoronoi(
+      {required int pointSet,
+      required ComputeRect bounds,
+      required int width,
+      required int height,
+      dynamic hint});
+
+  Future<KMeans?> kmeans(
+      {required int pointSet,
+      required int k,
+      required int maxIterations,
+      required double tolerance,
+      required int seed,
+      dynamic hint});
+
+  Future<Neighbours?> knn(
+      {required int pointSet,
+      required List<F32Array2> queryPoints,
+      required int k,
+      dynamic hint});
+
+  void closeLogStream({dynamic hint});
+
+  Stream<LogEntry> createLogStream({required LogLevel maxLevel, dynamic hint});
+
+  Future<bool> buildMarkerClusters(
+      {required int pointSet,
+      required double radius,
+      required int minZoom,
+      required int maxZoom,
+      dynamic hint});
+
+  Future<List<MarkerCluster>?> getMarkerClusters(
+      {required int pointSet,
+      required ComputeRect viewport,
+      required int zoom,
+      dynamic hint});
+
+  Future<int> createPointSet({required List<F32Array2> points, dynamic hint});
+
+  Future<int?> createPointSetFlat({required Float32List points, dynamic hint});
+
+  Future<bool> disposePointSet({required int pointSet, dynamic hint});
+
+  Future<IndexStats?> getPointSetIndexStats(
+      {required int pointSet, dynamic hint});
+
+  Future<Uint32List?> insertPoints(
+      {required int pointSet, required List<F32Array2> points, dynamic hint});
+
+  Future<bool> movePoints(
+      {required int pointSet,
+      required Uint32List indices,
+      required List<F32Array2> points,
+      dynamic hint});
+
+  Future<bool> removePoints(
+      {required int pointSet, required Uint32List ids, dynamic hint});
+
+  Future<IndexStats?> setPointSetIndex(
+      {required int pointSet, required SpatialIndexKind kind, dynamic hint});
+
+  Future<bool> updatePointSet(
+      {required int pointSet, required List<F32Array2> points, dynamic hint});
+
+  bool isProfilingEnabled({dynamic hint});
+
+  void setProfilingEnabled({required bool enabled, dynamic hint});
+
+  List<ComputeMetrics> takeComputeMetrics({dynamic hint});
+
+  Future<ScreenPoints?> projectPointSet(
+      {required int pointSet, required Camera camera, dynamic hint});
+
+  Future<FlatScreenPoints?> projectPointSetFlat(
+      {required int pointSet, required Camera camera, dynamic hint});
+
+  Future<Uint32List?> queryCircle(
+      {required int pointSet,
+      required F32Array2 center,
+      required double radius,
+      dynamic hint});
+
+  Future<Uint32List?> queryCircleFiltered(
+      {required int pointSet,
+      required F32Array2 center,
+      required double radius,
+      required List<AttributeFilter> filters,
+      dynamic hint});
+
+  Future<Uint32List?> queryPolygon(
+      {required int pointSet, required List<F32Array2> polygon, dynamic hint});
+
+  Future<Uint32List?> queryPolygonFiltered(
+      {required int pointSet,
+      required List<F32Array2> polygon,
+      required List<AttributeFilter> filters,
+      dynamic hint});
+
+  Future<Uint32List?> queryRect(
+      {required int pointSet, required ComputeRect rect, dynamic hint});
+
+  Future<Uint32List?> queryRectFiltered(
+      {required int pointSet,
+      required ComputeRect rect,
+      required List<AttributeFilter> filters,
+      dynamic hint});
+
+  Future<ProximityGraph?> radiusGraph(
+      {required int pointSet, required double radius, dynamic hint});
+
   Future<void> initApp({dynamic hint});
 
   Future<List<F32Array2>?> runCompute(
       {required List<F32Array2> points,
       required ComputeRect rect,
       dynamic hint});
+
+  Future<Float32List?> runComputeFlat(
+      {required Float32List points, required ComputeRect rect, dynamic hint});
+
+  AffineTransform affineTransformIdentity({dynamic hint});
+
+  AffineTransform affineTransformRotation(
+      {required double radians, dynamic hint});
+
+  AffineTransform affineTransformScaling(
+      {required double x, required double y, dynamic hint});
+
+  AffineTransform affineTransformSkew(
+      {required double xRadians, required double yRadians, dynamic hint});
+
+  AffineTransform affineTransformThen(
+      {required AffineTransform that,
+      required AffineTransform next,
+      dynamic hint});
+
+  AffineTransform affineTransformTranslation(
+      {required double x, required double y, dynamic hint});
+
+  Future<int?> createTransformedPointSet(
+      {required int pointSet,
+      required AffineTransform transform,
+      dynamic hint});
+
+  Future<int?> createTransformedPointSetGroups(
+      {required int pointSet,
+      required String groupAttribute,
+      required List<AffineTransform> transforms,
+      dynamic hint});
+
+  Future<bool> transformPointSet(
+      {required int pointSet,
+      required AffineTransform transform,
+      dynamic hint});
+
+  Future<bool> transformPointSetGroups(
+      {required int pointSet,
+      required String groupAttribute,
+      required List<AffineTransform> transforms,
+      dynamic hint});
+
+  Future<VoronoiDiagram?> voronoi(
+      {required int pointSet, required ComputeRect bounds, dynamic hint});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -81,7 +369,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<void> initApp({dynamic hint}) {
+  Future<AdapterReport?> getAdapterReport({dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -89,222 +377,4666 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 1, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_box_autoadd_adapter_report,
         decodeErrorData: null,
       ),
-      constMeta: kInitAppConstMeta,
+      constMeta: kGetAdapterReportConstMeta,
       argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
+  TaskConstMeta get kGetAdapterReportConstMeta => const TaskConstMeta(
+        debugName: "get_adapter_report",
         argNames: [],
       );
 
   @override
-  Future<List<F32Array2>?> runCompute(
-      {required List<F32Array2> points,
+  Future<List<ShapePolygon>?> alphaShape(
+      {required int pointSet, required double alpha, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32(alpha, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 2, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_shape_polygon,
+        decodeErrorData: null,
+      ),
+      constMeta: kAlphaShapeConstMeta,
+      argValues: [pointSet, alpha],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAlphaShapeConstMeta => const TaskConstMeta(
+        debugName: "alpha_shape",
+        argNames: ["pointSet", "alpha"],
+      );
+
+  @override
+  Future<List<ShapePolygon>?> alphaShapeInCircle(
+      {required int pointSet,
+      required double alpha,
+      required F32Array2 center,
+      required double radius,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32(alpha, serializer);
+        sse_encode_f_32_array_2(center, serializer);
+        sse_encode_f_32(radius, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 3, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_shape_polygon,
+        decodeErrorData: null,
+      ),
+      constMeta: kAlphaShapeInCircleConstMeta,
+      argValues: [pointSet, alpha, center, radius],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAlphaShapeInCircleConstMeta => const TaskConstMeta(
+        debugName: "alpha_shape_in_circle",
+        argNames: ["pointSet", "alpha", "center", "radius"],
+      );
+
+  @override
+  Future<List<ShapePolygon>?> alphaShapeInPolygon(
+      {required int pointSet,
+      required double alpha,
+      required List<F32Array2> polygon,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32(alpha, serializer);
+        sse_encode_list_f_32_array_2(polygon, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 4, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_shape_polygon,
+        decodeErrorData: null,
+      ),
+      constMeta: kAlphaShapeInPolygonConstMeta,
+      argValues: [pointSet, alpha, polygon],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAlphaShapeInPolygonConstMeta => const TaskConstMeta(
+        debugName: "alpha_shape_in_polygon",
+        argNames: ["pointSet", "alpha", "polygon"],
+      );
+
+  @override
+  Future<List<ShapePolygon>?> alphaShapeInRect(
+      {required int pointSet,
+      required double alpha,
       required ComputeRect rect,
       dynamic hint}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_f_32_array_2(points, serializer);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32(alpha, serializer);
         sse_encode_box_autoadd_compute_rect(rect, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
+            funcId: 5, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_list_f_32_array_2,
+        decodeSuccessData: sse_decode_opt_list_shape_polygon,
         decodeErrorData: null,
       ),
-      constMeta: kRunComputeConstMeta,
-      argValues: [points, rect],
+      constMeta: kAlphaShapeInRectConstMeta,
+      argValues: [pointSet, alpha, rect],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAlphaShapeInRectConstMeta => const TaskConstMeta(
+        debugName: "alpha_shape_in_rect",
+        argNames: ["pointSet", "alpha", "rect"],
+      );
+
+  @override
+  Future<bool> removePointAttribute(
+      {required int pointSet, required String name, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 6, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kRemovePointAttributeConstMeta,
+      argValues: [pointSet, name],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRemovePointAttributeConstMeta => const TaskConstMeta(
+        debugName: "remove_point_attribute",
+        argNames: ["pointSet", "name"],
+      );
+
+  @override
+  Future<bool> setPointAttribute(
+      {required int pointSet,
+      required String name,
+      required AttributeValues values,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_box_autoadd_attribute_values(values, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 7, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kSetPointAttributeConstMeta,
+      argValues: [pointSet, name, values],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSetPointAttributeConstMeta => const TaskConstMeta(
+        debugName: "set_point_attribute",
+        argNames: ["pointSet", "name", "values"],
+      );
+
+  @override
+  Future<Clustering?> dbscan(
+      {required int pointSet,
+      required double eps,
+      required int minPoints,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32(eps, serializer);
+        sse_encode_u_32(minPoints, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 8, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_clustering,
+        decodeErrorData: null,
+      ),
+      constMeta: kDbscanConstMeta,
+      argValues: [pointSet, eps, minPoints],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kDbscanConstMeta => const TaskConstMeta(
+        debugName: "dbscan",
+        argNames: ["pointSet", "eps", "minPoints"],
+      );
+
+  @override
+  Future<DelaunayTriangulation?> delaunay(
+      {required int pointSet, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 9, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_delaunay_triangulation,
+        decodeErrorData: null,
+      ),
+      constMeta: kDelaunayConstMeta,
+      argValues: [pointSet],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kDelaunayConstMeta => const TaskConstMeta(
+        debugName: "delaunay",
+        argNames: ["pointSet"],
+      );
+
+  @override
+  int? dispatchCalibrationCrossoverPoints(
+      {required DispatchCalibration that,
+      required bool resident,
+      dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_dispatch_calibration(that, serializer);
+        sse_encode_bool(resident, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kDispatchCalibrationCrossoverPointsConstMeta,
+      argValues: [that, resident],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kDispatchCalibrationCrossoverPointsConstMeta =>
+      const TaskConstMeta(
+        debugName: "DispatchCalibration_crossover_points",
+        argNames: ["that", "resident"],
+      );
+
+  @override
+  Future<DispatchCalibration?> calibrateDispatch({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 11, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_dispatch_calibration,
+        decodeErrorData: null,
+      ),
+      constMeta: kCalibrateDispatchConstMeta,
+      argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kRunComputeConstMeta => const TaskConstMeta(
-        debugName: "run_compute",
-        argNames: ["points", "rect"],
-      );
+  TaskConstMeta get kCalibrateDispatchConstMeta => const TaskConstMeta(
+        debugName: "calibrate_dispatch",
+        argNames: [],
+      );
+
+  @override
+  ComputeBackend getComputeBackend({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_compute_backend,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetComputeBackendConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetComputeBackendConstMeta => const TaskConstMeta(
+        debugName: "get_compute_backend",
+        argNames: [],
+      );
+
+  @override
+  void setComputeBackend({required ComputeBackend backend, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_compute_backend(backend, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kSetComputeBackendConstMeta,
+      argValues: [backend],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSetComputeBackendConstMeta => const TaskConstMeta(
+        debugName: "set_compute_backend",
+        argNames: ["backend"],
+      );
+
+  @override
+  Future<Uint32List?> queryPredicate(
+      {required int pointSet, required Predicate predicate, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_predicate(predicate, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 14, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kQueryPredicateConstMeta,
+      argValues: [pointSet, predicate],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kQueryPredicateConstMeta => const TaskConstMeta(
+        debugName: "query_predicate",
+        argNames: ["pointSet", "predicate"],
+      );
+
+  @override
+  Future<DensityGrid?> densityGrid(
+      {required int pointSet,
+      required ComputeRect viewport,
+      required int width,
+      required int height,
+      required double bandwidth,
+      required KernelShape shape,
+      required Float32List? weights,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_compute_rect(viewport, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        sse_encode_f_32(bandwidth, serializer);
+        sse_encode_kernel_shape(shape, serializer);
+        sse_encode_opt_list_prim_f_32_strict(weights, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 15, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_density_grid,
+        decodeErrorData: null,
+      ),
+      constMeta: kDensityGridConstMeta,
+      argValues: [pointSet, viewport, width, height, bandwidth, shape, weights],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kDensityGridConstMeta => const TaskConstMeta(
+        debugName: "density_grid",
+        argNames: [
+          "pointSet",
+          "viewport",
+          "width",
+          "height",
+          "bandwidth",
+          "shape",
+          "weights",
+        ],
+      );
+
+  @override
+  Future<HeatmapImage?> densityImage(
+      {required int pointSet,
+      required ComputeRect viewport,
+      required int width,
+      required int height,
+      required double bandwidth,
+      required KernelShape shape,
+      required Float32List? weights,
+      required Colormap colormap,
+      required double? max,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_compute_rect(viewport, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        sse_encode_f_32(bandwidth, serializer);
+        sse_encode_kernel_shape(shape, serializer);
+        sse_encode_opt_list_prim_f_32_strict(weights, serializer);
+        sse_encode_colormap(colormap, serializer);
+        sse_encode_opt_box_autoadd_f_32(max, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 16, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_heatmap_image,
+        decodeErrorData: null,
+      ),
+      constMeta: kDensityImageConstMeta,
+      argValues: [
+        pointSet,
+        viewport,
+        width,
+        height,
+        bandwidth,
+        shape,
+        weights,
+        colormap,
+        max,
+      ],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kDensityImageConstMeta => const TaskConstMeta(
+        debugName: "density_image",
+        argNames: [
+          "pointSet",
+          "viewport",
+          "width",
+          "height",
+          "bandwidth",
+          "shape",
+          "weights",
+          "colormap",
+          "max",
+        ],
+      );
+
+  @override
+  Future<Histogram2d?> histogram2d(
+      {required int pointSet,
+      required ComputeRect extent,
+      required int binsX,
+      required int binsY,
+      required Float32List? values,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_compute_rect(extent, serializer);
+        sse_encode_u_32(binsX, serializer);
+        sse_encode_u_32(binsY, serializer);
+        sse_encode_opt_list_prim_f_32_strict(values, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 17, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_histogram_2_d,
+        decodeErrorData: null,
+      ),
+      constMeta: kHistogram2dConstMeta,
+      argValues: [pointSet, extent, binsX, binsY, values],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kHistogram2dConstMeta => const TaskConstMeta(
+        debugName: "histogram_2d",
+        argNames: ["pointSet", "extent", "binsX", "binsY", "values"],
+      );
+
+  @override
+  Future<int?> hitTest(
+      {required int pointSet,
+      required F32Array2 position,
+      required double tolerance,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32_array_2(position, serializer);
+        sse_encode_f_32(tolerance, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 18, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kHitTestConstMeta,
+      argValues: [pointSet, position, tolerance],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kHitTestConstMeta => const TaskConstMeta(
+        debugName: "hit_test",
+        argNames: ["pointSet", "position", "tolerance"],
+      );
+
+  @override
+  Future<Uint32List?> convexHull({required int pointSet, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 19, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kConvexHullConstMeta,
+      argValues: [pointSet],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kConvexHullConstMeta => const TaskConstMeta(
+        debugName: "convex_hull",
+        argNames: ["pointSet"],
+      );
+
+  @override
+  Future<Uint32List?> convexHullInCircle(
+      {required int pointSet,
+      required F32Array2 center,
+      required double radius,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32_array_2(center, serializer);
+        sse_encode_f_32(radius, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 20, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kConvexHullInCircleConstMeta,
+      argValues: [pointSet, center, radius],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kConvexHullInCircleConstMeta => const TaskConstMeta(
+        debugName: "convex_hull_in_circle",
+        argNames: ["pointSet", "center", "radius"],
+      );
+
+  @override
+  Future<Uint32List?> convexHullInPolygon(
+      {required int pointSet, required List<F32Array2> polygon, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_list_f_32_array_2(polygon, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 21, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kConvexHullInPolygonConstMeta,
+      argValues: [pointSet, polygon],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kConvexHullInPolygonConstMeta => const TaskConstMeta(
+        debugName: "convex_hull_in_polygon",
+        argNames: ["pointSet", "polygon"],
+      );
+
+  @override
+  Future<Uint32List?> convexHullInRect(
+      {required int pointSet, required ComputeRect rect, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_compute_rect(rect, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 22, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kConvexHullInRectConstMeta,
+      argValues: [pointSet, rect],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kConvexHullInRectConstMeta => const TaskConstMeta(
+        debugName: "convex_hull_in_rect",
+        argNames: ["pointSet", "rect"],
+      );
+
+  @override
+  Future<VoronoiRaster?> rasterizeVoronoi(
+      {required int pointSet,
+      required ComputeRect bounds,
+      required int width,
+      required int height,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_compute_rect(bounds, serializer);
+        sse_encode_u_32(width, serializer);
+        sse_encode_u_32(height, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 23, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_voronoi_raster,
+        decodeErrorData: null,
+      ),
+      constMeta: kRasterizeVoronoiConstMeta,
+      argValues: [pointSet, bounds, width, height],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRasterizeVoronoiConstMeta => const TaskConstMeta(
+        debugName: "rasterize_voronoi",
+        argNames: ["pointSet", "bounds", "width", "height"],
+      );
+
+  @override
+  Future<KMeans?> kmeans(
+      {required int pointSet,
+      required int k,
+      required int maxIterations,
+      required double tolerance,
+      required int seed,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_u_32(k, serializer);
+        sse_encode_u_32(maxIterations, serializer);
+        sse_encode_f_32(tolerance, serializer);
+        sse_encode_u_64(seed, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 24, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_k_means,
+        decodeErrorData: null,
+      ),
+      constMeta: kKmeansConstMeta,
+      argValues: [pointSet, k, maxIterations, tolerance, seed],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kKmeansConstMeta => const TaskConstMeta(
+        debugName: "kmeans",
+        argNames: ["pointSet", "k", "maxIterations", "tolerance", "seed"],
+      );
+
+  @override
+  Future<Neighbours?> knn(
+      {required int pointSet,
+      required List<F32Array2> queryPoints,
+      required int k,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_list_f_32_array_2(queryPoints, serializer);
+        sse_encode_u_32(k, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 25, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_neighbours,
+        decodeErrorData: null,
+      ),
+      constMeta: kKnnConstMeta,
+      argValues: [pointSet, queryPoints, k],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kKnnConstMeta => const TaskConstMeta(
+        debugName: "knn",
+        argNames: ["pointSet", "queryPoints", "k"],
+      );
+
+  @override
+  void closeLogStream({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCloseLogStreamConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kCloseLogStreamConstMeta => const TaskConstMeta(
+        debugName: "close_log_stream",
+        argNames: [],
+      );
+
+  @override
+  Stream<LogEntry> createLogStream({required LogLevel maxLevel, dynamic hint}) {
+    return handler.executeStream(StreamTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_log_level(maxLevel, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 27, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_log_entry,
+        decodeErrorData: null,
+      ),
+      constMeta: kCreateLogStreamConstMeta,
+      argValues: [maxLevel],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kCreateLogStreamConstMeta => const TaskConstMeta(
+        debugName: "create_log_stream",
+        argNames: ["maxLevel"],
+      );
+
+  @override
+  Future<bool> buildMarkerClusters(
+      {required int pointSet,
+      required double radius,
+      required int minZoom,
+      required int maxZoom,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32(radius, serializer);
+        sse_encode_u_32(minZoom, serializer);
+        sse_encode_u_32(maxZoom, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 28, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kBuildMarkerClustersConstMeta,
+      argValues: [pointSet, radius, minZoom, maxZoom],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kBuildMarkerClustersConstMeta => const TaskConstMeta(
+        debugName: "build_marker_clusters",
+        argNames: ["pointSet", "radius", "minZoom", "maxZoom"],
+      );
+
+  @override
+  Future<List<MarkerCluster>?> getMarkerClusters(
+      {required int pointSet,
+      required ComputeRect viewport,
+      required int zoom,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_compute_rect(viewport, serializer);
+        sse_encode_u_32(zoom, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 29, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_marker_cluster,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetMarkerClustersConstMeta,
+      argValues: [pointSet, viewport, zoom],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetMarkerClustersConstMeta => const TaskConstMeta(
+        debugName: "get_marker_clusters",
+        argNames: ["pointSet", "viewport", "zoom"],
+      );
+
+  @override
+  Future<int> createPointSet({required List<F32Array2> points, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_f_32_array_2(points, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 30, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCreatePointSetConstMeta,
+      argValues: [points],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kCreatePointSetConstMeta => const TaskConstMeta(
+        debugName: "create_point_set",
+        argNames: ["points"],
+      );
+
+  @override
+  Future<int?> createPointSetFlat({required Float32List points, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_f_32_strict(points, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 31, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCreatePointSetFlatConstMeta,
+      argValues: [points],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kCreatePointSetFlatConstMeta => const TaskConstMeta(
+        debugName: "create_point_set_flat",
+        argNames: ["points"],
+      );
+
+  @override
+  Future<bool> disposePointSet({required int pointSet, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 32, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kDisposePointSetConstMeta,
+      argValues: [pointSet],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kDisposePointSetConstMeta => const TaskConstMeta(
+        debugName: "dispose_point_set",
+        argNames: ["pointSet"],
+      );
+
+  @override
+  Future<IndexStats?> getPointSetIndexStats(
+      {required int pointSet, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 33, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_index_stats,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetPointSetIndexStatsConstMeta,
+      argValues: [pointSet],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetPointSetIndexStatsConstMeta => const TaskConstMeta(
+        debugName: "get_point_set_index_stats",
+        argNames: ["pointSet"],
+      );
+
+  @override
+  Future<Uint32List?> insertPoints(
+      {required int pointSet, required List<F32Array2> points, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_list_f_32_array_2(points, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 34, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kInsertPointsConstMeta,
+      argValues: [pointSet, points],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kInsertPointsConstMeta => const TaskConstMeta(
+        debugName: "insert_points",
+        argNames: ["pointSet", "points"],
+      );
+
+  @override
+  Future<bool> movePoints(
+      {required int pointSet,
+      required Uint32List indices,
+      required List<F32Array2> points,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_list_prim_u_32_strict(indices, serializer);
+        sse_encode_list_f_32_array_2(points, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kMovePointsConstMeta,
+      argValues: [pointSet, indices, points],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kMovePointsConstMeta => const TaskConstMeta(
+        debugName: "move_points",
+        argNames: ["pointSet", "indices", "points"],
+      );
+
+  @override
+  Future<bool> removePoints(
+      {required int pointSet, required Uint32List ids, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_list_prim_u_32_strict(ids, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kRemovePointsConstMeta,
+      argValues: [pointSet, ids],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRemovePointsConstMeta => const TaskConstMeta(
+        debugName: "remove_points",
+        argNames: ["pointSet", "ids"],
+      );
+
+  @override
+  Future<IndexStats?> setPointSetIndex(
+      {required int pointSet, required SpatialIndexKind kind, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_spatial_index_kind(kind, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_index_stats,
+        decodeErrorData: null,
+      ),
+      constMeta: kSetPointSetIndexConstMeta,
+      argValues: [pointSet, kind],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSetPointSetIndexConstMeta => const TaskConstMeta(
+        debugName: "set_point_set_index",
+        argNames: ["pointSet", "kind"],
+      );
+
+  @override
+  Future<bool> updatePointSet(
+      {required int pointSet, required List<F32Array2> points, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_list_f_32_array_2(points, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kUpdatePointSetConstMeta,
+      argValues: [pointSet, points],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kUpdatePointSetConstMeta => const TaskConstMeta(
+        debugName: "update_point_set",
+        argNames: ["pointSet", "points"],
+      );
+
+  @override
+  bool isProfilingEnabled({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kIsProfilingEnabledConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kIsProfilingEnabledConstMeta => const TaskConstMeta(
+        debugName: "is_profiling_enabled",
+        argNames: [],
+      );
+
+  @override
+  void setProfilingEnabled({required bool enabled, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(enabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kSetProfilingEnabledConstMeta,
+      argValues: [enabled],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSetProfilingEnabledConstMeta => const TaskConstMeta(
+        debugName: "set_profiling_enabled",
+        argNames: ["enabled"],
+      );
+
+  @override
+  List<ComputeMetrics> takeComputeMetrics({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_compute_metrics,
+        decodeErrorData: null,
+      ),
+      constMeta: kTakeComputeMetricsConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kTakeComputeMetricsConstMeta => const TaskConstMeta(
+        debugName: "take_compute_metrics",
+        argNames: [],
+      );
+
+  @override
+  Future<ScreenPoints?> projectPointSet(
+      {required int pointSet, required Camera camera, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_camera(camera, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_screen_points,
+        decodeErrorData: null,
+      ),
+      constMeta: kProjectPointSetConstMeta,
+      argValues: [pointSet, camera],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kProjectPointSetConstMeta => const TaskConstMeta(
+        debugName: "project_point_set",
+        argNames: ["pointSet", "camera"],
+      );
+
+  @override
+  Future<FlatScreenPoints?> projectPointSetFlat(
+      {required int pointSet, required Camera camera, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_camera(camera, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_flat_screen_points,
+        decodeErrorData: null,
+      ),
+      constMeta: kProjectPointSetFlatConstMeta,
+      argValues: [pointSet, camera],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kProjectPointSetFlatConstMeta => const TaskConstMeta(
+        debugName: "project_point_set_flat",
+        argNames: ["pointSet", "camera"],
+      );
+
+  @override
+  Future<Uint32List?> queryCircle(
+      {required int pointSet,
+      required F32Array2 center,
+      required double radius,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32_array_2(center, serializer);
+        sse_encode_f_32(radius, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kQueryCircleConstMeta,
+      argValues: [pointSet, center, radius],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kQueryCircleConstMeta => const TaskConstMeta(
+        debugName: "query_circle",
+        argNames: ["pointSet", "center", "radius"],
+      );
+
+  @override
+  Future<Uint32List?> queryCircleFiltered(
+      {required int pointSet,
+      required F32Array2 center,
+      required double radius,
+      required List<AttributeFilter> filters,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32_array_2(center, serializer);
+        sse_encode_f_32(radius, serializer);
+        sse_encode_list_attribute_filter(filters, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kQueryCircleFilteredConstMeta,
+      argValues: [pointSet, center, radius, filters],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kQueryCircleFilteredConstMeta => const TaskConstMeta(
+        debugName: "query_circle_filtered",
+        argNames: ["pointSet", "center", "radius", "filters"],
+      );
+
+  @override
+  Future<Uint32List?> queryPolygon(
+      {required int pointSet, required List<F32Array2> polygon, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_list_f_32_array_2(polygon, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kQueryPolygonConstMeta,
+      argValues: [pointSet, polygon],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kQueryPolygonConstMeta => const TaskConstMeta(
+        debugName: "query_polygon",
+        argNames: ["pointSet", "polygon"],
+      );
+
+  @override
+  Future<Uint32List?> queryPolygonFiltered(
+      {required int pointSet,
+      required List<F32Array2> polygon,
+      required List<AttributeFilter> filters,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_list_f_32_array_2(polygon, serializer);
+        sse_encode_list_attribute_filter(filters, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kQueryPolygonFilteredConstMeta,
+      argValues: [pointSet, polygon, filters],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kQueryPolygonFilteredConstMeta => const TaskConstMeta(
+        debugName: "query_polygon_filtered",
+        argNames: ["pointSet", "polygon", "filters"],
+      );
+
+  @override
+  Future<Uint32List?> queryRect(
+      {required int pointSet, required ComputeRect rect, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_compute_rect(rect, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kQueryRectConstMeta,
+      argValues: [pointSet, rect],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kQueryRectConstMeta => const TaskConstMeta(
+        debugName: "query_rect",
+        argNames: ["pointSet", "rect"],
+      );
+
+  @override
+  Future<Uint32List?> queryRectFiltered(
+      {required int pointSet,
+      required ComputeRect rect,
+      required List<AttributeFilter> filters,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_compute_rect(rect, serializer);
+        sse_encode_list_attribute_filter(filters, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 49, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kQueryRectFilteredConstMeta,
+      argValues: [pointSet, rect, filters],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kQueryRectFilteredConstMeta => const TaskConstMeta(
+        debugName: "query_rect_filtered",
+        argNames: ["pointSet", "rect", "filters"],
+      );
+
+  @override
+  Future<ProximityGraph?> radiusGraph(
+      {required int pointSet, required double radius, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_f_32(radius, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_proximity_graph,
+        decodeErrorData: null,
+      ),
+      constMeta: kRadiusGraphConstMeta,
+      argValues: [pointSet, radius],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRadiusGraphConstMeta => const TaskConstMeta(
+        debugName: "radius_graph",
+        argNames: ["pointSet", "radius"],
+      );
+
+  @override
+  Future<void> initApp({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kInitAppConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kInitAppConstMeta => const TaskConstMeta(
+        debugName: "init_app",
+        argNames: [],
+      );
+
+  @override
+  Future<List<F32Array2>?> runCompute(
+      {required List<F32Array2> points,
+      required ComputeRect rect,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_f_32_array_2(points, serializer);
+        sse_encode_box_autoadd_compute_rect(rect, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 52, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_f_32_array_2,
+        decodeErrorData: null,
+      ),
+      constMeta: kRunComputeConstMeta,
+      argValues: [points, rect],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRunComputeConstMeta => const TaskConstMeta(
+        debugName: "run_compute",
+        argNames: ["points", "rect"],
+      );
+
+  @override
+  Future<Float32List?> runComputeFlat(
+      {required Float32List points, required ComputeRect rect, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_f_32_strict(points, serializer);
+        sse_encode_box_autoadd_compute_rect(rect, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 53, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_f_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kRunComputeFlatConstMeta,
+      argValues: [points, rect],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRunComputeFlatConstMeta => const TaskConstMeta(
+        debugName: "run_compute_flat",
+        argNames: ["points", "rect"],
+      );
+
+  @override
+  AffineTransform affineTransformIdentity({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_affine_transform,
+        decodeErrorData: null,
+      ),
+      constMeta: kAffineTransformIdentityConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAffineTransformIdentityConstMeta => const TaskConstMeta(
+        debugName: "AffineTransform_identity",
+        argNames: [],
+      );
+
+  @override
+  AffineTransform affineTransformRotation(
+      {required double radians, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(radians, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_affine_transform,
+        decodeErrorData: null,
+      ),
+      constMeta: kAffineTransformRotationConstMeta,
+      argValues: [radians],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAffineTransformRotationConstMeta => const TaskConstMeta(
+        debugName: "AffineTransform_rotation",
+        argNames: ["radians"],
+      );
+
+  @override
+  AffineTransform affineTransformScaling(
+      {required double x, required double y, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(x, serializer);
+        sse_encode_f_32(y, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_affine_transform,
+        decodeErrorData: null,
+      ),
+      constMeta: kAffineTransformScalingConstMeta,
+      argValues: [x, y],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAffineTransformScalingConstMeta => const TaskConstMeta(
+        debugName: "AffineTransform_scaling",
+        argNames: ["x", "y"],
+      );
+
+  @override
+  AffineTransform affineTransformSkew(
+      {required double xRadians, required double yRadians, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(xRadians, serializer);
+        sse_encode_f_32(yRadians, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_affine_transform,
+        decodeErrorData: null,
+      ),
+      constMeta: kAffineTransformSkewConstMeta,
+      argValues: [xRadians, yRadians],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAffineTransformSkewConstMeta => const TaskConstMeta(
+        debugName: "AffineTransform_skew",
+        argNames: ["xRadians", "yRadians"],
+      );
+
+  @override
+  AffineTransform affineTransformThen(
+      {required AffineTransform that,
+      required AffineTransform next,
+      dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_affine_transform(that, serializer);
+        sse_encode_box_autoadd_affine_transform(next, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_affine_transform,
+        decodeErrorData: null,
+      ),
+      constMeta: kAffineTransformThenConstMeta,
+      argValues: [that, next],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAffineTransformThenConstMeta => const TaskConstMeta(
+        debugName: "AffineTransform_then",
+        argNames: ["that", "next"],
+      );
+
+  @override
+  AffineTransform affineTransformTranslation(
+      {required double x, required double y, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(x, serializer);
+        sse_encode_f_32(y, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_affine_transform,
+        decodeErrorData: null,
+      ),
+      constMeta: kAffineTransformTranslationConstMeta,
+      argValues: [x, y],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kAffineTransformTranslationConstMeta => const TaskConstMeta(
+        debugName: "AffineTransform_translation",
+        argNames: ["x", "y"],
+      );
+
+  @override
+  Future<int?> createTransformedPointSet(
+      {required int pointSet,
+      required AffineTransform transform,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_affine_transform(transform, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 60, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCreateTransformedPointSetConstMeta,
+      argValues: [pointSet, transform],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kCreateTransformedPointSetConstMeta => const TaskConstMeta(
+        debugName: "create_transformed_point_set",
+        argNames: ["pointSet", "transform"],
+      );
+
+  @override
+  Future<int?> createTransformedPointSetGroups(
+      {required int pointSet,
+      required String groupAttribute,
+      required List<AffineTransform> transforms,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_String(groupAttribute, serializer);
+        sse_encode_list_affine_transform(transforms, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 61, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCreateTransformedPointSetGroupsConstMeta,
+      argValues: [pointSet, groupAttribute, transforms],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kCreateTransformedPointSetGroupsConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_transformed_point_set_groups",
+        argNames: ["pointSet", "groupAttribute", "transforms"],
+      );
+
+  @override
+  Future<bool> transformPointSet(
+      {required int pointSet,
+      required AffineTransform transform,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_affine_transform(transform, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 62, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kTransformPointSetConstMeta,
+      argValues: [pointSet, transform],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kTransformPointSetConstMeta => const TaskConstMeta(
+        debugName: "transform_point_set",
+        argNames: ["pointSet", "transform"],
+      );
+
+  @override
+  Future<bool> transformPointSetGroups(
+      {required int pointSet,
+      required String groupAttribute,
+      required List<AffineTransform> transforms,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_String(groupAttribute, serializer);
+        sse_encode_list_affine_transform(transforms, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 63, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kTransformPointSetGroupsConstMeta,
+      argValues: [pointSet, groupAttribute, transforms],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kTransformPointSetGroupsConstMeta => const TaskConstMeta(
+        debugName: "transform_point_set_groups",
+        argNames: ["pointSet", "groupAttribute", "transforms"],
+      );
+
+  @override
+  Future<VoronoiDiagram?> voronoi(
+      {required int pointSet, required ComputeRect bounds, dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(pointSet, serializer);
+        sse_encode_box_autoadd_compute_rect(bounds, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 64, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_voronoi_diagram,
+        decodeErrorData: null,
+      ),
+      constMeta: kVoronoiConstMeta,
+      argValues: [pointSet, bounds],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kVoronoiConstMeta => const TaskConstMeta(
+        debugName: "voronoi",
+        argNames: ["pointSet", "bounds"],
+      );
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AdapterReport dco_decode_adapter_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return AdapterReport(
+      name: dco_decode_String(arr[0]),
+      vendor: dco_decode_u_32(arr[1]),
+      device: dco_decode_u_32(arr[2]),
+      deviceType: dco_decode_String(arr[3]),
+      backend: dco_decode_String(arr[4]),
+      driver: dco_decode_String(arr[5]),
+      driverInfo: dco_decode_String(arr[6]),
+      computeShaders: dco_decode_bool(arr[7]),
+      adapterFeatures: dco_decode_list_String(arr[8]),
+      deviceFeatures: dco_decode_list_String(arr[9]),
+      adapterLimits: dco_decode_compute_limits(arr[10]),
+      deviceLimits: dco_decode_compute_limits(arr[11]),
+      kernels: dco_decode_list_kernel_support(arr[12]),
+    );
+  }
+
+  @protected
+  AffineTransform dco_decode_affine_transform(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AffineTransform(
+      xx: dco_decode_f_32(arr[0]),
+      xy: dco_decode_f_32(arr[1]),
+      yx: dco_decode_f_32(arr[2]),
+      yy: dco_decode_f_32(arr[3]),
+      tx: dco_decode_f_32(arr[4]),
+      ty: dco_decode_f_32(arr[5]),
+    );
+  }
+
+  @protected
+  AttributeFilter dco_decode_attribute_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return AttributeFilter_Range(
+          attribute: dco_decode_String(raw[1]),
+          min: dco_decode_f_64(raw[2]),
+          max: dco_decode_f_64(raw[3]),
+        );
+      case 1:
+        return AttributeFilter_OneOf(
+          attribute: dco_decode_String(raw[1]),
+          values: dco_decode_list_prim_f_64_strict(raw[2]),
+        );
+      case 2:
+        return AttributeFilter_Bitmask(
+          attribute: dco_decode_String(raw[1]),
+          mask: dco_decode_u_32(raw[2]),
+          all: dco_decode_bool(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  AttributeValues dco_decode_attribute_values(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return AttributeValues_F32(
+          dco_decode_list_prim_f_32_strict(raw[1]),
+        );
+      case 1:
+        return AttributeValues_U32(
+          dco_decode_list_prim_u_32_strict(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AdapterReport dco_decode_box_autoadd_adapter_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_adapter_report(raw);
+  }
+
+  @protected
+  AffineTransform dco_decode_box_autoadd_affine_transform(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_affine_transform(raw);
+  }
+
+  @protected
+  AttributeValues dco_decode_box_autoadd_attribute_values(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_attribute_values(raw);
+  }
+
+  @protected
+  Camera dco_decode_box_autoadd_camera(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_camera(raw);
+  }
+
+  @protected
+  Clustering dco_decode_box_autoadd_clustering(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_clustering(raw);
+  }
+
+  @protected
+  ComputeRect dco_decode_box_autoadd_compute_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_compute_rect(raw);
+  }
+
+  @protected
+  DelaunayTriangulation dco_decode_box_autoadd_delaunay_triangulation(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_delaunay_triangulation(raw);
+  }
+
+  @protected
+  DensityGrid dco_decode_box_autoadd_density_grid(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_density_grid(raw);
+  }
+
+  @protected
+  DispatchCalibration dco_decode_box_autoadd_dispatch_calibration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_dispatch_calibration(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FlatScreenPoints dco_decode_box_autoadd_flat_screen_points(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_flat_screen_points(raw);
+  }
+
+  @protected
+  HeatmapImage dco_decode_box_autoadd_heatmap_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_heatmap_image(raw);
+  }
+
+  @protected
+  Histogram2d dco_decode_box_autoadd_histogram_2_d(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_histogram_2_d(raw);
+  }
+
+  @protected
+  IndexStats dco_decode_box_autoadd_index_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_index_stats(raw);
+  }
+
+  @protected
+  KMeans dco_decode_box_autoadd_k_means(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_k_means(raw);
+  }
+
+  @protected
+  Neighbours dco_decode_box_autoadd_neighbours(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_neighbours(raw);
+  }
+
+  @protected
+  Predicate dco_decode_box_autoadd_predicate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_predicate(raw);
+  }
+
+  @protected
+  ProximityGraph dco_decode_box_autoadd_proximity_graph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_proximity_graph(raw);
+  }
+
+  @protected
+  ScreenPoints dco_decode_box_autoadd_screen_points(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_screen_points(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return castInt(raw);
+  }
+
+  @protected
+  VoronoiDiagram dco_decode_box_autoadd_voronoi_diagram(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_voronoi_diagram(raw);
+  }
+
+  @protected
+  VoronoiRaster dco_decode_box_autoadd_voronoi_raster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_voronoi_raster(raw);
+  }
+
+  @protected
+  Predicate dco_decode_box_predicate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_predicate(raw);
+  }
+
+  @protected
+  Camera dco_decode_camera(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Camera(
+      view: dco_decode_affine_transform(arr[0]),
+      viewport: dco_decode_compute_rect(arr[1]),
+      margin: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  Clustering dco_decode_clustering(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Clustering(
+      labels: dco_decode_list_prim_i_32_strict(arr[0]),
+      clusterCount: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  Colormap dco_decode_colormap(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Colormap.values[raw as int];
+  }
+
+  @protected
+  ComputeBackend dco_decode_compute_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ComputeBackend.values[raw as int];
+  }
+
+  @protected
+  ComputeLimits dco_decode_compute_limits(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ComputeLimits(
+      maxBufferSize: dco_decode_u_64(arr[0]),
+      maxStorageBufferBindingSize: dco_decode_u_32(arr[1]),
+      maxStorageBuffersPerShaderStage: dco_decode_u_32(arr[2]),
+      maxComputeWorkgroupStorageSize: dco_decode_u_32(arr[3]),
+      maxComputeInvocationsPerWorkgroup: dco_decode_u_32(arr[4]),
+      maxComputeWorkgroupSizeX: dco_decode_u_32(arr[5]),
+      maxComputeWorkgroupsPerDimension: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  ComputeMetrics dco_decode_compute_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ComputeMetrics(
+      requestId: dco_decode_u_64(arr[0]),
+      kernel: dco_decode_String(arr[1]),
+      pointCount: dco_decode_u_64(arr[2]),
+      onGpu: dco_decode_bool(arr[3]),
+      uploadMicros: dco_decode_f_64(arr[4]),
+      dispatchMicros: dco_decode_f_64(arr[5]),
+      gpuDispatchMicros: dco_decode_opt_box_autoadd_f_64(arr[6]),
+      readbackMicros: dco_decode_f_64(arr[7]),
+      totalMicros: dco_decode_f_64(arr[8]),
+    );
+  }
+
+  @protected
+  ComputePoint dco_decode_compute_point(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ComputePoint(
+      x: dco_decode_f_32(arr[0]),
+      y: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  ComputeRect dco_decode_compute_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ComputeRect(
+      min: dco_decode_f_32_array_2(arr[0]),
+      max: dco_decode_f_32_array_2(arr[1]),
+    );
+  }
+
+  @protected
+  DelaunayTriangulation dco_decode_delaunay_triangulation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DelaunayTriangulation(
+      triangles: dco_decode_list_prim_u_32_strict(arr[0]),
+      hull: dco_decode_list_prim_u_32_strict(arr[1]),
+    );
+  }
+
+  @protected
+  DensityGrid dco_decode_density_grid(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DensityGrid(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      values: dco_decode_list_prim_f_32_strict(arr[2]),
+      max: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  DispatchCalibration dco_decode_dispatch_calibration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DispatchCalibration(
+      gpuFixedMicros: dco_decode_f_64(arr[0]),
+      gpuPerPointNanos: dco_decode_f_64(arr[1]),
+      uploadPerPointNanos: dco_decode_f_64(arr[2]),
+      cpuPerPointNanos: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  F32Array2 dco_decode_f_32_array_2(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return F32Array2(dco_decode_list_prim_f_32_strict(raw));
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FlatScreenPoints dco_decode_flat_screen_points(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FlatScreenPoints(
+      positions: dco_decode_list_prim_f_32_strict(arr[0]),
+      indices: dco_decode_list_prim_u_32_strict(arr[1]),
+    );
+  }
+
+  @protected
+  HeatmapImage dco_decode_heatmap_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HeatmapImage(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      pixels: dco_decode_list_prim_u_8_strict(arr[2]),
+      max: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  Histogram2d dco_decode_histogram_2_d(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Histogram2d(
+      binsX: dco_decode_u_32(arr[0]),
+      binsY: dco_decode_u_32(arr[1]),
+      counts: dco_decode_list_prim_u_32_strict(arr[2]),
+      sums: dco_decode_list_prim_f_32_strict(arr[3]),
+      means: dco_decode_list_prim_f_32_strict(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  IndexStats dco_decode_index_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return IndexStats(
+      kind: dco_decode_spatial_index_kind(arr[0]),
+      buildMicros: dco_decode_f_64(arr[1]),
+      size: dco_decode_u_64(arr[2]),
+      refitted: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  KMeans dco_decode_k_means(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return KMeans(
+      assignments: dco_decode_list_prim_u_32_strict(arr[0]),
+      centroids: dco_decode_list_compute_point(arr[1]),
+      iterations: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  KernelShape dco_decode_kernel_shape(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return KernelShape.values[raw as int];
+  }
+
+  @protected
+  KernelSupport dco_decode_kernel_support(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return KernelSupport(
+      name: dco_decode_String(arr[0]),
+      available: dco_decode_bool(arr[1]),
+      missingFeatures: dco_decode_list_String(arr[2]),
+      storageBuffers: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AffineTransform> dco_decode_list_affine_transform(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_affine_transform).toList();
+  }
+
+  @protected
+  List<AttributeFilter> dco_decode_list_attribute_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_attribute_filter).toList();
+  }
+
+  @protected
+  List<ComputeMetrics> dco_decode_list_compute_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_compute_metrics).toList();
+  }
+
+  @protected
+  List<ComputePoint> dco_decode_list_compute_point(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_compute_point).toList();
+  }
+
+  @protected
+  List<F32Array2> dco_decode_list_f_32_array_2(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_f_32_array_2).toList();
+  }
+
+  @protected
+  List<KernelSupport> dco_decode_list_kernel_support(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_kernel_support).toList();
+  }
+
+  @protected
+  List<Uint32List> dco_decode_list_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_list_prim_u_32_strict)
+        .toList();
+  }
+
+  @protected
+  List<MarkerCluster> dco_decode_list_marker_cluster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_marker_cluster).toList();
+  }
+
+  @protected
+  List<Predicate> dco_decode_list_predicate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_predicate).toList();
+  }
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float32List;
+  }
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float64List;
+  }
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Int32List;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ShapePolygon> dco_decode_list_shape_polygon(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_shape_polygon).toList();
+  }
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LogEntry(
+      level: dco_decode_log_level(arr[0]),
+      target: dco_decode_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      fields: dco_decode_String(arr[3]),
+      spans: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogLevel.values[raw as int];
+  }
+
+  @protected
+  MarkerCluster dco_decode_marker_cluster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MarkerCluster(
+      position: dco_decode_f_32_array_2(arr[0]),
+      count: dco_decode_u_32(arr[1]),
+      representative: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  Neighbours dco_decode_neighbours(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Neighbours(
+      k: dco_decode_u_32(arr[0]),
+      indices: dco_decode_list_prim_u_32_strict(arr[1]),
+      distances: dco_decode_list_prim_f_32_strict(arr[2]),
+    );
+  }
+
+  @protected
+  AdapterReport? dco_decode_opt_box_autoadd_adapter_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_adapter_report(raw);
+  }
+
+  @protected
+  Clustering? dco_decode_opt_box_autoadd_clustering(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_clustering(raw);
+  }
+
+  @protected
+  DelaunayTriangulation? dco_decode_opt_box_autoadd_delaunay_triangulation(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_delaunay_triangulation(raw);
+  }
+
+  @protected
+  DensityGrid? dco_decode_opt_box_autoadd_density_grid(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_density_grid(raw);
+  }
+
+  @protected
+  DispatchCalibration? dco_decode_opt_box_autoadd_dispatch_calibration(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_dispatch_calibration(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  FlatScreenPoints? dco_decode_opt_box_autoadd_flat_screen_points(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_flat_screen_points(raw);
+  }
+
+  @protected
+  HeatmapImage? dco_decode_opt_box_autoadd_heatmap_image(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_heatmap_image(raw);
+  }
+
+  @protected
+  Histogram2d? dco_decode_opt_box_autoadd_histogram_2_d(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_histogram_2_d(raw);
+  }
+
+  @protected
+  IndexStats? dco_decode_opt_box_autoadd_index_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_index_stats(raw);
+  }
+
+  @protected
+  KMeans? dco_decode_opt_box_autoadd_k_means(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_k_means(raw);
+  }
+
+  @protected
+  Neighbours? dco_decode_opt_box_autoadd_neighbours(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_neighbours(raw);
+  }
+
+  @protected
+  ProximityGraph? dco_decode_opt_box_autoadd_proximity_graph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_proximity_graph(raw);
+  }
+
+  @protected
+  ScreenPoints? dco_decode_opt_box_autoadd_screen_points(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_screen_points(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  VoronoiDiagram? dco_decode_opt_box_autoadd_voronoi_diagram(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_voronoi_diagram(raw);
+  }
+
+  @protected
+  VoronoiRaster? dco_decode_opt_box_autoadd_voronoi_raster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_voronoi_raster(raw);
+  }
+
+  @protected
+  List<F32Array2>? dco_decode_opt_list_f_32_array_2(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_f_32_array_2(raw);
+  }
+
+  @protected
+  List<MarkerCluster>? dco_decode_opt_list_marker_cluster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_marker_cluster(raw);
+  }
+
+  @protected
+  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_f_32_strict(raw);
+  }
+
+  @protected
+  Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_32_strict(raw);
+  }
+
+  @protected
+  List<ShapePolygon>? dco_decode_opt_list_shape_polygon(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_shape_polygon(raw);
+  }
+
+  @protected
+  Predicate dco_decode_predicate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return Predicate_InRect(
+          dco_decode_compute_rect(raw[1]),
+        );
+      case 1:
+        return Predicate_InCircle(
+          center: dco_decode_f_32_array_2(raw[1]),
+          radius: dco_decode_f_32(raw[2]),
+        );
+      case 2:
+        return Predicate_InPolygon(
+          dco_decode_list_compute_point(raw[1]),
+        );
+      case 3:
+        return Predicate_Attribute(
+          dco_decode_attribute_filter(raw[1]),
+        );
+      case 4:
+        return Predicate_And(
+          dco_decode_list_predicate(raw[1]),
+        );
+      case 5:
+        return Predicate_Or(
+          dco_decode_list_predicate(raw[1]),
+        );
+      case 6:
+        return Predicate_Not(
+          dco_decode_box_predicate(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ProximityGraph dco_decode_proximity_graph(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ProximityGraph(
+      offsets: dco_decode_list_prim_u_32_strict(arr[0]),
+      neighbours: dco_decode_list_prim_u_32_strict(arr[1]),
+    );
+  }
+
+  @protected
+  ScreenPoints dco_decode_screen_points(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ScreenPoints(
+      positions: dco_decode_list_compute_point(arr[0]),
+      indices: dco_decode_list_prim_u_32_strict(arr[1]),
+    );
+  }
+
+  @protected
+  ShapePolygon dco_decode_shape_polygon(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ShapePolygon(
+      exterior: dco_decode_list_prim_u_32_strict(arr[0]),
+      holes: dco_decode_list_list_prim_u_32_strict(arr[1]),
+    );
+  }
+
+  @protected
+  SpatialIndexKind dco_decode_spatial_index_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SpatialIndexKind.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return castInt(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  VoronoiDiagram dco_decode_voronoi_diagram(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return VoronoiDiagram(
+      offsets: dco_decode_list_prim_u_32_strict(arr[0]),
+      vertices: dco_decode_list_compute_point(arr[1]),
+    );
+  }
+
+  @protected
+  VoronoiRaster dco_decode_voronoi_raster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return VoronoiRaster(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      labels: dco_decode_list_prim_u_8_strict(arr[2]),
+      distances: dco_decode_list_prim_u_8_strict(arr[3]),
+    );
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AdapterReport sse_decode_adapter_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_vendor = sse_decode_u_32(deserializer);
+    var var_device = sse_decode_u_32(deserializer);
+    var var_deviceType = sse_decode_String(deserializer);
+    var var_backend = sse_decode_String(deserializer);
+    var var_driver = sse_decode_String(deserializer);
+    var var_driverInfo = sse_decode_String(deserializer);
+    var var_computeShaders = sse_decode_bool(deserializer);
+    var var_adapterFeatures = sse_decode_list_String(deserializer);
+    var var_deviceFeatures = sse_decode_list_String(deserializer);
+    var var_adapterLimits = sse_decode_compute_limits(deserializer);
+    var var_deviceLimits = sse_decode_compute_limits(deserializer);
+    var var_kernels = sse_decode_list_kernel_support(deserializer);
+    return AdapterReport(
+        name: var_name,
+        vendor: var_vendor,
+        device: var_device,
+        deviceType: var_deviceType,
+        backend: var_backend,
+        driver: var_driver,
+        driverInfo: var_driverInfo,
+        computeShaders: var_computeShaders,
+        adapterFeatures: var_adapterFeatures,
+        deviceFeatures: var_deviceFeatures,
+        adapterLimits: var_adapterLimits,
+        deviceLimits: var_deviceLimits,
+        kernels: var_kernels);
+  }
+
+  @protected
+  AffineTransform sse_decode_affine_transform(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_xx = sse_decode_f_32(deserializer);
+    var var_xy = sse_decode_f_32(deserializer);
+    var var_yx = sse_decode_f_32(deserializer);
+    var var_yy = sse_decode_f_32(deserializer);
+    var var_tx = sse_decode_f_32(deserializer);
+    var var_ty = sse_decode_f_32(deserializer);
+    return AffineTransform(
+        xx: var_xx, xy: var_xy, yx: var_yx, yy: var_yy, tx: var_tx, ty: var_ty);
+  }
+
+  @protected
+  AttributeFilter sse_decode_attribute_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_attribute = sse_decode_String(deserializer);
+        var var_min = sse_decode_f_64(deserializer);
+        var var_max = sse_decode_f_64(deserializer);
+        return AttributeFilter_Range(
+            attribute: var_attribute, min: var_min, max: var_max);
+      case 1:
+        var var_attribute = sse_decode_String(deserializer);
+        var var_values = sse_decode_list_prim_f_64_strict(deserializer);
+        return AttributeFilter_OneOf(
+            attribute: var_attribute, values: var_values);
+      case 2:
+        var var_attribute = sse_decode_String(deserializer);
+        var var_mask = sse_decode_u_32(deserializer);
+        var var_all = sse_decode_bool(deserializer);
+        return AttributeFilter_Bitmask(
+            attribute: var_attribute, mask: var_mask, all: var_all);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  AttributeValues sse_decode_attribute_values(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_list_prim_f_32_strict(deserializer);
+        return AttributeValues_F32(var_field0);
+      case 1:
+        var var_field0 = sse_decode_list_prim_u_32_strict(deserializer);
+        return AttributeValues_U32(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  AdapterReport sse_decode_box_autoadd_adapter_report(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_adapter_report(deserializer));
+  }
+
+  @protected
+  AffineTransform sse_decode_box_autoadd_affine_transform(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_affine_transform(deserializer));
+  }
+
+  @protected
+  AttributeValues sse_decode_box_autoadd_attribute_values(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_attribute_values(deserializer));
+  }
+
+  @protected
+  Camera sse_decode_box_autoadd_camera(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_camera(deserializer));
+  }
+
+  @protected
+  Clustering sse_decode_box_autoadd_clustering(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_clustering(deserializer));
+  }
+
+  @protected
+  ComputeRect sse_decode_box_autoadd_compute_rect(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_compute_rect(deserializer));
+  }
+
+  @protected
+  DelaunayTriangulation sse_decode_box_autoadd_delaunay_triangulation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_delaunay_triangulation(deserializer));
+  }
+
+  @protected
+  DensityGrid sse_decode_box_autoadd_density_grid(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_density_grid(deserializer));
+  }
+
+  @protected
+  DispatchCalibration sse_decode_box_autoadd_dispatch_calibration(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_dispatch_calibration(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  FlatScreenPoints sse_decode_box_autoadd_flat_screen_points(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_flat_screen_points(deserializer));
+  }
+
+  @protected
+  HeatmapImage sse_decode_box_autoadd_heatmap_image(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_heatmap_image(deserializer));
+  }
+
+  @protected
+  Histogram2d sse_decode_box_autoadd_histogram_2_d(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_histogram_2_d(deserializer));
+  }
+
+  @protected
+  IndexStats sse_decode_box_autoadd_index_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_index_stats(deserializer));
+  }
+
+  @protected
+  KMeans sse_decode_box_autoadd_k_means(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_k_means(deserializer));
+  }
+
+  @protected
+  Neighbours sse_decode_box_autoadd_neighbours(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_neighbours(deserializer));
+  }
+
+  @protected
+  Predicate sse_decode_box_autoadd_predicate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_predicate(deserializer));
+  }
+
+  @protected
+  ProximityGraph sse_decode_box_autoadd_proximity_graph(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_proximity_graph(deserializer));
+  }
+
+  @protected
+  ScreenPoints sse_decode_box_autoadd_screen_points(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_screen_points(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  VoronoiDiagram sse_decode_box_autoadd_voronoi_diagram(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_voronoi_diagram(deserializer));
+  }
+
+  @protected
+  VoronoiRaster sse_decode_box_autoadd_voronoi_raster(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_voronoi_raster(deserializer));
+  }
+
+  @protected
+  Predicate sse_decode_box_predicate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_predicate(deserializer));
+  }
+
+  @protected
+  Camera sse_decode_camera(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_view = sse_decode_affine_transform(deserializer);
+    var var_viewport = sse_decode_compute_rect(deserializer);
+    var var_margin = sse_decode_f_32(deserializer);
+    return Camera(view: var_view, viewport: var_viewport, margin: var_margin);
+  }
+
+  @protected
+  Clustering sse_decode_clustering(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_labels = sse_decode_list_prim_i_32_strict(deserializer);
+    var var_clusterCount = sse_decode_u_32(deserializer);
+    return Clustering(labels: var_labels, clusterCount: var_clusterCount);
+  }
+
+  @protected
+  Colormap sse_decode_colormap(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Colormap.values[inner];
+  }
+
+  @protected
+  ComputeBackend sse_decode_compute_backend(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ComputeBackend.values[inner];
+  }
+
+  @protected
+  ComputeLimits sse_decode_compute_limits(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxBufferSize = sse_decode_u_64(deserializer);
+    var var_maxStorageBufferBindingSize = sse_decode_u_32(deserializer);
+    var var_maxStorageBuffersPerShaderStage = sse_decode_u_32(deserializer);
+    var var_maxComputeWorkgroupStorageSize = sse_decode_u_32(deserializer);
+    var var_maxComputeInvocationsPerWorkgroup = sse_decode_u_32(deserializer);
+    var var_maxComputeWorkgroupSizeX = sse_decode_u_32(deserializer);
+    var var_maxComputeWorkgroupsPerDimension = sse_decode_u_32(deserializer);
+    return ComputeLimits(
+        maxBufferSize: var_maxBufferSize,
+        maxStorageBufferBindingSize: var_maxStorageBufferBindingSize,
+        maxStorageBuffersPerShaderStage: var_maxStorageBuffersPerShaderStage,
+        maxComputeWorkgroupStorageSize: var_maxComputeWorkgroupStorageSize,
+        maxComputeInvocationsPerWorkgroup:
+            var_maxComputeInvocationsPerWorkgroup,
+        maxComputeWorkgroupSizeX: var_maxComputeWorkgroupSizeX,
+        maxComputeWorkgroupsPerDimension: var_maxComputeWorkgroupsPerDimension);
+  }
+
+  @protected
+  ComputeMetrics sse_decode_compute_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_requestId = sse_decode_u_64(deserializer);
+    var var_kernel = sse_decode_String(deserializer);
+    var var_pointCount = sse_decode_u_64(deserializer);
+    var var_onGpu = sse_decode_bool(deserializer);
+    var var_uploadMicros = sse_decode_f_64(deserializer);
+    var var_dispatchMicros = sse_decode_f_64(deserializer);
+    var var_gpuDispatchMicros = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_readbackMicros = sse_decode_f_64(deserializer);
+    var var_totalMicros = sse_decode_f_64(deserializer);
+    return ComputeMetrics(
+        requestId: var_requestId,
+        kernel: var_kernel,
+        pointCount: var_pointCount,
+        onGpu: var_onGpu,
+        uploadMicros: var_uploadMicros,
+        dispatchMicros: var_dispatchMicros,
+        gpuDispatchMicros: var_gpuDispatchMicros,
+        readbackMicros: var_readbackMicros,
+        totalMicros: var_totalMicros);
+  }
+
+  @protected
+  ComputePoint sse_decode_compute_point(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_x = sse_decode_f_32(deserializer);
+    var var_y = sse_decode_f_32(deserializer);
+    return ComputePoint(x: var_x, y: var_y);
+  }
+
+  @protected
+  ComputeRect sse_decode_compute_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_min = sse_decode_f_32_array_2(deserializer);
+    var var_max = sse_decode_f_32_array_2(deserializer);
+    return ComputeRect(min: var_min, max: var_max);
+  }
+
+  @protected
+  DelaunayTriangulation sse_decode_delaunay_triangulation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_triangles = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_hull = sse_decode_list_prim_u_32_strict(deserializer);
+    return DelaunayTriangulation(triangles: var_triangles, hull: var_hull);
+  }
+
+  @protected
+  DensityGrid sse_decode_density_grid(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_values = sse_decode_list_prim_f_32_strict(deserializer);
+    var var_max = sse_decode_f_32(deserializer);
+    return DensityGrid(
+        width: var_width, height: var_height, values: var_values, max: var_max);
+  }
+
+  @protected
+  DispatchCalibration sse_decode_dispatch_calibration(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_gpuFixedMicros = sse_decode_f_64(deserializer);
+    var var_gpuPerPointNanos = sse_decode_f_64(deserializer);
+    var var_uploadPerPointNanos = sse_decode_f_64(deserializer);
+    var var_cpuPerPointNanos = sse_decode_f_64(deserializer);
+    return DispatchCalibration(
+        gpuFixedMicros: var_gpuFixedMicros,
+        gpuPerPointNanos: var_gpuPerPointNanos,
+        uploadPerPointNanos: var_uploadPerPointNanos,
+        cpuPerPointNanos: var_cpuPerPointNanos);
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  F32Array2 sse_decode_f_32_array_2(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_f_32_strict(deserializer);
+    return F32Array2(inner);
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FlatScreenPoints sse_decode_flat_screen_points(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_positions = sse_decode_list_prim_f_32_strict(deserializer);
+    var var_indices = sse_decode_list_prim_u_32_strict(deserializer);
+    return FlatScreenPoints(positions: var_positions, indices: var_indices);
+  }
+
+  @protected
+  HeatmapImage sse_decode_heatmap_image(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_pixels = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_max = sse_decode_f_32(deserializer);
+    return HeatmapImage(
+        width: var_width, height: var_height, pixels: var_pixels, max: var_max);
+  }
+
+  @protected
+  Histogram2d sse_decode_histogram_2_d(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_binsX = sse_decode_u_32(deserializer);
+    var var_binsY = sse_decode_u_32(deserializer);
+    var var_counts = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_sums = sse_decode_list_prim_f_32_strict(deserializer);
+    var var_means = sse_decode_list_prim_f_32_strict(deserializer);
+    return Histogram2d(
+        binsX: var_binsX,
+        binsY: var_binsY,
+        counts: var_counts,
+        sums: var_sums,
+        means: var_means);
+  }
+
+  @protected
+  IndexStats sse_decode_index_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_spatial_index_kind(deserializer);
+    var var_buildMicros = sse_decode_f_64(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_refitted = sse_decode_bool(deserializer);
+    return IndexStats(
+        kind: var_kind,
+        buildMicros: var_buildMicros,
+        size: var_size,
+        refitted: var_refitted);
+  }
+
+  @protected
+  KMeans sse_decode_k_means(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assignments = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_centroids = sse_decode_list_compute_point(deserializer);
+    var var_iterations = sse_decode_u_32(deserializer);
+    return KMeans(
+        assignments: var_assignments,
+        centroids: var_centroids,
+        iterations: var_iterations);
+  }
+
+  @protected
+  KernelShape sse_decode_kernel_shape(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return KernelShape.values[inner];
+  }
+
+  @protected
+  KernelSupport sse_decode_kernel_support(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_available = sse_decode_bool(deserializer);
+    var var_missingFeatures = sse_decode_list_String(deserializer);
+    var var_storageBuffers = sse_decode_u_32(deserializer);
+    return KernelSupport(
+        name: var_name,
+        available: var_available,
+        missingFeatures: var_missingFeatures,
+        storageBuffers: var_storageBuffers);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AffineTransform> sse_decode_list_affine_transform(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AffineTransform>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_affine_transform(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AttributeFilter> sse_decode_list_attribute_filter(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AttributeFilter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_attribute_filter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ComputeMetrics> sse_decode_list_compute_metrics(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ComputeMetrics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_compute_metrics(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ComputePoint> sse_decode_list_compute_point(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ComputePoint>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_compute_point(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<F32Array2> sse_decode_list_f_32_array_2(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <F32Array2>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_f_32_array_2(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KernelSupport> sse_decode_list_kernel_support(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KernelSupport>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_kernel_support(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Uint32List> sse_decode_list_list_prim_u_32_strict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Uint32List>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_prim_u_32_strict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MarkerCluster> sse_decode_list_marker_cluster(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MarkerCluster>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_marker_cluster(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Predicate> sse_decode_list_predicate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Predicate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_predicate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat64List(len_);
+  }
+
+  @protected
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt32List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ShapePolygon> sse_decode_list_shape_polygon(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ShapePolygon>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_shape_polygon(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_log_level(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_fields = sse_decode_String(deserializer);
+    var var_spans = sse_decode_String(deserializer);
+    return LogEntry(
+        level: var_level,
+        target: var_target,
+        message: var_message,
+        fields: var_fields,
+        spans: var_spans);
+  }
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LogLevel.values[inner];
+  }
+
+  @protected
+  MarkerCluster sse_decode_marker_cluster(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_position = sse_decode_f_32_array_2(deserializer);
+    var var_count = sse_decode_u_32(deserializer);
+    var var_representative = sse_decode_u_32(deserializer);
+    return MarkerCluster(
+        position: var_position,
+        count: var_count,
+        representative: var_representative);
+  }
+
+  @protected
+  Neighbours sse_decode_neighbours(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_k = sse_decode_u_32(deserializer);
+    var var_indices = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_distances = sse_decode_list_prim_f_32_strict(deserializer);
+    return Neighbours(k: var_k, indices: var_indices, distances: var_distances);
+  }
+
+  @protected
+  AdapterReport? sse_decode_opt_box_autoadd_adapter_report(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_adapter_report(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Clustering? sse_decode_opt_box_autoadd_clustering(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_clustering(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DelaunayTriangulation? sse_decode_opt_box_autoadd_delaunay_triangulation(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_delaunay_triangulation(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DensityGrid? sse_decode_opt_box_autoadd_density_grid(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_density_grid(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DispatchCalibration? sse_decode_opt_box_autoadd_dispatch_calibration(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_dispatch_calibration(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FlatScreenPoints? sse_decode_opt_box_autoadd_flat_screen_points(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_flat_screen_points(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  HeatmapImage? sse_decode_opt_box_autoadd_heatmap_image(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_heatmap_image(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Histogram2d? sse_decode_opt_box_autoadd_histogram_2_d(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_histogram_2_d(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  IndexStats? sse_decode_opt_box_autoadd_index_stats(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_index_stats(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  KMeans? sse_decode_opt_box_autoadd_k_means(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_k_means(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Neighbours? sse_decode_opt_box_autoadd_neighbours(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_neighbours(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProximityGraph? sse_decode_opt_box_autoadd_proximity_graph(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_proximity_graph(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ScreenPoints? sse_decode_opt_box_autoadd_screen_points(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_screen_points(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  VoronoiDiagram? sse_decode_opt_box_autoadd_voronoi_diagram(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_voronoi_diagram(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  VoronoiRaster? sse_decode_opt_box_autoadd_voronoi_raster(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_voronoi_raster(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<F32Array2>? sse_decode_opt_list_f_32_array_2(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_f_32_array_2(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<MarkerCluster>? sse_decode_opt_list_marker_cluster(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_marker_cluster(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Float32List? sse_decode_opt_list_prim_f_32_strict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_f_32_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint32List? sse_decode_opt_list_prim_u_32_strict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_32_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<ShapePolygon>? sse_decode_opt_list_shape_polygon(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_shape_polygon(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Predicate sse_decode_predicate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_compute_rect(deserializer);
+        return Predicate_InRect(var_field0);
+      case 1:
+        var var_center = sse_decode_f_32_array_2(deserializer);
+        var var_radius = sse_decode_f_32(deserializer);
+        return Predicate_InCircle(center: var_center, radius: var_radius);
+      case 2:
+        var var_field0 = sse_decode_list_compute_point(deserializer);
+        return Predicate_InPolygon(var_field0);
+      case 3:
+        var var_field0 = sse_decode_attribute_filter(deserializer);
+        return Predicate_Attribute(var_field0);
+      case 4:
+        var var_field0 = sse_decode_list_predicate(deserializer);
+        return Predicate_And(var_field0);
+      case 5:
+        var var_field0 = sse_decode_list_predicate(deserializer);
+        return Predicate_Or(var_field0);
+      case 6:
+        var var_field0 = sse_decode_box_predicate(deserializer);
+        return Predicate_Not(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ProximityGraph sse_decode_proximity_graph(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offsets = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_neighbours = sse_decode_list_prim_u_32_strict(deserializer);
+    return ProximityGraph(offsets: var_offsets, neighbours: var_neighbours);
+  }
+
+  @protected
+  ScreenPoints sse_decode_screen_points(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_positions = sse_decode_list_compute_point(deserializer);
+    var var_indices = sse_decode_list_prim_u_32_strict(deserializer);
+    return ScreenPoints(positions: var_positions, indices: var_indices);
+  }
+
+  @protected
+  ShapePolygon sse_decode_shape_polygon(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exterior = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_holes = sse_decode_list_list_prim_u_32_strict(deserializer);
+    return ShapePolygon(exterior: var_exterior, holes: var_holes);
+  }
+
+  @protected
+  SpatialIndexKind sse_decode_spatial_index_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SpatialIndexKind.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  int sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  VoronoiDiagram sse_decode_voronoi_diagram(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offsets = sse_decode_list_prim_u_32_strict(deserializer);
+    var var_vertices = sse_decode_list_compute_point(deserializer);
+    return VoronoiDiagram(offsets: var_offsets, vertices: var_vertices);
+  }
+
+  @protected
+  VoronoiRaster sse_decode_voronoi_raster(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_labels = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_distances = sse_decode_list_prim_u_8_strict(deserializer);
+    return VoronoiRaster(
+        width: var_width,
+        height: var_height,
+        labels: var_labels,
+        distances: var_distances);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_adapter_report(AdapterReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.vendor, serializer);
+    sse_encode_u_32(self.device, serializer);
+    sse_encode_String(self.deviceType, serializer);
+    sse_encode_String(self.backend, serializer);
+    sse_encode_String(self.driver, serializer);
+    sse_encode_String(self.driverInfo, serializer);
+    sse_encode_bool(self.computeShaders, serializer);
+    sse_encode_list_String(self.adapterFeatures, serializer);
+    sse_encode_list_String(self.deviceFeatures, serializer);
+    sse_encode_compute_limits(self.adapterLimits, serializer);
+    sse_encode_compute_limits(self.deviceLimits, serializer);
+    sse_encode_list_kernel_support(self.kernels, serializer);
+  }
+
+  @protected
+  void sse_encode_affine_transform(
+      AffineTransform self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.xx, serializer);
+    sse_encode_f_32(self.xy, serializer);
+    sse_encode_f_32(self.yx, serializer);
+    sse_encode_f_32(self.yy, serializer);
+    sse_encode_f_32(self.tx, serializer);
+    sse_encode_f_32(self.ty, serializer);
+  }
+
+  @protected
+  void sse_encode_attribute_filter(
+      AttributeFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case AttributeFilter_Range(
+          attribute: final attribute, min: final min, max: final max):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(attribute, serializer);
+        sse_encode_f_64(min, serializer);
+        sse_encode_f_64(max, serializer);
+      case AttributeFilter_OneOf(
+          attribute: final attribute, values: final values):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(attribute, serializer);
+        sse_encode_list_prim_f_64_strict(values, serializer);
+      case AttributeFilter_Bitmask(
+          attribute: final attribute, mask: final mask, all: final all):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(attribute, serializer);
+        sse_encode_u_32(mask, serializer);
+        sse_encode_bool(all, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_attribute_values(
+      AttributeValues self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case AttributeValues_F32(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_list_prim_f_32_strict(field0, serializer);
+      case AttributeValues_U32(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_prim_u_32_strict(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_box_autoadd_adapter_report(
+      AdapterReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_adapter_report(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_affine_transform(
+      AffineTransform self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_affine_transform(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_attribute_values(
+      AttributeValues self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_attribute_values(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_camera(Camera self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_camera(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_clustering(
+      Clustering self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_clustering(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_compute_rect(
+      ComputeRect self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_compute_rect(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_delaunay_triangulation(
+      DelaunayTriangulation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_delaunay_triangulation(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_density_grid(
+      DensityGrid self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_density_grid(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_dispatch_calibration(
+      DispatchCalibration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_dispatch_calibration(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_flat_screen_points(
+      FlatScreenPoints self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_flat_screen_points(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_heatmap_image(
+      HeatmapImage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_heatmap_image(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_histogram_2_d(
+      Histogram2d self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_histogram_2_d(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_index_stats(
+      IndexStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_index_stats(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_k_means(KMeans self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_k_means(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_neighbours(
+      Neighbours self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_neighbours(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_predicate(
+      Predicate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_predicate(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_proximity_graph(
+      ProximityGraph self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_proximity_graph(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_screen_points(
+      ScreenPoints self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_screen_points(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_voronoi_diagram(
+      VoronoiDiagram self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_voronoi_diagram(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_voronoi_raster(
+      VoronoiRaster self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_voronoi_raster(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_predicate(Predicate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_predicate(self, serializer);
+  }
+
+  @protected
+  void sse_encode_camera(Camera self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_affine_transform(self.view, serializer);
+    sse_encode_compute_rect(self.viewport, serializer);
+    sse_encode_f_32(self.margin, serializer);
+  }
+
+  @protected
+  void sse_encode_clustering(Clustering self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_i_32_strict(self.labels, serializer);
+    sse_encode_u_32(self.clusterCount, serializer);
+  }
+
+  @protected
+  void sse_encode_colormap(Colormap self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_compute_backend(
+      ComputeBackend self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_compute_limits(ComputeLimits self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.maxBufferSize, serializer);
+    sse_encode_u_32(self.maxStorageBufferBindingSize, serializer);
+    sse_encode_u_32(self.maxStorageBuffersPerShaderStage, serializer);
+    sse_encode_u_32(self.maxComputeWorkgroupStorageSize, serializer);
+    sse_encode_u_32(self.maxComputeInvocationsPerWorkgroup, serializer);
+    sse_encode_u_32(self.maxComputeWorkgroupSizeX, serializer);
+    sse_encode_u_32(self.maxComputeWorkgroupsPerDimension, serializer);
+  }
+
+  @protected
+  void sse_encode_compute_metrics(
+      ComputeMetrics self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.requestId, serializer);
+    sse_encode_String(self.kernel, serializer);
+    sse_encode_u_64(self.pointCount, serializer);
+    sse_encode_bool(self.onGpu, serializer);
+    sse_encode_f_64(self.uploadMicros, serializer);
+    sse_encode_f_64(self.dispatchMicros, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.gpuDispatchMicros, serializer);
+    sse_encode_f_64(self.readbackMicros, serializer);
+    sse_encode_f_64(self.totalMicros, serializer);
+  }
+
+  @protected
+  void sse_encode_compute_point(ComputePoint self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.x, serializer);
+    sse_encode_f_32(self.y, serializer);
+  }
+
+  @protected
+  void sse_encode_compute_rect(ComputeRect self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32_array_2(self.min, serializer);
+    sse_encode_f_32_array_2(self.max, serializer);
+  }
+
+  @protected
+  void sse_encode_delaunay_triangulation(
+      DelaunayTriangulation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_32_strict(self.triangles, serializer);
+    sse_encode_list_prim_u_32_strict(self.hull, serializer);
+  }
+
+  @protected
+  void sse_encode_density_grid(DensityGrid self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_list_prim_f_32_strict(self.values, serializer);
+    sse_encode_f_32(self.max, serializer);
+  }
+
+  @protected
+  void sse_encode_dispatch_calibration(
+      DispatchCalibration self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.gpuFixedMicros, serializer);
+    sse_encode_f_64(self.gpuPerPointNanos, serializer);
+    sse_encode_f_64(self.uploadPerPointNanos, serializer);
+    sse_encode_f_64(self.cpuPerPointNanos, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
+  }
+
+  @protected
+  void sse_encode_f_32_array_2(F32Array2 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_f_32_strict(self.inner, serializer);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_flat_screen_points(
+      FlatScreenPoints self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_f_32_strict(self.positions, serializer);
+    sse_encode_list_prim_u_32_strict(self.indices, serializer);
+  }
+
+  @protected
+  void sse_encode_heatmap_image(HeatmapImage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_list_prim_u_8_strict(self.pixels, serializer);
+    sse_encode_f_32(self.max, serializer);
+  }
+
+  @protected
+  void sse_encode_histogram_2_d(Histogram2d self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.binsX, serializer);
+    sse_encode_u_32(self.binsY, serializer);
+    sse_encode_list_prim_u_32_strict(self.counts, serializer);
+    sse_encode_list_prim_f_32_strict(self.sums, serializer);
+    sse_encode_list_prim_f_32_strict(self.means, serializer);
+  }
+
+  @protected
+  void sse_encode_index_stats(IndexStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_spatial_index_kind(self.kind, serializer);
+    sse_encode_f_64(self.buildMicros, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_bool(self.refitted, serializer);
+  }
+
+  @protected
+  void sse_encode_k_means(KMeans self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_32_strict(self.assignments, serializer);
+    sse_encode_list_compute_point(self.centroids, serializer);
+    sse_encode_u_32(self.iterations, serializer);
+  }
+
+  @protected
+  void sse_encode_kernel_shape(KernelShape self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_kernel_support(KernelSupport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_bool(self.available, serializer);
+    sse_encode_list_String(self.missingFeatures, serializer);
+    sse_encode_u_32(self.storageBuffers, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_affine_transform(
+      List<AffineTransform> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_affine_transform(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_attribute_filter(
+      List<AttributeFilter> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_attribute_filter(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_compute_metrics(
+      List<ComputeMetrics> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_compute_metrics(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_compute_point(
+      List<ComputePoint> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_compute_point(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_f_32_array_2(
+      List<F32Array2> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_f_32_array_2(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_kernel_support(
+      List<KernelSupport> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_kernel_support(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_list_prim_u_32_strict(
+      List<Uint32List> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_prim_u_32_strict(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_marker_cluster(
+      List<MarkerCluster> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_marker_cluster(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_predicate(
+      List<Predicate> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_predicate(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+      Float32List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_f_64_strict(
+      Float64List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat64List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_i_32_strict(
+      Int32List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt32List(self);
+  }
 
   @protected
-  ComputeRect dco_decode_box_autoadd_compute_rect(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_compute_rect(raw);
+  void sse_encode_list_prim_u_32_strict(
+      Uint32List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  ComputeRect dco_decode_compute_rect(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ComputeRect(
-      min: dco_decode_f_32_array_2(arr[0]),
-      max: dco_decode_f_32_array_2(arr[1]),
-    );
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_list_shape_polygon(
+      List<ShapePolygon> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_shape_polygon(item, serializer);
+    }
   }
 
   @protected
-  F32Array2 dco_decode_f_32_array_2(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return F32Array2(dco_decode_list_prim_f_32_strict(raw));
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_log_level(self.level, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_String(self.fields, serializer);
+    sse_encode_String(self.spans, serializer);
   }
 
   @protected
-  List<F32Array2> dco_decode_list_f_32_array_2(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_f_32_array_2).toList();
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float32List;
+  void sse_encode_marker_cluster(MarkerCluster self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32_array_2(self.position, serializer);
+    sse_encode_u_32(self.count, serializer);
+    sse_encode_u_32(self.representative, serializer);
   }
 
   @protected
-  List<F32Array2>? dco_decode_opt_list_f_32_array_2(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_f_32_array_2(raw);
+  void sse_encode_neighbours(Neighbours self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.k, serializer);
+    sse_encode_list_prim_u_32_strict(self.indices, serializer);
+    sse_encode_list_prim_f_32_strict(self.distances, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_opt_box_autoadd_adapter_report(
+      AdapterReport? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_adapter_report(self, serializer);
+    }
   }
 
   @protected
-  ComputeRect sse_decode_box_autoadd_compute_rect(
-      SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_clustering(
+      Clustering? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_compute_rect(deserializer));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_clustering(self, serializer);
+    }
   }
 
   @protected
-  ComputeRect sse_decode_compute_rect(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_delaunay_triangulation(
+      DelaunayTriangulation? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_min = sse_decode_f_32_array_2(deserializer);
-    var var_max = sse_decode_f_32_array_2(deserializer);
-    return ComputeRect(min: var_min, max: var_max);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_delaunay_triangulation(self, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_density_grid(
+      DensityGrid? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_density_grid(self, serializer);
+    }
   }
 
   @protected
-  F32Array2 sse_decode_f_32_array_2(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_dispatch_calibration(
+      DispatchCalibration? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_f_32_strict(deserializer);
-    return F32Array2(inner);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_dispatch_calibration(self, serializer);
+    }
   }
 
   @protected
-  List<F32Array2> sse_decode_list_f_32_array_2(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <F32Array2>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_f_32_array_2(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  List<F32Array2>? sse_decode_opt_list_f_32_array_2(
-      SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_flat_screen_points(
+      FlatScreenPoints? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_f_32_array_2(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_flat_screen_points(self, serializer);
     }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_heatmap_image(
+      HeatmapImage? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_heatmap_image(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_histogram_2_d(
+      Histogram2d? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_histogram_2_d(self, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_index_stats(
+      IndexStats? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_index_stats(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_compute_rect(
-      ComputeRect self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_k_means(
+      KMeans? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_compute_rect(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_k_means(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_compute_rect(ComputeRect self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_neighbours(
+      Neighbours? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32_array_2(self.min, serializer);
-    sse_encode_f_32_array_2(self.max, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_neighbours(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_proximity_graph(
+      ProximityGraph? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_proximity_graph(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_f_32_array_2(F32Array2 self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_screen_points(
+      ScreenPoints? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_f_32_strict(self.inner, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_screen_points(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_f_32_array_2(
-      List<F32Array2> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_f_32_array_2(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_f_32_strict(
-      Float32List self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_voronoi_diagram(
+      VoronoiDiagram? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_voronoi_diagram(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_voronoi_raster(
+      VoronoiRaster? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_voronoi_raster(self, serializer);
+    }
   }
 
   @protected
@@ -318,11 +5050,148 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_marker_cluster(
+      List<MarkerCluster>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_marker_cluster(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_prim_f_32_strict(
+      Float32List? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_f_32_strict(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_prim_u_32_strict(
+      Uint32List? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_32_strict(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_shape_polygon(
+      List<ShapePolygon>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_shape_polygon(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_predicate(Predicate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case Predicate_InRect(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_compute_rect(field0, serializer);
+      case Predicate_InCircle(center: final center, radius: final radius):
+        sse_encode_i_32(1, serializer);
+        sse_encode_f_32_array_2(center, serializer);
+        sse_encode_f_32(radius, serializer);
+      case Predicate_InPolygon(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_list_compute_point(field0, serializer);
+      case Predicate_Attribute(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_attribute_filter(field0, serializer);
+      case Predicate_And(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_list_predicate(field0, serializer);
+      case Predicate_Or(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_list_predicate(field0, serializer);
+      case Predicate_Not(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_box_predicate(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_proximity_graph(
+      ProximityGraph self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_32_strict(self.offsets, serializer);
+    sse_encode_list_prim_u_32_strict(self.neighbours, serializer);
+  }
+
+  @protected
+  void sse_encode_screen_points(ScreenPoints self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_compute_point(self.positions, serializer);
+    sse_encode_list_prim_u_32_strict(self.indices, serializer);
+  }
+
+  @protected
+  void sse_encode_shape_polygon(ShapePolygon self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_32_strict(self.exterior, serializer);
+    sse_encode_list_list_prim_u_32_strict(self.holes, serializer);
+  }
+
+  @protected
+  void sse_encode_spatial_index_kind(
+      SpatialIndexKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint64(self);
+  }
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self);
+  }
+
   @protected
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_voronoi_diagram(
+      VoronoiDiagram self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_32_strict(self.offsets, serializer);
+    sse_encode_list_compute_point(self.vertices, serializer);
+  }
+
+  @protected
+  void sse_encode_voronoi_raster(VoronoiRaster self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_list_prim_u_8_strict(self.labels, serializer);
+    sse_encode_list_prim_u_8_strict(self.distances, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
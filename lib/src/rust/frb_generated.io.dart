@@ -3,12 +3,36 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/adapter.dart';
+import 'api/alpha_shape.dart';
+import 'api/attributes.dart';
+import 'api/dbscan.dart';
+import 'api/delaunay.dart';
+import 'api/dispatch.dart';
+import 'api/expression.dart';
+import 'api/heatmap.dart';
+import 'api/histogram.dart';
+import 'api/hit_test.dart';
+import 'api/hull.dart';
+import 'api/jump_flood.dart';
+import 'api/kmeans.dart';
+import 'api/knn.dart';
+import 'api/logging.dart';
+import 'api/marker_cluster.dart';
+import 'api/point_set.dart';
+import 'api/profiling.dart';
+import 'api/projection.dart';
+import 'api/query.dart';
+import 'api/radius_graph.dart';
 import 'api/simple.dart';
+import 'api/transform.dart';
+import 'api/voronoi.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -18,89 +42,1080 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  AdapterReport dco_decode_adapter_report(dynamic raw);
+
+  @protected
+  AffineTransform dco_decode_affine_transform(dynamic raw);
+
+  @protected
+  AttributeFilter dco_decode_attribute_filter(dynamic raw);
+
+  @protected
+  AttributeValues dco_decode_attribute_values(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  AdapterReport dco_decode_box_autoadd_adapter_report(dynamic raw);
+
+  @protected
+  AffineTransform dco_decode_box_autoadd_affine_transform(dynamic raw);
+
+  @protected
+  AttributeValues dco_decode_box_autoadd_attribute_values(dynamic raw);
+
+  @protected
+  Camera dco_decode_box_autoadd_camera(dynamic raw);
+
+  @protected
+  Clustering dco_decode_box_autoadd_clustering(dynamic raw);
+
   @protected
   ComputeRect dco_decode_box_autoadd_compute_rect(dynamic raw);
 
   @protected
-  ComputeRect dco_decode_compute_rect(dynamic raw);
+  DelaunayTriangulation dco_decode_box_autoadd_delaunay_triangulation(
+      dynamic raw);
+
+  @protected
+  DensityGrid dco_decode_box_autoadd_density_grid(dynamic raw);
+
+  @protected
+  DispatchCalibration dco_decode_box_autoadd_dispatch_calibration(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FlatScreenPoints dco_decode_box_autoadd_flat_screen_points(dynamic raw);
+
+  @protected
+  HeatmapImage dco_decode_box_autoadd_heatmap_image(dynamic raw);
+
+  @protected
+  Histogram2d dco_decode_box_autoadd_histogram_2_d(dynamic raw);
+
+  @protected
+  IndexStats dco_decode_box_autoadd_index_stats(dynamic raw);
+
+  @protected
+  KMeans dco_decode_box_autoadd_k_means(dynamic raw);
+
+  @protected
+  Neighbours dco_decode_box_autoadd_neighbours(dynamic raw);
+
+  @protected
+  Predicate dco_decode_box_autoadd_predicate(dynamic raw);
+
+  @protected
+  ProximityGraph dco_decode_box_autoadd_proximity_graph(dynamic raw);
+
+  @protected
+  ScreenPoints dco_decode_box_autoadd_screen_points(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  VoronoiDiagram dco_decode_box_autoadd_voronoi_diagram(dynamic raw);
+
+  @protected
+  VoronoiRaster dco_decode_box_autoadd_voronoi_raster(dynamic raw);
+
+  @protected
+  Predicate dco_decode_box_predicate(dynamic raw);
+
+  @protected
+  Camera dco_decode_camera(dynamic raw);
+
+  @protected
+  Clustering dco_decode_clustering(dynamic raw);
+
+  @protected
+  Colormap dco_decode_colormap(dynamic raw);
+
+  @protected
+  ComputeBackend dco_decode_compute_backend(dynamic raw);
+
+  @protected
+  ComputeLimits dco_decode_compute_limits(dynamic raw);
+
+  @protected
+  ComputeMetrics dco_decode_compute_metrics(dynamic raw);
+
+  @protected
+  ComputePoint dco_decode_compute_point(dynamic raw);
+
+  @protected
+  ComputeRect dco_decode_compute_rect(dynamic raw);
+
+  @protected
+  DelaunayTriangulation dco_decode_delaunay_triangulation(dynamic raw);
+
+  @protected
+  DensityGrid dco_decode_density_grid(dynamic raw);
+
+  @protected
+  DispatchCalibration dco_decode_dispatch_calibration(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  F32Array2 dco_decode_f_32_array_2(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FlatScreenPoints dco_decode_flat_screen_points(dynamic raw);
+
+  @protected
+  HeatmapImage dco_decode_heatmap_image(dynamic raw);
+
+  @protected
+  Histogram2d dco_decode_histogram_2_d(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  IndexStats dco_decode_index_stats(dynamic raw);
+
+  @protected
+  KMeans dco_decode_k_means(dynamic raw);
+
+  @protected
+  KernelShape dco_decode_kernel_shape(dynamic raw);
+
+  @protected
+  KernelSupport dco_decode_kernel_support(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<AffineTransform> dco_decode_list_affine_transform(dynamic raw);
+
+  @protected
+  List<AttributeFilter> dco_decode_list_attribute_filter(dynamic raw);
+
+  @protected
+  List<ComputeMetrics> dco_decode_list_compute_metrics(dynamic raw);
+
+  @protected
+  List<ComputePoint> dco_decode_list_compute_point(dynamic raw);
+
+  @protected
+  List<F32Array2> dco_decode_list_f_32_array_2(dynamic raw);
+
+  @protected
+  List<KernelSupport> dco_decode_list_kernel_support(dynamic raw);
+
+  @protected
+  List<Uint32List> dco_decode_list_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  List<MarkerCluster> dco_decode_list_marker_cluster(dynamic raw);
+
+  @protected
+  List<Predicate> dco_decode_list_predicate(dynamic raw);
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<ShapePolygon> dco_decode_list_shape_polygon(dynamic raw);
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw);
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw);
+
+  @protected
+  MarkerCluster dco_decode_marker_cluster(dynamic raw);
+
+  @protected
+  Neighbours dco_decode_neighbours(dynamic raw);
+
+  @protected
+  AdapterReport? dco_decode_opt_box_autoadd_adapter_report(dynamic raw);
+
+  @protected
+  Clustering? dco_decode_opt_box_autoadd_clustering(dynamic raw);
+
+  @protected
+  DelaunayTriangulation? dco_decode_opt_box_autoadd_delaunay_triangulation(
+      dynamic raw);
+
+  @protected
+  DensityGrid? dco_decode_opt_box_autoadd_density_grid(dynamic raw);
+
+  @protected
+  DispatchCalibration? dco_decode_opt_box_autoadd_dispatch_calibration(
+      dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FlatScreenPoints? dco_decode_opt_box_autoadd_flat_screen_points(dynamic raw);
+
+  @protected
+  HeatmapImage? dco_decode_opt_box_autoadd_heatmap_image(dynamic raw);
+
+  @protected
+  Histogram2d? dco_decode_opt_box_autoadd_histogram_2_d(dynamic raw);
+
+  @protected
+  IndexStats? dco_decode_opt_box_autoadd_index_stats(dynamic raw);
+
+  @protected
+  KMeans? dco_decode_opt_box_autoadd_k_means(dynamic raw);
+
+  @protected
+  Neighbours? dco_decode_opt_box_autoadd_neighbours(dynamic raw);
+
+  @protected
+  ProximityGraph? dco_decode_opt_box_autoadd_proximity_graph(dynamic raw);
+
+  @protected
+  ScreenPoints? dco_decode_opt_box_autoadd_screen_points(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  VoronoiDiagram? dco_decode_opt_box_autoadd_voronoi_diagram(dynamic raw);
+
+  @protected
+  VoronoiRaster? dco_decode_opt_box_autoadd_voronoi_raster(dynamic raw);
+
+  @protected
+  List<F32Array2>? dco_decode_opt_list_f_32_array_2(dynamic raw);
+
+  @protected
+  List<MarkerCluster>? dco_decode_opt_list_marker_cluster(dynamic raw);
+
+  @protected
+  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw);
+
+  @protected
+  Uint32List? dco_decode_opt_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  List<ShapePolygon>? dco_decode_opt_list_shape_polygon(dynamic raw);
+
+  @protected
+  Predicate dco_decode_predicate(dynamic raw);
+
+  @protected
+  ProximityGraph dco_decode_proximity_graph(dynamic raw);
+
+  @protected
+  ScreenPoints dco_decode_screen_points(dynamic raw);
+
+  @protected
+  ShapePolygon dco_decode_shape_polygon(dynamic raw);
+
+  @protected
+  SpatialIndexKind dco_decode_spatial_index_kind(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  int dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  VoronoiDiagram dco_decode_voronoi_diagram(dynamic raw);
+
+  @protected
+  VoronoiRaster dco_decode_voronoi_raster(dynamic raw);
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AdapterReport sse_decode_adapter_report(SseDeserializer deserializer);
+
+  @protected
+  AffineTransform sse_decode_affine_transform(SseDeserializer deserializer);
+
+  @protected
+  AttributeFilter sse_decode_attribute_filter(SseDeserializer deserializer);
+
+  @protected
+  AttributeValues sse_decode_attribute_values(SseDeserializer deserializer);
+
+  @protected
+  AdapterReport sse_decode_box_autoadd_adapter_report(
+      SseDeserializer deserializer);
+
+  @protected
+  AffineTransform sse_decode_box_autoadd_affine_transform(
+      SseDeserializer deserializer);
+
+  @protected
+  AttributeValues sse_decode_box_autoadd_attribute_values(
+      SseDeserializer deserializer);
+
+  @protected
+  Camera sse_decode_box_autoadd_camera(SseDeserializer deserializer);
+
+  @protected
+  Clustering sse_decode_box_autoadd_clustering(SseDeserializer deserializer);
+
+  @protected
+  ComputeRect sse_decode_box_autoadd_compute_rect(SseDeserializer deserializer);
+
+  @protected
+  DelaunayTriangulation sse_decode_box_autoadd_delaunay_triangulation(
+      SseDeserializer deserializer);
+
+  @protected
+  DensityGrid sse_decode_box_autoadd_density_grid(SseDeserializer deserializer);
+
+  @protected
+  DispatchCalibration sse_decode_box_autoadd_dispatch_calibration(
+      SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FlatScreenPoints sse_decode_box_autoadd_flat_screen_points(
+      SseDeserializer deserializer);
+
+  @protected
+  HeatmapImage sse_decode_box_autoadd_heatmap_image(
+      SseDeserializer deserializer);
+
+  @protected
+  Histogram2d sse_decode_box_autoadd_histogram_2_d(
+      SseDeserializer deserializer);
+
+  @protected
+  IndexStats sse_decode_box_autoadd_index_stats(SseDeserializer deserializer);
+
+  @protected
+  KMeans sse_decode_box_autoadd_k_means(SseDeserializer deserializer);
+
+  @protected
+  Neighbours sse_decode_box_autoadd_neighbours(SseDeserializer deserializer);
+
+  @protected
+  Predicate sse_decode_box_autoadd_predicate(SseDeserializer deserializer);
+
+  @protected
+  ProximityGraph sse_decode_box_autoadd_proximity_graph(
+      SseDeserializer deserializer);
+
+  @protected
+  ScreenPoints sse_decode_box_autoadd_screen_points(
+      SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  VoronoiDiagram sse_decode_box_autoadd_voronoi_diagram(
+      SseDeserializer deserializer);
+
+  @protected
+  VoronoiRaster sse_decode_box_autoadd_voronoi_raster(
+      SseDeserializer deserializer);
+
+  @protected
+  Predicate sse_decode_box_predicate(SseDeserializer deserializer);
+
+  @protected
+  Camera sse_decode_camera(SseDeserializer deserializer);
+
+  @protected
+  Clustering sse_decode_clustering(SseDeserializer deserializer);
+
+  @protected
+  Colormap sse_decode_colormap(SseDeserializer deserializer);
+
+  @protected
+  ComputeBackend sse_decode_compute_backend(SseDeserializer deserializer);
+
+  @protected
+  ComputeLimits sse_decode_compute_limits(SseDeserializer deserializer);
+
+  @protected
+  ComputeMetrics sse_decode_compute_metrics(SseDeserializer deserializer);
+
+  @protected
+  ComputePoint sse_decode_compute_point(SseDeserializer deserializer);
+
+  @protected
+  ComputeRect sse_decode_compute_rect(SseDeserializer deserializer);
+
+  @protected
+  DelaunayTriangulation sse_decode_delaunay_triangulation(
+      SseDeserializer deserializer);
+
+  @protected
+  DensityGrid sse_decode_density_grid(SseDeserializer deserializer);
+
+  @protected
+  DispatchCalibration sse_decode_dispatch_calibration(
+      SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  F32Array2 sse_decode_f_32_array_2(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FlatScreenPoints sse_decode_flat_screen_points(SseDeserializer deserializer);
+
+  @protected
+  HeatmapImage sse_decode_heatmap_image(SseDeserializer deserializer);
+
+  @protected
+  Histogram2d sse_decode_histogram_2_d(SseDeserializer deserializer);
+
+  @protected
+  IndexStats sse_decode_index_stats(SseDeserializer deserializer);
+
+  @protected
+  KMeans sse_decode_k_means(SseDeserializer deserializer);
+
+  @protected
+  KernelShape sse_decode_kernel_shape(SseDeserializer deserializer);
+
+  @protected
+  KernelSupport sse_decode_kernel_support(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AffineTransform> sse_decode_list_affine_transform(
+      SseDeserializer deserializer);
+
+  @protected
+  List<AttributeFilter> sse_decode_list_attribute_filter(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ComputeMetrics> sse_decode_list_compute_metrics(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ComputePoint> sse_decode_list_compute_point(
+      SseDeserializer deserializer);
+
+  @protected
+  List<F32Array2> sse_decode_list_f_32_array_2(SseDeserializer deserializer);
+
+  @protected
+  List<KernelSupport> sse_decode_list_kernel_support(
+      SseDeserializer deserializer);
+
+  @protected
+  List<Uint32List> sse_decode_list_list_prim_u_32_strict(
+      SseDeserializer deserializer);
+
+  @protected
+  List<MarkerCluster> sse_decode_list_marker_cluster(
+      SseDeserializer deserializer);
+
+  @protected
+  List<Predicate> sse_decode_list_predicate(SseDeserializer deserializer);
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+  @protected
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<ShapePolygon> sse_decode_list_shape_polygon(
+      SseDeserializer deserializer);
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+  @protected
+  MarkerCluster sse_decode_marker_cluster(SseDeserializer deserializer);
+
+  @protected
+  Neighbours sse_decode_neighbours(SseDeserializer deserializer);
+
+  @protected
+  AdapterReport? sse_decode_opt_box_autoadd_adapter_report(
+      SseDeserializer deserializer);
+
+  @protected
+  Clustering? sse_decode_opt_box_autoadd_clustering(
+      SseDeserializer deserializer);
+
+  @protected
+  DelaunayTriangulation? sse_decode_opt_box_autoadd_delaunay_triangulation(
+      SseDeserializer deserializer);
+
+  @protected
+  DensityGrid? sse_decode_opt_box_autoadd_density_grid(
+      SseDeserializer deserializer);
+
+  @protected
+  DispatchCalibration? sse_decode_opt_box_autoadd_dispatch_calibration(
+      SseDeserializer deserializer);
 
   @protected
-  double dco_decode_f_32(dynamic raw);
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
 
   @protected
-  F32Array2 dco_decode_f_32_array_2(dynamic raw);
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
 
   @protected
-  List<F32Array2> dco_decode_list_f_32_array_2(dynamic raw);
+  FlatScreenPoints? sse_decode_opt_box_autoadd_flat_screen_points(
+      SseDeserializer deserializer);
 
   @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+  HeatmapImage? sse_decode_opt_box_autoadd_heatmap_image(
+      SseDeserializer deserializer);
 
   @protected
-  List<F32Array2>? dco_decode_opt_list_f_32_array_2(dynamic raw);
+  Histogram2d? sse_decode_opt_box_autoadd_histogram_2_d(
+      SseDeserializer deserializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  IndexStats? sse_decode_opt_box_autoadd_index_stats(
+      SseDeserializer deserializer);
 
   @protected
-  ComputeRect sse_decode_box_autoadd_compute_rect(SseDeserializer deserializer);
+  KMeans? sse_decode_opt_box_autoadd_k_means(SseDeserializer deserializer);
 
   @protected
-  ComputeRect sse_decode_compute_rect(SseDeserializer deserializer);
+  Neighbours? sse_decode_opt_box_autoadd_neighbours(
+      SseDeserializer deserializer);
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  ProximityGraph? sse_decode_opt_box_autoadd_proximity_graph(
+      SseDeserializer deserializer);
 
   @protected
-  F32Array2 sse_decode_f_32_array_2(SseDeserializer deserializer);
+  ScreenPoints? sse_decode_opt_box_autoadd_screen_points(
+      SseDeserializer deserializer);
 
   @protected
-  List<F32Array2> sse_decode_list_f_32_array_2(SseDeserializer deserializer);
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+  int? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  VoronoiDiagram? sse_decode_opt_box_autoadd_voronoi_diagram(
+      SseDeserializer deserializer);
+
+  @protected
+  VoronoiRaster? sse_decode_opt_box_autoadd_voronoi_raster(
+      SseDeserializer deserializer);
 
   @protected
   List<F32Array2>? sse_decode_opt_list_f_32_array_2(
       SseDeserializer deserializer);
 
+  @protected
+  List<MarkerCluster>? sse_decode_opt_list_marker_cluster(
+      SseDeserializer deserializer);
+
+  @protected
+  Float32List? sse_decode_opt_list_prim_f_32_strict(
+      SseDeserializer deserializer);
+
+  @protected
+  Uint32List? sse_decode_opt_list_prim_u_32_strict(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ShapePolygon>? sse_decode_opt_list_shape_polygon(
+      SseDeserializer deserializer);
+
+  @protected
+  Predicate sse_decode_predicate(SseDeserializer deserializer);
+
+  @protected
+  ProximityGraph sse_decode_proximity_graph(SseDeserializer deserializer);
+
+  @protected
+  ScreenPoints sse_decode_screen_points(SseDeserializer deserializer);
+
+  @protected
+  ShapePolygon sse_decode_shape_polygon(SseDeserializer deserializer);
+
+  @protected
+  SpatialIndexKind sse_decode_spatial_index_kind(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  VoronoiDiagram sse_decode_voronoi_diagram(SseDeserializer deserializer);
+
+  @protected
+  VoronoiRaster sse_decode_voronoi_raster(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_adapter_report(AdapterReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_affine_transform(
+      AffineTransform self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_attribute_filter(
+      AttributeFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_attribute_values(
+      AttributeValues self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_adapter_report(
+      AdapterReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_affine_transform(
+      AffineTransform self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_attribute_values(
+      AttributeValues self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_camera(Camera self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_clustering(
+      Clustering self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_compute_rect(
       ComputeRect self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_delaunay_triangulation(
+      DelaunayTriangulation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_density_grid(
+      DensityGrid self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_dispatch_calibration(
+      DispatchCalibration self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_flat_screen_points(
+      FlatScreenPoints self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_heatmap_image(
+      HeatmapImage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_histogram_2_d(
+      Histogram2d self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_index_stats(
+      IndexStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_k_means(KMeans self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_neighbours(
+      Neighbours self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_predicate(
+      Predicate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_proximity_graph(
+      ProximityGraph self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_screen_points(
+      ScreenPoints self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_voronoi_diagram(
+      VoronoiDiagram self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_voronoi_raster(
+      VoronoiRaster self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_predicate(Predicate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_camera(Camera self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_clustering(Clustering self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_colormap(Colormap self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_compute_backend(
+      ComputeBackend self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_compute_limits(ComputeLimits self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_compute_metrics(
+      ComputeMetrics self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_compute_point(ComputePoint self, SseSerializer serializer);
+
   @protected
   void sse_encode_compute_rect(ComputeRect self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_delaunay_triangulation(
+      DelaunayTriangulation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_density_grid(DensityGrid self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_dispatch_calibration(
+      DispatchCalibration self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
 
   @protected
   void sse_encode_f_32_array_2(F32Array2 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_flat_screen_points(
+      FlatScreenPoints self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_heatmap_image(HeatmapImage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_histogram_2_d(Histogram2d self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_index_stats(IndexStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_k_means(KMeans self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_kernel_shape(KernelShape self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_kernel_support(KernelSupport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_affine_transform(
+      List<AffineTransform> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_attribute_filter(
+      List<AttributeFilter> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_compute_metrics(
+      List<ComputeMetrics> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_compute_point(
+      List<ComputePoint> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_f_32_array_2(
       List<F32Array2> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_kernel_support(
+      List<KernelSupport> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_list_prim_u_32_strict(
+      List<Uint32List> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_marker_cluster(
+      List<MarkerCluster> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_predicate(
+      List<Predicate> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_f_32_strict(
       Float32List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_prim_f_64_strict(
+      Float64List self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_i_32_strict(
+      Int32List self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+      Uint32List self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_shape_polygon(
+      List<ShapePolygon> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_marker_cluster(MarkerCluster self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_neighbours(Neighbours self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_adapter_report(
+      AdapterReport? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_clustering(
+      Clustering? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_delaunay_triangulation(
+      DelaunayTriangulation? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_density_grid(
+      DensityGrid? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_dispatch_calibration(
+      DispatchCalibration? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_flat_screen_points(
+      FlatScreenPoints? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_heatmap_image(
+      HeatmapImage? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_histogram_2_d(
+      Histogram2d? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_index_stats(
+      IndexStats? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_k_means(
+      KMeans? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_neighbours(
+      Neighbours? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_proximity_graph(
+      ProximityGraph? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_screen_points(
+      ScreenPoints? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_voronoi_diagram(
+      VoronoiDiagram? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_voronoi_raster(
+      VoronoiRaster? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_list_f_32_array_2(
       List<F32Array2>? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_list_marker_cluster(
+      List<MarkerCluster>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_prim_f_32_strict(
+      Float32List? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_prim_u_32_strict(
+      Uint32List? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_shape_polygon(
+      List<ShapePolygon>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_predicate(Predicate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_proximity_graph(
+      ProximityGraph self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_screen_points(ScreenPoints self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_shape_polygon(ShapePolygon self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_spatial_index_kind(
+      SpatialIndexKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_voronoi_diagram(
+      VoronoiDiagram self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_voronoi_raster(VoronoiRaster self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
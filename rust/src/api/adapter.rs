@@ -0,0 +1,140 @@
+use super::simple::{send_command, ComputeCommand, ComputeResponse};
+
+// Kernels and optional modes, with the features they need on top of compute shader support and the most
+// storage buffers any of their entry points binds. Pipelines derive their layout from the entry point, so only
+// the buffers it reads count; the indexed variants of the queries are what push these above 4.
+const KERNELS: &[(&str, wgpu::Features, u32)] = &[
+    ("rect", wgpu::Features::empty(), 4),
+    ("grid_index", wgpu::Features::empty(), 4),
+    ("bvh_index", wgpu::Features::empty(), 5),
+    ("query_rect", wgpu::Features::empty(), 6),
+    ("query_circle", wgpu::Features::empty(), 6),
+    ("query_polygon", wgpu::Features::empty(), 6),
+    ("knn", wgpu::Features::empty(), 6),
+    ("hit_test", wgpu::Features::empty(), 6),
+    ("radius_graph", wgpu::Features::empty(), 5),
+    ("dbscan", wgpu::Features::empty(), 5),
+    ("kmeans", wgpu::Features::empty(), 3),
+    ("convex_hull", wgpu::Features::empty(), 4),
+    ("jump_flood", wgpu::Features::empty(), 3),
+    ("heatmap", wgpu::Features::empty(), 3),
+    ("histogram", wgpu::Features::empty(), 4),
+    ("expression", wgpu::Features::empty(), 5),
+    ("transform", wgpu::Features::empty(), 4),
+    ("projection", wgpu::Features::empty(), 5),
+    ("timestamp_profiling", wgpu::Features::TIMESTAMP_QUERY, 0),
+];
+
+/// What the selected adapter is and what it can run. Meant for bug reports and for gating GPU only features.
+#[derive(Clone, Debug)]
+pub struct AdapterReport {
+    pub name: String,
+    pub vendor: u32,
+    pub device: u32,
+    /// `DiscreteGpu`, `IntegratedGpu`, `VirtualGpu`, `Cpu` or `Other`.
+    pub device_type: String,
+    /// `Vulkan`, `Metal`, `Dx12`, `Gl` or `BrowserWebGpu`.
+    pub backend: String,
+    pub driver: String,
+    pub driver_info: String,
+    pub compute_shaders: bool,
+    /// Features the adapter supports.
+    pub adapter_features: Vec<String>,
+    /// Features enabled on the device the compute thread uses.
+    pub device_features: Vec<String>,
+    pub adapter_limits: ComputeLimits,
+    pub device_limits: ComputeLimits,
+    pub kernels: Vec<KernelSupport>,
+}
+
+/// The subset of `wgpu::Limits` that bounds compute workloads.
+#[derive(Clone, Debug)]
+pub struct ComputeLimits {
+    pub max_buffer_size: u64,
+    pub max_storage_buffer_binding_size: u32,
+    pub max_storage_buffers_per_shader_stage: u32,
+    pub max_compute_workgroup_storage_size: u32,
+    pub max_compute_invocations_per_workgroup: u32,
+    pub max_compute_workgroup_size_x: u32,
+    pub max_compute_workgroups_per_dimension: u32,
+}
+
+impl From<&wgpu::Limits> for ComputeLimits {
+    fn from(limits: &wgpu::Limits) -> ComputeLimits {
+        ComputeLimits {
+            max_buffer_size: limits.max_buffer_size,
+            max_storage_buffer_binding_size: limits.max_storage_buffer_binding_size,
+            max_storage_buffers_per_shader_stage: limits.max_storage_buffers_per_shader_stage,
+            max_compute_workgroup_storage_size: limits.max_compute_workgroup_storage_size,
+            max_compute_invocations_per_workgroup: limits.max_compute_invocations_per_workgroup,
+            max_compute_workgroup_size_x: limits.max_compute_workgroup_size_x,
+            max_compute_workgroups_per_dimension: limits.max_compute_workgroups_per_dimension,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct KernelSupport {
+    pub name: String,
+    pub available: bool,
+    /// Features the device is missing for this kernel, empty when available.
+    pub missing_features: Vec<String>,
+    /// Storage buffers the kernel binds at once. It is unavailable when the adapter's
+    /// `max_storage_buffers_per_shader_stage` is lower.
+    pub storage_buffers: u32,
+}
+
+pub(crate) fn report(adapter: &wgpu::Adapter, device: &wgpu::Device) -> AdapterReport {
+    let info = adapter.get_info();
+    let compute_shaders = adapter
+        .get_downlevel_capabilities()
+        .flags
+        .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS);
+    let device_features = device.features();
+    let adapter_limits = adapter.limits();
+
+    AdapterReport {
+        name: info.name,
+        vendor: info.vendor,
+        device: info.device,
+        device_type: format!("{:?}", info.device_type),
+        backend: format!("{:?}", info.backend),
+        driver: info.driver,
+        driver_info: info.driver_info,
+        compute_shaders,
+        adapter_features: feature_names(adapter.features()),
+        device_features: feature_names(device_features),
+        adapter_limits: (&adapter_limits).into(),
+        device_limits: (&device.limits()).into(),
+        kernels: KERNELS
+            .iter()
+            .map(|&(name, required, storage_buffers)| {
+                let missing = required - device_features;
+                KernelSupport {
+                    name: name.to_string(),
+                    available: compute_shaders
+                        && missing.is_empty()
+                        && storage_buffers <= adapter_limits.max_storage_buffers_per_shader_stage,
+                    missing_features: feature_names(missing),
+                    storage_buffers,
+                }
+            })
+            .collect(),
+    }
+}
+
+fn feature_names(features: wgpu::Features) -> Vec<String> {
+    features
+        .iter_names()
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+pub async fn get_adapter_report() -> Option<AdapterReport> {
+    match send_command(ComputeCommand::DescribeAdapter).await {
+        ComputeResponse::Adapter(report) => Some(*report),
+        _ => None,
+    }
+}
@@ -0,0 +1,280 @@
+use super::{
+    delaunay::{self, next_halfedge, EMPTY},
+    query::QueryShape,
+    simple::{send_command, ComputeCommand, ComputeRect, ComputeResponse, Vec2},
+};
+
+/// One connected piece of an alpha shape, as point indices.
+#[derive(Clone, Debug)]
+pub struct ShapePolygon {
+    /// Outer ring, counter-clockwise with y pointing up (clockwise on screen).
+    pub exterior: Vec<u32>,
+    /// Rings around the holes, clockwise with y pointing up (counter-clockwise on screen).
+    pub holes: Vec<Vec<u32>>,
+}
+
+fn signed_area(points: &[Vec2], ring: &[u32]) -> f64 {
+    let mut area = 0.0;
+    for (k, &i) in ring.iter().enumerate() {
+        let a = points[i as usize];
+        let b = points[ring[(k + 1) % ring.len()] as usize];
+        area += a[0] as f64 * b[1] as f64 - b[0] as f64 * a[1] as f64;
+    }
+    area / 2.0
+}
+
+// Even-odd rule, like the polygon queries.
+fn contains(points: &[Vec2], ring: &[u32], point: [f64; 2]) -> bool {
+    let mut inside = false;
+    for (k, &i) in ring.iter().enumerate() {
+        let a = points[i as usize].map(f64::from);
+        let b = points[ring[(k + 1) % ring.len()] as usize].map(f64::from);
+        if (a[1] > point[1]) != (b[1] > point[1])
+            && point[0] < (b[0] - a[0]) * (point[1] - a[1]) / (b[1] - a[1]) + a[0]
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+// Keeps the Delaunay triangles whose circumradius is at most `alpha` and traces the boundary of their
+// union. Where pieces touch at a single point the rings are split there, so every ring is simple.
+pub(crate) fn alpha_shape_cpu(
+    points: &[Vec2],
+    candidates: &[u32],
+    alpha: f32,
+) -> Vec<ShapePolygon> {
+    let triangulation = delaunay::triangulate(points, candidates);
+    let triangles = &triangulation.triangles;
+    let halfedges = &triangulation.halfedges;
+    let limit = alpha as f64 * alpha as f64;
+    let kept: Vec<bool> = triangles
+        .chunks_exact(3)
+        .map(|t| {
+            alpha > 0.0
+                && delaunay::circumradius_squared(
+                    &points[t[0] as usize],
+                    &points[t[1] as usize],
+                    &points[t[2] as usize],
+                ) <= limit
+        })
+        .collect();
+    let boundary = |e: u32| {
+        let twin = halfedges[e as usize];
+        kept[e as usize / 3] && (twin == EMPTY || !kept[twin as usize / 3])
+    };
+
+    // Kept triangles run counter-clockwise, so outer rings do too and holes run the other way.
+    let mut visited = vec![false; triangles.len()];
+    let mut exteriors = Vec::new();
+    let mut holes = Vec::new();
+    for start in 0..triangles.len() as u32 {
+        if visited[start as usize] || !boundary(start) {
+            continue;
+        }
+        let mut ring = Vec::new();
+        let mut e = start;
+        loop {
+            visited[e as usize] = true;
+            ring.push(triangles[e as usize]);
+            // Turn around the end point through kept triangles to the boundary edge leaving it.
+            e = next_halfedge(e);
+            while !boundary(e) {
+                e = next_halfedge(halfedges[e as usize]);
+            }
+            if e == start {
+                break;
+            }
+        }
+        if signed_area(points, &ring) > 0.0 {
+            exteriors.push(ring);
+        } else {
+            holes.push(ring);
+        }
+    }
+
+    let areas: Vec<f64> = exteriors
+        .iter()
+        .map(|ring| signed_area(points, ring))
+        .collect();
+    let mut polygons: Vec<ShapePolygon> = exteriors
+        .into_iter()
+        .map(|exterior| ShapePolygon {
+            exterior,
+            holes: Vec::new(),
+        })
+        .collect();
+    // A hole belongs to the smallest outer ring around it. The middle of one of its edges is inside that
+    // ring and on no other ring, since rings never share edges.
+    for hole in holes {
+        let a = points[hole[0] as usize];
+        let b = points[hole[1] as usize];
+        let middle = [
+            (a[0] as f64 + b[0] as f64) / 2.0,
+            (a[1] as f64 + b[1] as f64) / 2.0,
+        ];
+        let owner = (0..polygons.len())
+            .filter(|&p| contains(points, &polygons[p].exterior, middle))
+            .min_by(|&p, &q| areas[p].total_cmp(&areas[q]));
+        if let Some(owner) = owner {
+            polygons[owner].holes.push(hole);
+        }
+    }
+    polygons
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Outline of `point_set` as an alpha shape: the union of the Delaunay triangles whose circumcircle has a
+/// radius of at most `alpha`, as polygons with holes. Smaller values follow the points more tightly and
+/// split sparse areas apart, points no kept triangle touches are left out. Coincident points resolve to the
+/// lowest index. `None` if the point set does not exist.
+pub async fn alpha_shape(point_set: u32, alpha: f32) -> Option<Vec<ShapePolygon>> {
+    shape(point_set, alpha, None).await
+}
+
+/// Like `alpha_shape`, over only the points of `point_set` inside `rect`.
+pub async fn alpha_shape_in_rect(
+    point_set: u32,
+    alpha: f32,
+    rect: ComputeRect,
+) -> Option<Vec<ShapePolygon>> {
+    shape(point_set, alpha, Some(QueryShape::Rect(rect))).await
+}
+
+/// Like `alpha_shape`, over only the points of `point_set` within `radius` of `center`.
+pub async fn alpha_shape_in_circle(
+    point_set: u32,
+    alpha: f32,
+    center: Vec2,
+    radius: f32,
+) -> Option<Vec<ShapePolygon>> {
+    shape(
+        point_set,
+        alpha,
+        Some(QueryShape::Circle { center, radius }),
+    )
+    .await
+}
+
+/// Like `alpha_shape`, over only the points of `point_set` inside `polygon` (even-odd rule).
+pub async fn alpha_shape_in_polygon(
+    point_set: u32,
+    alpha: f32,
+    polygon: Vec<Vec2>,
+) -> Option<Vec<ShapePolygon>> {
+    shape(point_set, alpha, Some(QueryShape::Polygon(polygon))).await
+}
+
+async fn shape(
+    point_set: u32,
+    alpha: f32,
+    selection: Option<QueryShape>,
+) -> Option<Vec<ShapePolygon>> {
+    match send_command(ComputeCommand::AlphaShape(point_set, alpha, selection)).await {
+        ComputeResponse::Polygons(polygons) => polygons,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn grid(size: usize, origin: Vec2) -> Vec<Vec2> {
+        (0..size * size)
+            .map(|i| [origin[0] + (i % size) as f32, origin[1] + (i / size) as f32])
+            .collect()
+    }
+
+    fn shape(points: &[Vec2], alpha: f32) -> Vec<ShapePolygon> {
+        let polygons =
+            alpha_shape_cpu(points, &(0..points.len() as u32).collect::<Vec<_>>(), alpha);
+        // Every ring is simple, runs the documented way and steps along edges of kept triangles.
+        for polygon in &polygons {
+            assert!(signed_area(points, &polygon.exterior) > 0.0);
+            assert!(polygon
+                .holes
+                .iter()
+                .all(|hole| signed_area(points, hole) < 0.0));
+            for ring in std::iter::once(&polygon.exterior).chain(&polygon.holes) {
+                assert_eq!(ring.iter().collect::<HashSet<_>>().len(), ring.len());
+                for (k, &i) in ring.iter().enumerate() {
+                    let (a, b) = (
+                        points[i as usize],
+                        points[ring[(k + 1) % ring.len()] as usize],
+                    );
+                    assert!(((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt() <= 2.0 * alpha);
+                }
+            }
+        }
+        polygons
+    }
+
+    #[test]
+    fn annulus() {
+        let mut points = Vec::new();
+        for ring in 0..6 {
+            let radius = 50.0 + ring as f32 * 2.0;
+            for k in 0..120 {
+                let (sin, cos) = (k as f32 * std::f32::consts::TAU / 120.0).sin_cos();
+                points.push([radius * cos, radius * sin]);
+            }
+        }
+        let polygons = shape(&points, 4.0);
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].holes.len(), 1);
+        assert!(polygons[0].exterior.iter().all(|&i| i >= 600));
+        assert!(polygons[0].holes[0].iter().all(|&i| i < 120));
+        assert_eq!(polygons[0].exterior.len(), 120);
+        assert_eq!(polygons[0].holes[0].len(), 120);
+
+        // Large enough to keep every triangle: the convex hull.
+        let polygons = shape(&points, 1e9);
+        assert_eq!(polygons.len(), 1);
+        assert!(polygons[0].holes.is_empty());
+        assert_eq!(polygons[0].exterior.len(), 120);
+    }
+
+    #[test]
+    fn separate_pieces() {
+        let mut points = grid(4, [0.0, 0.0]);
+        points.extend(grid(4, [10.0, 0.0]));
+        let polygons = shape(&points, 0.75);
+        assert_eq!(polygons.len(), 2);
+        for polygon in &polygons {
+            assert_eq!(polygon.exterior.len(), 12);
+            assert!(polygon.holes.is_empty());
+        }
+    }
+
+    // Two triangles meeting at a vertex give two rings through it rather than one figure eight.
+    #[test]
+    fn touching_pieces() {
+        let points = [
+            [0.0, 0.0],
+            [-2.0, -1.0],
+            [-2.0, 1.0],
+            [2.0, -1.0],
+            [2.0, 1.0],
+        ];
+        let polygons = shape(&points, 1.3);
+        assert_eq!(polygons.len(), 2);
+        for polygon in &polygons {
+            assert_eq!(polygon.exterior.len(), 3);
+            assert!(polygon.exterior.contains(&0));
+        }
+    }
+
+    #[test]
+    fn no_triangles() {
+        let points = grid(4, [0.0, 0.0]);
+        assert!(shape(&points, 0.0).is_empty());
+        assert!(shape(&points, -1.0).is_empty());
+        assert!(shape(&points, 0.5).is_empty());
+        assert!(shape(&points[..2], 10.0).is_empty());
+    }
+}
@@ -0,0 +1,384 @@
+use super::{
+    gpu,
+    simple::{send_command, ComputeCommand, ComputeResponse, WgpuCompute},
+};
+
+// Matches the filter kinds in `expression.wgsl`.
+const FILTER_RANGE: u32 = 0;
+const FILTER_ONE_OF: u32 = 1;
+const FILTER_BITMASK_ANY: u32 = 2;
+const FILTER_BITMASK_ALL: u32 = 3;
+
+/// Values of one attribute channel, one per point.
+#[derive(Clone, Debug)]
+pub enum AttributeValues {
+    F32(Vec<f32>),
+    U32(Vec<u32>),
+}
+
+impl AttributeValues {
+    fn len(&self) -> usize {
+        match self {
+            AttributeValues::F32(values) => values.len(),
+            AttributeValues::U32(values) => values.len(),
+        }
+    }
+}
+
+/// A condition on an attribute channel. Queries keep the points meeting all of their filters.
+#[derive(Clone, Debug)]
+pub enum AttributeFilter {
+    /// Values from `min` to `max`, both included. NaN never matches.
+    Range {
+        attribute: String,
+        min: f64,
+        max: f64,
+    },
+    /// Values equal to one of `values`.
+    OneOf { attribute: String, values: Vec<f64> },
+    /// Values sharing any bit with `mask`, or all of its bits with `all`. Only for `U32` channels.
+    Bitmask {
+        attribute: String,
+        mask: u32,
+        all: bool,
+    },
+}
+
+#[derive(Clone, Copy)]
+struct Filter {
+    kind: u32,
+    float: u32,
+    // Start of the channel in the attributes buffer.
+    offset: u32,
+    // Range of `OneOf` values in `values`.
+    first: u32,
+    count: u32,
+    // Bounds of a range, or the mask, as the bits of the channel's values.
+    low: u32,
+    high: u32,
+}
+
+// Filters resolved against the channels of a point set.
+#[derive(Default)]
+pub(crate) struct AttributeFilters {
+    filters: Vec<Filter>,
+    values: Vec<u32>,
+}
+
+impl AttributeFilters {
+    // Appends the filter count, then every filter as `kind, float, offset, low, high, count` followed by
+    // its `OneOf` values, the layout `passesFilters` in `expression.wgsl` reads.
+    pub(crate) fn encode(&self, constants: &mut Vec<u32>) {
+        constants.push(self.filters.len() as u32);
+        for filter in &self.filters {
+            constants.extend([
+                filter.kind,
+                filter.float,
+                filter.offset,
+                filter.low,
+                filter.high,
+                filter.count,
+            ]);
+            constants.extend(
+                &self.values[filter.first as usize..(filter.first + filter.count) as usize],
+            );
+        }
+    }
+
+    // Same tests as `passesFilters` in `expression.wgsl`.
+    pub(crate) fn matches(&self, channels: &Channels, index: u32) -> bool {
+        self.filters.iter().all(|filter| {
+            let bits = channels.values[(filter.offset + index) as usize];
+            let float = filter.float != 0;
+            let equal = |other: u32| {
+                if float {
+                    f32::from_bits(bits) == f32::from_bits(other)
+                } else {
+                    bits == other
+                }
+            };
+            match filter.kind {
+                FILTER_RANGE if float => {
+                    let value = f32::from_bits(bits);
+                    value >= f32::from_bits(filter.low) && value <= f32::from_bits(filter.high)
+                }
+                FILTER_RANGE => bits >= filter.low && bits <= filter.high,
+                FILTER_ONE_OF => self.values
+                    [filter.first as usize..(filter.first + filter.count) as usize]
+                    .iter()
+                    .any(|&other| equal(other)),
+                FILTER_BITMASK_ANY => bits & filter.low != 0,
+                _ => bits & filter.low == filter.low,
+            }
+        })
+    }
+}
+
+// Attribute channels of a point set, stored one after another in a single device buffer so any of them
+// can be read by a query kernel.
+pub(crate) struct Attributes {
+    channels: Channels,
+    pub(crate) buffer: wgpu::Buffer,
+}
+
+impl Attributes {
+    pub(crate) fn new(gpu: &WgpuCompute, point_count: usize) -> Attributes {
+        Attributes {
+            channels: Channels::new(point_count),
+            buffer: attributes_buffer(gpu, &[]),
+        }
+    }
+
+    pub(crate) fn channels(&self) -> &Channels {
+        &self.channels
+    }
+
+    // Adds or replaces a channel. False if it does not have one value per point.
+    pub(crate) fn set(&mut self, gpu: &WgpuCompute, name: String, values: AttributeValues) -> bool {
+        let set = self.channels.set(name, values);
+        if set {
+            self.buffer = attributes_buffer(gpu, &self.channels.values);
+        }
+        set
+    }
+
+    pub(crate) fn remove(&mut self, gpu: &WgpuCompute, name: &str) -> bool {
+        let removed = self.channels.remove(name);
+        if removed {
+            self.buffer = attributes_buffer(gpu, &self.channels.values);
+        }
+        removed
+    }
+
+    // Gives every channel `point_count` values, the added ones zero, and zeroes the values at `indices`.
+    pub(crate) fn reset_points(&mut self, gpu: &WgpuCompute, point_count: usize, indices: &[u32]) {
+        self.channels.reset_points(point_count, indices);
+        self.buffer = attributes_buffer(gpu, &self.channels.values);
+    }
+
+    pub(crate) fn copy(&self, gpu: &WgpuCompute) -> Attributes {
+        Attributes {
+            channels: self.channels.clone(),
+            buffer: attributes_buffer(gpu, &self.channels.values),
+        }
+    }
+
+    pub(crate) fn u32_channel(&self, name: &str) -> Option<(u32, &[u32])> {
+        self.channels.u32_channel(name)
+    }
+}
+
+// The CPU copy of the channels, which the CPU paths and the predicate compiler work from.
+#[derive(Clone)]
+pub(crate) struct Channels {
+    channels: Vec<(String, bool)>,
+    // Bits of the values, `point_count` per channel.
+    values: Vec<u32>,
+    point_count: usize,
+}
+
+impl Channels {
+    pub(crate) fn new(point_count: usize) -> Channels {
+        Channels {
+            channels: Vec::new(),
+            values: Vec::new(),
+            point_count,
+        }
+    }
+
+    pub(crate) fn set(&mut self, name: String, values: AttributeValues) -> bool {
+        if values.len() != self.point_count {
+            return false;
+        }
+        let float = matches!(values, AttributeValues::F32(_));
+        let bits: Vec<u32> = match values {
+            AttributeValues::F32(values) => values.iter().map(|value| value.to_bits()).collect(),
+            AttributeValues::U32(values) => values,
+        };
+        match self
+            .channels
+            .iter()
+            .position(|(channel, _)| *channel == name)
+        {
+            Some(channel) => {
+                self.channels[channel].1 = float;
+                let start = channel * self.point_count;
+                self.values[start..start + self.point_count].copy_from_slice(&bits);
+            }
+            None => {
+                self.channels.push((name, float));
+                self.values.extend(bits);
+            }
+        }
+        true
+    }
+
+    fn remove(&mut self, name: &str) -> bool {
+        let Some(channel) = self
+            .channels
+            .iter()
+            .position(|(channel, _)| channel == name)
+        else {
+            return false;
+        };
+        self.channels.remove(channel);
+        let start = channel * self.point_count;
+        self.values.drain(start..start + self.point_count);
+        true
+    }
+
+    fn reset_points(&mut self, point_count: usize, indices: &[u32]) {
+        let mut values = Vec::with_capacity(self.channels.len() * point_count);
+        for channel in self.values.chunks(self.point_count.max(1)) {
+            let start = values.len();
+            values.extend(channel.iter().take(point_count));
+            values.resize(start + point_count, 0);
+            for &i in indices {
+                values[start + i as usize] = 0;
+            }
+        }
+        self.values = values;
+        self.point_count = point_count;
+    }
+
+    // Start of a `U32` channel in the buffer and its values. `None` if it is missing or holds `F32` values.
+    fn u32_channel(&self, name: &str) -> Option<(u32, &[u32])> {
+        let channel = self
+            .channels
+            .iter()
+            .position(|(channel, float)| channel == name && !float)?;
+        let start = channel * self.point_count;
+        Some((start as u32, &self.values[start..start + self.point_count]))
+    }
+
+    // `None` when a filter names a missing channel or is a bitmask on an `F32` channel.
+    pub(crate) fn resolve(&self, filters: &[AttributeFilter]) -> Option<AttributeFilters> {
+        let mut resolved = AttributeFilters::default();
+        for filter in filters {
+            let attribute = match filter {
+                AttributeFilter::Range { attribute, .. }
+                | AttributeFilter::OneOf { attribute, .. }
+                | AttributeFilter::Bitmask { attribute, .. } => attribute,
+            };
+            let channel = self
+                .channels
+                .iter()
+                .position(|(channel, _)| channel == attribute)?;
+            let float = self.channels[channel].1;
+            let mut resolved_filter = Filter {
+                kind: FILTER_RANGE,
+                float: float as u32,
+                offset: (channel * self.point_count) as u32,
+                first: 0,
+                count: 0,
+                low: 0,
+                high: 0,
+            };
+            match filter {
+                AttributeFilter::Range { min, max, .. } => {
+                    (resolved_filter.low, resolved_filter.high) = if float {
+                        (f32_at_least(*min).to_bits(), f32_at_most(*max).to_bits())
+                    } else {
+                        u32_range(*min, *max)
+                    };
+                }
+                AttributeFilter::OneOf { values, .. } => {
+                    resolved_filter.kind = FILTER_ONE_OF;
+                    resolved_filter.first = resolved.values.len() as u32;
+                    resolved.values.extend(values.iter().filter_map(|&value| {
+                        if float {
+                            let narrowed = value as f32;
+                            (narrowed as f64 == value).then_some(narrowed.to_bits())
+                        } else {
+                            (value >= 0.0 && value <= u32::MAX as f64 && value.fract() == 0.0)
+                                .then_some(value as u32)
+                        }
+                    }));
+                    resolved_filter.count = resolved.values.len() as u32 - resolved_filter.first;
+                }
+                AttributeFilter::Bitmask { mask, all, .. } => {
+                    if float {
+                        return None;
+                    }
+                    resolved_filter.kind = if *all {
+                        FILTER_BITMASK_ALL
+                    } else {
+                        FILTER_BITMASK_ANY
+                    };
+                    resolved_filter.low = *mask;
+                }
+            }
+            resolved.filters.push(resolved_filter);
+        }
+        Some(resolved)
+    }
+}
+
+fn attributes_buffer(gpu: &WgpuCompute, values: &[u32]) -> wgpu::Buffer {
+    gpu::storage_buffer(
+        &gpu.device,
+        "Attributes Buffer",
+        values,
+        wgpu::BufferUsages::empty(),
+    )
+}
+
+// Smallest `f32` not below `value`, so the narrowed range keeps exactly the same values.
+fn f32_at_least(value: f64) -> f32 {
+    let narrowed = value as f32;
+    if (narrowed as f64) < value {
+        step_up(narrowed)
+    } else {
+        narrowed
+    }
+}
+
+fn f32_at_most(value: f64) -> f32 {
+    let narrowed = value as f32;
+    if (narrowed as f64) > value {
+        -step_up(-narrowed)
+    } else {
+        narrowed
+    }
+}
+
+// Next `f32` towards positive infinity, for values below the largest finite one.
+fn step_up(value: f32) -> f32 {
+    if value == 0.0 {
+        f32::from_bits(1)
+    } else if value > 0.0 {
+        f32::from_bits(value.to_bits() + 1)
+    } else {
+        f32::from_bits(value.to_bits() - 1)
+    }
+}
+
+// Integer bounds of a range, empty (low above high) when no `u32` is in it.
+fn u32_range(min: f64, max: f64) -> (u32, u32) {
+    let (low, high) = (min.max(0.0).ceil(), max.min(u32::MAX as f64).floor());
+    if low <= high {
+        (low as u32, high as u32)
+    } else {
+        (1, 0)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Adds an attribute channel named `name` to `point_set`, or replaces it, with one value per point. Channels
+/// stay on the device next to the points and are dropped when `update_point_set` changes the number of
+/// points. Returns false if the set does not exist or `values` does not have one value per point.
+pub async fn set_point_attribute(point_set: u32, name: String, values: AttributeValues) -> bool {
+    matches!(
+        send_command(ComputeCommand::SetAttribute(point_set, name, values)).await,
+        ComputeResponse::Done(true)
+    )
+}
+
+/// Removes the attribute channel `name` of `point_set`. Returns false if the set or channel does not exist.
+pub async fn remove_point_attribute(point_set: u32, name: String) -> bool {
+    matches!(
+        send_command(ComputeCommand::RemoveAttribute(point_set, name)).await,
+        ComputeResponse::Done(true)
+    )
+}
@@ -0,0 +1,204 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    simple::{Vec2, WgpuCompute},
+};
+
+const BVH_SHADER: &str = include_str!("bvh.wgsl");
+
+// Refitting keeps the Morton order of the last build, so bounds loosen as points wander off.
+// After this many refits in a row the next update rebuilds from scratch.
+const MAX_REFITS: u32 = 8;
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct BvhParams {
+    origin: Vec2,
+    scale: Vec2,
+    point_count: u32,
+    _padding: u32,
+}
+
+// Matches `Node` in `bvh.wgsl`.
+const NODE_SIZE: u64 = 32;
+
+// Linear BVH with one leaf per point. `sorted_indices` holds point indices in Morton order and
+// `nodes` the `point_count - 1` internal nodes, node 0 being the root.
+pub(crate) struct BvhIndex {
+    params: BvhParams,
+    pub(crate) sorted_indices: wgpu::Buffer,
+    pub(crate) nodes: wgpu::Buffer,
+    parents: wgpu::Buffer,
+    visits: wgpu::Buffer,
+    refits: u32,
+}
+
+impl BvhIndex {
+    pub(crate) fn build(
+        gpu: &WgpuCompute,
+        points: &[Vec2],
+        points_buffer: &wgpu::Buffer,
+    ) -> BvhIndex {
+        let device = &gpu.device;
+        let params = bvh_params(points);
+        let point_count = points.len() as u64;
+
+        let params_buffer = gpu::uniform_buffer(device, "BVH Params Buffer", &params);
+        let codes = gpu::empty_storage_buffer(
+            device,
+            "BVH Morton Codes Buffer",
+            point_count * 4,
+            wgpu::BufferUsages::empty(),
+        );
+        let sorted_indices = gpu::empty_storage_buffer(
+            device,
+            "BVH Sorted Indices Buffer",
+            point_count * 4,
+            wgpu::BufferUsages::COPY_SRC,
+        );
+        let nodes = gpu::empty_storage_buffer(
+            device,
+            "BVH Nodes Buffer",
+            point_count.saturating_sub(1).max(1) * NODE_SIZE,
+            wgpu::BufferUsages::COPY_SRC,
+        );
+        let parents = gpu::empty_storage_buffer(
+            device,
+            "BVH Parents Buffer",
+            (point_count * 2).saturating_sub(1).max(1) * 4,
+            wgpu::BufferUsages::empty(),
+        );
+        let visits = gpu::empty_storage_buffer(
+            device,
+            "BVH Visits Buffer",
+            point_count.saturating_sub(1).max(1) * 4,
+            wgpu::BufferUsages::COPY_DST,
+        );
+
+        let morton_pipeline = gpu.pipeline(BVH_SHADER, "morton_codes");
+        let hierarchy_pipeline = gpu.pipeline(BVH_SHADER, "build_hierarchy");
+        let morton_bind_group = gpu::bind_group(
+            device,
+            &morton_pipeline,
+            &[points_buffer, &params_buffer, &codes, &sorted_indices],
+        );
+        let hierarchy_bind_group = gpu::bind_group_at(
+            device,
+            &hierarchy_pipeline,
+            &[(1, &params_buffer), (2, &codes), (4, &nodes), (5, &parents)],
+        );
+
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        gpu::dispatch(
+            device,
+            &mut encoder,
+            &morton_pipeline,
+            &morton_bind_group,
+            params.point_count,
+        );
+        gpu::radix_sort(
+            gpu,
+            &mut encoder,
+            &codes,
+            &sorted_indices,
+            params.point_count,
+        );
+        gpu::dispatch(
+            device,
+            &mut encoder,
+            &hierarchy_pipeline,
+            &hierarchy_bind_group,
+            params.point_count,
+        );
+        let bvh = BvhIndex {
+            params,
+            sorted_indices,
+            nodes,
+            parents,
+            visits,
+            refits: 0,
+        };
+        bvh.encode_fit(gpu, &mut encoder, points_buffer, &params_buffer);
+        gpu.queue.submit(Some(encoder.finish()));
+        bvh
+    }
+
+    // Recomputes node bounds for points that moved without changing count. Returns false when the
+    // tree has been refitted too often and should be rebuilt instead.
+    pub(crate) fn refit(&mut self, gpu: &WgpuCompute, points_buffer: &wgpu::Buffer) -> bool {
+        if self.refits >= MAX_REFITS {
+            return false;
+        }
+        self.refits += 1;
+        let params_buffer = gpu::uniform_buffer(&gpu.device, "BVH Params Buffer", &self.params);
+        let mut encoder = gpu
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        self.encode_fit(gpu, &mut encoder, points_buffer, &params_buffer);
+        gpu.queue.submit(Some(encoder.finish()));
+        true
+    }
+
+    fn encode_fit(
+        &self,
+        gpu: &WgpuCompute,
+        encoder: &mut wgpu::CommandEncoder,
+        points_buffer: &wgpu::Buffer,
+        params_buffer: &wgpu::Buffer,
+    ) {
+        let pipeline = gpu.pipeline(BVH_SHADER, "fit_bounds");
+        let bind_group = gpu::bind_group_at(
+            &gpu.device,
+            &pipeline,
+            &[
+                (0, points_buffer),
+                (1, params_buffer),
+                (3, &self.sorted_indices),
+                (4, &self.nodes),
+                (5, &self.parents),
+                (6, &self.visits),
+            ],
+        );
+        encoder.clear_buffer(&self.visits, 0, None);
+        gpu::dispatch(
+            &gpu.device,
+            encoder,
+            &pipeline,
+            &bind_group,
+            self.params.point_count,
+        );
+    }
+
+    pub(crate) fn node_count(&self) -> u32 {
+        (self.params.point_count * 2).saturating_sub(1)
+    }
+}
+
+fn bvh_params(points: &[Vec2]) -> BvhParams {
+    let mut min = [f32::MAX; 2];
+    let mut max = [f32::MIN; 2];
+    for point in points {
+        for axis in 0..2 {
+            min[axis] = min[axis].min(point[axis]);
+            max[axis] = max[axis].max(point[axis]);
+        }
+    }
+    if points.is_empty() {
+        min = [0.0; 2];
+        max = [0.0; 2];
+    }
+
+    // Morton codes quantize each axis to 16 bits.
+    let extent = [
+        (max[0] - min[0]).max(f32::EPSILON),
+        (max[1] - min[1]).max(f32::EPSILON),
+    ];
+    BvhParams {
+        origin: min,
+        scale: [65535.0 / extent[0], 65535.0 / extent[1]],
+        point_count: points.len() as u32,
+        _padding: 0,
+    }
+}
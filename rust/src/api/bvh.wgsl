@@ -0,0 +1,181 @@
+// Linear BVH over a resident point set (Karras 2012). Points are ordered by the Morton code of their
+// position (`morton_codes`, then `radix_sort` in `sort.wgsl`), every internal node is emitted in
+// parallel from the sorted codes (`build_hierarchy`), and `fit_bounds` computes node bounds bottom up.
+// Node `0` is the root; child references with `LEAF` set are positions in `sortedIndices`.
+struct Bvh {
+    origin: vec2<f32>,
+    scale: vec2<f32>,
+    point_count: u32,
+    _padding: u32,
+};
+
+struct Node {
+    min: vec2<f32>,
+    max: vec2<f32>,
+    left: u32,
+    right: u32,
+    first: u32,
+    last: u32,
+};
+
+struct Bounds {
+    min: vec2<f32>,
+    max: vec2<f32>,
+};
+
+const LEAF: u32 = 0x80000000u;
+
+// Bounds of a leaf whose point is not finite, empty so that no query descends towards it.
+const EMPTY: f32 = 3.4e38;
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> bvh: Bvh;
+@group(0) @binding(2) var<storage, read_write> codes: array<u32>;
+@group(0) @binding(3) var<storage, read_write> sortedIndices: array<u32>;
+@group(0) @binding(4) var<storage, read_write> nodes: array<Node>;
+// Parent of every internal node, then of every leaf at `point_count - 1 + position`.
+@group(0) @binding(5) var<storage, read_write> parents: array<u32>;
+// Children of every internal node fitted so far, cleared before each fit.
+@group(0) @binding(6) var<storage, read_write> visits: array<atomic<u32>>;
+
+// Spreads the low 16 bits of `v` over the even bits.
+fn spread(v: u32) -> u32 {
+    var x = v & 0xffffu;
+    x = (x | (x << 8u)) & 0x00ff00ffu;
+    x = (x | (x << 4u)) & 0x0f0f0f0fu;
+    x = (x | (x << 2u)) & 0x33333333u;
+    x = (x | (x << 1u)) & 0x55555555u;
+    return x;
+}
+
+@compute
+@workgroup_size(64)
+fn morton_codes(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= bvh.point_count) {
+        return;
+    }
+    let cell = clamp((points[i] - bvh.origin) * bvh.scale, vec2<f32>(0.0), vec2<f32>(65535.0));
+    codes[i] = spread(u32(cell.x)) | (spread(u32(cell.y)) << 1u);
+    sortedIndices[i] = i;
+}
+
+// Length of the common prefix of the keys at `i` and `j`, with the index breaking ties between equal codes.
+fn delta(i: i32, j: i32) -> i32 {
+    if (j < 0 || j >= i32(bvh.point_count)) {
+        return -1;
+    }
+    let a = codes[i];
+    let b = codes[j];
+    if (a == b) {
+        return 32 + i32(countLeadingZeros(u32(i) ^ u32(j)));
+    }
+    return i32(countLeadingZeros(a ^ b));
+}
+
+@compute
+@workgroup_size(64)
+fn build_hierarchy(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let index = gid.x + gid.y * groups.x * 64u;
+    if (index + 1u >= bvh.point_count) {
+        return;
+    }
+    let i = i32(index);
+
+    // Direction of the range and its far end.
+    let d = select(-1, 1, delta(i, i + 1) > delta(i, i - 1));
+    let delta_min = delta(i, i - d);
+    var span_max = 2;
+    while (delta(i, i + span_max * d) > delta_min) {
+        span_max *= 2;
+    }
+    var span = 0;
+    for (var t = span_max / 2; t >= 1; t /= 2) {
+        if (delta(i, i + (span + t) * d) > delta_min) {
+            span += t;
+        }
+    }
+    let j = i + span * d;
+
+    // Split position, the last key sharing the longer prefix with `i`.
+    let delta_node = delta(i, j);
+    var split = 0;
+    var divisor = 2;
+    loop {
+        let t = (span + divisor - 1) / divisor;
+        if (delta(i, i + (split + t) * d) > delta_node) {
+            split += t;
+        }
+        if (t <= 1) {
+            break;
+        }
+        divisor *= 2;
+    }
+    let gamma = i + split * d + min(d, 0);
+
+    let first = u32(min(i, j));
+    let last = u32(max(i, j));
+    var node: Node;
+    node.left = select(u32(gamma), u32(gamma) | LEAF, first == u32(gamma));
+    node.right = select(u32(gamma + 1), u32(gamma + 1) | LEAF, last == u32(gamma + 1));
+    node.first = first;
+    node.last = last;
+    nodes[index] = node;
+    setParent(node.left, index);
+    setParent(node.right, index);
+}
+
+fn setParent(child: u32, parent: u32) {
+    if ((child & LEAF) != 0u) {
+        parents[bvh.point_count - 1u + (child & ~LEAF)] = parent;
+    } else {
+        parents[child] = parent;
+    }
+}
+
+fn isFinite(point: vec2<f32>) -> bool {
+    let exponents = (bitcast<vec2<u32>>(point) >> vec2<u32>(23u)) & vec2<u32>(0xffu);
+    return all(exponents != vec2<u32>(0xffu));
+}
+
+fn childBounds(child: u32) -> Bounds {
+    if ((child & LEAF) != 0u) {
+        let point = points[sortedIndices[child & ~LEAF]];
+        if (isFinite(point)) {
+            return Bounds(point, point);
+        }
+        return Bounds(vec2<f32>(EMPTY), vec2<f32>(-EMPTY));
+    }
+    let node = nodes[child];
+    return Bounds(node.min, node.max);
+}
+
+// One invocation per leaf climbs towards the root. The first child to reach a node stops there, and the
+// second fits the node from both children and carries on, so every node is fitted once after its children
+// and the total work is linear in the point count. Only the parents are needed, so this also refits a tree
+// whose points have moved since it was built.
+@compute
+@workgroup_size(64)
+fn fit_bounds(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let leaf = gid.x + gid.y * groups.x * 64u;
+    // A single point has no internal nodes.
+    if (leaf >= bvh.point_count || bvh.point_count < 2u) {
+        return;
+    }
+    var index = parents[bvh.point_count - 1u + leaf];
+    loop {
+        if (atomicAdd(&visits[index], 1u) == 0u) {
+            return;
+        }
+        var node = nodes[index];
+        let left = childBounds(node.left);
+        let right = childBounds(node.right);
+        node.min = min(left.min, right.min);
+        node.max = max(left.max, right.max);
+        nodes[index] = node;
+        if (index == 0u) {
+            return;
+        }
+        index = parents[index];
+    }
+}
@@ -0,0 +1,53 @@
+use super::{
+    query::QueryShape,
+    simple::{ComputeRect, Vec2},
+};
+
+pub(crate) fn is_point_inside_rect(point: &Vec2, rect: &ComputeRect) -> bool {
+    point[0] >= rect.min[0]
+        && point[1] >= rect.min[1]
+        && point[0] <= rect.max[0]
+        && point[1] <= rect.max[1]
+}
+
+// CPU counterpart of the `main` kernel in `shader.wgsl`, keeping the input order.
+pub(crate) fn points_in_rect(points: &[Vec2], rect: &ComputeRect) -> Vec<Vec2> {
+    points
+        .iter()
+        .filter(|point| is_point_inside_rect(point, rect))
+        .copied()
+        .collect()
+}
+
+pub(crate) fn is_point_inside_circle(point: &Vec2, center: &Vec2, radius: f32) -> bool {
+    let dx = point[0] - center[0];
+    let dy = point[1] - center[1];
+    dx * dx + dy * dy <= radius * radius
+}
+
+// Even-odd rule, matching `isPointInsidePolygon` in `query.wgsl`.
+pub(crate) fn is_point_inside_polygon(point: &Vec2, polygon: &[Vec2]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len().wrapping_sub(1);
+    for (i, a) in polygon.iter().enumerate() {
+        let b = &polygon[j];
+        if (a[1] > point[1]) != (b[1] > point[1]) {
+            let x = (b[0] - a[0]) * (point[1] - a[1]) / (b[1] - a[1]) + a[0];
+            if point[0] < x {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
+
+// CPU counterpart of `query_linear` in `query.wgsl`, returning indices in ascending order.
+pub(crate) fn points_in_shape(points: &[Vec2], shape: &QueryShape) -> Vec<u32> {
+    points
+        .iter()
+        .enumerate()
+        .filter(|(_, point)| shape.contains(point))
+        .map(|(i, _)| i as u32)
+        .collect()
+}
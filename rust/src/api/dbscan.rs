@@ -0,0 +1,349 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::{PointSet, PointSetIndex},
+    radius_graph::{self, GraphOverflow, ProximityGraph},
+    simple::{send_command, ComputeCommand, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const DBSCAN_SHADER: &str = include_str!("dbscan.wgsl");
+
+// `propagate` and `jump` rounds submitted between checks for convergence.
+const ROUNDS_PER_CHECK: u32 = 4;
+
+// Matches `NOISE` in `dbscan.wgsl`.
+const NOISE: u32 = u32::MAX;
+
+/// Cluster label per point, `-1` for noise. Clusters are numbered from 0 in the order their first
+/// point appears in the set, so the GPU and CPU paths label the same input identically.
+#[derive(Clone, Debug, Default)]
+pub struct Clustering {
+    pub labels: Vec<i32>,
+    pub cluster_count: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct DbscanParams {
+    point_count: u32,
+    min_points: u32,
+}
+
+// Renumbers raw labels (any `u32` per cluster, `NOISE` for noise) by first appearance.
+fn compact(raw: &[u32]) -> Clustering {
+    let mut ids = std::collections::HashMap::new();
+    let labels = raw
+        .iter()
+        .map(|&label| match label {
+            NOISE => -1,
+            _ => {
+                let next = ids.len() as i32;
+                *ids.entry(label).or_insert(next)
+            }
+        })
+        .collect();
+    Clustering {
+        labels,
+        cluster_count: ids.len() as u32,
+    }
+}
+
+fn find(parents: &mut [u32], mut i: u32) -> u32 {
+    while parents[i as usize] != i {
+        parents[i as usize] = parents[parents[i as usize] as usize];
+        i = parents[i as usize];
+    }
+    i
+}
+
+// Same rules as `dbscan.wgsl`, with union find over the core points.
+pub(crate) fn dbscan_cpu(
+    points: &[Vec2],
+    index: &PointSetIndex,
+    eps: f32,
+    min_points: u32,
+) -> Option<Clustering> {
+    let ProximityGraph {
+        offsets,
+        neighbours,
+    } = radius_graph::radius_graph_cpu(points, index, eps)?;
+    let row = |i: usize| &neighbours[offsets[i] as usize..offsets[i + 1] as usize];
+    let point_count = points.len();
+    let core: Vec<bool> = (0..point_count)
+        .map(|i| row(i).len() as u32 + 1 >= min_points && points[i].iter().all(|v| v.is_finite()))
+        .collect();
+
+    let mut parents: Vec<u32> = (0..point_count as u32).collect();
+    for i in (0..point_count).filter(|&i| core[i]) {
+        for &j in row(i).iter().filter(|&&j| core[j as usize]) {
+            let a = find(&mut parents, i as u32);
+            let b = find(&mut parents, j);
+            parents[a.max(b) as usize] = a.min(b);
+        }
+    }
+    let raw: Vec<u32> = (0..point_count)
+        .map(|i| {
+            if core[i] {
+                find(&mut parents, i as u32)
+            } else {
+                match row(i).iter().find(|&&j| core[j as usize]) {
+                    Some(&j) => find(&mut parents, j),
+                    None => NOISE,
+                }
+            }
+        })
+        .collect();
+    Some(compact(&raw))
+}
+
+pub(crate) async fn dbscan_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    eps: f32,
+    min_points: u32,
+) -> Result<Clustering, GraphOverflow> {
+    let device = &gpu.device;
+    let point_count = set.points.len() as u32;
+    if point_count == 0 {
+        return Ok(Clustering::default());
+    }
+    let graph = radius_graph::build_graph_buffers(gpu, set, eps).await?;
+
+    let params = DbscanParams {
+        point_count,
+        min_points,
+    };
+    let params_buffer = gpu::uniform_buffer(device, "DBSCAN Params Buffer", &params);
+    let labels = gpu::empty_storage_buffer(
+        device,
+        "DBSCAN Labels Buffer",
+        point_count as u64 * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let changed = gpu::empty_storage_buffer(
+        device,
+        "DBSCAN Changed Buffer",
+        4,
+        wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+    );
+    let core = gpu::empty_storage_buffer(
+        device,
+        "DBSCAN Core Buffer",
+        point_count as u64 * 4,
+        wgpu::BufferUsages::empty(),
+    );
+
+    let init_pipeline = gpu.pipeline(DBSCAN_SHADER, "init_labels");
+    let propagate_pipeline = gpu.pipeline(DBSCAN_SHADER, "propagate");
+    let jump_pipeline = gpu.pipeline(DBSCAN_SHADER, "jump");
+    let borders_pipeline = gpu.pipeline(DBSCAN_SHADER, "assign_borders");
+    let init_bind_group = gpu::bind_group_at(
+        device,
+        &init_pipeline,
+        &[
+            (0, &params_buffer),
+            (1, &graph.offsets),
+            (3, &labels),
+            (5, &core),
+            (6, &set.buffer),
+        ],
+    );
+    let propagate_bind_group = gpu::bind_group(
+        device,
+        &propagate_pipeline,
+        &[
+            &params_buffer,
+            &graph.offsets,
+            &graph.neighbours,
+            &labels,
+            &changed,
+        ],
+    );
+    let jump_bind_group =
+        gpu::bind_group_at(device, &jump_pipeline, &[(0, &params_buffer), (3, &labels)]);
+    let borders_bind_group = gpu::bind_group_at(
+        device,
+        &borders_pipeline,
+        &[
+            (0, &params_buffer),
+            (1, &graph.offsets),
+            (2, &graph.neighbours),
+            (3, &labels),
+            (5, &core),
+        ],
+    );
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &init_pipeline,
+        &init_bind_group,
+        point_count,
+    );
+    loop {
+        encoder.clear_buffer(&changed, 0, None);
+        for _ in 0..ROUNDS_PER_CHECK {
+            gpu::dispatch(
+                device,
+                &mut encoder,
+                &propagate_pipeline,
+                &propagate_bind_group,
+                point_count,
+            );
+            gpu::dispatch(
+                device,
+                &mut encoder,
+                &jump_pipeline,
+                &jump_bind_group,
+                point_count,
+            );
+        }
+        gpu.queue.submit(Some(encoder.finish()));
+        encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        if gpu::read_buffer::<u32>(device, &gpu.queue, &changed, 0, 1).await[0] == 0 {
+            break;
+        }
+    }
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &borders_pipeline,
+        &borders_bind_group,
+        point_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let raw = gpu::read_buffer::<u32>(device, &gpu.queue, &labels, 0, point_count as usize).await;
+    Ok(compact(&raw))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Density based clustering of `point_set`. A point with at least `min_points` points within `eps`
+/// (itself included) is a core point; core points within `eps` of each other share a cluster, and other
+/// points within `eps` of a core point join the cluster of the lowest indexed one. Points that are not
+/// finite are always noise. `None` if the point set does not exist, `eps` is negative or not finite, or
+/// the points have more than `u32::MAX` neighbours in total.
+pub async fn dbscan(point_set: u32, eps: f32, min_points: u32) -> Option<Clustering> {
+    match send_command(ComputeCommand::Dbscan(point_set, eps, min_points)).await {
+        ComputeResponse::Clusters(clustering) => clustering,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::rtree::RTreeIndex;
+
+    // Xorshift points in four loose blobs plus scattered noise on a lattice, so there are duplicates.
+    fn blob_points(count: usize) -> Vec<Vec2> {
+        let mut state = 0x1234_5679u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state >> 8
+        };
+        (0..count)
+            .map(|i| {
+                let (x, y) = ((next() % 12) as f32, (next() % 12) as f32);
+                match i % 5 {
+                    4 => [x * 8.0, y * 8.0],
+                    blob => [(blob % 2) as f32 * 60.0 + x, (blob / 2) as f32 * 60.0 + y],
+                }
+            })
+            .collect()
+    }
+
+    // Textbook DBSCAN: flood fill from every unvisited core point, borders taking the cluster of their
+    // lowest indexed core neighbour.
+    fn brute_force(points: &[Vec2], eps: f32, min_points: u32) -> Clustering {
+        let neighbours: Vec<Vec<usize>> = points
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                (0..points.len())
+                    .filter(|&j| {
+                        let b = &points[j];
+                        let (dx, dy) = (a[0] - b[0], a[1] - b[1]);
+                        j != i && dx * dx + dy * dy <= eps * eps
+                    })
+                    .collect()
+            })
+            .collect();
+        let core: Vec<bool> = neighbours
+            .iter()
+            .zip(points)
+            .map(|(row, point)| {
+                row.len() as u32 + 1 >= min_points && point.iter().all(|v| v.is_finite())
+            })
+            .collect();
+        let mut raw = vec![NOISE; points.len()];
+        for start in 0..points.len() {
+            if !core[start] || raw[start] != NOISE {
+                continue;
+            }
+            let mut stack = vec![start];
+            raw[start] = start as u32;
+            while let Some(i) = stack.pop() {
+                for &j in &neighbours[i] {
+                    if core[j] && raw[j] == NOISE {
+                        raw[j] = start as u32;
+                        stack.push(j);
+                    }
+                }
+            }
+        }
+        for i in (0..points.len()).filter(|&i| !core[i]) {
+            if let Some(&j) = neighbours[i].iter().find(|&&j| core[j]) {
+                raw[i] = raw[j];
+            }
+        }
+        compact(&raw)
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let points = blob_points(400);
+        let tree = PointSetIndex::RTree(Box::new(RTreeIndex::build(&points)));
+        for (eps, min_points) in [(1.0, 4), (1.5, 6), (3.0, 2), (0.0, 2), (10.0, 1)] {
+            let expected = brute_force(&points, eps, min_points);
+            for index in [&PointSetIndex::None, &tree] {
+                let clustering = dbscan_cpu(&points, index, eps, min_points).unwrap();
+                assert_eq!(
+                    clustering.labels, expected.labels,
+                    "eps {eps}, {min_points}"
+                );
+                assert_eq!(clustering.cluster_count, expected.cluster_count);
+            }
+        }
+    }
+
+    #[test]
+    fn small_inputs() {
+        let empty = dbscan_cpu(&[], &PointSetIndex::None, 1.0, 2).unwrap();
+        assert!(empty.labels.is_empty());
+        assert_eq!(empty.cluster_count, 0);
+
+        // Duplicates are within any `eps` of each other, and points that are not finite are always noise.
+        let points = [
+            [f32::NAN, 0.0],
+            [5.0, 5.0],
+            [0.0, 0.0],
+            [5.0, 5.0],
+            [0.0, 0.0],
+            [f32::INFINITY; 2],
+            [9.0, 9.0],
+        ];
+        let clustering = dbscan_cpu(&points, &PointSetIndex::None, 0.0, 2).unwrap();
+        assert_eq!(clustering.labels, [-1, 0, 1, 0, 1, -1, -1]);
+        assert_eq!(clustering.cluster_count, 2);
+        // Even when a single point makes a cluster.
+        let clustering = dbscan_cpu(&points, &PointSetIndex::None, 1.0, 1).unwrap();
+        assert_eq!(clustering.labels, [-1, 0, 1, 0, 1, -1, 2]);
+    }
+}
@@ -0,0 +1,89 @@
+// DBSCAN over a proximity graph from `radius_graph.wgsl`. Core points (at least `min_points` points
+// within `eps`, counting themselves) start labelled with their own index; `propagate` and `jump`
+// repeat until every core point carries the lowest index of its connected component. Border points
+// then join the cluster of their lowest indexed core neighbour, everything else stays `NOISE`. Points
+// that are not finite have no neighbours and are never core, so they are always `NOISE`.
+struct Dbscan {
+    point_count: u32,
+    min_points: u32,
+};
+
+const NOISE: u32 = 0xffffffffu;
+
+@group(0) @binding(0) var<uniform> dbscan: Dbscan;
+@group(0) @binding(1) var<storage, read> offsets: array<u32>;
+@group(0) @binding(2) var<storage, read> neighbours: array<u32>;
+@group(0) @binding(3) var<storage, read_write> labels: array<atomic<u32>>;
+@group(0) @binding(4) var<storage, read_write> changed: atomic<u32>;
+@group(0) @binding(5) var<storage, read_write> corePoints: array<u32>;
+@group(0) @binding(6) var<storage, read> points: array<vec2<f32>>;
+
+fn isFinite(point: vec2<f32>) -> bool {
+    let exponents = (bitcast<vec2<u32>>(point) >> vec2<u32>(23u)) & vec2<u32>(0xffu);
+    return all(exponents != vec2<u32>(0xffu));
+}
+
+@compute
+@workgroup_size(64)
+fn init_labels(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= dbscan.point_count) {
+        return;
+    }
+    let is_core = offsets[i + 1u] - offsets[i] + 1u >= dbscan.min_points && isFinite(points[i]);
+    corePoints[i] = u32(is_core);
+    atomicStore(&labels[i], select(NOISE, i, is_core));
+}
+
+// Non-core points are `NOISE`, the largest label, so taking the minimum skips them.
+@compute
+@workgroup_size(64)
+fn propagate(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= dbscan.point_count) {
+        return;
+    }
+    let label = atomicLoad(&labels[i]);
+    if (label == NOISE) {
+        return;
+    }
+    var lowest = label;
+    for (var k = offsets[i]; k < offsets[i + 1u]; k++) {
+        lowest = min(lowest, atomicLoad(&labels[neighbours[k]]));
+    }
+    if (lowest < label) {
+        atomicMin(&labels[i], lowest);
+        // Hook the old label's root as well, which merges whole trees per round.
+        atomicMin(&labels[label], lowest);
+        atomicStore(&changed, 1u);
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn jump(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= dbscan.point_count) {
+        return;
+    }
+    let label = atomicLoad(&labels[i]);
+    if (label != NOISE) {
+        atomicMin(&labels[i], atomicLoad(&labels[label]));
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn assign_borders(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= dbscan.point_count || corePoints[i] == 1u) {
+        return;
+    }
+    for (var k = offsets[i]; k < offsets[i + 1u]; k++) {
+        let j = neighbours[k];
+        if (corePoints[j] == 1u) {
+            atomicStore(&labels[i], atomicLoad(&labels[j]));
+            return;
+        }
+    }
+}
@@ -0,0 +1,541 @@
+use super::{
+    predicates::{incircle, orient2d},
+    simple::{send_command, ComputeCommand, ComputeResponse, Vec2},
+};
+
+// Marks a half-edge on the convex hull, with no triangle on its other side.
+pub(crate) const EMPTY: u32 = u32::MAX;
+
+// Delaunay triangulation in the half-edge layout of Delaunator: triangle `t` is made of half-edges
+// `3t`, `3t + 1` and `3t + 2`, half-edge `e` starts at point `triangles[e]` and `halfedges[e]` is its twin
+// in the adjacent triangle. Triangles run counter-clockwise with y pointing up.
+pub(crate) struct Triangulation {
+    // Point indices, three per triangle.
+    pub(crate) triangles: Vec<u32>,
+    pub(crate) halfedges: Vec<u32>,
+    // Convex hull, counter-clockwise (y up), including points on its edges.
+    pub(crate) hull: Vec<u32>,
+}
+
+pub(crate) fn next_halfedge(e: u32) -> u32 {
+    if e % 3 == 2 {
+        e - 2
+    } else {
+        e + 1
+    }
+}
+
+fn distance_squared(a: &Vec2, b: &Vec2) -> f64 {
+    let dx = a[0] as f64 - b[0] as f64;
+    let dy = a[1] as f64 - b[1] as f64;
+    dx * dx + dy * dy
+}
+
+// Offset from `a` to the circumcenter of `a`, `b`, `c`. Infinite or NaN when they are collinear.
+fn circumcenter_offset(a: &Vec2, b: &Vec2, c: &Vec2) -> (f64, f64) {
+    let (dx, dy) = (b[0] as f64 - a[0] as f64, b[1] as f64 - a[1] as f64);
+    let (ex, ey) = (c[0] as f64 - a[0] as f64, c[1] as f64 - a[1] as f64);
+    let bl = dx * dx + dy * dy;
+    let cl = ex * ex + ey * ey;
+    let d = 0.5 / (dx * ey - dy * ex);
+    ((ey * bl - dy * cl) * d, (dx * cl - ex * bl) * d)
+}
+
+pub(crate) fn circumradius_squared(a: &Vec2, b: &Vec2, c: &Vec2) -> f64 {
+    let (x, y) = circumcenter_offset(a, b, c);
+    x * x + y * y
+}
+
+pub(crate) fn circumcenter(a: &Vec2, b: &Vec2, c: &Vec2) -> [f64; 2] {
+    let (x, y) = circumcenter_offset(a, b, c);
+    [a[0] as f64 + x, a[1] as f64 + y]
+}
+
+// Monotonic in the angle of (dx, dy), in [0, 1).
+fn pseudo_angle(dx: f64, dy: f64) -> f64 {
+    let p = dx / (dx.abs() + dy.abs());
+    (if dy > 0.0 { 3.0 - p } else { 1.0 + p }) / 4.0
+}
+
+// Drops non-finite points and all but the lowest index of coincident points, sorted by x, then y.
+fn unique(points: &[Vec2], candidates: &[u32]) -> Vec<u32> {
+    let mut sorted: Vec<u32> = candidates
+        .iter()
+        .copied()
+        .filter(|&i| points[i as usize].iter().all(|v| v.is_finite()))
+        .collect();
+    sorted.sort_unstable_by(|&a, &b| {
+        let (p, q) = (&points[a as usize], &points[b as usize]);
+        p[0].total_cmp(&q[0])
+            .then(p[1].total_cmp(&q[1]))
+            .then(a.cmp(&b))
+    });
+    sorted.dedup_by(|a, b| points[*a as usize] == points[*b as usize]);
+    sorted
+}
+
+struct Builder<'a> {
+    points: Vec<&'a Vec2>,
+    triangles: Vec<u32>,
+    halfedges: Vec<u32>,
+    hull_start: u32,
+    hull_previous: Vec<u32>,
+    hull_next: Vec<u32>,
+    hull_triangle: Vec<u32>,
+    hull_hash: Vec<u32>,
+    center: [f64; 2],
+    edge_stack: Vec<u32>,
+}
+
+impl Builder<'_> {
+    fn hash_key(&self, point: &Vec2) -> usize {
+        let angle = pseudo_angle(
+            point[0] as f64 - self.center[0],
+            point[1] as f64 - self.center[1],
+        );
+        ((angle * self.hull_hash.len() as f64) as usize) % self.hull_hash.len()
+    }
+
+    fn link(&mut self, a: u32, b: u32) {
+        self.halfedges[a as usize] = b;
+        if b != EMPTY {
+            self.halfedges[b as usize] = a;
+        }
+    }
+
+    fn add_triangle(&mut self, i0: u32, i1: u32, i2: u32, a: u32, b: u32, c: u32) -> u32 {
+        let t = self.triangles.len() as u32;
+        self.triangles.extend([i0, i1, i2]);
+        self.halfedges.extend([EMPTY; 3]);
+        self.link(t, a);
+        self.link(t + 1, b);
+        self.link(t + 2, c);
+        t
+    }
+
+    // Flips edges from half-edge `a` outward until every triangle around it is locally Delaunay. Like
+    // Delaunator, returns the predecessor of the last half-edge checked.
+    fn legalize(&mut self, mut a: u32) -> u32 {
+        let mut ar;
+        loop {
+            let b = self.halfedges[a as usize];
+            let a0 = a - a % 3;
+            ar = a0 + (a + 2) % 3;
+            if b == EMPTY {
+                match self.edge_stack.pop() {
+                    Some(next) => {
+                        a = next;
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+            let b0 = b - b % 3;
+            let al = a0 + (a + 1) % 3;
+            let bl = b0 + (b + 2) % 3;
+            let p0 = self.triangles[ar as usize];
+            let pr = self.triangles[a as usize];
+            let pl = self.triangles[al as usize];
+            let p1 = self.triangles[bl as usize];
+            let illegal = incircle(
+                self.points[p0 as usize],
+                self.points[pr as usize],
+                self.points[pl as usize],
+                self.points[p1 as usize],
+            ) > 0.0;
+            if !illegal {
+                match self.edge_stack.pop() {
+                    Some(next) => {
+                        a = next;
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+            self.triangles[a as usize] = p1;
+            self.triangles[b as usize] = p0;
+            let hbl = self.halfedges[bl as usize];
+            // The flipped edge was on the hull, so the hull has to point at its new half-edge.
+            if hbl == EMPTY {
+                let mut e = self.hull_start;
+                loop {
+                    if self.hull_triangle[e as usize] == bl {
+                        self.hull_triangle[e as usize] = a;
+                        break;
+                    }
+                    e = self.hull_previous[e as usize];
+                    if e == self.hull_start {
+                        break;
+                    }
+                }
+            }
+            self.link(a, hbl);
+            self.link(b, self.halfedges[ar as usize]);
+            self.link(ar, bl);
+            self.edge_stack.push(b0 + (b + 1) % 3);
+        }
+        ar
+    }
+
+    // Right of the hull edge from `from` to `to`, so that edge is visible from `point`.
+    fn sees(&self, point: &Vec2, from: u32, to: u32) -> bool {
+        orient2d(self.points[from as usize], self.points[to as usize], point) < 0.0
+    }
+
+    fn add_point(&mut self, i: u32) {
+        let point = self.points[i as usize];
+        let key = self.hash_key(point);
+        let size = self.hull_hash.len();
+        let mut start = 0;
+        for j in 0..size {
+            start = self.hull_hash[(key + j) % size];
+            if start != EMPTY && start != self.hull_next[start as usize] {
+                break;
+            }
+        }
+        start = self.hull_previous[start as usize];
+        let mut e = start;
+        loop {
+            let q = self.hull_next[e as usize];
+            if self.sees(point, e, q) {
+                break;
+            }
+            e = q;
+            // Inside the hull, which exact predicates only allow for points the sort order misplaced.
+            if e == start {
+                return;
+            }
+        }
+
+        let t = self.add_triangle(
+            e,
+            i,
+            self.hull_next[e as usize],
+            EMPTY,
+            EMPTY,
+            self.hull_triangle[e as usize],
+        );
+        self.hull_triangle[i as usize] = self.legalize(t + 2);
+        self.hull_triangle[e as usize] = t;
+
+        let mut n = self.hull_next[e as usize];
+        loop {
+            let q = self.hull_next[n as usize];
+            if !self.sees(point, n, q) {
+                break;
+            }
+            let t = self.add_triangle(
+                n,
+                i,
+                q,
+                self.hull_triangle[i as usize],
+                EMPTY,
+                self.hull_triangle[n as usize],
+            );
+            self.hull_triangle[i as usize] = self.legalize(t + 2);
+            self.hull_next[n as usize] = n;
+            n = q;
+        }
+
+        if e == start {
+            loop {
+                let q = self.hull_previous[e as usize];
+                if !self.sees(point, q, e) {
+                    break;
+                }
+                let t = self.add_triangle(
+                    q,
+                    i,
+                    e,
+                    EMPTY,
+                    self.hull_triangle[e as usize],
+                    self.hull_triangle[q as usize],
+                );
+                self.legalize(t + 2);
+                self.hull_triangle[q as usize] = t;
+                self.hull_next[e as usize] = e;
+                e = q;
+            }
+        }
+
+        self.hull_start = e;
+        self.hull_previous[i as usize] = e;
+        self.hull_next[e as usize] = i;
+        self.hull_previous[n as usize] = i;
+        self.hull_next[i as usize] = n;
+        let key = self.hash_key(point);
+        self.hull_hash[key] = i;
+        let key = self.hash_key(self.points[e as usize]);
+        self.hull_hash[key] = e;
+    }
+}
+
+// Sweep-hull triangulation of the `candidates` of `points` (Delaunator's algorithm) with exact predicates.
+// Coincident points are triangulated once, under the lowest index, and non-finite points are skipped.
+// Fewer than three distinct points, or all of them collinear, give no triangles and a hull listing the
+// points in order along their line.
+pub(crate) fn triangulate(points: &[Vec2], candidates: &[u32]) -> Triangulation {
+    let ids = unique(points, candidates);
+    let local: Vec<&Vec2> = ids.iter().map(|&i| &points[i as usize]).collect();
+    let n = local.len();
+    let empty = Triangulation {
+        triangles: Vec::new(),
+        halfedges: Vec::new(),
+        hull: ids.clone(),
+    };
+    if n < 3 {
+        return empty;
+    }
+
+    // Seed with the point closest to the middle of the bounds, its nearest neighbour, and the point
+    // completing the smallest circumcircle with them.
+    let (mut min, mut max) = ([f64::MAX; 2], [f64::MIN; 2]);
+    for point in &local {
+        for axis in 0..2 {
+            min[axis] = min[axis].min(point[axis] as f64);
+            max[axis] = max[axis].max(point[axis] as f64);
+        }
+    }
+    let middle = [
+        ((min[0] + max[0]) / 2.0) as f32,
+        ((min[1] + max[1]) / 2.0) as f32,
+    ];
+    let closest = |to: &Vec2, skip: &[usize]| {
+        (0..n)
+            .filter(|i| !skip.contains(i))
+            .min_by(|&a, &b| {
+                distance_squared(local[a], to).total_cmp(&distance_squared(local[b], to))
+            })
+            .unwrap()
+    };
+    let i0 = closest(&middle, &[]);
+    let mut i1 = closest(local[i0], &[i0]);
+    let mut i2 = None;
+    let mut min_radius = f64::INFINITY;
+    for i in 0..n {
+        if i == i0 || i == i1 {
+            continue;
+        }
+        let radius = circumradius_squared(local[i0], local[i1], local[i]);
+        if radius < min_radius && orient2d(local[i0], local[i1], local[i]) != 0.0 {
+            i2 = Some(i);
+            min_radius = radius;
+        }
+    }
+    let Some(mut i2) = i2 else {
+        return empty;
+    };
+    if orient2d(local[i0], local[i1], local[i2]) < 0.0 {
+        std::mem::swap(&mut i1, &mut i2);
+    }
+    let center = circumcenter(local[i0], local[i1], local[i2]);
+
+    let distances: Vec<f64> = local
+        .iter()
+        .map(|point| {
+            let dx = point[0] as f64 - center[0];
+            let dy = point[1] as f64 - center[1];
+            dx * dx + dy * dy
+        })
+        .collect();
+    let mut order: Vec<u32> = (0..n as u32).collect();
+    order.sort_unstable_by(|&a, &b| {
+        distances[a as usize]
+            .total_cmp(&distances[b as usize])
+            .then(a.cmp(&b))
+    });
+
+    let mut builder = Builder {
+        points: local,
+        triangles: Vec::with_capacity((2 * n - 5) * 3),
+        halfedges: Vec::with_capacity((2 * n - 5) * 3),
+        hull_start: i0 as u32,
+        hull_previous: vec![0; n],
+        hull_next: vec![0; n],
+        hull_triangle: vec![0; n],
+        hull_hash: vec![EMPTY; (n as f64).sqrt().ceil() as usize],
+        center,
+        edge_stack: Vec::new(),
+    };
+    let (i0, i1, i2) = (i0 as u32, i1 as u32, i2 as u32);
+    for (t, (a, b)) in [(i0, i1), (i1, i2), (i2, i0)].into_iter().enumerate() {
+        builder.hull_next[a as usize] = b;
+        builder.hull_previous[b as usize] = a;
+        builder.hull_triangle[a as usize] = t as u32;
+        let key = builder.hash_key(builder.points[a as usize]);
+        builder.hull_hash[key] = a;
+    }
+    builder.add_triangle(i0, i1, i2, EMPTY, EMPTY, EMPTY);
+
+    for i in order {
+        if i != i0 && i != i1 && i != i2 {
+            builder.add_point(i);
+        }
+    }
+
+    let mut hull = Vec::new();
+    let mut e = builder.hull_start;
+    loop {
+        hull.push(ids[e as usize]);
+        e = builder.hull_next[e as usize];
+        if e == builder.hull_start {
+            break;
+        }
+    }
+    Triangulation {
+        triangles: builder.triangles.iter().map(|&i| ids[i as usize]).collect(),
+        halfedges: builder.halfedges,
+        hull,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Delaunay triangulation of a point set, as point indices.
+#[derive(Clone, Debug)]
+pub struct DelaunayTriangulation {
+    /// Three indices per triangle, counter-clockwise with y pointing up (clockwise on screen).
+    pub triangles: Vec<u32>,
+    /// Convex hull in the same winding, including points lying on its edges. Points in order along their
+    /// line when they are all collinear.
+    pub hull: Vec<u32>,
+}
+
+/// Delaunay triangulation of `point_set`, using exact orientation and in-circle tests so nearly degenerate
+/// input still triangulates consistently. Coincident points are triangulated once, under the lowest index,
+/// and non-finite points are left out. `None` if the point set does not exist.
+pub async fn delaunay(point_set: u32) -> Option<DelaunayTriangulation> {
+    match send_command(ComputeCommand::Delaunay(point_set)).await {
+        ComputeResponse::Triangulation(triangulation) => triangulation,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    // Xorshift points in `[0, scale)`, so failures reproduce.
+    fn random_points(count: usize, scale: f32) -> Vec<Vec2> {
+        let mut state = 0x9e37_79b9u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1 << 24) as f32 * scale
+        };
+        (0..count).map(|_| [next(), next()]).collect()
+    }
+
+    // Checks the layout and the Delaunay property of the triangulation of all `points`, and that every
+    // distinct finite point made it in.
+    fn check(points: &[Vec2]) -> Triangulation {
+        let all: Vec<u32> = (0..points.len() as u32).collect();
+        let triangulation = triangulate(points, &all);
+        let Triangulation {
+            triangles,
+            halfedges,
+            hull,
+        } = &triangulation;
+        let point = |e: u32| &points[triangles[e as usize] as usize];
+        assert_eq!(triangles.len(), halfedges.len());
+
+        let used: HashSet<u32> = triangles.iter().copied().collect();
+        assert_eq!(used, unique(points, &all).into_iter().collect());
+
+        for t in triangles.chunks(3) {
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| &points[i as usize]);
+            assert!(orient2d(a, b, c) > 0.0, "{t:?} is not counter-clockwise");
+        }
+        let mut boundary = 0;
+        for (e, &twin) in halfedges.iter().enumerate() {
+            let e = e as u32;
+            if twin == EMPTY {
+                boundary += 1;
+                continue;
+            }
+            assert_eq!(halfedges[twin as usize], e);
+            assert_eq!(point(e), point(next_halfedge(twin)));
+            assert_eq!(point(twin), point(next_halfedge(e)));
+            // The point across the edge lies on or outside the circumcircle.
+            let triangle = e - e % 3;
+            let opposite = point(next_halfedge(next_halfedge(twin)));
+            assert!(
+                incircle(
+                    point(triangle),
+                    point(triangle + 1),
+                    point(triangle + 2),
+                    opposite
+                ) <= 0.0,
+                "edge {e} is not locally Delaunay"
+            );
+        }
+        assert_eq!(boundary, hull.len());
+        // Euler's formula for a triangulated convex polygon with every boundary point on the hull.
+        assert_eq!(triangles.len() / 3, 2 * used.len() - hull.len() - 2);
+        triangulation
+    }
+
+    #[test]
+    fn random() {
+        check(&random_points(2000, 100.0));
+    }
+
+    // Every square of the grid has four cocircular corners.
+    #[test]
+    fn integer_grid() {
+        let grid: Vec<Vec2> = (0..900)
+            .map(|i| [(i % 30) as f32, (i / 30) as f32])
+            .collect();
+        let triangulation = check(&grid);
+        assert_eq!(triangulation.triangles.len() / 3, 2 * 29 * 29);
+        assert_eq!(triangulation.hull.len(), 4 * 29);
+    }
+
+    // Integer points of the circle of radius 65 around its centre, all exactly cocircular.
+    #[test]
+    fn cocircular() {
+        let mut points = vec![[0.0, 0.0]];
+        for x in -65i32..=65 {
+            for y in -65i32..=65 {
+                if x * x + y * y == 65 * 65 {
+                    points.push([x as f32, y as f32]);
+                }
+            }
+        }
+        let triangulation = check(&points);
+        assert_eq!(triangulation.hull.len(), points.len() - 1);
+    }
+
+    // Coordinates a few ulps apart far from the origin.
+    #[test]
+    fn offset() {
+        let points: Vec<Vec2> = random_points(1000, 1.0)
+            .iter()
+            .map(|p| [1e6 + p[0], 1e6 + p[1]])
+            .collect();
+        check(&points);
+    }
+
+    #[test]
+    fn duplicates_and_non_finite() {
+        let mut points = random_points(500, 10.0);
+        points.extend(random_points(500, 10.0));
+        points.push([f32::NAN, 1.0]);
+        points.push([f32::INFINITY, 0.0]);
+        let triangulation = check(&points);
+        assert!(triangulation.triangles.iter().all(|&i| i < 500));
+    }
+
+    #[test]
+    fn collinear() {
+        let points: Vec<Vec2> = (0..50)
+            .map(|i| [(49 - i) as f32, (49 - i) as f32])
+            .collect();
+        let triangulation = triangulate(&points, &(0..50).collect::<Vec<_>>());
+        assert!(triangulation.triangles.is_empty());
+        assert_eq!(triangulation.hull, (0..50).rev().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,437 @@
+use std::{sync::RwLock, time::Duration};
+
+use web_time::Instant;
+
+use super::{
+    cpu,
+    point_set::PointSet,
+    profiling,
+    simple::{send_command, ComputeCommand, ComputeRect, ComputeResponse, Vec2, WgpuCompute},
+};
+
+lazy_static::lazy_static! {
+    static ref BACKEND: RwLock<ComputeBackend> = RwLock::new(ComputeBackend::Auto);
+}
+
+const CALIBRATION_SMALL: usize = 1_024;
+const CALIBRATION_LARGE: usize = 32_768;
+const CALIBRATION_RUNS: usize = 3;
+
+// Most bytes a grid or raster request may produce. Larger requests fail before either backend runs, since
+// the CPU takes over whatever the GPU refuses and would otherwise allocate without bound.
+pub(crate) const MAX_OUTPUT_BYTES: u64 = 256 << 20;
+// Most point sized steps of work the CPU takes on for a single request, about a second. Larger requests
+// go to the GPU whenever there is one, whatever the calibration says.
+pub(crate) const MAX_CPU_WORK: u64 = 1 << 30;
+
+/// Where queries are executed. `Auto` lets the scheduler decide per query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComputeBackend {
+    Auto,
+    Cpu,
+    Gpu,
+}
+
+/// Cost model measured on the current device, used by `ComputeBackend::Auto`.
+#[derive(Clone, Copy, Debug)]
+pub struct DispatchCalibration {
+    /// Cost paid by every GPU query regardless of size: buffer creation, submission and mapping.
+    pub gpu_fixed_micros: f64,
+    /// Per point cost of a GPU query once the points are on the device.
+    pub gpu_per_point_nanos: f64,
+    /// Per point cost of uploading points that are not resident on the device.
+    pub upload_per_point_nanos: f64,
+    pub cpu_per_point_nanos: f64,
+}
+
+impl DispatchCalibration {
+    /// Smallest point count for which the GPU is expected to win, `None` if it never does.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn crossover_points(&self, resident: bool) -> Option<u64> {
+        let gpu_per_point = self.gpu_per_point_nanos
+            + if resident {
+                0.0
+            } else {
+                self.upload_per_point_nanos
+            };
+        if self.cpu_per_point_nanos <= gpu_per_point {
+            return None;
+        }
+        Some(
+            (self.gpu_fixed_micros * 1_000.0 / (self.cpu_per_point_nanos - gpu_per_point)).ceil()
+                as u64,
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DispatchTarget {
+    Cpu,
+    Gpu,
+}
+
+pub(crate) struct DispatchScheduler {
+    gpu_available: bool,
+    calibration: Option<DispatchCalibration>,
+}
+
+impl DispatchScheduler {
+    pub(crate) fn new(gpu_available: bool) -> DispatchScheduler {
+        DispatchScheduler {
+            gpu_available,
+            calibration: None,
+        }
+    }
+
+    pub(crate) fn choose(&self, point_count: usize, resident: bool) -> DispatchTarget {
+        if point_count == 0 || !self.gpu_available {
+            return DispatchTarget::Cpu;
+        }
+        match *BACKEND.read().unwrap() {
+            ComputeBackend::Cpu => DispatchTarget::Cpu,
+            ComputeBackend::Gpu => DispatchTarget::Gpu,
+            ComputeBackend::Auto => match self
+                .calibration
+                .and_then(|calibration| calibration.crossover_points(resident))
+            {
+                Some(crossover) if point_count as u64 >= crossover => DispatchTarget::Gpu,
+                Some(_) => DispatchTarget::Cpu,
+                // Not calibrated yet: keep the previous behaviour and stay on the GPU.
+                None if self.calibration.is_none() => DispatchTarget::Gpu,
+                None => DispatchTarget::Cpu,
+            },
+        }
+    }
+
+    // Like `choose` for requests measured in `work` rather than points, which keeps work past
+    // `MAX_CPU_WORK` off the CPU unless it is the only backend allowed.
+    pub(crate) fn choose_work(&self, work: u64) -> DispatchTarget {
+        if work > MAX_CPU_WORK
+            && self.gpu_available
+            && *BACKEND.read().unwrap() != ComputeBackend::Cpu
+        {
+            return DispatchTarget::Gpu;
+        }
+        self.choose(usize::try_from(work).unwrap_or(usize::MAX), true)
+    }
+
+    pub(crate) async fn calibrate(&mut self, gpu: &WgpuCompute) -> DispatchCalibration {
+        let small = calibration_points(CALIBRATION_SMALL);
+        let large = calibration_points(CALIBRATION_LARGE);
+        let rect = ComputeRect {
+            min: [0.0, 0.0],
+            max: [128.0, 64.0],
+        };
+
+        // Warm up so pipeline creation is not counted as per query cost.
+        gpu.execute(&small, &rect).await;
+
+        let gpu_small = median(&mut measure_async(|| gpu.execute(&small, &rect)).await);
+        let gpu_large = median(&mut measure_async(|| gpu.execute(&large, &rect)).await);
+        let upload_large = median(
+            &mut (0..CALIBRATION_RUNS)
+                .map(|_| gpu.measure_upload(&large))
+                .collect::<Vec<_>>(),
+        );
+        let cpu_large = median(
+            &mut (0..CALIBRATION_RUNS)
+                .map(|_| {
+                    let start = Instant::now();
+                    std::hint::black_box(cpu::points_in_rect(&large, &rect));
+                    start.elapsed()
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let calibration = fit_calibration(gpu_small, gpu_large, upload_large, cpu_large);
+        self.calibration = Some(calibration);
+        calibration
+    }
+}
+
+// Cost model from the median timings of `calibrate`. Noisy timings can make the large GPU run look no
+// slower than the small one, or the upload slower than the whole query; no cost comes out negative.
+fn fit_calibration(
+    gpu_small: Duration,
+    gpu_large: Duration,
+    upload_large: Duration,
+    cpu_large: Duration,
+) -> DispatchCalibration {
+    let span = (CALIBRATION_LARGE - CALIBRATION_SMALL) as f64;
+    let total_per_point =
+        (gpu_large.as_nanos() as f64 - gpu_small.as_nanos() as f64).max(0.0) / span;
+    let upload_per_point =
+        (upload_large.as_nanos() as f64 / CALIBRATION_LARGE as f64).min(total_per_point);
+    let gpu_fixed =
+        (gpu_small.as_nanos() as f64 - total_per_point * CALIBRATION_SMALL as f64).max(0.0);
+
+    DispatchCalibration {
+        gpu_fixed_micros: gpu_fixed / 1_000.0,
+        gpu_per_point_nanos: total_per_point - upload_per_point,
+        upload_per_point_nanos: upload_per_point,
+        cpu_per_point_nanos: cpu_large.as_nanos() as f64 / CALIBRATION_LARGE as f64,
+    }
+}
+
+// A request on its way through the worker: where it runs, and the tracing and profiling of it once done.
+pub(crate) struct Dispatch {
+    request_id: u64,
+    kernel: &'static str,
+    point_count: usize,
+    target: DispatchTarget,
+    start: Instant,
+}
+
+impl Dispatch {
+    pub(crate) fn new(
+        request_id: u64,
+        kernel: &'static str,
+        point_count: usize,
+        target: DispatchTarget,
+    ) -> Dispatch {
+        profiling::begin_stages();
+        Dispatch {
+            request_id,
+            kernel,
+            point_count,
+            target,
+            start: Instant::now(),
+        }
+    }
+
+    // Sets whose index lives in CPU memory answer every query themselves.
+    pub(crate) fn for_set(
+        scheduler: &DispatchScheduler,
+        request_id: u64,
+        kernel: &'static str,
+        set: &PointSet,
+    ) -> Dispatch {
+        let target = if set.index.on_cpu() {
+            DispatchTarget::Cpu
+        } else {
+            scheduler.choose(set.points.len(), true)
+        };
+        Dispatch::new(request_id, kernel, set.points.len(), target)
+    }
+
+    // For grids and rasters, which cost about `work` point sized steps whatever the point count, and do
+    // not go through the index.
+    pub(crate) fn for_output(
+        scheduler: &DispatchScheduler,
+        request_id: u64,
+        kernel: &'static str,
+        set: &PointSet,
+        work: u64,
+    ) -> Dispatch {
+        let target = if set.points.is_empty() {
+            DispatchTarget::Cpu
+        } else {
+            scheduler.choose_work(work)
+        };
+        Dispatch::new(request_id, kernel, set.points.len(), target)
+    }
+
+    pub(crate) fn target(&self) -> DispatchTarget {
+        self.target
+    }
+
+    pub(crate) fn on_gpu(&self) -> bool {
+        self.target == DispatchTarget::Gpu
+    }
+
+    // For requests the GPU path cannot take, decided before anything ran.
+    pub(crate) fn force_cpu(&mut self) {
+        self.target = DispatchTarget::Cpu;
+    }
+
+    // For GPU paths that gave up part way, `reason` says why.
+    pub(crate) fn fall_back(&mut self, reason: &str) {
+        tracing::warn!(kernel = self.kernel, "{reason}, using the CPU");
+        self.target = DispatchTarget::Cpu;
+    }
+
+    pub(crate) fn finish(self) {
+        let stages = profiling::end_stages();
+        let total = self.start.elapsed();
+        tracing::debug!(
+            kernel = self.kernel,
+            points = self.point_count,
+            on_gpu = self.on_gpu(),
+            micros = total.as_micros() as u64,
+            "dispatched"
+        );
+        if profiling::is_enabled() {
+            profiling::record(stages.into_metrics(
+                self.request_id,
+                self.kernel,
+                self.point_count,
+                self.on_gpu(),
+                total,
+            ));
+        }
+    }
+}
+
+fn calibration_points(count: usize) -> Vec<Vec2> {
+    (0..count)
+        .map(|i| [(i % 256) as f32, (i / 256) as f32])
+        .collect()
+}
+
+async fn measure_async<F, Fut, T>(mut run: F) -> Vec<Duration>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = T>,
+{
+    let mut timings = Vec::with_capacity(CALIBRATION_RUNS);
+    for _ in 0..CALIBRATION_RUNS {
+        let start = Instant::now();
+        std::hint::black_box(run().await);
+        timings.push(start.elapsed());
+    }
+    timings
+}
+
+fn median(timings: &mut [Duration]) -> Duration {
+    timings.sort();
+    timings[timings.len() / 2]
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Forces queries onto the CPU or the GPU, or hands the choice back to the scheduler with `Auto`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_compute_backend(backend: ComputeBackend) {
+    *BACKEND.write().unwrap() = backend;
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_compute_backend() -> ComputeBackend {
+    *BACKEND.read().unwrap()
+}
+
+/// Re-measures the CPU and GPU cost model, e.g. after the app returns from the background. `None` if the
+/// adapter has no compute shader support, in which case every query runs on the CPU.
+pub async fn calibrate_dispatch() -> Option<DispatchCalibration> {
+    match send_command(ComputeCommand::Calibrate).await {
+        ComputeResponse::Calibration(calibration) => calibration,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 10µs of fixed cost, and the CPU 1ns per point slower than a resident GPU query, 0.5ns faster than an
+    // upload.
+    const CALIBRATION: DispatchCalibration = DispatchCalibration {
+        gpu_fixed_micros: 10.0,
+        gpu_per_point_nanos: 1.0,
+        upload_per_point_nanos: 1.5,
+        cpu_per_point_nanos: 2.0,
+    };
+
+    fn calibrated(calibration: DispatchCalibration) -> DispatchScheduler {
+        DispatchScheduler {
+            gpu_available: true,
+            calibration: Some(calibration),
+        }
+    }
+
+    #[test]
+    fn crossover_points() {
+        assert_eq!(CALIBRATION.crossover_points(true), Some(10_000));
+        assert_eq!(CALIBRATION.crossover_points(false), None);
+        let cheap_upload = DispatchCalibration {
+            upload_per_point_nanos: 0.75,
+            ..CALIBRATION
+        };
+        assert_eq!(cheap_upload.crossover_points(false), Some(40_000));
+        // Rounded up, so the GPU is never picked below the break even point.
+        let uneven = DispatchCalibration {
+            gpu_fixed_micros: 10.0005,
+            ..CALIBRATION
+        };
+        assert_eq!(uneven.crossover_points(true), Some(10_001));
+        let equal = DispatchCalibration {
+            cpu_per_point_nanos: 1.0,
+            ..CALIBRATION
+        };
+        assert_eq!(equal.crossover_points(true), None);
+    }
+
+    // The tests leave `BACKEND` at `Auto`, so they can run in parallel.
+    #[test]
+    fn choose_switches_at_the_crossover() {
+        let scheduler = calibrated(CALIBRATION);
+        assert_eq!(scheduler.choose(9_999, true), DispatchTarget::Cpu);
+        assert_eq!(scheduler.choose(10_000, true), DispatchTarget::Gpu);
+        assert_eq!(scheduler.choose(1 << 30, false), DispatchTarget::Cpu);
+        assert_eq!(scheduler.choose(0, true), DispatchTarget::Cpu);
+    }
+
+    #[test]
+    fn uncalibrated_scheduler_stays_on_the_gpu() {
+        let scheduler = DispatchScheduler::new(true);
+        assert_eq!(scheduler.choose(1, true), DispatchTarget::Gpu);
+        assert_eq!(scheduler.choose(1, false), DispatchTarget::Gpu);
+        assert_eq!(scheduler.choose(0, true), DispatchTarget::Cpu);
+
+        let without_gpu = DispatchScheduler::new(false);
+        assert_eq!(without_gpu.choose(1 << 20, true), DispatchTarget::Cpu);
+        assert_eq!(without_gpu.choose_work(u64::MAX), DispatchTarget::Cpu);
+    }
+
+    #[test]
+    fn work_past_the_cpu_budget_goes_to_the_gpu() {
+        let never = calibrated(DispatchCalibration {
+            cpu_per_point_nanos: 0.5,
+            ..CALIBRATION
+        });
+        assert_eq!(never.choose_work(MAX_CPU_WORK), DispatchTarget::Cpu);
+        assert_eq!(never.choose_work(MAX_CPU_WORK + 1), DispatchTarget::Gpu);
+        assert_eq!(
+            calibrated(CALIBRATION).choose_work(10_000),
+            DispatchTarget::Gpu
+        );
+    }
+
+    #[test]
+    fn calibration_from_timings() {
+        let nanos = Duration::from_nanos;
+        // 1ns per point of which a quarter is upload, on top of 20µs.
+        let calibration = fit_calibration(
+            nanos(20_000 + CALIBRATION_SMALL as u64),
+            nanos(20_000 + CALIBRATION_LARGE as u64),
+            nanos(CALIBRATION_LARGE as u64 / 4),
+            nanos(CALIBRATION_LARGE as u64 * 3),
+        );
+        assert_eq!(calibration.gpu_fixed_micros, 20.0);
+        assert_eq!(calibration.gpu_per_point_nanos, 0.75);
+        assert_eq!(calibration.upload_per_point_nanos, 0.25);
+        assert_eq!(calibration.cpu_per_point_nanos, 3.0);
+        assert_eq!(calibration.crossover_points(true), Some(8_889));
+
+        // A large run measured faster than the small one, and an upload slower than the whole query.
+        let noisy = fit_calibration(
+            nanos(50_000),
+            nanos(40_000),
+            nanos(1_000_000),
+            nanos(CALIBRATION_LARGE as u64),
+        );
+        assert_eq!(noisy.gpu_fixed_micros, 50.0);
+        assert_eq!(noisy.gpu_per_point_nanos, 0.0);
+        assert_eq!(noisy.upload_per_point_nanos, 0.0);
+        assert_eq!(noisy.crossover_points(false), Some(50_000));
+    }
+
+    #[test]
+    fn calibration_points_fill_rows_of_256() {
+        let points = calibration_points(CALIBRATION_SMALL);
+        assert_eq!(points.len(), CALIBRATION_SMALL);
+        assert_eq!(points[255], [255.0, 0.0]);
+        assert_eq!(points[256], [0.0, 1.0]);
+        assert_eq!(points[CALIBRATION_SMALL - 1], [255.0, 3.0]);
+    }
+}
@@ -0,0 +1,559 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+};
+
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    attributes::{AttributeFilter, AttributeFilters, Channels},
+    gpu,
+    point_set::PointSet,
+    query::QueryShape,
+    simple::{
+        send_command, ComputeCommand, ComputePoint, ComputeRect, ComputeResponse, Vec2, WgpuCompute,
+    },
+};
+
+const EXPRESSION_PRELUDE: &str = include_str!("expression.wgsl");
+
+/// A condition on the points of a set, composed of shapes and attribute filters. Compiled to a WGSL kernel
+/// that is cached by the structure of the predicate, so changing only its rects, radii, vertices or filter
+/// values reuses the kernel.
+#[derive(Clone)]
+pub enum Predicate {
+    InRect(ComputeRect),
+    InCircle {
+        center: Vec2,
+        radius: f32,
+    },
+    /// Even-odd rule.
+    InPolygon(Vec<ComputePoint>),
+    Attribute(AttributeFilter),
+    /// Holds when all of the predicates hold, so also for an empty list.
+    And(Vec<Predicate>),
+    /// Holds when any of the predicates holds, so never for an empty list.
+    Or(Vec<Predicate>),
+    Not(Box<Predicate>),
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct ExpressionParams {
+    point_count: u32,
+    _padding: [u32; 3],
+}
+
+enum Node {
+    Shape(QueryShape),
+    Attribute(AttributeFilters),
+    And(Vec<Node>),
+    Or(Vec<Node>),
+    Not(Box<Node>),
+}
+
+impl Node {
+    fn holds(&self, points: &[Vec2], channels: &Channels, index: u32) -> bool {
+        match self {
+            Node::Shape(shape) => shape.contains(&points[index as usize]),
+            Node::Attribute(filters) => filters.matches(channels, index),
+            Node::And(nodes) => nodes.iter().all(|node| node.holds(points, channels, index)),
+            Node::Or(nodes) => nodes.iter().any(|node| node.holds(points, channels, index)),
+            Node::Not(node) => !node.holds(points, channels, index),
+        }
+    }
+}
+
+// A predicate resolved against the attributes of a point set: the WGSL expression of its kernel, which only
+// depends on its structure, the data of its leaves, and a tree to evaluate it on the CPU.
+pub(crate) struct CompiledPredicate {
+    expression: String,
+    // Hash of `expression`, identifying the kernel.
+    key: u64,
+    // Offset of the data of every leaf, followed by that data.
+    constants: Vec<u32>,
+    leaf_count: usize,
+    uses_attributes: bool,
+    root: Node,
+}
+
+impl CompiledPredicate {
+    // `None` when an attribute filter does not resolve against `channels`.
+    pub(crate) fn new(predicate: &Predicate, channels: &Channels) -> Option<CompiledPredicate> {
+        let mut compiler = Compiler {
+            channels,
+            offsets: Vec::new(),
+            data: Vec::new(),
+            uses_attributes: false,
+        };
+        let mut expression = String::new();
+        let root = compiler.compile(predicate, &mut expression)?;
+        let leaf_count = compiler.offsets.len();
+        let mut constants: Vec<u32> = compiler
+            .offsets
+            .iter()
+            .map(|offset| offset + leaf_count as u32)
+            .collect();
+        constants.extend(compiler.data);
+        let mut hasher = DefaultHasher::new();
+        expression.hash(&mut hasher);
+        Some(CompiledPredicate {
+            key: hasher.finish(),
+            expression,
+            constants,
+            leaf_count,
+            uses_attributes: compiler.uses_attributes,
+            root,
+        })
+    }
+
+    pub(crate) fn holds(&self, points: &[Vec2], channels: &Channels, index: u32) -> bool {
+        self.root.holds(points, channels, index)
+    }
+}
+
+struct Compiler<'a> {
+    channels: &'a Channels,
+    offsets: Vec<u32>,
+    data: Vec<u32>,
+    uses_attributes: bool,
+}
+
+impl Compiler<'_> {
+    // Starts the data of a new leaf, returning its number.
+    fn leaf(&mut self) -> usize {
+        self.offsets.push(self.data.len() as u32);
+        self.offsets.len() - 1
+    }
+
+    // Appends the WGSL expression of `predicate` to `out`.
+    fn compile(&mut self, predicate: &Predicate, out: &mut String) -> Option<Node> {
+        let node = match predicate {
+            Predicate::InRect(rect) => {
+                let leaf = self.leaf();
+                self.data
+                    .extend([rect.min, rect.max].iter().flatten().map(|v| v.to_bits()));
+                write!(out, "inRect({leaf}u, point)").unwrap();
+                Node::Shape(QueryShape::Rect(*rect))
+            }
+            Predicate::InCircle { center, radius } => {
+                let leaf = self.leaf();
+                self.data
+                    .extend([center[0], center[1], *radius].map(f32::to_bits));
+                write!(out, "inCircle({leaf}u, point)").unwrap();
+                Node::Shape(QueryShape::Circle {
+                    center: *center,
+                    radius: *radius,
+                })
+            }
+            Predicate::InPolygon(vertices) => {
+                let leaf = self.leaf();
+                let vertices: Vec<Vec2> = vertices.iter().map(|&vertex| vertex.into()).collect();
+                let shape = QueryShape::Polygon(vertices.clone());
+                let bounds = shape.bounds();
+                self.data.push(vertices.len() as u32);
+                self.data.extend(
+                    [bounds.min, bounds.max]
+                        .iter()
+                        .chain(&vertices)
+                        .flatten()
+                        .map(|v| v.to_bits()),
+                );
+                write!(out, "inPolygon({leaf}u, point)").unwrap();
+                Node::Shape(shape)
+            }
+            Predicate::Attribute(filter) => {
+                let filters = self.channels.resolve(std::slice::from_ref(filter))?;
+                let leaf = self.leaf();
+                filters.encode(&mut self.data);
+                self.uses_attributes = true;
+                write!(out, "passesFilters({leaf}u, index)").unwrap();
+                Node::Attribute(filters)
+            }
+            Predicate::And(predicates) => {
+                Node::And(self.compile_all(predicates, " && ", "true", out)?)
+            }
+            Predicate::Or(predicates) => {
+                Node::Or(self.compile_all(predicates, " || ", "false", out)?)
+            }
+            Predicate::Not(predicate) => {
+                out.push_str("!(");
+                let node = self.compile(predicate, out)?;
+                out.push(')');
+                Node::Not(Box::new(node))
+            }
+        };
+        Some(node)
+    }
+
+    fn compile_all(
+        &mut self,
+        predicates: &[Predicate],
+        operator: &str,
+        empty: &str,
+        out: &mut String,
+    ) -> Option<Vec<Node>> {
+        if predicates.is_empty() {
+            out.push_str(empty);
+            return Some(Vec::new());
+        }
+        out.push('(');
+        let mut nodes = Vec::with_capacity(predicates.len());
+        for (k, predicate) in predicates.iter().enumerate() {
+            if k > 0 {
+                out.push_str(operator);
+            }
+            nodes.push(self.compile(predicate, out)?);
+        }
+        out.push(')');
+        Some(nodes)
+    }
+}
+
+pub(crate) fn query_predicate_cpu(
+    points: &[Vec2],
+    channels: &Channels,
+    predicate: &CompiledPredicate,
+) -> Vec<u32> {
+    (0..points.len() as u32)
+        .filter(|&i| predicate.holds(points, channels, i))
+        .collect()
+}
+
+pub(crate) async fn query_predicate_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    predicate: &CompiledPredicate,
+) -> Vec<u32> {
+    let device = &gpu.device;
+    if set.points.is_empty() {
+        return Vec::new();
+    }
+    let params = ExpressionParams {
+        point_count: set.points.len() as u32,
+        _padding: [0; 3],
+    };
+    let params_buffer = gpu::uniform_buffer(device, "Predicate Params Buffer", &params);
+    let constants_buffer = gpu::storage_buffer(
+        device,
+        "Predicate Constants Buffer",
+        &predicate.constants,
+        wgpu::BufferUsages::empty(),
+    );
+    let output_buffer = gpu::empty_storage_buffer(
+        device,
+        "Predicate Output Buffer",
+        set.points.len() as u64 * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let count_buffer = gpu::empty_storage_buffer(
+        device,
+        "Predicate Count Buffer",
+        4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let pipeline = gpu.generated_pipeline(
+        predicate.key,
+        || {
+            format!(
+                "{EXPRESSION_PRELUDE}\nfn holds(index: u32, point: vec2<f32>) -> bool {{\n    return {};\n}}\n",
+                predicate.expression
+            )
+        },
+        "query_predicate",
+    );
+
+    // The layout derived from the kernel leaves out the bindings its expression never reads.
+    let mut entries = vec![
+        (0, &set.buffer),
+        (1, &params_buffer),
+        (4, &output_buffer),
+        (5, &count_buffer),
+    ];
+    if predicate.leaf_count > 0 {
+        entries.push((2, &constants_buffer));
+    }
+    if predicate.uses_attributes {
+        entries.push((3, &set.attributes.buffer));
+    }
+    let bind_group = gpu::bind_group_at(device, &pipeline, &entries);
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &pipeline,
+        &bind_group,
+        params.point_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let count = gpu::read_buffer::<u32>(device, &gpu.queue, &count_buffer, 0, 1).await[0];
+    let mut indices =
+        gpu::read_buffer::<u32>(device, &gpu.queue, &output_buffer, 0, count as usize).await;
+    indices.sort_unstable();
+    indices
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Indices of the points of `point_set` for which `predicate` holds, in ascending order. `None` if the
+/// point set does not exist, or an attribute filter names a missing attribute or uses a bitmask on an
+/// `F32` attribute.
+pub async fn query_predicate(point_set: u32, predicate: Predicate) -> Option<Vec<u32>> {
+    match send_command(ComputeCommand::QueryPredicate(point_set, predicate)).await {
+        ComputeResponse::Indices(indices) => indices,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{attributes::AttributeValues, cpu};
+
+    // Xorshift values in `[0, 1)`, so failures reproduce.
+    fn random_values(count: usize) -> Vec<f32> {
+        let mut state = 0x2545_f491u32;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state >> 8) as f32 / (1 << 24) as f32
+            })
+            .collect()
+    }
+
+    struct Data {
+        points: Vec<Vec2>,
+        weights: Vec<f32>,
+        flags: Vec<u32>,
+        channels: Channels,
+    }
+
+    // Points in `[0, 100)` with an `F32` "weight" and a `U32` "flags" channel, and a few values that are
+    // not finite in both.
+    fn data(count: usize) -> Data {
+        let values = random_values(count * 4);
+        let mut points: Vec<Vec2> = values
+            .chunks(4)
+            .map(|v| [v[0] * 100.0, v[1] * 100.0])
+            .collect();
+        let mut weights: Vec<f32> = values.chunks(4).map(|v| v[2]).collect();
+        let flags: Vec<u32> = values.chunks(4).map(|v| (v[3] * 16.0) as u32).collect();
+        for i in (0..count).step_by(37) {
+            points[i] = [f32::NAN, 50.0];
+            weights[(i + 5) % count] = f32::NAN;
+        }
+        let mut channels = Channels::new(count);
+        assert!(channels.set("weight".into(), AttributeValues::F32(weights.clone())));
+        assert!(channels.set("flags".into(), AttributeValues::U32(flags.clone())));
+        Data {
+            points,
+            weights,
+            flags,
+            channels,
+        }
+    }
+
+    // Evaluates `predicate` straight from its definition.
+    fn brute_force(data: &Data, predicate: &Predicate, i: usize) -> bool {
+        let point = &data.points[i];
+        match predicate {
+            Predicate::InRect(rect) => cpu::is_point_inside_rect(point, rect),
+            Predicate::InCircle { center, radius } => {
+                cpu::is_point_inside_circle(point, center, *radius)
+            }
+            Predicate::InPolygon(vertices) => {
+                let vertices: Vec<Vec2> = vertices.iter().map(|&vertex| vertex.into()).collect();
+                cpu::is_point_inside_polygon(point, &vertices)
+            }
+            Predicate::Attribute(filter) => {
+                let value = |attribute: &str| match attribute {
+                    "weight" => data.weights[i] as f64,
+                    _ => data.flags[i] as f64,
+                };
+                match filter {
+                    AttributeFilter::Range {
+                        attribute,
+                        min,
+                        max,
+                    } => value(attribute) >= *min && value(attribute) <= *max,
+                    AttributeFilter::OneOf { attribute, values } => {
+                        values.contains(&value(attribute))
+                    }
+                    AttributeFilter::Bitmask { mask, all, .. } => {
+                        let bits = data.flags[i] & mask;
+                        if *all {
+                            bits == *mask
+                        } else {
+                            bits != 0
+                        }
+                    }
+                }
+            }
+            Predicate::And(predicates) => predicates.iter().all(|p| brute_force(data, p, i)),
+            Predicate::Or(predicates) => predicates.iter().any(|p| brute_force(data, p, i)),
+            Predicate::Not(predicate) => !brute_force(data, predicate, i),
+        }
+    }
+
+    fn rect(min: Vec2, max: Vec2) -> Predicate {
+        Predicate::InRect(ComputeRect { min, max })
+    }
+
+    fn range(attribute: &str, min: f64, max: f64) -> Predicate {
+        Predicate::Attribute(AttributeFilter::Range {
+            attribute: attribute.into(),
+            min,
+            max,
+        })
+    }
+
+    fn bitmask(mask: u32, all: bool) -> Predicate {
+        Predicate::Attribute(AttributeFilter::Bitmask {
+            attribute: "flags".into(),
+            mask,
+            all,
+        })
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let data = data(1000);
+        let circle = Predicate::InCircle {
+            center: [40.0, 60.0],
+            radius: 30.0,
+        };
+        let polygon = Predicate::InPolygon(
+            [
+                [10.0, 10.0],
+                [90.0, 20.0],
+                [50.0, 50.0],
+                [80.0, 90.0],
+                [5.0, 70.0],
+            ]
+            .into_iter()
+            .map(ComputePoint::from)
+            .collect(),
+        );
+        let predicates = [
+            rect([20.0, 10.0], [70.0, 55.0]),
+            circle.clone(),
+            polygon.clone(),
+            range("weight", 0.25, 0.7),
+            range("flags", 2.5, 9.0),
+            Predicate::Attribute(AttributeFilter::OneOf {
+                attribute: "flags".into(),
+                values: vec![1.0, 4.0, 4.5, -3.0, 15.0],
+            }),
+            Predicate::Attribute(AttributeFilter::OneOf {
+                attribute: "weight".into(),
+                values: vec![data.weights[3] as f64, 0.1],
+            }),
+            bitmask(0b0110, false),
+            bitmask(0b0101, true),
+            Predicate::And(vec![circle.clone(), range("weight", 0.0, 0.5)]),
+            Predicate::Or(vec![
+                polygon.clone(),
+                Predicate::And(vec![bitmask(1, false), rect([0.0, 0.0], [50.0, 50.0])]),
+            ]),
+            Predicate::Not(Box::new(Predicate::Or(vec![
+                circle,
+                range("weight", 0.5, 1.0),
+            ]))),
+            Predicate::And(vec![
+                Predicate::Not(Box::new(polygon)),
+                Predicate::Not(Box::new(Predicate::And(Vec::new()))),
+            ]),
+            Predicate::And(Vec::new()),
+            Predicate::Or(Vec::new()),
+            Predicate::Not(Box::new(Predicate::Or(Vec::new()))),
+        ];
+        for (k, predicate) in predicates.iter().enumerate() {
+            let compiled = CompiledPredicate::new(predicate, &data.channels).unwrap();
+            let expected: Vec<u32> = (0..data.points.len() as u32)
+                .filter(|&i| brute_force(&data, predicate, i as usize))
+                .collect();
+            assert_eq!(
+                query_predicate_cpu(&data.points, &data.channels, &compiled),
+                expected,
+                "predicate {k}"
+            );
+        }
+    }
+
+    #[test]
+    fn non_finite_values_only_pass_negations() {
+        let data = data(100);
+        let everywhere = rect([f32::MIN; 2], [f32::MAX; 2]);
+        let inside = CompiledPredicate::new(&everywhere, &data.channels).unwrap();
+        let outside =
+            CompiledPredicate::new(&Predicate::Not(Box::new(everywhere)), &data.channels).unwrap();
+        let any_weight = range("weight", f64::NEG_INFINITY, f64::INFINITY);
+        let weighted = CompiledPredicate::new(&any_weight, &data.channels).unwrap();
+        for i in 0..data.points.len() as u32 {
+            let finite = data.points[i as usize].iter().all(|v| v.is_finite());
+            assert_eq!(inside.holds(&data.points, &data.channels, i), finite);
+            assert_eq!(outside.holds(&data.points, &data.channels, i), !finite);
+            assert_eq!(
+                weighted.holds(&data.points, &data.channels, i),
+                !data.weights[i as usize].is_nan()
+            );
+        }
+        assert!(query_predicate_cpu(&[], &Channels::new(0), &inside).is_empty());
+    }
+
+    #[test]
+    fn kernels_are_keyed_by_structure() {
+        let data = data(10);
+        let compile =
+            |predicate: &Predicate| CompiledPredicate::new(predicate, &data.channels).unwrap();
+        let first = compile(&Predicate::And(vec![
+            rect([0.0, 0.0], [1.0, 1.0]),
+            range("weight", 0.0, 0.5),
+        ]));
+        let second = compile(&Predicate::And(vec![
+            rect([5.0, 2.0], [8.0, 9.0]),
+            range("flags", 3.0, 4.0),
+        ]));
+        assert_eq!(first.key, second.key);
+        assert_eq!(first.expression, second.expression);
+        assert_ne!(first.constants, second.constants);
+
+        let swapped = compile(&Predicate::And(vec![
+            range("weight", 0.0, 0.5),
+            rect([0.0, 0.0], [1.0, 1.0]),
+        ]));
+        let or = compile(&Predicate::Or(vec![
+            rect([0.0, 0.0], [1.0, 1.0]),
+            range("weight", 0.0, 0.5),
+        ]));
+        assert_ne!(first.key, swapped.key);
+        assert_ne!(first.key, or.key);
+        assert_eq!(first.leaf_count, 2);
+    }
+
+    #[test]
+    fn unresolvable_filters_fail_to_compile() {
+        let data = data(10);
+        let missing = range("height", 0.0, 1.0);
+        assert!(CompiledPredicate::new(&missing, &data.channels).is_none());
+        // Nested anywhere, even under a branch that could short circuit.
+        let nested = Predicate::Or(vec![
+            Predicate::And(Vec::new()),
+            Predicate::Not(Box::new(missing)),
+        ]);
+        assert!(CompiledPredicate::new(&nested, &data.channels).is_none());
+        let float_mask = Predicate::Attribute(AttributeFilter::Bitmask {
+            attribute: "weight".into(),
+            mask: 1,
+            all: false,
+        });
+        assert!(CompiledPredicate::new(&float_mask, &data.channels).is_none());
+        assert!(CompiledPredicate::new(&bitmask(1, true), &data.channels).is_some());
+    }
+}
@@ -0,0 +1,127 @@
+// Prelude of the kernels compiled from a `Predicate`. The compiler appends `holds(index, point)`, an
+// expression over the leaf tests below, and the `query_predicate` entry point emitting the points it holds
+// for. Leaf `k` keeps its data at `constants[constants[k]]`, so kernels only differ by the structure of
+// the predicate and are reused for any rects, circles, polygons and filter values.
+struct Params {
+    point_count: u32,
+};
+
+const FILTER_RANGE: u32 = 0u;
+const FILTER_ONE_OF: u32 = 1u;
+const FILTER_BITMASK_ANY: u32 = 2u;
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> params: Params;
+@group(0) @binding(2) var<storage, read> constants: array<u32>;
+@group(0) @binding(3) var<storage, read> attributes: array<u32>;
+@group(0) @binding(4) var<storage, read_write> outputIndices: array<u32>;
+@group(0) @binding(5) var<storage, read_write> outputCount: atomic<u32>;
+
+fn scalar(k: u32) -> f32 {
+    return bitcast<f32>(constants[k]);
+}
+
+fn vector(k: u32) -> vec2<f32> {
+    return vec2<f32>(scalar(k), scalar(k + 1u));
+}
+
+// Data: min, max.
+fn inRect(leaf: u32, point: vec2<f32>) -> bool {
+    let base = constants[leaf];
+    return all(point >= vector(base)) && all(point <= vector(base + 2u));
+}
+
+// Data: center, radius.
+fn inCircle(leaf: u32, point: vec2<f32>) -> bool {
+    let base = constants[leaf];
+    let d = point - vector(base);
+    let radius = scalar(base + 2u);
+    return dot(d, d) <= radius * radius;
+}
+
+// Data: vertex count, bounds min and max, vertices. Even-odd rule, matching `cpu::is_point_inside_polygon`.
+fn inPolygon(leaf: u32, point: vec2<f32>) -> bool {
+    let base = constants[leaf];
+    if (!(all(point >= vector(base + 1u)) && all(point <= vector(base + 3u)))) {
+        return false;
+    }
+    let count = constants[base];
+    let first = base + 5u;
+    var inside = false;
+    var j = count - 1u;
+    for (var i = 0u; i < count; i++) {
+        let a = vector(first + 2u * i);
+        let b = vector(first + 2u * j);
+        if ((a.y > point.y) != (b.y > point.y)) {
+            let x = (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x;
+            if (point.x < x) {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    return inside;
+}
+
+fn equals(bits: u32, other: u32, float: bool) -> bool {
+    if (float) {
+        return bitcast<f32>(bits) == bitcast<f32>(other);
+    }
+    return bits == other;
+}
+
+// Data: filter count, then every filter as kind, float, offset, low, high, value count and values, as
+// written by `AttributeFilters::encode`. Same tests as `AttributeFilters::matches`.
+fn passesFilters(leaf: u32, index: u32) -> bool {
+    var k = constants[leaf];
+    let filterCount = constants[k];
+    k++;
+    for (var f = 0u; f < filterCount; f++) {
+        let kind = constants[k];
+        let float = constants[k + 1u] != 0u;
+        let bits = attributes[constants[k + 2u] + index];
+        let low = constants[k + 3u];
+        let high = constants[k + 4u];
+        let valueCount = constants[k + 5u];
+        k += 6u;
+        var passes = false;
+        switch kind {
+            case FILTER_RANGE: {
+                if (float) {
+                    let value = bitcast<f32>(bits);
+                    passes = value >= bitcast<f32>(low) && value <= bitcast<f32>(high);
+                } else {
+                    passes = bits >= low && bits <= high;
+                }
+            }
+            case FILTER_ONE_OF: {
+                for (var v = 0u; v < valueCount; v++) {
+                    passes = passes || equals(bits, constants[k + v], float);
+                }
+            }
+            case FILTER_BITMASK_ANY: {
+                passes = (bits & low) != 0u;
+            }
+            default: {
+                passes = (bits & low) == low;
+            }
+        }
+        if (!passes) {
+            return false;
+        }
+        k += valueCount;
+    }
+    return true;
+}
+
+@compute
+@workgroup_size(64)
+fn query_predicate(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= params.point_count) {
+        return;
+    }
+    if (holds(i, points[i])) {
+        outputIndices[atomicAdd(&outputCount, 1u)] = i;
+    }
+}
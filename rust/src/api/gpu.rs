@@ -0,0 +1,279 @@
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+use web_time::Instant;
+
+use super::{
+    profiling::{self, Stage},
+    simple::WgpuCompute,
+};
+
+const SCAN_SHADER: &str = include_str!("scan.wgsl");
+const SORT_SHADER: &str = include_str!("sort.wgsl");
+
+const RADIX_BITS: u32 = 4;
+const RADIX: u64 = 1 << RADIX_BITS;
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct SortPass {
+    shift: u32,
+    count: u32,
+    block_count: u32,
+    _padding: u32,
+}
+
+// Shared by every kernel written after `shader.wgsl`. Kernels recover the flat invocation index with
+// `gid.x + gid.y * num_workgroups.x * WORKGROUP_SIZE` so large inputs can spill into a second dimension.
+pub(crate) const WORKGROUP_SIZE: u32 = 64;
+
+pub(crate) fn dispatch_size(device: &wgpu::Device, invocations: u32) -> (u32, u32) {
+    let max = device.limits().max_compute_workgroups_per_dimension.max(1);
+    let workgroups = invocations.div_ceil(WORKGROUP_SIZE).max(1);
+    if workgroups <= max {
+        (workgroups, 1)
+    } else {
+        (max, workgroups.div_ceil(max))
+    }
+}
+
+pub(crate) fn dispatch(
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    pipeline: &wgpu::ComputePipeline,
+    bind_group: &wgpu::BindGroup,
+    invocations: u32,
+) {
+    let (x, y) = dispatch_size(device, invocations);
+    let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+        label: None,
+        timestamp_writes: None,
+    });
+    cpass.set_pipeline(pipeline);
+    cpass.set_bind_group(0, bind_group, &[]);
+    cpass.dispatch_workgroups(x, y, 1);
+}
+
+// Exclusive prefix sum of the `u32`s in `values` into `offsets`, which has one extra element for the total.
+pub(crate) fn exclusive_scan(
+    gpu: &WgpuCompute,
+    encoder: &mut wgpu::CommandEncoder,
+    values: &wgpu::Buffer,
+    offsets: &wgpu::Buffer,
+) {
+    let pipeline = gpu.pipeline(SCAN_SHADER, "exclusive_scan");
+    let bind_group = bind_group(&gpu.device, &pipeline, &[values, offsets]);
+    let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+        label: None,
+        timestamp_writes: None,
+    });
+    cpass.set_pipeline(&pipeline);
+    cpass.set_bind_group(0, &bind_group, &[]);
+    cpass.dispatch_workgroups(1, 1, 1);
+}
+
+// Stable ascending sort of the first `count` keys in `keys`, moving the matching `values` along.
+// Eight 4-bit passes ping-pong through scratch buffers, so the result ends up back in `keys` and `values`.
+pub(crate) fn radix_sort(
+    gpu: &WgpuCompute,
+    encoder: &mut wgpu::CommandEncoder,
+    keys: &wgpu::Buffer,
+    values: &wgpu::Buffer,
+    count: u32,
+) {
+    let device = &gpu.device;
+    let block_count = count.div_ceil(WORKGROUP_SIZE);
+    let size = count as u64 * 4;
+    let keys_scratch = empty_storage_buffer(
+        device,
+        "Sort Keys Scratch Buffer",
+        size,
+        wgpu::BufferUsages::empty(),
+    );
+    let values_scratch = empty_storage_buffer(
+        device,
+        "Sort Values Scratch Buffer",
+        size,
+        wgpu::BufferUsages::empty(),
+    );
+    let counts = empty_storage_buffer(
+        device,
+        "Sort Block Counts Buffer",
+        RADIX * block_count as u64 * 4,
+        wgpu::BufferUsages::empty(),
+    );
+    let offsets = empty_storage_buffer(
+        device,
+        "Sort Block Offsets Buffer",
+        (RADIX * block_count as u64 + 1) * 4,
+        wgpu::BufferUsages::empty(),
+    );
+    let count_pipeline = gpu.pipeline(SORT_SHADER, "radix_count");
+    let scatter_pipeline = gpu.pipeline(SORT_SHADER, "radix_scatter");
+
+    let mut buffers = [(keys, values), (&keys_scratch, &values_scratch)];
+    for shift in (0..32).step_by(RADIX_BITS as usize) {
+        let [(keys_in, values_in), (keys_out, values_out)] = buffers;
+        let pass = uniform_buffer(
+            device,
+            "Sort Pass Buffer",
+            &SortPass {
+                shift,
+                count,
+                block_count,
+                _padding: 0,
+            },
+        );
+        let count_bind_group = bind_group(device, &count_pipeline, &[&pass, keys_in, &counts]);
+        let scatter_bind_group = bind_group(
+            device,
+            &scatter_pipeline,
+            &[&pass, keys_in, &offsets, values_in, keys_out, values_out],
+        );
+        dispatch(device, encoder, &count_pipeline, &count_bind_group, count);
+        exclusive_scan(gpu, encoder, &counts, &offsets);
+        dispatch(
+            device,
+            encoder,
+            &scatter_pipeline,
+            &scatter_bind_group,
+            count,
+        );
+        buffers.swap(0, 1);
+    }
+}
+
+// Binds `buffers` to bindings 0, 1, 2, ... of group 0, in order.
+pub(crate) fn bind_group(
+    device: &wgpu::Device,
+    pipeline: &wgpu::ComputePipeline,
+    buffers: &[&wgpu::Buffer],
+) -> wgpu::BindGroup {
+    let entries: Vec<(u32, &wgpu::Buffer)> = buffers
+        .iter()
+        .enumerate()
+        .map(|(binding, buffer)| (binding as u32, *buffer))
+        .collect();
+    bind_group_at(device, pipeline, &entries)
+}
+
+// For entry points that only use some of the bindings declared by their shader.
+pub(crate) fn bind_group_at(
+    device: &wgpu::Device,
+    pipeline: &wgpu::ComputePipeline,
+    buffers: &[(u32, &wgpu::Buffer)],
+) -> wgpu::BindGroup {
+    let entries: Vec<wgpu::BindGroupEntry> = buffers
+        .iter()
+        .map(|(binding, buffer)| wgpu::BindGroupEntry {
+            binding: *binding,
+            resource: buffer.as_entire_binding(),
+        })
+        .collect();
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &entries,
+    })
+}
+
+// Zero sized bindings are invalid, so empty inputs get a single zeroed element.
+pub(crate) fn storage_buffer<T: Pod>(
+    device: &wgpu::Device,
+    label: &str,
+    contents: &[T],
+    usage: wgpu::BufferUsages,
+) -> wgpu::Buffer {
+    let bytes: &[u8] = bytemuck::cast_slice(contents);
+    let padding = vec![0u8; std::mem::size_of::<T>().max(4)];
+    profiling::time_stage(Stage::Upload, || {
+        device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(label),
+            contents: if bytes.is_empty() { &padding } else { bytes },
+            usage: wgpu::BufferUsages::STORAGE | usage,
+        })
+    })
+}
+
+pub(crate) fn empty_storage_buffer(
+    device: &wgpu::Device,
+    label: &str,
+    size: wgpu::BufferAddress,
+    usage: wgpu::BufferUsages,
+) -> wgpu::Buffer {
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some(label),
+        size: size.max(4),
+        usage: wgpu::BufferUsages::STORAGE | usage,
+        mapped_at_creation: false,
+    })
+}
+
+pub(crate) fn uniform_buffer<T: Pod>(
+    device: &wgpu::Device,
+    label: &str,
+    value: &T,
+) -> wgpu::Buffer {
+    profiling::time_stage(Stage::Upload, || {
+        device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(label),
+            contents: bytemuck::bytes_of(value),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        })
+    })
+}
+
+// Copies `count` elements of `buffer` starting at element `offset` back to the CPU.
+// `buffer` needs `COPY_SRC` and any work writing to it must already be submitted.
+pub(crate) async fn read_buffer<T: Pod>(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    buffer: &wgpu::Buffer,
+    offset: usize,
+    count: usize,
+) -> Vec<T> {
+    if count == 0 {
+        return Vec::new();
+    }
+    if profiling::is_recording() {
+        // Wait for the work writing `buffer` on its own so it counts as dispatch rather than readback.
+        profiling::time_stage(Stage::Dispatch, || {
+            device.poll(wgpu::Maintain::wait()).panic_on_timeout()
+        });
+    }
+    let start = Instant::now();
+    let element = std::mem::size_of::<T>() as wgpu::BufferAddress;
+    let size = count as wgpu::BufferAddress * element;
+    let staging_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Readback Staging Buffer"),
+        size,
+        usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    encoder.copy_buffer_to_buffer(
+        buffer,
+        offset as wgpu::BufferAddress * element,
+        &staging_buffer,
+        0,
+        size,
+    );
+    queue.submit(Some(encoder.finish()));
+
+    let buffer_slice = staging_buffer.slice(..);
+    let (sender, receiver) = flume::bounded(1);
+    buffer_slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+    device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+
+    if let Ok(Ok(())) = receiver.recv_async().await {
+        let data = buffer_slice.get_mapped_range();
+        let result = bytemuck::cast_slice(&data).to_vec();
+        drop(data);
+        staging_buffer.unmap();
+        profiling::add_stage(Stage::Readback, start.elapsed());
+        result
+    } else {
+        panic!("Failed to read buffer from GPU!");
+    }
+}
@@ -0,0 +1,253 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    simple::{ComputeRect, Vec2, WgpuCompute},
+};
+
+const GRID_SHADER: &str = include_str!("grid.wgsl");
+
+// Aim for a handful of points per cell, within what a single workgroup scan handles comfortably.
+const POINTS_PER_CELL: usize = 4;
+const MAX_CELLS_PER_AXIS: u32 = 1024;
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct GridParams {
+    origin: Vec2,
+    inv_cell_size: Vec2,
+    dims: [u32; 2],
+    point_count: u32,
+    cell_count: u32,
+}
+
+// Uniform grid over a resident point set: `sorted_indices` holds point indices grouped by cell,
+// cell `c` owning `sorted_indices[cell_start[c]..cell_start[c + 1]]`.
+pub(crate) struct GridIndex {
+    params: GridParams,
+    pub(crate) cell_start: wgpu::Buffer,
+    pub(crate) sorted_indices: wgpu::Buffer,
+}
+
+impl GridIndex {
+    pub(crate) fn build(
+        gpu: &WgpuCompute,
+        points: &[Vec2],
+        points_buffer: &wgpu::Buffer,
+    ) -> GridIndex {
+        GridIndex::build_with_params(gpu, points_buffer, grid_params(points, None))
+    }
+
+    // Grid whose cells are at least `min_cell_size` wide on both axes, so everything within that
+    // distance of a point lies in the 3x3 cells around it.
+    pub(crate) fn build_with_cell_size(
+        gpu: &WgpuCompute,
+        points: &[Vec2],
+        points_buffer: &wgpu::Buffer,
+        min_cell_size: f32,
+    ) -> GridIndex {
+        GridIndex::build_with_params(gpu, points_buffer, grid_params(points, Some(min_cell_size)))
+    }
+
+    fn build_with_params(
+        gpu: &WgpuCompute,
+        points_buffer: &wgpu::Buffer,
+        params: GridParams,
+    ) -> GridIndex {
+        let device = &gpu.device;
+        let cell_count = params.cell_count as u64;
+        let point_count = params.point_count as u64;
+
+        let params_buffer = gpu::uniform_buffer(device, "Grid Params Buffer", &params);
+        let cell_counts = gpu::empty_storage_buffer(
+            device,
+            "Grid Cell Counts Buffer",
+            cell_count * 4,
+            wgpu::BufferUsages::COPY_DST,
+        );
+        let cell_start = gpu::empty_storage_buffer(
+            device,
+            "Grid Cell Start Buffer",
+            (cell_count + 1) * 4,
+            wgpu::BufferUsages::COPY_SRC,
+        );
+        let sorted_indices = gpu::empty_storage_buffer(
+            device,
+            "Grid Sorted Indices Buffer",
+            point_count * 4,
+            wgpu::BufferUsages::COPY_SRC,
+        );
+
+        let count_pipeline = gpu.pipeline(GRID_SHADER, "count_points");
+        let scatter_pipeline = gpu.pipeline(GRID_SHADER, "scatter_points");
+        let count_bind_group = gpu::bind_group(
+            device,
+            &count_pipeline,
+            &[points_buffer, &params_buffer, &cell_counts],
+        );
+        let scatter_bind_group = gpu::bind_group(
+            device,
+            &scatter_pipeline,
+            &[
+                points_buffer,
+                &params_buffer,
+                &cell_counts,
+                &cell_start,
+                &sorted_indices,
+            ],
+        );
+
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        gpu::dispatch(
+            device,
+            &mut encoder,
+            &count_pipeline,
+            &count_bind_group,
+            params.point_count,
+        );
+        gpu::exclusive_scan(gpu, &mut encoder, &cell_counts, &cell_start);
+        encoder.clear_buffer(&cell_counts, 0, None);
+        gpu::dispatch(
+            device,
+            &mut encoder,
+            &scatter_pipeline,
+            &scatter_bind_group,
+            params.point_count,
+        );
+        gpu.queue.submit(Some(encoder.finish()));
+
+        GridIndex {
+            params,
+            cell_start,
+            sorted_indices,
+        }
+    }
+
+    pub(crate) fn dims(&self) -> [u32; 2] {
+        self.params.dims
+    }
+
+    pub(crate) fn origin(&self) -> Vec2 {
+        self.params.origin
+    }
+
+    pub(crate) fn inv_cell_size(&self) -> Vec2 {
+        self.params.inv_cell_size
+    }
+
+    pub(crate) fn cell_count(&self) -> u32 {
+        self.params.cell_count
+    }
+
+    // First cell and number of cells per axis covering `bounds`, `None` when it misses the grid.
+    pub(crate) fn cell_range(&self, bounds: &ComputeRect) -> Option<([u32; 2], [u32; 2])> {
+        self.params.cell_range(bounds)
+    }
+}
+
+impl GridParams {
+    // Padded by one cell on each side so CPU and GPU rounding can never drop a candidate. Bounds with
+    // `min` above `max`, as a negative radius gives, cover no cell at all.
+    fn cell_range(&self, bounds: &ComputeRect) -> Option<([u32; 2], [u32; 2])> {
+        let mut cell_min = [0; 2];
+        let mut cell_span = [0; 2];
+        for axis in 0..2 {
+            let to_cell =
+                |value: f32| ((value - self.origin[axis]) * self.inv_cell_size[axis]).floor();
+            let low = to_cell(bounds.min[axis]) - 1.0;
+            let high = to_cell(bounds.max[axis]) + 1.0;
+            let last = (self.dims[axis] - 1) as f32;
+            // Also false for NaN.
+            if !(low <= high && high >= 0.0 && low <= last) {
+                return None;
+            }
+            let low = low.max(0.0) as u32;
+            let high = high.min(last) as u32;
+            cell_min[axis] = low;
+            cell_span[axis] = high - low + 1;
+        }
+        Some((cell_min, cell_span))
+    }
+}
+
+fn grid_params(points: &[Vec2], min_cell_size: Option<f32>) -> GridParams {
+    let mut min = [f32::MAX; 2];
+    let mut max = [f32::MIN; 2];
+    for point in points {
+        for axis in 0..2 {
+            min[axis] = min[axis].min(point[axis]);
+            max[axis] = max[axis].max(point[axis]);
+        }
+    }
+    if points.is_empty() {
+        min = [0.0; 2];
+        max = [0.0; 2];
+    }
+
+    let extent = [
+        (max[0] - min[0]).max(f32::EPSILON),
+        (max[1] - min[1]).max(f32::EPSILON),
+    ];
+    let (dims_x, dims_y) = match min_cell_size {
+        Some(size) => (
+            (extent[0] / size).clamp(1.0, MAX_CELLS_PER_AXIS as f32) as u32,
+            (extent[1] / size).clamp(1.0, MAX_CELLS_PER_AXIS as f32) as u32,
+        ),
+        None => {
+            let cells = (points.len() / POINTS_PER_CELL).max(1) as f32;
+            let dims_x = (cells * extent[0] / extent[1])
+                .sqrt()
+                .clamp(1.0, MAX_CELLS_PER_AXIS as f32) as u32;
+            let dims_y = (cells / dims_x as f32).clamp(1.0, MAX_CELLS_PER_AXIS as f32) as u32;
+            (dims_x, dims_y)
+        }
+    };
+
+    GridParams {
+        origin: min,
+        inv_cell_size: [dims_x as f32 / extent[0], dims_y as f32 / extent[1]],
+        dims: [dims_x, dims_y],
+        point_count: points.len() as u32,
+        cell_count: dims_x * dims_y,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(min: Vec2, max: Vec2) -> ComputeRect {
+        ComputeRect { min, max }
+    }
+
+    #[test]
+    fn cell_range_is_clamped_to_the_grid() {
+        let points: Vec<Vec2> = (0..64).map(|i| [(i % 8) as f32, (i / 8) as f32]).collect();
+        let params = grid_params(&points, Some(1.0));
+        assert_eq!(params.dims, [7, 7]);
+        assert_eq!(
+            params.cell_range(&rect([2.5, 2.5], [3.5, 3.5])),
+            Some(([1, 1], [4, 4]))
+        );
+        assert_eq!(
+            params.cell_range(&rect([-50.0, 6.0], [50.0, 60.0])),
+            Some(([0, 5], [7, 2]))
+        );
+        assert_eq!(params.cell_range(&rect([20.0, 0.0], [30.0, 1.0])), None);
+        assert_eq!(params.cell_range(&rect([f32::NAN, 0.0], [1.0, 1.0])), None);
+    }
+
+    // A circle with a negative radius has its bounds inside out, which used to underflow the span.
+    #[test]
+    fn inverted_bounds_cover_no_cells() {
+        let points: Vec<Vec2> = (0..64).map(|i| [(i % 8) as f32, (i / 8) as f32]).collect();
+        let params = grid_params(&points, Some(1.0));
+        assert_eq!(params.cell_range(&rect([6.0, 3.0], [1.0, 4.0])), None);
+        let circle = crate::api::query::QueryShape::Circle {
+            center: [3.0, 3.0],
+            radius: -2.5,
+        };
+        assert_eq!(params.cell_range(&circle.bounds()), None);
+    }
+}
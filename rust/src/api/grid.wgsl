@@ -0,0 +1,47 @@
+// Uniform grid construction: count points per cell, then scatter point indices into cell order.
+// Offsets between the two passes come from `scan.wgsl`.
+struct Grid {
+    origin: vec2<f32>,
+    inv_cell_size: vec2<f32>,
+    dims: vec2<u32>,
+    point_count: u32,
+    cell_count: u32,
+};
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> grid: Grid;
+@group(0) @binding(2) var<storage, read_write> cellCounts: array<atomic<u32>>;
+@group(0) @binding(3) var<storage, read> cellStart: array<u32>;
+@group(0) @binding(4) var<storage, read_write> sortedIndices: array<u32>;
+
+fn cellOf(point: vec2<f32>) -> u32 {
+    let cell = clamp(
+        vec2<i32>(floor((point - grid.origin) * grid.inv_cell_size)),
+        vec2<i32>(0, 0),
+        vec2<i32>(grid.dims) - vec2<i32>(1, 1),
+    );
+    return u32(cell.y) * grid.dims.x + u32(cell.x);
+}
+
+@compute
+@workgroup_size(64)
+fn count_points(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= grid.point_count) {
+        return;
+    }
+    atomicAdd(&cellCounts[cellOf(points[i])], 1u);
+}
+
+// `cellCounts` must be cleared again before this pass, it serves as the per cell write cursor.
+@compute
+@workgroup_size(64)
+fn scatter_points(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= grid.point_count) {
+        return;
+    }
+    let cell = cellOf(points[i]);
+    let slot = atomicAdd(&cellCounts[cell], 1u);
+    sortedIndices[cellStart[cell] + slot] = i;
+}
@@ -0,0 +1,574 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::PointSet,
+    simple::{send_command, ComputeCommand, ComputeRect, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const HEATMAP_SHADER: &str = include_str!("heatmap.wgsl");
+
+// Every pixel sums up to (2 * MAX_RADIUS + 1)² bins.
+const MAX_RADIUS: f32 = 64.0;
+
+/// Shape of the kernel spread around every point. All of them integrate to the weight of the point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KernelShape {
+    /// Bandwidth is the standard deviation, cut off at three of them.
+    Gaussian,
+    /// `1 - (d / bandwidth)²` inside the bandwidth.
+    Epanechnikov,
+    /// `(1 - (d / bandwidth)²)²` inside the bandwidth, smoother at the edge than Epanechnikov.
+    Quartic,
+    /// Constant inside the bandwidth.
+    Uniform,
+}
+
+impl KernelShape {
+    fn support(&self, bandwidth: f32) -> f32 {
+        match self {
+            KernelShape::Gaussian => 3.0 * bandwidth,
+            _ => bandwidth,
+        }
+    }
+
+    // Unnormalized value at distance `d`.
+    fn value(&self, d: f32, bandwidth: f32) -> f32 {
+        let u = d / bandwidth;
+        if u > self.support(1.0) {
+            return 0.0;
+        }
+        match self {
+            KernelShape::Gaussian => (-0.5 * u * u).exp(),
+            KernelShape::Epanechnikov => 1.0 - u * u,
+            KernelShape::Quartic => (1.0 - u * u).powi(2),
+            KernelShape::Uniform => 1.0,
+        }
+    }
+}
+
+/// Colormaps for `density_image`, from low to high density.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colormap {
+    Viridis,
+    Magma,
+    Inferno,
+    Grayscale,
+}
+
+impl Colormap {
+    fn stops(&self) -> &'static [[u8; 3]] {
+        match self {
+            Colormap::Viridis => &[
+                [0x44, 0x01, 0x54],
+                [0x47, 0x2d, 0x7b],
+                [0x3b, 0x52, 0x8b],
+                [0x2c, 0x72, 0x8e],
+                [0x21, 0x91, 0x8c],
+                [0x28, 0xae, 0x80],
+                [0x5e, 0xc9, 0x62],
+                [0xad, 0xdc, 0x30],
+                [0xfd, 0xe7, 0x25],
+            ],
+            Colormap::Magma => &[
+                [0x00, 0x00, 0x04],
+                [0x1c, 0x10, 0x44],
+                [0x4f, 0x12, 0x7b],
+                [0x81, 0x25, 0x81],
+                [0xb5, 0x36, 0x7a],
+                [0xe5, 0x59, 0x64],
+                [0xfb, 0x87, 0x61],
+                [0xfe, 0xc2, 0x87],
+                [0xfc, 0xfd, 0xbf],
+            ],
+            Colormap::Inferno => &[
+                [0x00, 0x00, 0x04],
+                [0x1f, 0x0c, 0x48],
+                [0x55, 0x0f, 0x6d],
+                [0x88, 0x22, 0x6a],
+                [0xba, 0x36, 0x55],
+                [0xe3, 0x59, 0x33],
+                [0xf9, 0x8e, 0x09],
+                [0xf9, 0xcb, 0x35],
+                [0xfc, 0xff, 0xa4],
+            ],
+            Colormap::Grayscale => &[[0, 0, 0], [0xff, 0xff, 0xff]],
+        }
+    }
+
+    fn color(&self, t: f32) -> [u8; 3] {
+        let stops = self.stops();
+        let position = t.clamp(0.0, 1.0) * (stops.len() - 1) as f32;
+        let k = (position as usize).min(stops.len() - 2);
+        let f = position - k as f32;
+        std::array::from_fn(|c| {
+            (stops[k][c] as f32 + (stops[k + 1][c] as f32 - stops[k][c] as f32) * f).round() as u8
+        })
+    }
+}
+
+/// Density of a point set over a grid of pixels, in weight per unit area, row by row starting at the `min`
+/// corner of the viewport.
+#[derive(Clone, Debug)]
+pub struct DensityGrid {
+    pub width: u32,
+    pub height: u32,
+    pub values: Vec<f32>,
+    pub max: f32,
+}
+
+/// A `DensityGrid` colored with a colormap, 4 RGBA bytes per pixel in the same order. Pixels without any
+/// density are transparent.
+#[derive(Clone, Debug)]
+pub struct HeatmapImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+    /// The density drawn with the top color of the colormap.
+    pub max: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct HeatmapParams {
+    origin: Vec2,
+    cell: Vec2,
+    width: u32,
+    height: u32,
+    radius_x: u32,
+    radius_y: u32,
+    point_count: u32,
+    weighted: u32,
+    scale: f32,
+    _padding: u32,
+}
+
+// Pixel grid of an estimate, padded by the kernel support on every side, and the kernel weights for every
+// pixel offset in that support, normalized so each point adds its weight to the whole grid.
+pub(crate) struct Layout {
+    params: HeatmapParams,
+    kernel: Vec<f32>,
+}
+
+impl Layout {
+    // `None` for empty or oversized grids, empty viewports, and bandwidths that are not positive or reach more than
+    // `MAX_RADIUS` pixels.
+    pub(crate) fn new(
+        viewport: &ComputeRect,
+        width: u32,
+        height: u32,
+        bandwidth: f32,
+        shape: KernelShape,
+    ) -> Option<Layout> {
+        let size = [
+            viewport.max[0] - viewport.min[0],
+            viewport.max[1] - viewport.min[1],
+        ];
+        if width == 0 || height == 0 || !(size[0] > 0.0 && size[1] > 0.0 && bandwidth > 0.0) {
+            return None;
+        }
+        let cell = [size[0] / width as f32, size[1] / height as f32];
+        let support = shape.support(bandwidth);
+        let radius_x = (support / cell[0]).ceil();
+        let radius_y = (support / cell[1]).ceil();
+        if !(radius_x <= MAX_RADIUS && radius_y <= MAX_RADIUS) {
+            return None;
+        }
+        let (radius_x, radius_y) = (radius_x as u32, radius_y as u32);
+        width
+            .checked_add(2 * radius_x)?
+            .checked_mul(height.checked_add(2 * radius_y)?)?;
+        let mut kernel = Vec::with_capacity(((2 * radius_x + 1) * (2 * radius_y + 1)) as usize);
+        for dy in -(radius_y as i32)..=radius_y as i32 {
+            for dx in -(radius_x as i32)..=radius_x as i32 {
+                let d = (dx as f32 * cell[0]).hypot(dy as f32 * cell[1]);
+                kernel.push(shape.value(d, bandwidth));
+            }
+        }
+        let total: f32 = kernel.iter().sum::<f32>() * cell[0] * cell[1];
+        kernel.iter_mut().for_each(|k| *k /= total);
+        Some(Layout {
+            params: HeatmapParams {
+                origin: [
+                    viewport.min[0] - radius_x as f32 * cell[0],
+                    viewport.min[1] - radius_y as f32 * cell[1],
+                ],
+                cell,
+                width,
+                height,
+                radius_x,
+                radius_y,
+                point_count: 0,
+                weighted: 0,
+                scale: 1.0,
+                _padding: 0,
+            },
+            kernel,
+        })
+    }
+
+    // Kernel taps summed over the grid, which is what a density grid costs on either backend.
+    pub(crate) fn taps(&self) -> u64 {
+        self.params.width as u64 * self.params.height as u64 * self.kernel.len() as u64
+    }
+
+    // Bytes of the padded bins and the grid.
+    pub(crate) fn size(&self) -> u64 {
+        let padded = self.padded_width() as u64 * self.padded_height() as u64;
+        (padded + self.params.width as u64 * self.params.height as u64) * 4
+    }
+
+    fn padded_width(&self) -> u32 {
+        self.params.width + 2 * self.params.radius_x
+    }
+
+    fn padded_height(&self) -> u32 {
+        self.params.height + 2 * self.params.radius_y
+    }
+}
+
+// Fixed point scale of the bins, a power of two so that the sum of all weights fits in an `i32`.
+fn fixed_point_scale(point_count: usize, weights: Option<&[f32]>) -> f32 {
+    let total = weights.map_or(point_count as f64, |weights| {
+        weights.iter().map(|w| w.abs() as f64).sum()
+    });
+    let exponent = (f64::from(1 << 30) / total).log2().floor();
+    2f32.powi(exponent.clamp(-100.0, 100.0) as i32)
+}
+
+fn density_grid_of(width: u32, height: u32, values: Vec<f32>) -> DensityGrid {
+    let max = values.iter().copied().fold(0.0, f32::max);
+    DensityGrid {
+        width,
+        height,
+        values,
+        max,
+    }
+}
+
+// Same binning and convolution as `heatmap.wgsl`.
+pub(crate) fn density_cpu(
+    points: &[Vec2],
+    weights: Option<&[f32]>,
+    layout: &Layout,
+) -> DensityGrid {
+    let params = &layout.params;
+    let scale = fixed_point_scale(points.len(), weights);
+    let (padded_width, padded_height) = (layout.padded_width(), layout.padded_height());
+    let mut bins = vec![0i32; (padded_width * padded_height) as usize];
+    for (i, point) in points.iter().enumerate() {
+        let x = ((point[0] - params.origin[0]) / params.cell[0]).floor();
+        let y = ((point[1] - params.origin[1]) / params.cell[1]).floor();
+        if !(x >= 0.0 && y >= 0.0 && x < padded_width as f32 && y < padded_height as f32) {
+            continue;
+        }
+        let weight = weights.map_or(1.0, |w| w[i]);
+        bins[(y as u32 * padded_width + x as u32) as usize] += (weight * scale).round() as i32;
+    }
+    let span = (2 * params.radius_x + 1) as usize;
+    let mut values = Vec::with_capacity((params.width * params.height) as usize);
+    for y in 0..params.height as usize {
+        for x in 0..params.width as usize {
+            let mut sum = 0.0;
+            for (dy, kernel) in layout.kernel.chunks(span).enumerate() {
+                let row = (y + dy) * padded_width as usize + x;
+                sum += bins[row..row + span]
+                    .iter()
+                    .zip(kernel)
+                    .map(|(&bin, k)| bin as f32 * k)
+                    .sum::<f32>();
+            }
+            values.push(sum / scale);
+        }
+    }
+    density_grid_of(params.width, params.height, values)
+}
+
+// `None` when the padded grid does not fit in a storage binding.
+pub(crate) async fn density_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    weights: Option<&[f32]>,
+    layout: &Layout,
+) -> Option<DensityGrid> {
+    let device = &gpu.device;
+    let limit = device.limits().max_storage_buffer_binding_size as u64;
+    let bins_size = layout.padded_width() as u64 * layout.padded_height() as u64 * 4;
+    if bins_size > limit || layout.kernel.len() as u64 * 4 > limit {
+        return None;
+    }
+    let params = HeatmapParams {
+        point_count: set.points.len() as u32,
+        weighted: weights.is_some() as u32,
+        scale: fixed_point_scale(set.points.len(), weights),
+        ..layout.params
+    };
+    let pixel_count = params.width * params.height;
+
+    let params_buffer = gpu::uniform_buffer(device, "Heatmap Params Buffer", &params);
+    let weights_buffer = gpu::storage_buffer(
+        device,
+        "Heatmap Weights Buffer",
+        weights.unwrap_or_default(),
+        wgpu::BufferUsages::empty(),
+    );
+    let kernel_buffer = gpu::storage_buffer(
+        device,
+        "Heatmap Kernel Buffer",
+        &layout.kernel,
+        wgpu::BufferUsages::empty(),
+    );
+    let bins_buffer = gpu::empty_storage_buffer(
+        device,
+        "Heatmap Bins Buffer",
+        bins_size,
+        wgpu::BufferUsages::COPY_DST,
+    );
+    let density_buffer = gpu::empty_storage_buffer(
+        device,
+        "Heatmap Density Buffer",
+        pixel_count as u64 * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let splat_pipeline = gpu.pipeline(HEATMAP_SHADER, "splat");
+    let gather_pipeline = gpu.pipeline(HEATMAP_SHADER, "gather");
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    encoder.clear_buffer(&bins_buffer, 0, None);
+    let splat_bind_group = gpu::bind_group_at(
+        device,
+        &splat_pipeline,
+        &[
+            (0, &set.buffer),
+            (1, &params_buffer),
+            (2, &weights_buffer),
+            (3, &bins_buffer),
+        ],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &splat_pipeline,
+        &splat_bind_group,
+        params.point_count,
+    );
+    let gather_bind_group = gpu::bind_group_at(
+        device,
+        &gather_pipeline,
+        &[
+            (1, &params_buffer),
+            (4, &bins_buffer),
+            (5, &kernel_buffer),
+            (6, &density_buffer),
+        ],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &gather_pipeline,
+        &gather_bind_group,
+        pixel_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let values =
+        gpu::read_buffer(device, &gpu.queue, &density_buffer, 0, pixel_count as usize).await;
+    Some(density_grid_of(params.width, params.height, values))
+}
+
+pub(crate) fn colorize(grid: &DensityGrid, colormap: Colormap, max: Option<f32>) -> HeatmapImage {
+    let max = max.unwrap_or(grid.max);
+    let mut pixels = Vec::with_capacity(grid.values.len() * 4);
+    for &value in &grid.values {
+        if value > 0.0 && max > 0.0 {
+            pixels.extend(colormap.color(value / max));
+            pixels.push(0xff);
+        } else {
+            pixels.extend([0; 4]);
+        }
+    }
+    HeatmapImage {
+        width: grid.width,
+        height: grid.height,
+        pixels,
+        max,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Kernel density estimate of `point_set` over `viewport` at `width` by `height` pixels. Every point adds
+/// its weight, 1 without `weights`, spread by a kernel of `shape` with `bandwidth` in point units; points
+/// are binned to pixels first, so the result is exact up to half a pixel. Points outside the viewport
+/// still count near its edges. `None` if the point set does not exist, `weights` does not have one finite
+/// value per point, the grid or viewport is empty, or `bandwidth` is not positive. Also `None` when the
+/// kernel reaches more than 64 pixels (a third of that for `Gaussian`), when the grid takes more than
+/// 256 MiB, or when pixels times kernel size passes 2³⁰ and the GPU cannot take it; use a coarser grid for
+/// wide kernels.
+#[allow(clippy::too_many_arguments)]
+pub async fn density_grid(
+    point_set: u32,
+    viewport: ComputeRect,
+    width: u32,
+    height: u32,
+    bandwidth: f32,
+    shape: KernelShape,
+    weights: Option<Vec<f32>>,
+) -> Option<DensityGrid> {
+    match send_command(ComputeCommand::Density(
+        point_set, viewport, width, height, bandwidth, shape, weights,
+    ))
+    .await
+    {
+        ComputeResponse::Density(grid) => grid,
+        _ => None,
+    }
+}
+
+/// `density_grid` drawn with `colormap`, scaled so `max` gets the top color, or the densest pixel without
+/// it. Useful to keep colors stable while panning.
+#[allow(clippy::too_many_arguments)]
+pub async fn density_image(
+    point_set: u32,
+    viewport: ComputeRect,
+    width: u32,
+    height: u32,
+    bandwidth: f32,
+    shape: KernelShape,
+    weights: Option<Vec<f32>>,
+    colormap: Colormap,
+    max: Option<f32>,
+) -> Option<HeatmapImage> {
+    let grid = density_grid(
+        point_set, viewport, width, height, bandwidth, shape, weights,
+    )
+    .await?;
+    Some(colorize(&grid, colormap, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: ComputeRect = ComputeRect {
+        min: [0.0, 0.0],
+        max: [40.0, 20.0],
+    };
+
+    // Xorshift points in `[-5, 45) x [-5, 25)`, so some fall outside the viewport but within reach of it.
+    fn random_points(count: usize) -> Vec<Vec2> {
+        let mut state = 0x7f4a_7c15u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1 << 24) as f32
+        };
+        (0..count)
+            .map(|_| [next() * 50.0 - 5.0, next() * 30.0 - 5.0])
+            .collect()
+    }
+
+    // Every point spread over the pixels around its own, the other way round from `density_cpu`.
+    fn brute_force(points: &[Vec2], weights: Option<&[f32]>, layout: &Layout) -> Vec<f64> {
+        let params = &layout.params;
+        let (width, height) = (params.width as i64, params.height as i64);
+        let span = 2 * params.radius_x as i64 + 1;
+        let mut values = vec![0.0f64; (width * height) as usize];
+        for (i, point) in points.iter().enumerate() {
+            if !point.iter().all(|v| v.is_finite()) {
+                continue;
+            }
+            let bin_x = ((point[0] - params.origin[0]) / params.cell[0]).floor() as i64;
+            let bin_y = ((point[1] - params.origin[1]) / params.cell[1]).floor() as i64;
+            let weight = weights.map_or(1.0, |w| w[i]) as f64;
+            for (k, &kernel) in layout.kernel.iter().enumerate() {
+                let (x, y) = (bin_x - k as i64 % span, bin_y - k as i64 / span);
+                if (0..width).contains(&x) && (0..height).contains(&y) {
+                    values[(y * width + x) as usize] += weight * kernel as f64;
+                }
+            }
+        }
+        values
+    }
+
+    fn assert_close(grid: &DensityGrid, expected: &[f64]) {
+        assert_eq!(grid.values.len(), expected.len());
+        let scale = expected
+            .iter()
+            .fold(0.0f64, |m, v| m.max(v.abs()))
+            .max(1e-6);
+        for (i, (&value, &expected)) in grid.values.iter().zip(expected).enumerate() {
+            assert!(
+                (value as f64 - expected).abs() <= scale * 1e-4,
+                "pixel {i}: {value} against {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let points = random_points(400);
+        let weights: Vec<f32> = (0..400).map(|i| (i % 7) as f32 - 2.0).collect();
+        for shape in [
+            KernelShape::Gaussian,
+            KernelShape::Epanechnikov,
+            KernelShape::Quartic,
+            KernelShape::Uniform,
+        ] {
+            let layout = Layout::new(&VIEWPORT, 40, 10, 1.5, shape).unwrap();
+            assert_close(
+                &density_cpu(&points, None, &layout),
+                &brute_force(&points, None, &layout),
+            );
+            assert_close(
+                &density_cpu(&points, Some(&weights), &layout),
+                &brute_force(&points, Some(&weights), &layout),
+            );
+        }
+    }
+
+    #[test]
+    fn each_point_adds_its_weight() {
+        let layout = Layout::new(&VIEWPORT, 80, 40, 2.0, KernelShape::Quartic).unwrap();
+        let area = layout.params.cell[0] * layout.params.cell[1];
+        let total = |grid: &DensityGrid| grid.values.iter().sum::<f32>() * area;
+
+        let grid = density_cpu(&[[20.0, 10.0]], None, &layout);
+        assert!((total(&grid) - 1.0).abs() < 1e-4);
+        // Duplicates count once each, and points that are not finite not at all.
+        let points = [
+            [20.0, 10.0],
+            [f32::NAN, 10.0],
+            [20.0, 10.0],
+            [f32::INFINITY; 2],
+        ];
+        let doubled = density_cpu(&points, None, &layout);
+        assert!((total(&doubled) - 2.0).abs() < 1e-4);
+        assert_eq!(doubled.max, grid.max * 2.0);
+        let weighted = density_cpu(&points, Some(&[0.5, 3.0, 1.0, 3.0]), &layout);
+        assert!((total(&weighted) - 1.5).abs() < 1e-4);
+
+        let empty = density_cpu(&[], None, &layout);
+        assert_eq!((empty.width, empty.height), (80, 40));
+        assert!(empty.values.iter().all(|&v| v == 0.0));
+        assert_eq!(empty.max, 0.0);
+    }
+
+    #[test]
+    fn unusable_layouts() {
+        let flat = ComputeRect {
+            min: [0.0, 5.0],
+            max: [10.0, 5.0],
+        };
+        assert!(Layout::new(&flat, 10, 10, 1.0, KernelShape::Uniform).is_none());
+        assert!(Layout::new(&VIEWPORT, 0, 10, 1.0, KernelShape::Uniform).is_none());
+        assert!(Layout::new(&VIEWPORT, 10, 10, 0.0, KernelShape::Uniform).is_none());
+        assert!(Layout::new(&VIEWPORT, 10, 10, f32::NAN, KernelShape::Uniform).is_none());
+        // 64 pixels of reach is the most, a third of that for a Gaussian.
+        assert!(Layout::new(&VIEWPORT, 40, 20, 64.0, KernelShape::Uniform).is_some());
+        assert!(Layout::new(&VIEWPORT, 40, 20, 65.0, KernelShape::Uniform).is_none());
+        assert!(Layout::new(&VIEWPORT, 40, 20, 22.0, KernelShape::Gaussian).is_none());
+    }
+}
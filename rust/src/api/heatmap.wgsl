@@ -0,0 +1,77 @@
+// Kernel density estimate over a pixel grid. `splat` adds the weight of every point to the bin it falls
+// in, over a grid padded by the kernel radius so points just outside the viewport still count. There is
+// no atomic float add, so bins hold weights in fixed point, scaled so that all of them fit in one bin.
+// `gather` then convolves the bins with the kernel weights, computed on the CPU for every pixel offset in
+// the kernel's support.
+struct Heatmap {
+    // Corner of the padded grid.
+    origin: vec2<f32>,
+    cell: vec2<f32>,
+    width: u32,
+    height: u32,
+    radius_x: u32,
+    radius_y: u32,
+    point_count: u32,
+    weighted: u32,
+    scale: f32,
+};
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> heatmap: Heatmap;
+@group(0) @binding(2) var<storage, read> weights: array<f32>;
+@group(0) @binding(3) var<storage, read_write> bins: array<atomic<i32>>;
+@group(0) @binding(4) var<storage, read> totals: array<i32>;
+@group(0) @binding(5) var<storage, read> kernel: array<f32>;
+@group(0) @binding(6) var<storage, read_write> density: array<f32>;
+
+fn padded_width() -> u32 {
+    return heatmap.width + 2u * heatmap.radius_x;
+}
+
+fn padded_height() -> u32 {
+    return heatmap.height + 2u * heatmap.radius_y;
+}
+
+@compute
+@workgroup_size(64)
+fn splat(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= heatmap.point_count) {
+        return;
+    }
+    let cell = floor((points[i] - heatmap.origin) / heatmap.cell);
+    // Also false for NaN.
+    if (!(all(cell >= vec2<f32>(0.0)) && cell.x < f32(padded_width()) && cell.y < f32(padded_height()))) {
+        return;
+    }
+    var weight = 1.0;
+    if (heatmap.weighted != 0u) {
+        weight = weights[i];
+    }
+    let fixed = i32(round(weight * heatmap.scale));
+    if (fixed != 0) {
+        atomicAdd(&bins[u32(cell.y) * padded_width() + u32(cell.x)], fixed);
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn gather(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= heatmap.width * heatmap.height) {
+        return;
+    }
+    // Output pixel (x, y) sits at (x + radius_x, y + radius_y) in the padded grid, so its support starts
+    // at (x, y).
+    let x = i % heatmap.width;
+    let y = i / heatmap.width;
+    let span = 2u * heatmap.radius_x + 1u;
+    var sum = 0.0;
+    for (var dy = 0u; dy <= 2u * heatmap.radius_y; dy++) {
+        let row = (y + dy) * padded_width() + x;
+        for (var dx = 0u; dx < span; dx++) {
+            sum += f32(totals[row + dx]) * kernel[dy * span + dx];
+        }
+    }
+    density[i] = sum / heatmap.scale;
+}
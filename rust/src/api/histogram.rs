@@ -0,0 +1,375 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::PointSet,
+    simple::{send_command, ComputeCommand, ComputeRect, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const HISTOGRAM_SHADER: &str = include_str!("histogram.wgsl");
+
+/// Points binned into a grid over an extent, row by row starting at the `min` corner of the extent.
+#[derive(Clone, Debug)]
+pub struct Histogram2d {
+    pub bins_x: u32,
+    pub bins_y: u32,
+    pub counts: Vec<u32>,
+    /// Sum of the values of the points in every bin, empty without values.
+    pub sums: Vec<f32>,
+    /// Mean of the values of the points in every bin, 0 for empty bins, empty without values.
+    pub means: Vec<f32>,
+}
+
+// Uniforms of `histogram.wgsl`, also the bin layout of the CPU path.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub(crate) struct HistogramParams {
+    origin: Vec2,
+    end: Vec2,
+    cell: Vec2,
+    bins_x: u32,
+    bins_y: u32,
+    point_count: u32,
+    max_magnitude: f32,
+}
+
+impl HistogramParams {
+    // `None` for empty or oversized grids and empty extents.
+    pub(crate) fn new(extent: &ComputeRect, bins_x: u32, bins_y: u32) -> Option<HistogramParams> {
+        let size = [extent.max[0] - extent.min[0], extent.max[1] - extent.min[1]];
+        if bins_x == 0 || bins_y == 0 || !(size[0] > 0.0 && size[1] > 0.0) {
+            return None;
+        }
+        bins_x.checked_mul(bins_y)?;
+        Some(HistogramParams {
+            origin: extent.min,
+            end: extent.max,
+            cell: [size[0] / bins_x as f32, size[1] / bins_y as f32],
+            bins_x,
+            bins_y,
+            point_count: 0,
+            max_magnitude: 0.0,
+        })
+    }
+
+    // Same as `bin_of` in `histogram.wgsl`.
+    fn bin_of(&self, point: &Vec2) -> Option<usize> {
+        if !(point[0] >= self.origin[0]
+            && point[1] >= self.origin[1]
+            && point[0] <= self.end[0]
+            && point[1] <= self.end[1])
+        {
+            return None;
+        }
+        let x = ((point[0] - self.origin[0]) / self.cell[0])
+            .floor()
+            .min((self.bins_x - 1) as f32);
+        let y = ((point[1] - self.origin[1]) / self.cell[1])
+            .floor()
+            .min((self.bins_y - 1) as f32);
+        Some(y as usize * self.bins_x as usize + x as usize)
+    }
+}
+
+pub(crate) fn histogram_cpu(
+    points: &[Vec2],
+    values: Option<&[f32]>,
+    params: &HistogramParams,
+) -> Histogram2d {
+    let (bins_x, bins_y) = (params.bins_x, params.bins_y);
+    let bin_count = (bins_x * bins_y) as usize;
+    let mut counts = vec![0u32; bin_count];
+    let mut totals = vec![0.0f64; if values.is_some() { bin_count } else { 0 }];
+    for (i, point) in points.iter().enumerate() {
+        if let Some(bin) = params.bin_of(point) {
+            counts[bin] += 1;
+            if let Some(values) = values {
+                totals[bin] += values[i] as f64;
+            }
+        }
+    }
+    let sums = totals.iter().map(|&sum| sum as f32).collect();
+    let means = totals
+        .iter()
+        .zip(&counts)
+        .map(|(&sum, &count)| {
+            if count == 0 {
+                0.0
+            } else {
+                (sum / count as f64) as f32
+            }
+        })
+        .collect();
+    Histogram2d {
+        bins_x,
+        bins_y,
+        counts,
+        sums,
+        means,
+    }
+}
+
+// `None` when the bins do not fit in a storage binding.
+pub(crate) async fn histogram_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    values: Option<&[f32]>,
+    params: &HistogramParams,
+) -> Option<Histogram2d> {
+    let device = &gpu.device;
+    let (bins_x, bins_y) = (params.bins_x, params.bins_y);
+    let bin_count = bins_x * bins_y;
+    let size = bin_count as u64 * 4;
+    if size > device.limits().max_storage_buffer_binding_size as u64 {
+        return None;
+    }
+    let params = HistogramParams {
+        point_count: set.points.len() as u32,
+        max_magnitude: values.map_or(0.0, |values| {
+            values.iter().fold(0.0, |max, value| value.abs().max(max))
+        }),
+        ..*params
+    };
+
+    let params_buffer = gpu::uniform_buffer(device, "Histogram Params Buffer", &params);
+    let counts_buffer = gpu::empty_storage_buffer(
+        device,
+        "Histogram Counts Buffer",
+        size,
+        wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+    );
+    let count_pipeline = gpu.pipeline(HISTOGRAM_SHADER, "count_points");
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    encoder.clear_buffer(&counts_buffer, 0, None);
+    let count_bind_group = gpu::bind_group_at(
+        device,
+        &count_pipeline,
+        &[(0, &set.buffer), (1, &params_buffer), (3, &counts_buffer)],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &count_pipeline,
+        &count_bind_group,
+        params.point_count,
+    );
+
+    let Some(values) = values else {
+        gpu.queue.submit(Some(encoder.finish()));
+        return Some(Histogram2d {
+            bins_x,
+            bins_y,
+            counts: gpu::read_buffer(device, &gpu.queue, &counts_buffer, 0, bin_count as usize)
+                .await,
+            sums: Vec::new(),
+            means: Vec::new(),
+        });
+    };
+
+    let values_buffer = gpu::storage_buffer(
+        device,
+        "Histogram Values Buffer",
+        values,
+        wgpu::BufferUsages::empty(),
+    );
+    let fixed_sums_buffer = gpu::empty_storage_buffer(
+        device,
+        "Histogram Fixed Sums Buffer",
+        size,
+        wgpu::BufferUsages::COPY_DST,
+    );
+    let sums_buffer = gpu::empty_storage_buffer(
+        device,
+        "Histogram Sums Buffer",
+        size,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let means_buffer = gpu::empty_storage_buffer(
+        device,
+        "Histogram Means Buffer",
+        size,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let sum_pipeline = gpu.pipeline(HISTOGRAM_SHADER, "sum_values");
+    let finish_pipeline = gpu.pipeline(HISTOGRAM_SHADER, "finish");
+
+    encoder.clear_buffer(&fixed_sums_buffer, 0, None);
+    let sum_bind_group = gpu::bind_group_at(
+        device,
+        &sum_pipeline,
+        &[
+            (0, &set.buffer),
+            (1, &params_buffer),
+            (2, &values_buffer),
+            (4, &fixed_sums_buffer),
+            (5, &counts_buffer),
+        ],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &sum_pipeline,
+        &sum_bind_group,
+        params.point_count,
+    );
+    let finish_bind_group = gpu::bind_group_at(
+        device,
+        &finish_pipeline,
+        &[
+            (1, &params_buffer),
+            (5, &counts_buffer),
+            (6, &fixed_sums_buffer),
+            (7, &sums_buffer),
+            (8, &means_buffer),
+        ],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &finish_pipeline,
+        &finish_bind_group,
+        bin_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    Some(Histogram2d {
+        bins_x,
+        bins_y,
+        counts: gpu::read_buffer(device, &gpu.queue, &counts_buffer, 0, bin_count as usize).await,
+        sums: gpu::read_buffer(device, &gpu.queue, &sums_buffer, 0, bin_count as usize).await,
+        means: gpu::read_buffer(device, &gpu.queue, &means_buffer, 0, bin_count as usize).await,
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Bins the points of `point_set` into `bins_x` by `bins_y` equal bins over `extent`, counting them and,
+/// with `values` holding one value per point, summing and averaging those. Points on the `max` edge of
+/// the extent go into the last bins, points outside it are left out. On the GPU the sums are accurate to
+/// about 2⁻³⁰ of the bin count times the largest magnitude. `None` if the point set does not exist,
+/// `values` does not have one finite value per point, the grid or extent is empty, or the bins take more
+/// than 256 MiB.
+pub async fn histogram_2d(
+    point_set: u32,
+    extent: ComputeRect,
+    bins_x: u32,
+    bins_y: u32,
+    values: Option<Vec<f32>>,
+) -> Option<Histogram2d> {
+    match send_command(ComputeCommand::Histogram(
+        point_set, extent, bins_x, bins_y, values,
+    ))
+    .await
+    {
+        ComputeResponse::Histogram(histogram) => histogram,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXTENT: ComputeRect = ComputeRect {
+        min: [0.0, 0.0],
+        max: [16.0, 8.0],
+    };
+
+    // Xorshift points on a half unit lattice over `[-2, 18] x [-2, 10]`, so many lie on bin edges, on the
+    // extent's edges and outside it, and many coincide.
+    fn lattice_points(count: usize) -> Vec<Vec2> {
+        let mut state = 0x51ed_270bu32;
+        let mut next = move |steps: u32| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            ((state >> 8) % steps) as f32 * 0.5 - 2.0
+        };
+        (0..count).map(|_| [next(41), next(25)]).collect()
+    }
+
+    // Counts and sums per bin, checking every point against the edges of every bin. Bins are half open
+    // except at the `max` edge of the extent.
+    fn brute_force(points: &[Vec2], values: &[f32], bins: (u32, u32)) -> (Vec<u32>, Vec<f64>) {
+        let cell = [16.0 / bins.0 as f32, 8.0 / bins.1 as f32];
+        let within = |value: f32, bin: u32, bin_count: u32, cell: f32| {
+            let (low, high) = (bin as f32 * cell, (bin + 1) as f32 * cell);
+            value >= low && (value < high || (bin + 1 == bin_count && value == high))
+        };
+        let mut counts = Vec::new();
+        let mut sums = Vec::new();
+        for y in 0..bins.1 {
+            for x in 0..bins.0 {
+                let members: Vec<usize> = (0..points.len())
+                    .filter(|&i| {
+                        within(points[i][0], x, bins.0, cell[0])
+                            && within(points[i][1], y, bins.1, cell[1])
+                    })
+                    .collect();
+                counts.push(members.len() as u32);
+                sums.push(members.iter().map(|&i| values[i] as f64).sum());
+            }
+        }
+        (counts, sums)
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut points = lattice_points(1000);
+        points[3] = [f32::NAN, 1.0];
+        points[4] = [f32::INFINITY, 1.0];
+        let values: Vec<f32> = (0..1000).map(|i| (i % 13) as f32 * 0.25 - 1.0).collect();
+        for bins in [(1, 1), (8, 4), (16, 8), (32, 2)] {
+            let params = HistogramParams::new(&EXTENT, bins.0, bins.1).unwrap();
+            let (counts, sums) = brute_force(&points, &values, bins);
+
+            let histogram = histogram_cpu(&points, Some(&values), &params);
+            assert_eq!((histogram.bins_x, histogram.bins_y), bins);
+            assert_eq!(histogram.counts, counts, "{bins:?}");
+            for (bin, &sum) in sums.iter().enumerate() {
+                assert_eq!(histogram.sums[bin], sum as f32);
+                let mean = if counts[bin] == 0 {
+                    0.0
+                } else {
+                    (sum / counts[bin] as f64) as f32
+                };
+                assert_eq!(histogram.means[bin], mean);
+            }
+
+            let unweighted = histogram_cpu(&points, None, &params);
+            assert_eq!(unweighted.counts, counts);
+            assert!(unweighted.sums.is_empty() && unweighted.means.is_empty());
+        }
+    }
+
+    #[test]
+    fn edges_and_empty_input() {
+        let params = HistogramParams::new(&EXTENT, 8, 4).unwrap();
+        let corners = [
+            [0.0, 0.0],
+            [16.0, 8.0],
+            [16.0, 0.0],
+            [16.5, 8.0],
+            [-0.5, 0.0],
+        ];
+        let histogram = histogram_cpu(&corners, None, &params);
+        assert_eq!(histogram.counts[0], 1);
+        assert_eq!(histogram.counts[31], 1);
+        assert_eq!(histogram.counts[7], 1);
+        assert_eq!(histogram.counts.iter().sum::<u32>(), 3);
+
+        let empty = histogram_cpu(&[], Some(&[]), &params);
+        assert!(empty.counts.iter().all(|&count| count == 0));
+        assert!(empty.means.iter().all(|&mean| mean == 0.0));
+
+        assert!(HistogramParams::new(&EXTENT, 0, 4).is_none());
+        assert!(HistogramParams::new(&EXTENT, u32::MAX, 2).is_none());
+        let flat = ComputeRect {
+            min: [0.0, 1.0],
+            max: [16.0, 1.0],
+        };
+        assert!(HistogramParams::new(&flat, 4, 4).is_none());
+    }
+}
@@ -0,0 +1,88 @@
+// 2D histogram of points over an extent. `count_points` counts the points of every bin. There is no
+// atomic float add, so `sum_values` adds the values in fixed point, with a scale per bin that lets its
+// count times the largest magnitude fit. `finish` turns the fixed point sums into sums and means.
+struct Histogram {
+    origin: vec2<f32>,
+    end: vec2<f32>,
+    cell: vec2<f32>,
+    bins_x: u32,
+    bins_y: u32,
+    point_count: u32,
+    max_magnitude: f32,
+};
+
+const NONE: u32 = 0xffffffffu;
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> histogram: Histogram;
+@group(0) @binding(2) var<storage, read> values: array<f32>;
+@group(0) @binding(3) var<storage, read_write> counts: array<atomic<u32>>;
+@group(0) @binding(4) var<storage, read_write> fixed_sums: array<atomic<i32>>;
+@group(0) @binding(5) var<storage, read> totals: array<u32>;
+@group(0) @binding(6) var<storage, read> fixed_totals: array<i32>;
+@group(0) @binding(7) var<storage, read_write> sums: array<f32>;
+@group(0) @binding(8) var<storage, read_write> means: array<f32>;
+
+// Points on the `max` edge of the extent go into the last bin.
+fn bin_of(point: vec2<f32>) -> u32 {
+    // Also false for NaN.
+    if (!(all(point >= histogram.origin) && all(point <= histogram.end))) {
+        return NONE;
+    }
+    let last = vec2<f32>(f32(histogram.bins_x - 1u), f32(histogram.bins_y - 1u));
+    let cell = min(floor((point - histogram.origin) / histogram.cell), last);
+    return u32(cell.y) * histogram.bins_x + u32(cell.x);
+}
+
+fn scale(count: u32) -> f32 {
+    let exponent = floor(log2(f32(1u << 30u) / (f32(count) * histogram.max_magnitude)));
+    return exp2(clamp(exponent, -100.0, 100.0));
+}
+
+@compute
+@workgroup_size(64)
+fn count_points(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= histogram.point_count) {
+        return;
+    }
+    let bin = bin_of(points[i]);
+    if (bin != NONE) {
+        atomicAdd(&counts[bin], 1u);
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn sum_values(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= histogram.point_count) {
+        return;
+    }
+    let bin = bin_of(points[i]);
+    if (bin == NONE) {
+        return;
+    }
+    let fixed = i32(round(values[i] * scale(totals[bin])));
+    if (fixed != 0) {
+        atomicAdd(&fixed_sums[bin], fixed);
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn finish(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= histogram.bins_x * histogram.bins_y) {
+        return;
+    }
+    let count = totals[i];
+    if (count == 0u) {
+        sums[i] = 0.0;
+        means[i] = 0.0;
+        return;
+    }
+    let sum = f32(fixed_totals[i]) / scale(count);
+    sums[i] = sum;
+    means[i] = sum / f32(count);
+}
@@ -0,0 +1,166 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::{PointSet, PointSetIndex},
+    query::{self, QueryShape},
+    simple::{send_command, ComputeCommand, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const HIT_TEST_SHADER: &str = include_str!("hit_test.wgsl");
+
+// Matches `NONE` in `hit_test.wgsl`.
+const NONE: u32 = u32::MAX;
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct HitTestParams {
+    position: Vec2,
+    tolerance_squared: f32,
+    point_count: u32,
+    partial_count: u32,
+    _padding: u32,
+}
+
+// Same tie breaking as the reduction in `hit_test.wgsl`: lowest squared distance, then lowest index.
+pub(crate) fn hit_test_cpu(set: &PointSet, position: &Vec2, tolerance: f32) -> Option<u32> {
+    let tolerance_squared = tolerance * tolerance;
+    if let PointSetIndex::RTree(tree) = &set.index {
+        let mut candidates = Vec::new();
+        tree.nearest(position, 1, &mut candidates);
+        return candidates
+            .into_iter()
+            .filter(|(distance, _)| *distance <= tolerance_squared)
+            .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)))
+            .map(|(_, index)| index);
+    }
+
+    closest(
+        &set.points,
+        0..set.points.len() as u32,
+        position,
+        tolerance_squared,
+    )
+}
+
+// Closest of the points at `indices`, which must be ascending.
+fn closest(
+    points: &[Vec2],
+    indices: impl Iterator<Item = u32>,
+    position: &Vec2,
+    tolerance_squared: f32,
+) -> Option<u32> {
+    let mut best: Option<(f32, u32)> = None;
+    for i in indices {
+        let point = &points[i as usize];
+        let dx = point[0] - position[0];
+        let dy = point[1] - position[1];
+        let distance = dx * dx + dy * dy;
+        // Ascending indices, so only a strictly closer point replaces the current one.
+        if distance <= tolerance_squared && best.is_none_or(|(d, _)| distance < d) {
+            best = Some((distance, i));
+        }
+    }
+    best.map(|(_, index)| index)
+}
+
+pub(crate) async fn hit_test_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    position: &Vec2,
+    tolerance: f32,
+) -> Option<u32> {
+    let device = &gpu.device;
+    let point_count = set.points.len() as u32;
+    if point_count == 0 {
+        return None;
+    }
+    // A grid or BVH narrows the search down to the few points within `tolerance`, which are then
+    // compared here instead of reducing over the whole set.
+    if matches!(set.index, PointSetIndex::Grid(_) | PointSetIndex::Bvh(_)) {
+        let shape = QueryShape::Circle {
+            center: *position,
+            radius: tolerance,
+        };
+        let candidates = query::query_gpu(gpu, set, &shape).await;
+        return closest(
+            &set.points,
+            candidates.into_iter(),
+            position,
+            tolerance * tolerance,
+        );
+    }
+    let params = HitTestParams {
+        position: *position,
+        tolerance_squared: tolerance * tolerance,
+        point_count,
+        partial_count: point_count.div_ceil(gpu::WORKGROUP_SIZE),
+        _padding: 0,
+    };
+
+    let params_buffer = gpu::uniform_buffer(device, "Hit Test Params Buffer", &params);
+    let partials_buffer = gpu::empty_storage_buffer(
+        device,
+        "Hit Test Partials Buffer",
+        params.partial_count as u64 * 8,
+        wgpu::BufferUsages::empty(),
+    );
+    let result_buffer = gpu::empty_storage_buffer(
+        device,
+        "Hit Test Result Buffer",
+        8,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+
+    let points_pipeline = gpu.pipeline(HIT_TEST_SHADER, "reduce_points");
+    let partials_pipeline = gpu.pipeline(HIT_TEST_SHADER, "reduce_partials");
+    let points_bind_group = gpu::bind_group(
+        device,
+        &points_pipeline,
+        &[&set.buffer, &params_buffer, &partials_buffer],
+    );
+    let partials_bind_group = gpu::bind_group_at(
+        device,
+        &partials_pipeline,
+        &[
+            (1, &params_buffer),
+            (2, &partials_buffer),
+            (3, &result_buffer),
+        ],
+    );
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &points_pipeline,
+        &points_bind_group,
+        point_count,
+    );
+    {
+        let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: None,
+            timestamp_writes: None,
+        });
+        cpass.set_pipeline(&partials_pipeline);
+        cpass.set_bind_group(0, &partials_bind_group, &[]);
+        cpass.dispatch_workgroups(1, 1, 1);
+    }
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let result = gpu::read_buffer::<u32>(device, &gpu.queue, &result_buffer, 0, 2).await;
+    (result[1] != NONE).then_some(result[1])
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Index of the point of `point_set` closest to `position`, if any lies within `tolerance` of it.
+/// Equally close points resolve to the lowest index. `None` as well if the point set does not exist or
+/// `tolerance` is negative or not finite.
+pub async fn hit_test(point_set: u32, position: Vec2, tolerance: f32) -> Option<u32> {
+    match send_command(ComputeCommand::HitTest(point_set, position, tolerance)).await {
+        ComputeResponse::Hit(index) => index,
+        _ => None,
+    }
+}
@@ -0,0 +1,77 @@
+// Closest point to a position within a tolerance, as a two level reduction: `reduce_points` keeps the
+// best candidate of each workgroup, `reduce_partials` folds those in a single workgroup. Candidates
+// compare by squared distance, then by index, so equally close points resolve to the lowest index.
+struct HitTest {
+    position: vec2<f32>,
+    tolerance_squared: f32,
+    point_count: u32,
+    partial_count: u32,
+};
+
+const NONE: u32 = 0xffffffffu;
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> hit: HitTest;
+@group(0) @binding(2) var<storage, read_write> partials: array<vec2<u32>>;
+@group(0) @binding(3) var<storage, read_write> result: vec2<u32>;
+
+var<workgroup> best: array<vec2<u32>, 256>;
+
+// Squared distances are never negative, so their bits order the same way as their values.
+fn closer(a: vec2<u32>, b: vec2<u32>) -> bool {
+    return a.x < b.x || (a.x == b.x && a.y < b.y);
+}
+
+fn reduce(t: u32, size: u32) {
+    for (var stride = size / 2u; stride > 0u; stride /= 2u) {
+        if (t < stride && closer(best[t + stride], best[t])) {
+            best[t] = best[t + stride];
+        }
+        workgroupBarrier();
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn reduce_points(
+    @builtin(workgroup_id) wid: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+    @builtin(local_invocation_index) t: u32,
+) {
+    let block = wid.x + wid.y * groups.x;
+    if (block >= hit.partial_count) {
+        return;
+    }
+    let i = block * 64u + t;
+    var candidate = vec2<u32>(NONE, NONE);
+    if (i < hit.point_count) {
+        let d = points[i] - hit.position;
+        let distance = dot(d, d);
+        if (distance <= hit.tolerance_squared) {
+            candidate = vec2<u32>(bitcast<u32>(distance), i);
+        }
+    }
+    best[t] = candidate;
+    workgroupBarrier();
+    reduce(t, 64u);
+    if (t == 0u) {
+        partials[block] = best[0];
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn reduce_partials(@builtin(local_invocation_index) t: u32) {
+    var candidate = vec2<u32>(NONE, NONE);
+    for (var i = t; i < hit.partial_count; i += 256u) {
+        if (closer(partials[i], candidate)) {
+            candidate = partials[i];
+        }
+    }
+    best[t] = candidate;
+    workgroupBarrier();
+    reduce(t, 256u);
+    if (t == 0u) {
+        result = best[0];
+    }
+}
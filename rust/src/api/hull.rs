@@ -0,0 +1,394 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::PointSet,
+    query::QueryShape,
+    simple::{send_command, ComputeCommand, ComputeRect, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const HULL_SHADER: &str = include_str!("hull.wgsl");
+
+// Matches `EPSILON` in `hull.wgsl`.
+const EPSILON: f32 = 1e-5;
+
+// Counter-clockwise from +x, matching `direction` in `hull.wgsl`.
+const DIRECTIONS: [Vec2; 8] = [
+    [1.0, 0.0],
+    [1.0, 1.0],
+    [0.0, 1.0],
+    [-1.0, 1.0],
+    [-1.0, 0.0],
+    [-1.0, -1.0],
+    [0.0, -1.0],
+    [1.0, -1.0],
+];
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct HullParams {
+    point_count: u32,
+    partial_count: u32,
+}
+
+fn dot(a: &Vec2, b: &Vec2) -> f32 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+// Twice the signed area of `a`, `b`, `c`, positive when they turn counter-clockwise (y up).
+fn cross(a: &Vec2, b: &Vec2, c: &Vec2) -> f64 {
+    let (ax, ay) = (a[0] as f64, a[1] as f64);
+    (b[0] as f64 - ax) * (c[1] as f64 - ay) - (b[1] as f64 - ay) * (c[0] as f64 - ax)
+}
+
+fn is_finite(point: &Vec2) -> bool {
+    point.iter().all(|v| v.is_finite())
+}
+
+// Candidates may come in any order, so ties go to the lower index like in `hull.wgsl`. Points that are not
+// finite are never extremes.
+fn further(points: &[Vec2], direction: &Vec2, i: u32, best: u32) -> bool {
+    if !is_finite(&points[i as usize]) {
+        return false;
+    }
+    if best == u32::MAX {
+        return true;
+    }
+    let value = dot(&points[i as usize], direction);
+    let best_value = dot(&points[best as usize], direction);
+    value > best_value || (value == best_value && i < best)
+}
+
+// Same filter as `hull.wgsl`, for the CPU backend and for query selections.
+fn filter(points: &[Vec2], candidates: &[u32]) -> Vec<u32> {
+    let mut extremes = [u32::MAX; 8];
+    for &i in candidates {
+        for (extreme, direction) in extremes.iter_mut().zip(&DIRECTIONS) {
+            if further(points, direction, i, *extreme) {
+                *extreme = i;
+            }
+        }
+    }
+    if extremes[0] == u32::MAX {
+        return Vec::new();
+    }
+    let octagon: Vec<(Vec2, Vec2)> = (0..8)
+        .map(|d| {
+            let a = points[extremes[d] as usize];
+            let b = points[extremes[(d + 1) % 8] as usize];
+            (a, [b[0] - a[0], b[1] - a[1]])
+        })
+        .filter(|(_, edge)| *edge != [0.0, 0.0])
+        .collect();
+    candidates
+        .iter()
+        .copied()
+        .filter(|&i| {
+            let point = points[i as usize];
+            is_finite(&point)
+                && (octagon.is_empty()
+                    || octagon.iter().any(|(a, edge)| {
+                        let offset = [point[0] - a[0], point[1] - a[1]];
+                        edge[0] * offset[1] - edge[1] * offset[0]
+                            <= EPSILON * dot(edge, edge).sqrt() * dot(&offset, &offset).sqrt()
+                    }))
+        })
+        .collect()
+}
+
+// Andrew's monotone chain over `candidates`. Returns hull vertices counter-clockwise (y up) from the
+// lowest x, then lowest y, without collinear points. Coincident points resolve to the lowest index.
+fn monotone_chain(points: &[Vec2], mut candidates: Vec<u32>) -> Vec<u32> {
+    candidates.sort_unstable_by(|&a, &b| {
+        let (p, q) = (&points[a as usize], &points[b as usize]);
+        p[0].total_cmp(&q[0])
+            .then(p[1].total_cmp(&q[1]))
+            .then(a.cmp(&b))
+    });
+    candidates.dedup_by(|a, b| points[*a as usize] == points[*b as usize]);
+    if candidates.len() < 3 {
+        return candidates;
+    }
+    let turns_left = |hull: &[u32], i: u32| {
+        let n = hull.len();
+        cross(
+            &points[hull[n - 2] as usize],
+            &points[hull[n - 1] as usize],
+            &points[i as usize],
+        ) > 0.0
+    };
+    let mut hull: Vec<u32> = Vec::with_capacity(candidates.len() + 1);
+    for &i in &candidates {
+        while hull.len() >= 2 && !turns_left(&hull, i) {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    let lower = hull.len() + 1;
+    for &i in candidates.iter().rev().skip(1) {
+        while hull.len() >= lower && !turns_left(&hull, i) {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    hull.pop();
+    hull
+}
+
+pub(crate) fn convex_hull_cpu(points: &[Vec2], candidates: &[u32]) -> Vec<u32> {
+    monotone_chain(points, filter(points, candidates))
+}
+
+pub(crate) async fn convex_hull_gpu(gpu: &WgpuCompute, set: &PointSet) -> Vec<u32> {
+    let device = &gpu.device;
+    let point_count = set.points.len() as u32;
+    if point_count == 0 {
+        return Vec::new();
+    }
+    let params = HullParams {
+        point_count,
+        partial_count: point_count.div_ceil(gpu::WORKGROUP_SIZE),
+    };
+
+    let params_buffer = gpu::uniform_buffer(device, "Hull Params Buffer", &params);
+    let partials_buffer = gpu::empty_storage_buffer(
+        device,
+        "Hull Partials Buffer",
+        params.partial_count as u64 * 8 * 4,
+        wgpu::BufferUsages::empty(),
+    );
+    let extremes_buffer = gpu::empty_storage_buffer(
+        device,
+        "Hull Extremes Buffer",
+        8 * 4,
+        wgpu::BufferUsages::empty(),
+    );
+    let survivors_buffer = gpu::empty_storage_buffer(
+        device,
+        "Hull Survivors Buffer",
+        point_count as u64 * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let count_buffer = gpu::empty_storage_buffer(
+        device,
+        "Hull Survivor Count Buffer",
+        4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+
+    let points_pipeline = gpu.pipeline(HULL_SHADER, "extremes_points");
+    let partials_pipeline = gpu.pipeline(HULL_SHADER, "extremes_partials");
+    let filter_pipeline = gpu.pipeline(HULL_SHADER, "filter_points");
+    let points_bind_group = gpu::bind_group(
+        device,
+        &points_pipeline,
+        &[&set.buffer, &params_buffer, &partials_buffer],
+    );
+    let partials_bind_group = gpu::bind_group(
+        device,
+        &partials_pipeline,
+        &[
+            &set.buffer,
+            &params_buffer,
+            &partials_buffer,
+            &extremes_buffer,
+        ],
+    );
+    let filter_bind_group = gpu::bind_group_at(
+        device,
+        &filter_pipeline,
+        &[
+            (0, &set.buffer),
+            (1, &params_buffer),
+            (3, &extremes_buffer),
+            (4, &survivors_buffer),
+            (5, &count_buffer),
+        ],
+    );
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &points_pipeline,
+        &points_bind_group,
+        point_count,
+    );
+    {
+        let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: None,
+            timestamp_writes: None,
+        });
+        cpass.set_pipeline(&partials_pipeline);
+        cpass.set_bind_group(0, &partials_bind_group, &[]);
+        cpass.dispatch_workgroups(1, 1, 1);
+    }
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &filter_pipeline,
+        &filter_bind_group,
+        point_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let count = gpu::read_buffer::<u32>(device, &gpu.queue, &count_buffer, 0, 1).await[0];
+    let survivors =
+        gpu::read_buffer::<u32>(device, &gpu.queue, &survivors_buffer, 0, count as usize).await;
+    tracing::trace!(points = point_count, survivors = count, "hull filter");
+    monotone_chain(&set.points, survivors)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Indices of the points on the convex hull of `point_set`, counter-clockwise with y pointing up (so
+/// clockwise on screen), starting from the point with the lowest x. Collinear points on hull edges are left
+/// out, coincident points resolve to the lowest index and points that are not finite are ignored. `None`
+/// if the point set does not exist.
+pub async fn convex_hull(point_set: u32) -> Option<Vec<u32>> {
+    hull(point_set, None).await
+}
+
+/// Like `convex_hull`, over only the points of `point_set` inside `rect`.
+pub async fn convex_hull_in_rect(point_set: u32, rect: ComputeRect) -> Option<Vec<u32>> {
+    hull(point_set, Some(QueryShape::Rect(rect))).await
+}
+
+/// Like `convex_hull`, over only the points of `point_set` within `radius` of `center`.
+pub async fn convex_hull_in_circle(point_set: u32, center: Vec2, radius: f32) -> Option<Vec<u32>> {
+    hull(point_set, Some(QueryShape::Circle { center, radius })).await
+}
+
+/// Like `convex_hull`, over only the points of `point_set` inside `polygon` (even-odd rule).
+pub async fn convex_hull_in_polygon(point_set: u32, polygon: Vec<Vec2>) -> Option<Vec<u32>> {
+    hull(point_set, Some(QueryShape::Polygon(polygon))).await
+}
+
+async fn hull(point_set: u32, selection: Option<QueryShape>) -> Option<Vec<u32>> {
+    match send_command(ComputeCommand::ConvexHull(point_set, selection)).await {
+        ComputeResponse::Indices(indices) => indices,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hull(points: &[Vec2]) -> Vec<u32> {
+        convex_hull_cpu(points, &(0..points.len() as u32).collect::<Vec<_>>())
+    }
+
+    // Xorshift points on a `size` x `size` lattice, so there are duplicates and collinear hull points.
+    fn lattice_points(count: usize, size: u32) -> Vec<Vec2> {
+        let mut state = 0x3c6e_f372u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            ((state >> 8) % size) as f32
+        };
+        (0..count).map(|_| [next(), next()]).collect()
+    }
+
+    // Corners of the hull by brute force: lowest indexed points of the positions that lie strictly outside
+    // the hull of all other positions, i.e. that some line through them has every other point strictly on
+    // one side. On a lattice it is enough to try the directions between lattice points.
+    fn brute_force(points: &[Vec2], candidates: &[u32]) -> Vec<u32> {
+        let mut unique: Vec<u32> = Vec::new();
+        for &i in candidates {
+            if !unique
+                .iter()
+                .any(|&j| points[j as usize] == points[i as usize])
+            {
+                unique.push(i);
+            }
+        }
+        let corner = |i: u32| {
+            let p = points[i as usize];
+            (-40..=40).any(|dx| {
+                (-40..=40).any(|dy| {
+                    (dx, dy) != (0, 0)
+                        && unique.iter().all(|&j| {
+                            let q = points[j as usize];
+                            j == i || (q[0] - p[0]) * dx as f32 + (q[1] - p[1]) * (dy as f32) < 0.0
+                        })
+                })
+            })
+        };
+        let mut corners: Vec<u32> = unique.iter().copied().filter(|&i| corner(i)).collect();
+        corners.sort_unstable();
+        corners
+    }
+
+    // Counter-clockwise from the lowest x, then lowest y, with every turn a strict left turn.
+    fn check_order(points: &[Vec2], hull: &[u32]) {
+        let first = points[hull[0] as usize];
+        assert!(hull
+            .iter()
+            .all(|&i| (points[i as usize][0], points[i as usize][1]) >= (first[0], first[1])));
+        if hull.len() >= 3 {
+            for k in 0..hull.len() {
+                let [a, b, c] = [k, k + 1, k + 2].map(|k| &points[hull[k % hull.len()] as usize]);
+                assert!(cross(a, b, c) > 0.0, "{hull:?} turns right at {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for (count, size) in [(3, 4), (20, 8), (200, 16), (2000, 20)] {
+            let points = lattice_points(count, size);
+            let all: Vec<u32> = (0..count as u32).collect();
+            let found = hull(&points);
+            check_order(&points, &found);
+            let mut sorted = found.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, brute_force(&points, &all), "{count} points");
+
+            // A query selection, like `convex_hull_in_rect` passes.
+            let selection: Vec<u32> = all.iter().copied().filter(|i| i % 3 != 0).collect();
+            let mut found = convex_hull_cpu(&points, &selection);
+            check_order(&points, &found);
+            found.sort_unstable();
+            assert_eq!(found, brute_force(&points, &selection));
+        }
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        assert!(hull(&[]).is_empty());
+        assert_eq!(hull(&[[1.0, 1.0]]), [0]);
+        // Coincident points resolve to the lowest index.
+        assert_eq!(
+            hull(&[[2.0, 2.0], [1.0, 1.0], [2.0, 2.0], [1.0, 1.0]]),
+            [1, 0]
+        );
+        // Collinear points keep only the two ends.
+        assert_eq!(
+            hull(&[[1.0, 1.0], [0.0, 0.0], [3.0, 3.0], [2.0, 2.0]]),
+            [1, 2]
+        );
+        assert!(convex_hull_cpu(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]], &[]).is_empty());
+    }
+
+    // A NaN coordinate compares false against everything, so it used to win the first direction it was
+    // offered and turn the octagon into NaN, which left no point to build the hull from.
+    #[test]
+    fn non_finite_points_are_ignored() {
+        let points = [
+            [f32::NAN, 0.0],
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [f32::INFINITY, 5.0],
+            [1.0, 2.0],
+            [1.0, 0.5],
+            [f32::NEG_INFINITY, f32::NAN],
+        ];
+        assert_eq!(hull(&points), [1, 2, 4]);
+        assert!(hull(&[[f32::NAN; 2], [f32::INFINITY; 2]]).is_empty());
+        // With every finite point in one place there is no octagon to filter against.
+        assert_eq!(hull(&[[f32::NAN, 1.0], [3.0, 3.0], [3.0, 3.0]]), [1]);
+    }
+}
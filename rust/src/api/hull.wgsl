@@ -0,0 +1,147 @@
+// Akl–Toussaint filter ahead of a convex hull. `extremes_points` and `extremes_partials` find the point
+// furthest along each of eight directions, 45 degrees apart, as a two level reduction like the hit test.
+// Those points span an octagon inside the hull, and `filter_points` appends every point not strictly
+// inside it to `survivors`, leaving the hull itself to the CPU. Points that are not finite are skipped
+// throughout.
+struct Hull {
+    point_count: u32,
+    partial_count: u32,
+};
+
+const NONE: u32 = 0xffffffffu;
+
+// Points strictly inside by less than this share of the edge and offset lengths are kept, so rounding
+// never drops a hull point.
+const EPSILON: f32 = 1e-5;
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> hull: Hull;
+@group(0) @binding(2) var<storage, read_write> partials: array<u32>;
+@group(0) @binding(3) var<storage, read_write> extremes: array<u32, 8>;
+@group(0) @binding(4) var<storage, read_write> survivors: array<u32>;
+@group(0) @binding(5) var<storage, read_write> survivor_count: atomic<u32>;
+
+var<workgroup> best_values: array<f32, 256>;
+var<workgroup> best_indices: array<u32, 256>;
+
+// Counter-clockwise from +x, so the extremes come out in hull order.
+fn direction(d: u32) -> vec2<f32> {
+    var directions = array<vec2<f32>, 8>(
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(-1.0, 1.0),
+        vec2<f32>(-1.0, 0.0),
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(0.0, -1.0),
+        vec2<f32>(1.0, -1.0),
+    );
+    return directions[d];
+}
+
+fn isFinite(point: vec2<f32>) -> bool {
+    let exponents = (bitcast<vec2<u32>>(point) >> vec2<u32>(23u)) & vec2<u32>(0xffu);
+    return all(exponents != vec2<u32>(0xffu));
+}
+
+fn candidate(index: u32, d: u32) -> f32 {
+    return dot(points[index], direction(d));
+}
+
+// Further along the direction wins, then the lower index.
+fn better(value: f32, index: u32, other_value: f32, other_index: u32) -> bool {
+    return index != NONE
+        && (other_index == NONE || value > other_value || (value == other_value && index < other_index));
+}
+
+fn reduce(t: u32, size: u32) {
+    for (var stride = size / 2u; stride > 0u; stride /= 2u) {
+        if (t < stride
+            && better(best_values[t + stride], best_indices[t + stride], best_values[t], best_indices[t])) {
+            best_values[t] = best_values[t + stride];
+            best_indices[t] = best_indices[t + stride];
+        }
+        workgroupBarrier();
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn extremes_points(
+    @builtin(workgroup_id) wid: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+    @builtin(local_invocation_index) t: u32,
+) {
+    let block = wid.x + wid.y * groups.x;
+    if (block >= hull.partial_count) {
+        return;
+    }
+    let i = block * 64u + t;
+    for (var d = 0u; d < 8u; d++) {
+        best_indices[t] = NONE;
+        best_values[t] = 0.0;
+        if (i < hull.point_count && isFinite(points[i])) {
+            best_indices[t] = i;
+            best_values[t] = candidate(i, d);
+        }
+        workgroupBarrier();
+        reduce(t, 64u);
+        if (t == 0u) {
+            partials[block * 8u + d] = best_indices[0];
+        }
+        workgroupBarrier();
+    }
+}
+
+@compute
+@workgroup_size(256)
+fn extremes_partials(@builtin(local_invocation_index) t: u32) {
+    for (var d = 0u; d < 8u; d++) {
+        var index = NONE;
+        var value = 0.0;
+        for (var i = t; i < hull.partial_count; i += 256u) {
+            let other = partials[i * 8u + d];
+            let other_value = candidate(other, d);
+            if (better(other_value, other, value, index)) {
+                index = other;
+                value = other_value;
+            }
+        }
+        best_indices[t] = index;
+        best_values[t] = value;
+        workgroupBarrier();
+        reduce(t, 256u);
+        if (t == 0u) {
+            extremes[d] = best_indices[0];
+        }
+        workgroupBarrier();
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn filter_points(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= hull.point_count || !isFinite(points[i])) {
+        return;
+    }
+    let point = points[i];
+    var edges = 0u;
+    var inside = true;
+    for (var d = 0u; d < 8u; d++) {
+        let a = points[extremes[d]];
+        let edge = points[extremes[(d + 1u) % 8u]] - a;
+        if (all(edge == vec2<f32>(0.0))) {
+            continue;
+        }
+        edges++;
+        let offset = point - a;
+        if (edge.x * offset.y - edge.y * offset.x <= EPSILON * length(edge) * length(offset)) {
+            inside = false;
+            break;
+        }
+    }
+    if (!inside || edges == 0u) {
+        survivors[atomicAdd(&survivor_count, 1u)] = i;
+    }
+}
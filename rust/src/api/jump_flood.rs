@@ -0,0 +1,267 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::{PointSet, PointSetIndex},
+    rtree::RTreeIndex,
+    simple::{send_command, ComputeCommand, ComputeRect, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const JUMP_FLOOD_SHADER: &str = include_str!("jump_flood.wgsl");
+
+// Matches `NONE` and `FAR` in `jump_flood.wgsl`.
+const NONE: u32 = u32::MAX;
+const FAR: f32 = f32::MAX;
+
+/// Voronoi regions of a point set rasterized over a rectangle. Both maps hold one little-endian 4 byte
+/// value per pixel, row by row starting at the `min` corner of the rectangle.
+#[derive(Clone, Debug)]
+pub struct VoronoiRaster {
+    pub width: u32,
+    pub height: u32,
+    /// `u32` index of the point closest to the pixel center, `u32::MAX` when the set is empty.
+    pub labels: Vec<u8>,
+    /// `f32` distance from the pixel center to that point, in point coordinates, `f32::MAX` when the set is
+    /// empty.
+    pub distances: Vec<u8>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct RasterParams {
+    origin: Vec2,
+    cell: Vec2,
+    width: u32,
+    height: u32,
+    point_count: u32,
+    step: u32,
+}
+
+// Exact nearest point of every pixel center, ties going to the lowest index.
+pub(crate) fn rasterize_cpu(
+    set: &PointSet,
+    bounds: &ComputeRect,
+    width: u32,
+    height: u32,
+) -> VoronoiRaster {
+    let built;
+    let tree = match &set.index {
+        PointSetIndex::RTree(tree) => tree.as_ref(),
+        _ => {
+            built = RTreeIndex::build(&set.points);
+            &built
+        }
+    };
+    let cell = [
+        (bounds.max[0] - bounds.min[0]) / width as f32,
+        (bounds.max[1] - bounds.min[1]) / height as f32,
+    ];
+    let pixel_count = width as usize * height as usize;
+    let mut labels = Vec::with_capacity(pixel_count * 4);
+    let mut distances = Vec::with_capacity(pixel_count * 4);
+    let mut candidates = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            let center = [
+                bounds.min[0] + (x as f32 + 0.5) * cell[0],
+                bounds.min[1] + (y as f32 + 0.5) * cell[1],
+            ];
+            candidates.clear();
+            tree.nearest(&center, 1, &mut candidates);
+            let (distance, label) = candidates
+                .iter()
+                .copied()
+                .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)))
+                .map_or((FAR, NONE), |(distance, label)| (distance.sqrt(), label));
+            labels.extend(label.to_le_bytes());
+            distances.extend(distance.to_le_bytes());
+        }
+    }
+    VoronoiRaster {
+        width,
+        height,
+        labels,
+        distances,
+    }
+}
+
+// `None` when the maps do not fit in a storage binding.
+pub(crate) async fn rasterize_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    bounds: &ComputeRect,
+    width: u32,
+    height: u32,
+) -> Option<VoronoiRaster> {
+    let device = &gpu.device;
+    let pixel_count = width.checked_mul(height)?;
+    let size = pixel_count as u64 * 4;
+    if size > device.limits().max_storage_buffer_binding_size as u64 {
+        return None;
+    }
+    let params = RasterParams {
+        origin: bounds.min,
+        cell: [
+            (bounds.max[0] - bounds.min[0]) / width as f32,
+            (bounds.max[1] - bounds.min[1]) / height as f32,
+        ],
+        width,
+        height,
+        point_count: set.points.len() as u32,
+        step: 0,
+    };
+
+    let nearest_buffer = gpu::empty_storage_buffer(
+        device,
+        "Jump Flood Nearest Buffer",
+        size,
+        wgpu::BufferUsages::empty(),
+    );
+    let labels = [
+        gpu::empty_storage_buffer(
+            device,
+            "Jump Flood Labels Buffer",
+            size,
+            wgpu::BufferUsages::COPY_SRC,
+        ),
+        gpu::empty_storage_buffer(
+            device,
+            "Jump Flood Labels Buffer",
+            size,
+            wgpu::BufferUsages::COPY_SRC,
+        ),
+    ];
+    let distances_buffer = gpu::empty_storage_buffer(
+        device,
+        "Jump Flood Distances Buffer",
+        size,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let clear_pipeline = gpu.pipeline(JUMP_FLOOD_SHADER, "clear");
+    let distances_pipeline = gpu.pipeline(JUMP_FLOOD_SHADER, "seed_distances");
+    let seeds_pipeline = gpu.pipeline(JUMP_FLOOD_SHADER, "seed_labels");
+    let jump_pipeline = gpu.pipeline(JUMP_FLOOD_SHADER, "jump");
+    let measure_pipeline = gpu.pipeline(JUMP_FLOOD_SHADER, "measure");
+
+    let params_buffer = gpu::uniform_buffer(device, "Jump Flood Params Buffer", &params);
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    let clear_bind_group = gpu::bind_group_at(
+        device,
+        &clear_pipeline,
+        &[(1, &params_buffer), (2, &nearest_buffer), (3, &labels[0])],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &clear_pipeline,
+        &clear_bind_group,
+        pixel_count,
+    );
+    let distances_bind_group = gpu::bind_group(
+        device,
+        &distances_pipeline,
+        &[&set.buffer, &params_buffer, &nearest_buffer],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &distances_pipeline,
+        &distances_bind_group,
+        params.point_count,
+    );
+    let seeds_bind_group = gpu::bind_group(
+        device,
+        &seeds_pipeline,
+        &[&set.buffer, &params_buffer, &nearest_buffer, &labels[0]],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &seeds_pipeline,
+        &seeds_bind_group,
+        params.point_count,
+    );
+
+    let mut steps = Vec::new();
+    let mut step = width.max(height).next_power_of_two() / 2;
+    while step > 0 {
+        steps.push(step);
+        step /= 2;
+    }
+    steps.push(1);
+    let mut current = 0;
+    for step in steps {
+        let pass = gpu::uniform_buffer(
+            device,
+            "Jump Flood Pass Buffer",
+            &RasterParams { step, ..params },
+        );
+        let bind_group = gpu::bind_group_at(
+            device,
+            &jump_pipeline,
+            &[
+                (0, &set.buffer),
+                (1, &pass),
+                (4, &labels[current]),
+                (5, &labels[1 - current]),
+            ],
+        );
+        gpu::dispatch(
+            device,
+            &mut encoder,
+            &jump_pipeline,
+            &bind_group,
+            pixel_count,
+        );
+        current = 1 - current;
+    }
+    let measure_bind_group = gpu::bind_group_at(
+        device,
+        &measure_pipeline,
+        &[
+            (0, &set.buffer),
+            (1, &params_buffer),
+            (4, &labels[current]),
+            (6, &distances_buffer),
+        ],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &measure_pipeline,
+        &measure_bind_group,
+        pixel_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    Some(VoronoiRaster {
+        width,
+        height,
+        labels: gpu::read_buffer(device, &gpu.queue, &labels[current], 0, size as usize).await,
+        distances: gpu::read_buffer(device, &gpu.queue, &distances_buffer, 0, size as usize).await,
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Rasterizes the Voronoi regions of `point_set` over `bounds` into `width` by `height` pixels: which point
+/// is closest to each pixel, and how far it is. The GPU path uses jump flooding, which keeps one point per
+/// pixel and can mislabel a few pixels near region borders, so distances may be off by up to a pixel; the
+/// CPU path is exact. `None` if the point set does not exist, the size is zero or the maps take more than
+/// 256 MiB.
+pub async fn rasterize_voronoi(
+    point_set: u32,
+    bounds: ComputeRect,
+    width: u32,
+    height: u32,
+) -> Option<VoronoiRaster> {
+    match send_command(ComputeCommand::RasterizeVoronoi(
+        point_set, bounds, width, height,
+    ))
+    .await
+    {
+        ComputeResponse::Raster(raster) => raster,
+        _ => None,
+    }
+}
@@ -0,0 +1,130 @@
+// Voronoi label map and distance field over a pixel grid by jump flooding. `clear` empties the grid,
+// `seed_distances` and `seed_labels` put every point into the pixel it falls in (points outside the grid
+// into the nearest border pixel), keeping the point closest to the pixel center, then the lowest index.
+// Each `jump` pass lets a pixel adopt the closest seed among its neighbours `step` pixels away, for steps
+// halving from half the grid size down to one, plus a final pass at one to repair most of the errors.
+// `measure` turns the labels into distances.
+struct Raster {
+    origin: vec2<f32>,
+    cell: vec2<f32>,
+    width: u32,
+    height: u32,
+    point_count: u32,
+    step: u32,
+};
+
+const NONE: u32 = 0xffffffffu;
+const FAR: f32 = 3.4028235e38;
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> raster: Raster;
+@group(0) @binding(2) var<storage, read_write> nearest: array<atomic<u32>>;
+@group(0) @binding(3) var<storage, read_write> seeds: array<atomic<u32>>;
+@group(0) @binding(4) var<storage, read> labels_in: array<u32>;
+@group(0) @binding(5) var<storage, read_write> labels_out: array<u32>;
+@group(0) @binding(6) var<storage, read_write> distances: array<f32>;
+
+fn center(pixel: vec2<u32>) -> vec2<f32> {
+    return raster.origin + (vec2<f32>(pixel) + 0.5) * raster.cell;
+}
+
+fn pixel_of(point: vec2<f32>) -> vec2<u32> {
+    let cell = floor((point - raster.origin) / raster.cell);
+    let last = vec2<f32>(f32(raster.width - 1u), f32(raster.height - 1u));
+    return vec2<u32>(clamp(cell, vec2<f32>(0.0), last));
+}
+
+fn seed_distance(i: u32) -> u32 {
+    let point = points[i];
+    // Distances are never negative, so their bits order the same way as their values.
+    return bitcast<u32>(distance(point, center(pixel_of(point))));
+}
+
+fn is_finite(point: vec2<f32>) -> bool {
+    return all(abs(point) <= vec2<f32>(FAR));
+}
+
+@compute
+@workgroup_size(64)
+fn clear(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= raster.width * raster.height) {
+        return;
+    }
+    atomicStore(&nearest[i], NONE);
+    atomicStore(&seeds[i], NONE);
+}
+
+@compute
+@workgroup_size(64)
+fn seed_distances(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= raster.point_count || !is_finite(points[i])) {
+        return;
+    }
+    let pixel = pixel_of(points[i]);
+    atomicMin(&nearest[pixel.y * raster.width + pixel.x], seed_distance(i));
+}
+
+@compute
+@workgroup_size(64)
+fn seed_labels(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= raster.point_count || !is_finite(points[i])) {
+        return;
+    }
+    let pixel = pixel_of(points[i]);
+    let p = pixel.y * raster.width + pixel.x;
+    if (seed_distance(i) == atomicLoad(&nearest[p])) {
+        atomicMin(&seeds[p], i);
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn jump(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= raster.width * raster.height) {
+        return;
+    }
+    let pixel = vec2<i32>(i32(i % raster.width), i32(i / raster.width));
+    let position = center(vec2<u32>(pixel));
+    let size = vec2<i32>(i32(raster.width), i32(raster.height));
+    let step = i32(raster.step);
+    var best = NONE;
+    var best_distance = FAR;
+    for (var dy = -1; dy <= 1; dy++) {
+        for (var dx = -1; dx <= 1; dx++) {
+            let q = pixel + vec2<i32>(dx, dy) * step;
+            if (any(q < vec2<i32>(0)) || any(q >= size)) {
+                continue;
+            }
+            let label = labels_in[u32(q.y) * raster.width + u32(q.x)];
+            if (label == NONE) {
+                continue;
+            }
+            let d = points[label] - position;
+            let candidate = dot(d, d);
+            if (candidate < best_distance || (candidate == best_distance && label < best)) {
+                best = label;
+                best_distance = candidate;
+            }
+        }
+    }
+    labels_out[i] = best;
+}
+
+@compute
+@workgroup_size(64)
+fn measure(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= raster.width * raster.height) {
+        return;
+    }
+    let label = labels_in[i];
+    if (label == NONE) {
+        distances[i] = FAR;
+        return;
+    }
+    distances[i] = distance(points[label], center(vec2<u32>(i % raster.width, i / raster.width)));
+}
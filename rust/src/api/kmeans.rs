@@ -0,0 +1,406 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::PointSet,
+    simple::{send_command, ComputeCommand, ComputePoint, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const KMEANS_SHADER: &str = include_str!("kmeans.wgsl");
+
+/// Result of k-means clustering.
+#[derive(Clone, Debug, Default)]
+pub struct KMeans {
+    /// Index into `centroids` for every point. Points that are not finite are assigned to the first centroid
+    /// without counting towards it.
+    pub assignments: Vec<u32>,
+    pub centroids: Vec<ComputePoint>,
+    /// Lloyd iterations run before converging or reaching the limit.
+    pub iterations: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct KMeansParams {
+    point_count: u32,
+    k: u32,
+}
+
+// SplitMix64, so a seed gives the same initial centroids on every platform.
+struct Random(u64);
+
+impl Random {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+fn is_finite(point: &Vec2) -> bool {
+    point.iter().all(|v| v.is_finite())
+}
+
+fn distance_squared(a: &Vec2, b: &Vec2) -> f32 {
+    let dx = a[0] - b[0];
+    let dy = a[1] - b[1];
+    dx * dx + dy * dy
+}
+
+// k-means++ seeding: each next centroid is a point drawn with probability proportional to its
+// squared distance from the closest centroid chosen so far. Points that are not finite are never drawn.
+fn initial_centroids(points: &[Vec2], k: usize, seed: u64) -> Vec<Vec2> {
+    let mut random = Random(seed);
+    let finite: Vec<&Vec2> = points.iter().filter(|p| is_finite(p)).collect();
+    let first = *finite[(random.next_u64() % finite.len() as u64) as usize];
+    let mut centroids = vec![first];
+    let mut distances: Vec<f32> = points
+        .iter()
+        .map(|p| {
+            if is_finite(p) {
+                distance_squared(p, &first)
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    while centroids.len() < k {
+        let total: f64 = distances.iter().map(|&d| d as f64).sum();
+        // Fewer distinct points than `k`, the remaining centroids can only repeat.
+        if total == 0.0 {
+            centroids.push(first);
+            continue;
+        }
+        let mut target = random.next_f64() * total;
+        let mut chosen = distances.iter().rposition(|&d| d > 0.0).unwrap();
+        for (i, &distance) in distances.iter().enumerate() {
+            target -= distance as f64;
+            if target < 0.0 {
+                chosen = i;
+                break;
+            }
+        }
+        let centroid = points[chosen];
+        for (distance, point) in distances.iter_mut().zip(points) {
+            *distance = distance.min(distance_squared(point, &centroid));
+        }
+        centroids.push(centroid);
+    }
+    centroids
+}
+
+fn converged(old: &[Vec2], new: &[Vec2], tolerance: f32) -> bool {
+    old.iter()
+        .zip(new)
+        .all(|(a, b)| distance_squared(a, b) <= tolerance * tolerance)
+}
+
+// Same steps as `kmeans.wgsl`.
+pub(crate) fn kmeans_cpu(
+    points: &[Vec2],
+    k: u32,
+    max_iterations: u32,
+    tolerance: f32,
+    seed: u64,
+) -> KMeans {
+    let k = (k as usize).min(points.iter().filter(|p| is_finite(p)).count());
+    if k == 0 {
+        return KMeans::default();
+    }
+    let mut centroids = initial_centroids(points, k, seed);
+    let mut assignments = vec![0u32; points.len()];
+    let mut iterations = 0;
+    while iterations < max_iterations {
+        iterations += 1;
+        for (assignment, point) in assignments.iter_mut().zip(points) {
+            let mut best_distance = f32::MAX;
+            for (c, centroid) in centroids.iter().enumerate() {
+                let distance = distance_squared(point, centroid);
+                if distance < best_distance {
+                    *assignment = c as u32;
+                    best_distance = distance;
+                }
+            }
+        }
+        let mut sums = vec![([0.0f32; 2], 0u32); k];
+        for (&assignment, point) in assignments.iter().zip(points) {
+            if !is_finite(point) {
+                continue;
+            }
+            let (sum, count) = &mut sums[assignment as usize];
+            sum[0] += point[0];
+            sum[1] += point[1];
+            *count += 1;
+        }
+        let previous = centroids.clone();
+        for (centroid, (sum, count)) in centroids.iter_mut().zip(sums) {
+            if count > 0 {
+                *centroid = [sum[0] / count as f32, sum[1] / count as f32];
+            }
+        }
+        if converged(&previous, &centroids, tolerance) {
+            break;
+        }
+    }
+    KMeans {
+        assignments,
+        centroids: centroids.into_iter().map(ComputePoint::from).collect(),
+        iterations,
+    }
+}
+
+pub(crate) async fn kmeans_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    k: u32,
+    max_iterations: u32,
+    tolerance: f32,
+    seed: u64,
+) -> KMeans {
+    let device = &gpu.device;
+    let k = k.min(set.points.iter().filter(|p| is_finite(p)).count() as u32);
+    if k == 0 {
+        return KMeans::default();
+    }
+    let point_count = set.points.len() as u32;
+    let mut centroids = initial_centroids(&set.points, k as usize, seed);
+
+    // Everything stays on the device between iterations, only the centroids come back to test convergence.
+    let params_buffer = gpu::uniform_buffer(
+        device,
+        "k-means Params Buffer",
+        &KMeansParams { point_count, k },
+    );
+    let centroids_buffer = gpu::storage_buffer(
+        device,
+        "k-means Centroids Buffer",
+        &centroids,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let assignments_buffer = gpu::empty_storage_buffer(
+        device,
+        "k-means Assignments Buffer",
+        point_count as u64 * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let assign_pipeline = gpu.pipeline(KMEANS_SHADER, "assign");
+    let update_pipeline = gpu.pipeline(KMEANS_SHADER, "update");
+    let buffers = [
+        &set.buffer,
+        &params_buffer,
+        &centroids_buffer,
+        &assignments_buffer,
+    ];
+    let assign_bind_group = gpu::bind_group(device, &assign_pipeline, &buffers);
+    let update_bind_group = gpu::bind_group(device, &update_pipeline, &buffers);
+    let (update_x, update_y) = {
+        let max = device.limits().max_compute_workgroups_per_dimension.max(1);
+        (k.min(max), k.div_ceil(max))
+    };
+
+    let mut iterations = 0;
+    while iterations < max_iterations {
+        iterations += 1;
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        gpu::dispatch(
+            device,
+            &mut encoder,
+            &assign_pipeline,
+            &assign_bind_group,
+            point_count,
+        );
+        {
+            let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: None,
+                timestamp_writes: None,
+            });
+            cpass.set_pipeline(&update_pipeline);
+            cpass.set_bind_group(0, &update_bind_group, &[]);
+            cpass.dispatch_workgroups(update_x, update_y, 1);
+        }
+        gpu.queue.submit(Some(encoder.finish()));
+
+        let previous = centroids;
+        centroids = gpu::read_buffer(device, &gpu.queue, &centroids_buffer, 0, k as usize).await;
+        if converged(&previous, &centroids, tolerance) {
+            break;
+        }
+    }
+
+    KMeans {
+        assignments: gpu::read_buffer(
+            device,
+            &gpu.queue,
+            &assignments_buffer,
+            0,
+            point_count as usize,
+        )
+        .await,
+        centroids: centroids.into_iter().map(ComputePoint::from).collect(),
+        iterations,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Partitions `point_set` into `k` clusters (fewer if the set has fewer finite points). Stops after `max_iterations`
+/// or once no centroid moves further than `tolerance` in an iteration. The same `seed` always picks the
+/// same initial centroids. `None` if the point set does not exist.
+pub async fn kmeans(
+    point_set: u32,
+    k: u32,
+    max_iterations: u32,
+    tolerance: f32,
+    seed: u64,
+) -> Option<KMeans> {
+    match send_command(ComputeCommand::KMeans(
+        point_set,
+        k,
+        max_iterations,
+        tolerance,
+        seed,
+    ))
+    .await
+    {
+        ComputeResponse::KMeans(result) => result,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Xorshift points scattered around `centers`, in turn.
+    fn blob_points(count: usize, centers: &[Vec2]) -> Vec<Vec2> {
+        let mut state = 0x6b8b_4567u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1 << 24) as f32 - 0.5
+        };
+        (0..count)
+            .map(|i| {
+                let center = centers[i % centers.len()];
+                [center[0] + next() * 4.0, center[1] + next() * 4.0]
+            })
+            .collect()
+    }
+
+    fn centroids(result: &KMeans) -> Vec<Vec2> {
+        result.centroids.iter().map(|&c| c.into()).collect()
+    }
+
+    // A converged result is a fixed point of Lloyd's algorithm: every point is assigned to its nearest
+    // centroid, lowest index on ties, and every centroid with points is their mean.
+    fn check_fixed_point(points: &[Vec2], result: &KMeans) {
+        let centroids = centroids(result);
+        for (point, &assignment) in points.iter().zip(&result.assignments) {
+            let distances: Vec<f32> = centroids
+                .iter()
+                .map(|c| distance_squared(point, c))
+                .collect();
+            let nearest = (0..distances.len())
+                .min_by(|&a, &b| distances[a].total_cmp(&distances[b]).then(a.cmp(&b)))
+                .unwrap();
+            assert_eq!(assignment as usize, nearest, "{point:?}");
+        }
+        for (c, centroid) in centroids.iter().enumerate() {
+            let members: Vec<&Vec2> = points
+                .iter()
+                .zip(&result.assignments)
+                .filter(|&(_, &a)| a as usize == c)
+                .map(|(point, _)| point)
+                .collect();
+            if members.is_empty() {
+                continue;
+            }
+            let mean = members.iter().fold([0.0f64; 2], |sum, p| {
+                [sum[0] + p[0] as f64, sum[1] + p[1] as f64]
+            });
+            let count = members.len() as f64;
+            assert!((mean[0] / count - centroid[0] as f64).abs() < 1e-3);
+            assert!((mean[1] / count - centroid[1] as f64).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn separated_blobs() {
+        let centers = [[0.0, 0.0], [50.0, 0.0], [0.0, 50.0]];
+        let points = blob_points(300, &centers);
+        let result = kmeans_cpu(&points, 3, 100, 0.0, 7);
+        assert!(result.iterations < 100);
+        check_fixed_point(&points, &result);
+        // Each blob is one cluster.
+        for blob in 0..3 {
+            let label = result.assignments[blob];
+            assert!((blob..300)
+                .step_by(3)
+                .all(|i| result.assignments[i] == label));
+        }
+        for center in centers {
+            assert!(centroids(&result)
+                .iter()
+                .any(|centroid| distance_squared(centroid, &center) < 1.0));
+        }
+
+        let again = kmeans_cpu(&points, 3, 100, 0.0, 7);
+        assert_eq!(again.assignments, result.assignments);
+        assert_eq!(centroids(&again), centroids(&result));
+    }
+
+    #[test]
+    fn more_clusters_than_points() {
+        assert_eq!(kmeans_cpu(&[], 4, 10, 0.0, 1).centroids.len(), 0);
+        assert_eq!(kmeans_cpu(&[[1.0, 1.0]], 0, 10, 0.0, 1).centroids.len(), 0);
+
+        let points = [[1.0, 1.0], [1.0, 1.0], [4.0, 0.0], [4.0, 0.0], [9.0, 9.0]];
+        let result = kmeans_cpu(&points, 10, 10, 0.0, 3);
+        assert_eq!(result.centroids.len(), 5);
+        check_fixed_point(&points, &result);
+        // Only three distinct points, so every point sits on its centroid.
+        for (point, &assignment) in points.iter().zip(&result.assignments) {
+            assert_eq!(
+                distance_squared(point, &centroids(&result)[assignment as usize]),
+                0.0
+            );
+        }
+    }
+
+    #[test]
+    fn non_finite_points_never_move_a_centroid() {
+        let mut points = blob_points(60, &[[0.0, 0.0], [30.0, 30.0]]);
+        points[5] = [f32::NAN, 0.0];
+        points[40] = [f32::INFINITY, f32::NEG_INFINITY];
+        for seed in 0..8 {
+            let result = kmeans_cpu(&points, 2, 50, 0.0, seed);
+            assert_eq!(result.centroids.len(), 2);
+            assert!(centroids(&result).iter().all(is_finite));
+            assert_eq!(result.assignments[5], 0);
+            assert_eq!(result.assignments[40], 0);
+            let finite: Vec<usize> = (0..60).filter(|&i| is_finite(&points[i])).collect();
+            let only_finite: Vec<Vec2> = finite.iter().map(|&i| points[i]).collect();
+            check_fixed_point(
+                &only_finite,
+                &KMeans {
+                    assignments: finite.iter().map(|&i| result.assignments[i]).collect(),
+                    ..result.clone()
+                },
+            );
+        }
+        let result = kmeans_cpu(&[[f32::NAN; 2], [1.0, 2.0]], 4, 10, 0.0, 1);
+        assert_eq!(centroids(&result), [[1.0, 2.0]]);
+        assert_eq!(result.assignments, [0, 0]);
+        assert!(kmeans_cpu(&[[f32::NAN; 2]], 4, 10, 0.0, 1)
+            .centroids
+            .is_empty());
+    }
+}
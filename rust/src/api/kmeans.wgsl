@@ -0,0 +1,76 @@
+// Lloyd iterations of k-means over a resident point set. `assign` moves every point to its nearest
+// centroid (lowest index on ties), `update` recomputes each centroid as the mean of its points, one
+// workgroup per centroid. Centroids without points stay where they are, and points that are not finite
+// never move a centroid.
+struct KMeans {
+    point_count: u32,
+    k: u32,
+};
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> kmeans: KMeans;
+@group(0) @binding(2) var<storage, read_write> centroids: array<vec2<f32>>;
+@group(0) @binding(3) var<storage, read_write> assignments: array<u32>;
+
+fn isFinite(point: vec2<f32>) -> bool {
+    let exponents = (bitcast<vec2<u32>>(point) >> vec2<u32>(23u)) & vec2<u32>(0xffu);
+    return all(exponents != vec2<u32>(0xffu));
+}
+
+var<workgroup> sums: array<vec2<f32>, 256>;
+var<workgroup> counts: array<u32, 256>;
+
+@compute
+@workgroup_size(64)
+fn assign(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= kmeans.point_count) {
+        return;
+    }
+    let point = points[i];
+    var best = 0u;
+    var best_distance = 3.4028235e38;
+    for (var c = 0u; c < kmeans.k; c++) {
+        let d = point - centroids[c];
+        let distance = dot(d, d);
+        if (distance < best_distance) {
+            best = c;
+            best_distance = distance;
+        }
+    }
+    assignments[i] = best;
+}
+
+@compute
+@workgroup_size(256)
+fn update(
+    @builtin(workgroup_id) wid: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+    @builtin(local_invocation_index) t: u32,
+) {
+    let c = wid.x + wid.y * groups.x;
+    if (c >= kmeans.k) {
+        return;
+    }
+    var sum = vec2<f32>(0.0);
+    var count = 0u;
+    for (var i = t; i < kmeans.point_count; i += 256u) {
+        if (assignments[i] == c && isFinite(points[i])) {
+            sum += points[i];
+            count++;
+        }
+    }
+    sums[t] = sum;
+    counts[t] = count;
+    workgroupBarrier();
+    for (var stride = 128u; stride > 0u; stride /= 2u) {
+        if (t < stride) {
+            sums[t] += sums[t + stride];
+            counts[t] += counts[t + stride];
+        }
+        workgroupBarrier();
+    }
+    if (t == 0u && counts[0] > 0u) {
+        centroids[c] = sums[0] / f32(counts[0]);
+    }
+}
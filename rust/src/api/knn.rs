@@ -0,0 +1,300 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::{PointSet, PointSetIndex},
+    simple::{send_command, ComputeCommand, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const KNN_SHADER: &str = include_str!("knn.wgsl");
+
+// Size of the per invocation heaps in `knn.wgsl`. Larger `k` runs on the CPU.
+pub(crate) const MAX_GPU_K: u32 = 32;
+
+/// Nearest neighbours of a batch of query points, nearest first. The neighbours of query `i` are
+/// `indices[i * k..(i + 1) * k]`, with their Euclidean distances at the same positions in `distances`.
+/// Equally distant points are ordered by index.
+#[derive(Clone, Debug, Default)]
+pub struct Neighbours {
+    /// The requested `k`, capped at the number of finite points in the set. Points that are not finite are
+    /// never neighbours.
+    pub k: u32,
+    pub indices: Vec<u32>,
+    pub distances: Vec<f32>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct KnnParams {
+    k: u32,
+    query_count: u32,
+    grid_dims: [u32; 2],
+    grid_origin: Vec2,
+    inv_cell_size: Vec2,
+}
+
+fn distance_squared(a: &Vec2, b: &Vec2) -> f32 {
+    let dx = a[0] - b[0];
+    let dy = a[1] - b[1];
+    dx * dx + dy * dy
+}
+
+// Orders candidates the same way as the heaps in `knn.wgsl`.
+fn sort_candidates(candidates: &mut [(f32, u32)]) {
+    candidates.sort_unstable_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+}
+
+// Points that can be neighbours. The R-tree leaves the others out and `knn.wgsl` skips them, so capping `k`
+// at this keeps every query at `k` neighbours on all paths.
+fn finite_count(points: &[Vec2]) -> u32 {
+    points
+        .iter()
+        .filter(|point| point.iter().all(|v| v.is_finite()))
+        .count() as u32
+}
+
+pub(crate) fn knn_cpu(
+    points: &[Vec2],
+    index: &PointSetIndex,
+    queries: &[Vec2],
+    k: u32,
+) -> Neighbours {
+    let k = k.min(finite_count(points));
+    let mut neighbours = Neighbours {
+        k,
+        indices: Vec::with_capacity(queries.len() * k as usize),
+        distances: Vec::with_capacity(queries.len() * k as usize),
+    };
+    if k == 0 {
+        return neighbours;
+    }
+    let mut candidates = Vec::new();
+    for query in queries {
+        candidates.clear();
+        match index {
+            PointSetIndex::RTree(tree) => tree.nearest(query, k as usize, &mut candidates),
+            _ => {
+                candidates.extend(
+                    points
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, point)| point.iter().all(|v| v.is_finite()))
+                        .map(|(i, point)| (distance_squared(point, query), i as u32)),
+                );
+                candidates.select_nth_unstable_by(k as usize - 1, |a, b| {
+                    a.0.total_cmp(&b.0).then(a.1.cmp(&b.1))
+                });
+                candidates.truncate(k as usize);
+            }
+        }
+        sort_candidates(&mut candidates);
+        for (distance, index) in &candidates[..k as usize] {
+            neighbours.indices.push(*index);
+            neighbours.distances.push(distance.sqrt());
+        }
+    }
+    neighbours
+}
+
+pub(crate) async fn knn_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    queries: &[Vec2],
+    k: u32,
+) -> Neighbours {
+    let device = &gpu.device;
+    let k = k.min(finite_count(&set.points));
+    if k == 0 || queries.is_empty() {
+        return Neighbours {
+            k,
+            ..Default::default()
+        };
+    }
+
+    let mut params = KnnParams {
+        k,
+        query_count: queries.len() as u32,
+        grid_dims: [0; 2],
+        grid_origin: [0.0; 2],
+        inv_cell_size: [0.0; 2],
+    };
+    if let PointSetIndex::Grid(grid) = &set.index {
+        params.grid_dims = grid.dims();
+        params.grid_origin = grid.origin();
+        params.inv_cell_size = grid.inv_cell_size();
+    }
+
+    let output_size = queries.len() as u64 * k as u64 * 4;
+    let params_buffer = gpu::uniform_buffer(device, "kNN Params Buffer", &params);
+    let queries_buffer = gpu::storage_buffer(
+        device,
+        "kNN Queries Buffer",
+        queries,
+        wgpu::BufferUsages::empty(),
+    );
+    let indices_buffer = gpu::empty_storage_buffer(
+        device,
+        "kNN Indices Buffer",
+        output_size,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let distances_buffer = gpu::empty_storage_buffer(
+        device,
+        "kNN Distances Buffer",
+        output_size,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let mut bindings = vec![
+        (0, &set.buffer),
+        (1, &params_buffer),
+        (2, &queries_buffer),
+        (3, &indices_buffer),
+        (4, &distances_buffer),
+    ];
+    let entry = match &set.index {
+        PointSetIndex::Grid(grid) => {
+            bindings.extend([(5, &grid.cell_start), (6, &grid.sorted_indices)]);
+            "knn_grid"
+        }
+        PointSetIndex::Bvh(bvh) => {
+            bindings.extend([(6, &bvh.sorted_indices), (7, &bvh.nodes)]);
+            "knn_bvh"
+        }
+        PointSetIndex::None | PointSetIndex::RTree(_) => "knn_linear",
+    };
+    let pipeline = gpu.pipeline(KNN_SHADER, entry);
+    let bind_group = gpu::bind_group_at(device, &pipeline, &bindings);
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &pipeline,
+        &bind_group,
+        params.query_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let count = queries.len() * k as usize;
+    Neighbours {
+        k,
+        indices: gpu::read_buffer(device, &gpu.queue, &indices_buffer, 0, count).await,
+        distances: gpu::read_buffer(device, &gpu.queue, &distances_buffer, 0, count).await,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// The `k` points of `point_set` nearest to each of `query_points`. `None` if the point set does not exist or
+/// a query point is not finite. Uses the set's spatial index when it has one.
+pub async fn knn(point_set: u32, query_points: Vec<Vec2>, k: u32) -> Option<Neighbours> {
+    match send_command(ComputeCommand::Knn(point_set, query_points, k)).await {
+        ComputeResponse::Neighbours(neighbours) => neighbours,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::rtree::RTreeIndex;
+
+    // Xorshift points on a 16 x 16 lattice, so there are plenty of duplicates and equal distances.
+    fn lattice_points(count: usize) -> Vec<Vec2> {
+        let mut state = 0x9e37_79b9u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 28) as f32
+        };
+        (0..count).map(|_| [next(), next()]).collect()
+    }
+
+    // Every point sorted by distance, then index.
+    fn brute_force(points: &[Vec2], query: &Vec2, k: usize) -> Vec<(f32, u32)> {
+        let mut all: Vec<(f32, u32)> = points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (distance_squared(point, query), i as u32))
+            .collect();
+        sort_candidates(&mut all);
+        all.truncate(k);
+        all
+    }
+
+    #[test]
+    fn matches_brute_force_on_both_paths() {
+        let points = lattice_points(500);
+        let queries = [
+            [0.0, 0.0],
+            [7.5, 7.5],
+            [3.0, 12.0],
+            [40.0, -3.0],
+            points[17],
+        ];
+        let tree = PointSetIndex::RTree(Box::new(RTreeIndex::build(&points)));
+        for k in [1, 5, 32, 33] {
+            for index in [&PointSetIndex::None, &tree] {
+                let neighbours = knn_cpu(&points, index, &queries, k);
+                assert_eq!(neighbours.k, k);
+                for (q, query) in queries.iter().enumerate() {
+                    let expected = brute_force(&points, query, k as usize);
+                    let range = q * k as usize..(q + 1) * k as usize;
+                    let indices: Vec<u32> = expected.iter().map(|&(_, i)| i).collect();
+                    let distances: Vec<f32> = expected.iter().map(|&(d, _)| d.sqrt()).collect();
+                    assert_eq!(
+                        neighbours.indices[range.clone()],
+                        indices,
+                        "k {k}, query {q}"
+                    );
+                    assert_eq!(neighbours.distances[range], distances, "k {k}, query {q}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn k_is_capped_at_the_point_count() {
+        let points = [[1.0, 1.0], [1.0, 1.0], [0.0, 0.0]];
+        let tree = PointSetIndex::RTree(Box::new(RTreeIndex::build(&points)));
+        for index in [&PointSetIndex::None, &tree] {
+            let neighbours = knn_cpu(&points, index, &[[1.0, 1.0]], 10);
+            assert_eq!(neighbours.k, 3);
+            // Duplicates come out by index.
+            assert_eq!(neighbours.indices, [0, 1, 2]);
+            assert_eq!(neighbours.distances, [0.0, 0.0, std::f32::consts::SQRT_2]);
+
+            assert_eq!(knn_cpu(&points, index, &[[1.0, 1.0]], 0).k, 0);
+            assert!(knn_cpu(&points, index, &[], 2).indices.is_empty());
+        }
+        let empty = knn_cpu(&[], &PointSetIndex::None, &[[0.0, 0.0]], 4);
+        assert_eq!(empty.k, 0);
+        assert!(empty.indices.is_empty() && empty.distances.is_empty());
+    }
+
+    // Points that are not finite are left out of R-trees, so asking for more neighbours than the finite
+    // points used to slice past the candidates.
+    #[test]
+    fn non_finite_points_are_never_neighbours() {
+        let points = [
+            [0.0, 0.0],
+            [f32::NAN, 1.0],
+            [3.0, 0.0],
+            [f32::INFINITY, f32::NAN],
+            [1.0, 0.0],
+        ];
+        let queries = [[0.5, 0.0], [10.0, 0.0]];
+        let tree = PointSetIndex::RTree(Box::new(RTreeIndex::build(&points)));
+        for index in [&PointSetIndex::None, &tree] {
+            let neighbours = knn_cpu(&points, index, &queries, 5);
+            assert_eq!(neighbours.k, 3);
+            assert_eq!(neighbours.indices, [0, 4, 2, 2, 4, 0]);
+            assert_eq!(neighbours.distances, [0.5, 0.5, 2.5, 7.0, 9.0, 10.0]);
+        }
+        let neighbours = knn_cpu(&[[f32::NAN; 2]], &PointSetIndex::None, &queries, 2);
+        assert_eq!(neighbours.k, 0);
+        assert!(neighbours.indices.is_empty());
+    }
+}
@@ -0,0 +1,235 @@
+// k nearest neighbours of each query point within a resident point set. Every invocation keeps its best
+// `k` candidates in a bounded max-heap ordered by squared distance, then index, so ties resolve the same
+// way as `knn::knn_cpu`. `knn_linear` tests every point, `knn_grid` searches rings of cells outwards
+// from the query and `knn_bvh` walks the BVH from `bvh.wgsl` nearest child first.
+struct Knn {
+    k: u32,
+    query_count: u32,
+    grid_dims: vec2<u32>,
+    grid_origin: vec2<f32>,
+    inv_cell_size: vec2<f32>,
+};
+
+struct Node {
+    min: vec2<f32>,
+    max: vec2<f32>,
+    left: u32,
+    right: u32,
+    first: u32,
+    last: u32,
+};
+
+const MAX_K: u32 = 32u;
+const LEAF: u32 = 0x80000000u;
+const STACK_SIZE: u32 = 96u;
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> knn: Knn;
+@group(0) @binding(2) var<storage, read> queries: array<vec2<f32>>;
+@group(0) @binding(3) var<storage, read_write> outputIndices: array<u32>;
+@group(0) @binding(4) var<storage, read_write> outputDistances: array<f32>;
+@group(0) @binding(5) var<storage, read> cellStart: array<u32>;
+@group(0) @binding(6) var<storage, read> sortedIndices: array<u32>;
+@group(0) @binding(7) var<storage, read> nodes: array<Node>;
+
+var<private> heapDistances: array<f32, MAX_K>;
+var<private> heapIndices: array<u32, MAX_K>;
+var<private> heapSize: u32;
+
+fn after(a: u32, b: u32) -> bool {
+    let da = heapDistances[a];
+    let db = heapDistances[b];
+    return da > db || (da == db && heapIndices[a] > heapIndices[b]);
+}
+
+fn swap(a: u32, b: u32) {
+    let distance = heapDistances[a];
+    let index = heapIndices[a];
+    heapDistances[a] = heapDistances[b];
+    heapIndices[a] = heapIndices[b];
+    heapDistances[b] = distance;
+    heapIndices[b] = index;
+}
+
+fn siftDown(start: u32, size: u32) {
+    var i = start;
+    loop {
+        let left = i * 2u + 1u;
+        let right = left + 1u;
+        var largest = i;
+        if (left < size && after(left, largest)) {
+            largest = left;
+        }
+        if (right < size && after(right, largest)) {
+            largest = right;
+        }
+        if (largest == i) {
+            break;
+        }
+        swap(i, largest);
+        i = largest;
+    }
+}
+
+// Squared distance a candidate has to beat, infinite until the heap is full.
+fn worst() -> f32 {
+    if (heapSize < knn.k) {
+        return 3.4028235e38;
+    }
+    return heapDistances[0];
+}
+
+// Whether both coordinates are finite, from their bits since the compiler may assume floats never are NaN.
+fn isFinite(point: vec2<f32>) -> bool {
+    let exponents = (bitcast<vec2<u32>>(point) >> vec2<u32>(23u)) & vec2<u32>(0xffu);
+    return all(exponents != vec2<u32>(0xffu));
+}
+
+fn offer(query: vec2<f32>, index: u32) {
+    // Same as the CPU path and the R-tree, which leave points that are not finite out.
+    if (!isFinite(points[index])) {
+        return;
+    }
+    let d = points[index] - query;
+    let distance = dot(d, d);
+    if (heapSize < knn.k) {
+        var i = heapSize;
+        heapDistances[i] = distance;
+        heapIndices[i] = index;
+        heapSize++;
+        while (i > 0u) {
+            let parent = (i - 1u) / 2u;
+            if (!after(i, parent)) {
+                break;
+            }
+            swap(i, parent);
+            i = parent;
+        }
+    } else if (distance < heapDistances[0] || (distance == heapDistances[0] && index < heapIndices[0])) {
+        heapDistances[0] = distance;
+        heapIndices[0] = index;
+        siftDown(0u, heapSize);
+    }
+}
+
+// Heap sorts the candidates in place and writes them nearest first.
+fn finish(q: u32) {
+    for (var size = heapSize; size > 1u; size--) {
+        swap(0u, size - 1u);
+        siftDown(0u, size - 1u);
+    }
+    for (var i = 0u; i < heapSize; i++) {
+        outputIndices[q * knn.k + i] = heapIndices[i];
+        outputDistances[q * knn.k + i] = sqrt(heapDistances[i]);
+    }
+}
+
+fn boxDistance(query: vec2<f32>, low: vec2<f32>, high: vec2<f32>) -> f32 {
+    let d = query - clamp(query, low, high);
+    return dot(d, d);
+}
+
+@compute
+@workgroup_size(64)
+fn knn_linear(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let q = gid.x + gid.y * groups.x * 64u;
+    if (q >= knn.query_count) {
+        return;
+    }
+    let query = queries[q];
+    heapSize = 0u;
+    for (var i = 0u; i < arrayLength(&points); i++) {
+        offer(query, i);
+    }
+    finish(q);
+}
+
+@compute
+@workgroup_size(64)
+fn knn_grid(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let q = gid.x + gid.y * groups.x * 64u;
+    if (q >= knn.query_count) {
+        return;
+    }
+    let query = queries[q];
+    let dims = vec2<i32>(knn.grid_dims);
+    let center = clamp(vec2<i32>(floor((query - knn.grid_origin) * knn.inv_cell_size)), vec2<i32>(0, 0), dims - vec2<i32>(1, 1));
+    let cell_size = 1.0 / knn.inv_cell_size;
+    let ring_width = min(cell_size.x, cell_size.y);
+    let rings = max(max(center.x, dims.x - 1 - center.x), max(center.y, dims.y - 1 - center.y));
+    heapSize = 0u;
+    for (var ring = 0; ring <= rings; ring++) {
+        for (var y = center.y - ring; y <= center.y + ring; y++) {
+            if (y < 0 || y >= dims.y) {
+                continue;
+            }
+            // Cells strictly inside the ring were visited already, so interior rows only take the two ends.
+            var step = 2 * ring;
+            if (y == center.y - ring || y == center.y + ring || ring == 0) {
+                step = 1;
+            }
+            for (var x = center.x - ring; x <= center.x + ring; x += step) {
+                if (x < 0 || x >= dims.x) {
+                    continue;
+                }
+                let cell = u32(y * dims.x + x);
+                for (var k = cellStart[cell]; k < cellStart[cell + 1u]; k++) {
+                    offer(query, sortedIndices[k]);
+                }
+            }
+        }
+        // Points in later rings are at least `ring` cells away from the query's cell.
+        let reach = f32(ring) * ring_width;
+        if (heapSize == knn.k && heapDistances[0] < reach * reach) {
+            break;
+        }
+    }
+    finish(q);
+}
+
+@compute
+@workgroup_size(64)
+fn knn_bvh(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let q = gid.x + gid.y * groups.x * 64u;
+    if (q >= knn.query_count) {
+        return;
+    }
+    let query = queries[q];
+    heapSize = 0u;
+    var stack: array<u32, STACK_SIZE>;
+    var top = 1u;
+    // A single point has no internal nodes.
+    stack[0] = select(0u, LEAF, arrayLength(&sortedIndices) == 1u);
+    while (top > 0u) {
+        top--;
+        let reference = stack[top];
+        if ((reference & LEAF) != 0u) {
+            offer(query, sortedIndices[reference & ~LEAF]);
+            continue;
+        }
+        let node = nodes[reference];
+        if (boxDistance(query, node.min, node.max) > worst()) {
+            continue;
+        }
+        // Push the nearer child last so it is visited first.
+        var near = node.left;
+        var far = node.right;
+        if (childDistance(query, node.right) < childDistance(query, node.left)) {
+            near = node.right;
+            far = node.left;
+        }
+        stack[top] = far;
+        stack[top + 1u] = near;
+        top += 2u;
+    }
+    finish(q);
+}
+
+fn childDistance(query: vec2<f32>, child: u32) -> f32 {
+    if ((child & LEAF) != 0u) {
+        let d = points[sortedIndices[child & ~LEAF]] - query;
+        return dot(d, d);
+    }
+    let node = nodes[child];
+    return boxDistance(query, node.min, node.max);
+}
@@ -0,0 +1,200 @@
+use std::{fmt::Write, sync::RwLock};
+
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Level, Subscriber,
+};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::{
+    filter::LevelFilter,
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    reload,
+    util::SubscriberInitExt,
+    EnvFilter, Layer, Registry,
+};
+
+use crate::frb_generated::StreamSink;
+
+lazy_static::lazy_static! {
+    static ref LOG_SINK: RwLock<Option<StreamSink<LogEntry>>> = RwLock::new(None);
+    static ref DART_FILTER: RwLock<Option<reload::Handle<LevelFilter, Registry>>> = RwLock::new(None);
+}
+
+const DEFAULT_FILTER: &str = "info,wgpu_core=warn,wgpu_hal=warn,naga=warn";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl From<Level> for LogLevel {
+    fn from(level: Level) -> LogLevel {
+        match level {
+            Level::TRACE => LogLevel::Trace,
+            Level::DEBUG => LogLevel::Debug,
+            Level::INFO => LogLevel::Info,
+            Level::WARN => LogLevel::Warn,
+            Level::ERROR => LogLevel::Error,
+        }
+    }
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> LevelFilter {
+        match level {
+            LogLevel::Trace => LevelFilter::TRACE,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Error => LevelFilter::ERROR,
+        }
+    }
+}
+
+/// A log event forwarded to Dart.
+#[derive(Clone, Debug)]
+pub struct LogEntry {
+    pub level: LogLevel,
+    pub target: String,
+    pub message: String,
+    /// Event fields other than the message, as `key=value` pairs.
+    pub fields: String,
+    /// Enclosing spans from the outermost in, e.g. `compute_request{request_id=3 command=rect}:upload{bytes=8000}`.
+    pub spans: String,
+}
+
+// Installs the global subscriber: `RUST_LOG` controls stderr (or browser console) output,
+// `create_log_stream` controls what reaches Dart.
+pub(crate) fn init() {
+    let (dart_filter, handle) = reload::Layer::new(LevelFilter::OFF);
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let output = tracing_subscriber::fmt::layer();
+    #[cfg(target_arch = "wasm32")]
+    let output = tracing_wasm::WASMLayer::new(tracing_wasm::WASMLayerConfig::default());
+
+    let result = tracing_subscriber::registry()
+        .with(DartLogLayer.with_filter(dart_filter))
+        .with(output.with_filter(env_filter))
+        .try_init();
+    if result.is_ok() {
+        *DART_FILTER.write().unwrap() = Some(handle);
+    }
+}
+
+struct DartLogLayer;
+
+impl<S> Layer<S> for DartLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(visitor);
+        }
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(visitor) = span.extensions_mut().get_mut::<FieldVisitor>() {
+                values.record(visitor);
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let sink = LOG_SINK.read().unwrap();
+        let Some(sink) = sink.as_ref() else {
+            return;
+        };
+
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let mut spans = String::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                if !spans.is_empty() {
+                    spans.push(':');
+                }
+                spans.push_str(span.name());
+                if let Some(fields) = span.extensions().get::<FieldVisitor>() {
+                    if !fields.fields.is_empty() {
+                        let _ = write!(spans, "{{{}}}", fields.fields);
+                    }
+                }
+            }
+        }
+
+        // A closed stream only means Dart stopped listening.
+        let _ = sink.add(LogEntry {
+            level: (*metadata.level()).into(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+            spans,
+        });
+    }
+}
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        match field.name() {
+            "message" => self.message = format!("{:?}", value),
+            // Already part of the normalized metadata of events coming from `log`.
+            name if name.starts_with("log.") => {}
+            name => {
+                if !self.fields.is_empty() {
+                    self.fields.push(' ');
+                }
+                let _ = write!(self.fields, "{}={:?}", name, value);
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Forwards log events at `max_level` and above to Dart. A new stream replaces the previous one.
+pub fn create_log_stream(sink: StreamSink<LogEntry>, max_level: LogLevel) {
+    *LOG_SINK.write().unwrap() = Some(sink);
+    set_dart_log_level(max_level.into());
+}
+
+/// Stops forwarding log events to Dart.
+#[flutter_rust_bridge::frb(sync)]
+pub fn close_log_stream() {
+    set_dart_log_level(LevelFilter::OFF);
+    *LOG_SINK.write().unwrap() = None;
+}
+
+fn set_dart_log_level(level: LevelFilter) {
+    if let Some(handle) = DART_FILTER.read().unwrap().as_ref() {
+        let _ = handle.reload(level);
+    }
+}
@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+
+use rstar::{primitives::GeomWithData, RTree, AABB};
+use web_time::Instant;
+
+use super::simple::{send_command, ComputeCommand, ComputeRect, ComputeResponse, Vec2};
+
+// Past this the cell size runs into the precision of `f32` point coordinates anyway.
+const MAX_ZOOM: u32 = 30;
+
+/// A group of nearby points shown as one marker at some zoom level.
+#[derive(Clone, Copy, Debug)]
+pub struct MarkerCluster {
+    /// Mean position of the points in the cluster.
+    pub position: Vec2,
+    pub count: u32,
+    /// Lowest index of the points in the cluster, to label or style the marker with.
+    pub representative: u32,
+}
+
+struct Level {
+    clusters: Vec<MarkerCluster>,
+    tree: RTree<GeomWithData<Vec2, u32>>,
+}
+
+impl Level {
+    fn new(clusters: Vec<MarkerCluster>) -> Level {
+        let entries = clusters
+            .iter()
+            .enumerate()
+            .map(|(i, cluster)| GeomWithData::new(cluster.position, i as u32))
+            .collect();
+        Level {
+            clusters,
+            tree: RTree::bulk_load(entries),
+        }
+    }
+}
+
+// Grid clustering precomputed for every zoom level of a point set, each level built by merging the
+// clusters of the level below so a cluster always splits into whole clusters when zooming in.
+pub(crate) struct MarkerClusterIndex {
+    radius: f32,
+    min_zoom: u32,
+    max_zoom: u32,
+    // From `min_zoom` to `max_zoom + 1`, the last one holding every point on its own.
+    levels: Vec<Level>,
+}
+
+impl MarkerClusterIndex {
+    // `None` if the parameters can not describe a grid.
+    pub(crate) fn build(
+        points: &[Vec2],
+        radius: f32,
+        min_zoom: u32,
+        max_zoom: u32,
+    ) -> Option<MarkerClusterIndex> {
+        if !(radius.is_finite() && radius > 0.0) || min_zoom > max_zoom || max_zoom > MAX_ZOOM {
+            return None;
+        }
+        let start = Instant::now();
+        // Points that are not finite belong to no cell and would poison the mean of the one they land in.
+        let mut clusters: Vec<MarkerCluster> = points
+            .iter()
+            .enumerate()
+            .filter(|(_, point)| point.iter().all(|v| v.is_finite()))
+            .map(|(i, point)| MarkerCluster {
+                position: *point,
+                count: 1,
+                representative: i as u32,
+            })
+            .collect();
+        let mut levels = Vec::with_capacity((max_zoom - min_zoom + 2) as usize);
+        for zoom in (min_zoom..=max_zoom).rev() {
+            let merged = merge(&clusters, radius / (1u64 << zoom) as f32);
+            levels.push(Level::new(std::mem::replace(&mut clusters, merged)));
+        }
+        levels.push(Level::new(clusters));
+        levels.reverse();
+        tracing::debug!(
+            points = points.len(),
+            levels = levels.len(),
+            micros = start.elapsed().as_micros() as u64,
+            "marker clusters built"
+        );
+        Some(MarkerClusterIndex {
+            radius,
+            min_zoom,
+            max_zoom,
+            levels,
+        })
+    }
+
+    // Rebuilds with the same parameters after the points of the set changed.
+    pub(crate) fn rebuild(&mut self, points: &[Vec2]) {
+        if let Some(index) = Self::build(points, self.radius, self.min_zoom, self.max_zoom) {
+            *self = index;
+        }
+    }
+
+    // Clusters of the level for `zoom` whose position lies inside `viewport`, in a stable order.
+    // Zooms past `max_zoom` return the points themselves.
+    pub(crate) fn query(&self, viewport: &ComputeRect, zoom: u32) -> Vec<MarkerCluster> {
+        let level =
+            &self.levels[(zoom.clamp(self.min_zoom, self.max_zoom + 1) - self.min_zoom) as usize];
+        let mut found: Vec<u32> = level
+            .tree
+            .locate_in_envelope(&AABB::from_corners(viewport.min, viewport.max))
+            .map(|entry| entry.data)
+            .collect();
+        found.sort_unstable();
+        found
+            .into_iter()
+            .map(|i| level.clusters[i as usize])
+            .collect()
+    }
+}
+
+// Merges the clusters falling into the same `cell_size` square, keeping the order in which cells are
+// first reached so results do not depend on hashing.
+fn merge(clusters: &[MarkerCluster], cell_size: f32) -> Vec<MarkerCluster> {
+    let mut cells: HashMap<(i64, i64), usize> = HashMap::new();
+    let mut sums: Vec<[f64; 2]> = Vec::new();
+    let mut merged: Vec<MarkerCluster> = Vec::new();
+    for cluster in clusters {
+        let cell = (
+            (cluster.position[0] / cell_size).floor() as i64,
+            (cluster.position[1] / cell_size).floor() as i64,
+        );
+        let i = *cells.entry(cell).or_insert_with(|| {
+            sums.push([0.0; 2]);
+            merged.push(MarkerCluster {
+                position: cluster.position,
+                count: 0,
+                representative: cluster.representative,
+            });
+            merged.len() - 1
+        });
+        let weight = cluster.count as f64;
+        sums[i][0] += cluster.position[0] as f64 * weight;
+        sums[i][1] += cluster.position[1] as f64 * weight;
+        let target = &mut merged[i];
+        target.count += cluster.count;
+        target.representative = target.representative.min(cluster.representative);
+    }
+    for (cluster, sum) in merged.iter_mut().zip(sums) {
+        let count = cluster.count as f64;
+        cluster.position = [(sum[0] / count) as f32, (sum[1] / count) as f32];
+    }
+    merged
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Precomputes marker clusters of `point_set` for every zoom level from `min_zoom` to `max_zoom`.
+/// At zoom 0 points sharing a `radius` sized grid cell (in point coordinates) form one cluster, and each
+/// zoom level halves the cell size. The clusters are rebuilt automatically when the points are updated.
+/// Returns false if the point set does not exist, `radius` is not positive, `min_zoom` is above `max_zoom`
+/// or `max_zoom` is above 30.
+pub async fn build_marker_clusters(
+    point_set: u32,
+    radius: f32,
+    min_zoom: u32,
+    max_zoom: u32,
+) -> bool {
+    matches!(
+        send_command(ComputeCommand::BuildMarkerClusters(
+            point_set, radius, min_zoom, max_zoom
+        ))
+        .await,
+        ComputeResponse::Done(true)
+    )
+}
+
+/// Marker clusters of `point_set` at `zoom` whose position is inside `viewport`. Zooms below `min_zoom`
+/// use the `min_zoom` clusters, zooms above `max_zoom` return every point as a cluster of one.
+/// `None` if the point set does not exist or has no marker clusters.
+pub async fn get_marker_clusters(
+    point_set: u32,
+    viewport: ComputeRect,
+    zoom: u32,
+) -> Option<Vec<MarkerCluster>> {
+    match send_command(ComputeCommand::MarkerClusters(point_set, viewport, zoom)).await {
+        ComputeResponse::MarkerClusters(clusters) => clusters,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    const EVERYWHERE: ComputeRect = ComputeRect {
+        min: [-1e9; 2],
+        max: [1e9; 2],
+    };
+
+    // Xorshift points in `[0, 1000)`, about one in ten repeating an earlier one.
+    fn random_points(count: usize) -> Vec<Vec2> {
+        let mut state = 0xdead_beefu32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1 << 24) as f32 * 1000.0
+        };
+        let mut points: Vec<Vec2> = Vec::with_capacity(count);
+        while points.len() < count {
+            let point = [next(), next()];
+            if next() < 100.0 && !points.is_empty() {
+                points.push(points[points.len() / 2]);
+            } else {
+                points.push(point);
+            }
+        }
+        points
+    }
+
+    // Point indices of every cluster of a level, merging the groups of the level above cell by cell.
+    fn merge_groups(points: &[Vec2], groups: &[Vec<u32>], cell_size: f32) -> Vec<Vec<u32>> {
+        let mut cells: BTreeMap<(i64, i64), (usize, Vec<u32>)> = BTreeMap::new();
+        for (order, group) in groups.iter().enumerate() {
+            let [x, y] = mean(points, group);
+            let cell = (
+                (x / cell_size).floor() as i64,
+                (y / cell_size).floor() as i64,
+            );
+            let entry = cells.entry(cell).or_insert((order, Vec::new()));
+            entry.1.extend(group);
+        }
+        let mut merged: Vec<(usize, Vec<u32>)> = cells.into_values().collect();
+        merged.sort_by_key(|(order, _)| *order);
+        merged.into_iter().map(|(_, group)| group).collect()
+    }
+
+    fn mean(points: &[Vec2], group: &[u32]) -> Vec2 {
+        let sum = group.iter().fold([0.0f64; 2], |sum, &i| {
+            let point = points[i as usize];
+            [sum[0] + point[0] as f64, sum[1] + point[1] as f64]
+        });
+        let count = group.len() as f64;
+        [(sum[0] / count) as f32, (sum[1] / count) as f32]
+    }
+
+    #[test]
+    fn levels_merge_whole_clusters() {
+        let mut points = random_points(2000);
+        points[10] = [f32::NAN, 1.0];
+        points[20] = [f32::INFINITY, 1.0];
+        let (radius, min_zoom, max_zoom) = (200.0, 1, 6);
+        let index = MarkerClusterIndex::build(&points, radius, min_zoom, max_zoom).unwrap();
+
+        let mut groups: Vec<Vec<u32>> = (0..points.len() as u32)
+            .filter(|&i| points[i as usize].iter().all(|v| v.is_finite()))
+            .map(|i| vec![i])
+            .collect();
+        for zoom in (min_zoom..=max_zoom + 1).rev() {
+            if zoom <= max_zoom {
+                groups = merge_groups(&points, &groups, radius / (1u64 << zoom) as f32);
+            }
+            let clusters = index.query(&EVERYWHERE, zoom);
+            assert_eq!(clusters.len(), groups.len(), "zoom {zoom}");
+            for (cluster, group) in clusters.iter().zip(&groups) {
+                assert_eq!(cluster.count as usize, group.len());
+                assert_eq!(cluster.representative, *group.iter().min().unwrap());
+                let expected = mean(&points, group);
+                assert!((cluster.position[0] - expected[0]).abs() < 1e-2);
+                assert!((cluster.position[1] - expected[1]).abs() < 1e-2);
+            }
+        }
+        assert_eq!(index.query(&EVERYWHERE, 0).len(), groups.len());
+
+        // Duplicates share a cluster up to the last level.
+        let duplicate = (1..points.len())
+            .find(|&i| points[i] == points[i / 2])
+            .unwrap();
+        let finest = index.query(&EVERYWHERE, max_zoom);
+        assert!(finest
+            .iter()
+            .any(|c| c.count >= 2 && points[c.representative as usize] == points[duplicate]));
+        assert_eq!(index.query(&EVERYWHERE, 50).len(), 1998);
+    }
+
+    #[test]
+    fn viewport_filters_by_position() {
+        let points = random_points(500);
+        let index = MarkerClusterIndex::build(&points, 100.0, 0, 4).unwrap();
+        let viewport = ComputeRect {
+            min: [250.0, 100.0],
+            max: [600.0, 450.0],
+        };
+        for zoom in 0..=5 {
+            let expected: Vec<u32> = index
+                .query(&EVERYWHERE, zoom)
+                .iter()
+                .filter(|c| crate::api::cpu::is_point_inside_rect(&c.position, &viewport))
+                .map(|c| c.representative)
+                .collect();
+            let found: Vec<u32> = index
+                .query(&viewport, zoom)
+                .iter()
+                .map(|c| c.representative)
+                .collect();
+            assert_eq!(found, expected, "zoom {zoom}");
+        }
+    }
+
+    #[test]
+    fn empty_sets_and_bad_parameters() {
+        let index = MarkerClusterIndex::build(&[], 10.0, 0, 3).unwrap();
+        assert!(index.query(&EVERYWHERE, 2).is_empty());
+        let nan = MarkerClusterIndex::build(&[[f32::NAN; 2]], 10.0, 0, 3).unwrap();
+        assert!(nan.query(&EVERYWHERE, 4).is_empty());
+
+        assert!(MarkerClusterIndex::build(&[], 0.0, 0, 3).is_none());
+        assert!(MarkerClusterIndex::build(&[], f32::NAN, 0, 3).is_none());
+        assert!(MarkerClusterIndex::build(&[], 10.0, 4, 3).is_none());
+        assert!(MarkerClusterIndex::build(&[], 10.0, 0, MAX_ZOOM + 1).is_none());
+    }
+}
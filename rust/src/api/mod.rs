@@ -2,4 +2,33 @@
 // Do not put code in `mod.rs`, but put in e.g. `simple.rs`.
 //
 
+pub mod adapter;
+pub mod alpha_shape;
+pub mod attributes;
+pub mod bvh;
+pub mod cpu;
+pub mod dbscan;
+pub mod delaunay;
+pub mod dispatch;
+pub mod expression;
+pub mod gpu;
+pub mod grid;
+pub mod heatmap;
+pub mod histogram;
+pub mod hit_test;
+pub mod hull;
+pub mod jump_flood;
+pub mod kmeans;
+pub mod knn;
+pub mod logging;
+pub mod marker_cluster;
+pub mod point_set;
+pub mod predicates;
+pub mod profiling;
+pub mod projection;
+pub mod query;
+pub mod radius_graph;
+pub mod rtree;
 pub mod simple;
+pub mod transform;
+pub mod voronoi;
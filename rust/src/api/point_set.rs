@@ -0,0 +1,498 @@
+use std::collections::HashMap;
+
+use web_time::Instant;
+
+use super::{
+    attributes::{AttributeValues, Attributes},
+    bvh::BvhIndex,
+    gpu,
+    grid::GridIndex,
+    marker_cluster::MarkerClusterIndex,
+    rtree::RTreeIndex,
+    simple::{send_command, unflatten_points, ComputeCommand, ComputeResponse, Vec2, WgpuCompute},
+};
+
+/// Spatial index kept alongside a resident point set to speed up queries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpatialIndexKind {
+    /// Every query scans all points.
+    None,
+    /// Uniform grid built on the GPU. Best for evenly spread points.
+    Grid,
+    /// Linear BVH over Morton ordered points, built on the GPU. Best for clustered points.
+    /// Refitted instead of rebuilt when points move without changing count.
+    Bvh,
+    /// R-tree kept in CPU memory, updated by inserting and removing the points that changed.
+    /// Queries on it always run on the CPU, which suits small sets and the CPU backend.
+    RTree,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct IndexStats {
+    pub kind: SpatialIndexKind,
+    pub build_micros: f64,
+    /// Cells for a grid, nodes for a BVH, points for an R-tree.
+    pub size: u64,
+    /// Whether the last update adjusted the existing index (BVH refit, R-tree insert and remove)
+    /// instead of rebuilding it.
+    pub refitted: bool,
+}
+
+pub(crate) enum PointSetIndex {
+    None,
+    Grid(Box<GridIndex>),
+    Bvh(Box<BvhIndex>),
+    RTree(Box<RTreeIndex>),
+}
+
+impl PointSetIndex {
+    fn kind(&self) -> SpatialIndexKind {
+        match self {
+            PointSetIndex::None => SpatialIndexKind::None,
+            PointSetIndex::Grid(_) => SpatialIndexKind::Grid,
+            PointSetIndex::Bvh(_) => SpatialIndexKind::Bvh,
+            PointSetIndex::RTree(_) => SpatialIndexKind::RTree,
+        }
+    }
+
+    // Indexes living in CPU memory answer every query themselves.
+    pub(crate) fn on_cpu(&self) -> bool {
+        matches!(self, PointSetIndex::RTree(_))
+    }
+
+    fn size(&self) -> u64 {
+        match self {
+            PointSetIndex::None => 0,
+            PointSetIndex::Grid(grid) => grid.cell_count() as u64,
+            PointSetIndex::Bvh(bvh) => bvh.node_count() as u64,
+            PointSetIndex::RTree(tree) => tree.size() as u64,
+        }
+    }
+}
+
+// Points uploaded once and kept on the device between queries, with a CPU copy for the CPU backend.
+pub(crate) struct PointSet {
+    pub(crate) points: Vec<Vec2>,
+    pub(crate) buffer: wgpu::Buffer,
+    pub(crate) index: PointSetIndex,
+    index_stats: Option<IndexStats>,
+    pub(crate) markers: Option<Box<MarkerClusterIndex>>,
+    pub(crate) attributes: Attributes,
+    // Slots of removed points, ascending. They hold NaN so no index or query returns them, and
+    // `insert_points` fills them before growing the set.
+    vacant: Vec<u32>,
+}
+
+impl PointSet {
+    fn new(gpu: &WgpuCompute, points: Vec<Vec2>) -> PointSet {
+        PointSet::with_buffer(
+            points_buffer(gpu, &points),
+            Attributes::new(gpu, points.len()),
+            points,
+        )
+    }
+
+    fn with_buffer(buffer: wgpu::Buffer, attributes: Attributes, points: Vec<Vec2>) -> PointSet {
+        PointSet {
+            buffer,
+            attributes,
+            points,
+            index: PointSetIndex::None,
+            index_stats: None,
+            markers: None,
+            vacant: Vec::new(),
+        }
+    }
+
+    fn build_index(&mut self, gpu: &WgpuCompute, kind: SpatialIndexKind) -> IndexStats {
+        let start = Instant::now();
+        // Grid and BVH are built by compute kernels, so adapters without them get the R-tree instead.
+        let kind = match kind {
+            SpatialIndexKind::Grid | SpatialIndexKind::Bvh if !gpu.supports_compute() => {
+                SpatialIndexKind::RTree
+            }
+            kind => kind,
+        };
+        self.index = match kind {
+            SpatialIndexKind::None => PointSetIndex::None,
+            SpatialIndexKind::Grid => {
+                PointSetIndex::Grid(Box::new(GridIndex::build(gpu, &self.points, &self.buffer)))
+            }
+            SpatialIndexKind::Bvh => {
+                PointSetIndex::Bvh(Box::new(BvhIndex::build(gpu, &self.points, &self.buffer)))
+            }
+            SpatialIndexKind::RTree => {
+                PointSetIndex::RTree(Box::new(RTreeIndex::build(&self.points)))
+            }
+        };
+        self.finish_index(gpu, start, false)
+    }
+
+    // Keeps the index in step with the points after they were replaced by `self.points`.
+    fn update_index(&mut self, gpu: &WgpuCompute, old: &[Vec2]) {
+        let start = Instant::now();
+        let moved = old.len() == self.points.len() && !old.is_empty();
+        let refitted = match &mut self.index {
+            PointSetIndex::None => return,
+            PointSetIndex::Bvh(bvh) => moved && bvh.refit(gpu, &self.buffer),
+            PointSetIndex::RTree(tree) => tree.update(old, &self.points),
+            PointSetIndex::Grid(_) => false,
+        };
+        if refitted {
+            self.finish_index(gpu, start, true);
+        } else {
+            self.build_index(gpu, self.index.kind());
+        }
+    }
+
+    // Same as `update_index` after the points at `indices` moved from `old` to `new`, one after another.
+    fn move_index(&mut self, gpu: &WgpuCompute, indices: &[u32], old: &[Vec2], new: &[Vec2]) {
+        let start = Instant::now();
+        let refitted = match &mut self.index {
+            PointSetIndex::None => return,
+            PointSetIndex::Bvh(bvh) => bvh.refit(gpu, &self.buffer),
+            PointSetIndex::RTree(tree) => tree.move_points(indices, old, new),
+            PointSetIndex::Grid(_) => false,
+        };
+        if refitted {
+            self.finish_index(gpu, start, true);
+        } else {
+            self.build_index(gpu, self.index.kind());
+        }
+    }
+
+    // Moves the points at `indices` to `points`, writing only them to the device.
+    fn write_points(&mut self, gpu: &WgpuCompute, indices: &[u32], points: &[Vec2]) {
+        let old: Vec<Vec2> = indices
+            .iter()
+            .zip(points)
+            .map(|(&i, point)| std::mem::replace(&mut self.points[i as usize], *point))
+            .collect();
+        // One write per run of consecutive indices.
+        let mut run = 0;
+        for k in 1..=indices.len() {
+            if k == indices.len() || indices[k] != indices[k - 1].wrapping_add(1) {
+                let (first, last) = (indices[run] as usize, indices[k - 1] as usize);
+                gpu.queue.write_buffer(
+                    &self.buffer,
+                    first as wgpu::BufferAddress * 8,
+                    bytemuck::cast_slice(&self.points[first..=last]),
+                );
+                run = k;
+            }
+        }
+        self.move_index(gpu, indices, &old, points);
+        if let Some(markers) = &mut self.markers {
+            markers.rebuild(&self.points);
+        }
+    }
+
+    fn finish_index(&mut self, gpu: &WgpuCompute, start: Instant, refitted: bool) -> IndexStats {
+        gpu.device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+        let stats = IndexStats {
+            kind: self.index.kind(),
+            build_micros: start.elapsed().as_nanos() as f64 / 1_000.0,
+            size: self.index.size(),
+            refitted,
+        };
+        tracing::debug!(kind = ?stats.kind, micros = stats.build_micros, refitted, "index built");
+        self.index_stats = Some(stats);
+        stats
+    }
+}
+
+pub(crate) fn points_buffer(gpu: &WgpuCompute, points: &[Vec2]) -> wgpu::Buffer {
+    gpu::storage_buffer(
+        &gpu.device,
+        "Resident Points Buffer",
+        points,
+        wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC,
+    )
+}
+
+#[derive(Default)]
+pub(crate) struct PointSets {
+    sets: HashMap<u32, PointSet>,
+    next_id: u32,
+}
+
+impl PointSets {
+    pub(crate) fn get(&self, id: u32) -> Option<&PointSet> {
+        self.sets.get(&id)
+    }
+
+    pub(crate) fn create(&mut self, gpu: &WgpuCompute, points: Vec<Vec2>) -> u32 {
+        self.next_id += 1;
+        self.sets.insert(self.next_id, PointSet::new(gpu, points));
+        self.next_id
+    }
+
+    // New set holding `points`, with copies of the attribute channels of the set `source`.
+    pub(crate) fn create_derived(
+        &mut self,
+        gpu: &WgpuCompute,
+        source: u32,
+        points: Vec<Vec2>,
+        buffer: Option<wgpu::Buffer>,
+    ) -> Option<u32> {
+        let source = self.sets.get(&source)?;
+        let attributes = source.attributes.copy(gpu);
+        let vacant = source.vacant.clone();
+        let buffer = buffer.unwrap_or_else(|| points_buffer(gpu, &points));
+        let mut set = PointSet::with_buffer(buffer, attributes, points);
+        set.vacant = vacant;
+        self.next_id += 1;
+        self.sets.insert(self.next_id, set);
+        Some(self.next_id)
+    }
+
+    // Replaces the points of a set, updating its index if it has one. Every slot holds a point afterwards.
+    pub(crate) fn update(&mut self, gpu: &WgpuCompute, id: u32, points: Vec<Vec2>) -> bool {
+        let replaced = self.replace(gpu, id, points, None);
+        if let Some(set) = self.sets.get_mut(&id) {
+            set.vacant.clear();
+        }
+        replaced
+    }
+
+    // Same as `update`, taking `buffer` as the device copy of `points` when they are already there. Vacant
+    // slots are kept for transforms, which leave NaN as it is.
+    pub(crate) fn replace(
+        &mut self,
+        gpu: &WgpuCompute,
+        id: u32,
+        points: Vec<Vec2>,
+        buffer: Option<wgpu::Buffer>,
+    ) -> bool {
+        let Some(set) = self.sets.get_mut(&id) else {
+            return false;
+        };
+        let moved = points.len() == set.points.len() && !points.is_empty();
+        match buffer {
+            Some(buffer) => set.buffer = buffer,
+            None if moved => gpu
+                .queue
+                .write_buffer(&set.buffer, 0, bytemuck::cast_slice(&points)),
+            None => set.buffer = points_buffer(gpu, &points),
+        }
+        if !moved {
+            set.attributes = Attributes::new(gpu, points.len());
+        }
+        let old = std::mem::replace(&mut set.points, points);
+        set.update_index(gpu, &old);
+        if let Some(markers) = &mut set.markers {
+            markers.rebuild(&set.points);
+        }
+        true
+    }
+
+    // Moves the points at `indices` to `points`, writing only them to the device.
+    pub(crate) fn move_points(
+        &mut self,
+        gpu: &WgpuCompute,
+        id: u32,
+        indices: &[u32],
+        points: &[Vec2],
+    ) -> bool {
+        let Some(set) = self.sets.get_mut(&id) else {
+            return false;
+        };
+        if indices.len() != points.len()
+            || indices
+                .iter()
+                .any(|&i| i as usize >= set.points.len() || set.vacant.binary_search(&i).is_ok())
+        {
+            return false;
+        }
+        set.write_points(gpu, indices, points);
+        true
+    }
+
+    // Adds `points` to a set, filling vacant slots before growing it, and returns their indices.
+    pub(crate) fn insert_points(
+        &mut self,
+        gpu: &WgpuCompute,
+        id: u32,
+        points: &[Vec2],
+    ) -> Option<Vec<u32>> {
+        let set = self.sets.get_mut(&id)?;
+        let reused = set.vacant.len().min(points.len());
+        let mut indices: Vec<u32> = set.vacant.drain(..reused).collect();
+        let point_count = set.points.len() + points.len() - reused;
+        set.attributes.reset_points(gpu, point_count, &indices);
+        if reused == points.len() {
+            set.write_points(gpu, &indices, points);
+            return Some(indices);
+        }
+        // Growing needs a new buffer anyway, so the whole set goes up at once.
+        let mut grown = set.points.clone();
+        for (&i, point) in indices.iter().zip(points) {
+            grown[i as usize] = *point;
+        }
+        indices.extend(grown.len() as u32..point_count as u32);
+        grown.extend_from_slice(&points[reused..]);
+        set.buffer = points_buffer(gpu, &grown);
+        let old = std::mem::replace(&mut set.points, grown);
+        set.update_index(gpu, &old);
+        if let Some(markers) = &mut set.markers {
+            markers.rebuild(&set.points);
+        }
+        Some(indices)
+    }
+
+    // Leaves the slots at `indices` vacant, so the other points keep their indices.
+    pub(crate) fn remove_points(&mut self, gpu: &WgpuCompute, id: u32, indices: &[u32]) -> bool {
+        let Some(set) = self.sets.get_mut(&id) else {
+            return false;
+        };
+        let mut removed = indices.to_vec();
+        removed.sort_unstable();
+        removed.dedup();
+        if removed.len() != indices.len()
+            || removed
+                .iter()
+                .any(|&i| i as usize >= set.points.len() || set.vacant.binary_search(&i).is_ok())
+        {
+            return false;
+        }
+        set.write_points(gpu, &removed, &vec![[f32::NAN; 2]; removed.len()]);
+        set.vacant.extend(removed);
+        set.vacant.sort_unstable();
+        true
+    }
+
+    pub(crate) fn dispose(&mut self, id: u32) -> bool {
+        self.sets.remove(&id).is_some()
+    }
+
+    pub(crate) fn set_index(
+        &mut self,
+        gpu: &WgpuCompute,
+        id: u32,
+        kind: SpatialIndexKind,
+    ) -> Option<IndexStats> {
+        let set = self.sets.get_mut(&id)?;
+        Some(set.build_index(gpu, kind))
+    }
+
+    pub(crate) fn index_stats(&self, id: u32) -> Option<IndexStats> {
+        self.sets.get(&id)?.index_stats
+    }
+
+    pub(crate) fn set_attribute(
+        &mut self,
+        gpu: &WgpuCompute,
+        id: u32,
+        name: String,
+        values: AttributeValues,
+    ) -> bool {
+        self.sets
+            .get_mut(&id)
+            .is_some_and(|set| set.attributes.set(gpu, name, values))
+    }
+
+    pub(crate) fn remove_attribute(&mut self, gpu: &WgpuCompute, id: u32, name: &str) -> bool {
+        self.sets
+            .get_mut(&id)
+            .is_some_and(|set| set.attributes.remove(gpu, name))
+    }
+
+    pub(crate) fn build_markers(
+        &mut self,
+        id: u32,
+        radius: f32,
+        min_zoom: u32,
+        max_zoom: u32,
+    ) -> bool {
+        let Some(set) = self.sets.get_mut(&id) else {
+            return false;
+        };
+        set.markers =
+            MarkerClusterIndex::build(&set.points, radius, min_zoom, max_zoom).map(Box::new);
+        set.markers.is_some()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Uploads `points` and keeps them on the device. Returns the id used by queries on this set.
+pub async fn create_point_set(points: Vec<Vec2>) -> u32 {
+    match send_command(ComputeCommand::CreatePointSet(points)).await {
+        ComputeResponse::PointSet(id) => id,
+        _ => panic!("Failed to create point set!"),
+    }
+}
+
+/// Same as `create_point_set`, with the coordinates of the points one after another as `x0, y0, x1, y1, ...`,
+/// so a `Float32List` is uploaded without wrapping every point. `None` if `points` has an odd length.
+pub async fn create_point_set_flat(points: Vec<f32>) -> Option<u32> {
+    Some(create_point_set(unflatten_points(&points)?).await)
+}
+
+/// Replaces the points of a resident set. Its attribute channels are kept if the number of points stays the
+/// same. Returns false if the set does not exist. When only some points move, `move_points` avoids sending
+/// and comparing the whole set, and `insert_points` and `remove_points` keep the ids of the other points.
+pub async fn update_point_set(point_set: u32, points: Vec<Vec2>) -> bool {
+    matches!(
+        send_command(ComputeCommand::UpdatePointSet(point_set, points)).await,
+        ComputeResponse::Done(true)
+    )
+}
+
+/// Moves the point at `indices[k]` of a resident set to `points[k]`, leaving the others and the attribute
+/// channels as they are. Only the moved points are written to the device, and an R-tree index inserts and
+/// removes just those. Returns false if the set does not exist, the lists differ in length or an index is
+/// out of range or was removed by `remove_points`.
+pub async fn move_points(point_set: u32, indices: Vec<u32>, points: Vec<Vec2>) -> bool {
+    matches!(
+        send_command(ComputeCommand::MovePoints(point_set, indices, points)).await,
+        ComputeResponse::Done(true)
+    )
+}
+
+/// Adds `points` to a resident set without changing the ids of the points already in it. Slots left by
+/// `remove_points` are filled first, lowest id first, then the set grows. Returns the ids of the new points
+/// in order, `None` if the set does not exist. Attribute channels are kept, with zero for the new points.
+pub async fn insert_points(point_set: u32, points: Vec<Vec2>) -> Option<Vec<u32>> {
+    match send_command(ComputeCommand::InsertPoints(point_set, points)).await {
+        ComputeResponse::Indices(indices) => indices,
+        _ => None,
+    }
+}
+
+/// Removes the points with the given ids from a resident set. The other points keep their ids, and the
+/// removed ones leave vacant slots that no query returns until `insert_points` fills them. Returns false if
+/// the set does not exist, or an id is out of range, repeated or already removed.
+pub async fn remove_points(point_set: u32, ids: Vec<u32>) -> bool {
+    matches!(
+        send_command(ComputeCommand::RemovePoints(point_set, ids)).await,
+        ComputeResponse::Done(true)
+    )
+}
+
+/// Frees the device memory of a resident set. Returns false if the set does not exist.
+pub async fn dispose_point_set(point_set: u32) -> bool {
+    matches!(
+        send_command(ComputeCommand::DisposePointSet(point_set)).await,
+        ComputeResponse::Done(true)
+    )
+}
+
+/// Builds (or drops, with `SpatialIndexKind::None`) the spatial index of a resident set.
+/// The index is rebuilt or refitted automatically when the points are updated. On adapters without compute
+/// shader support `Grid` and `Bvh` fall back to an `RTree`, see the `kind` of the returned stats.
+pub async fn set_point_set_index(point_set: u32, kind: SpatialIndexKind) -> Option<IndexStats> {
+    match send_command(ComputeCommand::SetPointSetIndex(point_set, kind)).await {
+        ComputeResponse::Index(stats) => stats,
+        _ => None,
+    }
+}
+
+/// Timing of the last build or refit of the index of a resident set, including the automatic ones done by
+/// `update_point_set`. `None` if the set does not exist or never had an index.
+pub async fn get_point_set_index_stats(point_set: u32) -> Option<IndexStats> {
+    match send_command(ComputeCommand::GetIndexStats(point_set)).await {
+        ComputeResponse::Index(stats) => stats,
+        _ => None,
+    }
+}
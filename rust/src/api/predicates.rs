@@ -0,0 +1,234 @@
+// Orientation and in-circle tests after Shewchuk's "Adaptive Precision Floating-Point Arithmetic and Fast
+// Robust Geometric Predicates": a floating point estimate, trusted when it clears a forward error bound,
+// otherwise an exact evaluation with expansion arithmetic. Only the sign of the results is exact.
+
+use super::simple::Vec2;
+
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENT_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+// Positive when `a`, `b`, `c` turn counter-clockwise (y up), negative when clockwise, zero when collinear.
+pub(crate) fn orient2d(a: &Vec2, b: &Vec2, c: &Vec2) -> f64 {
+    let [ax, ay, bx, by, cx, cy] = [a[0], a[1], b[0], b[1], c[0], c[1]].map(f64::from);
+    let left = (ax - cx) * (by - cy);
+    let right = (ay - cy) * (bx - cx);
+    let det = left - right;
+    if (left > 0.0 && right <= 0.0) || (left < 0.0 && right >= 0.0) || left == 0.0 {
+        return det;
+    }
+    if det.abs() >= ORIENT_BOUND * (left + right).abs() {
+        return det;
+    }
+    let acx = difference(ax, cx);
+    let bcy = difference(by, cy);
+    let acy = difference(ay, cy);
+    let bcx = difference(bx, cx);
+    estimate(&sum(&product(&acx, &bcy), &negate(&product(&acy, &bcx))))
+}
+
+// Positive when `d` lies inside the circle through `a`, `b`, `c` taken counter-clockwise (y up), negative
+// when outside, zero when the four points are cocircular.
+pub(crate) fn incircle(a: &Vec2, b: &Vec2, c: &Vec2, d: &Vec2) -> f64 {
+    let [ax, ay, bx, by, cx, cy, dx, dy] =
+        [a[0], a[1], b[0], b[1], c[0], c[1], d[0], d[1]].map(f64::from);
+    let (adx, ady, bdx, bdy, cdx, cdy) = (ax - dx, ay - dy, bx - dx, by - dy, cx - dx, cy - dy);
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    if det.abs() > INCIRCLE_BOUND * permanent {
+        return det;
+    }
+    let [adx, ady, bdx, bdy, cdx, cdy] = [
+        difference(ax, dx),
+        difference(ay, dy),
+        difference(bx, dx),
+        difference(by, dy),
+        difference(cx, dx),
+        difference(cy, dy),
+    ];
+    let lift = |x: &[f64], y: &[f64]| sum(&product(x, x), &product(y, y));
+    let cross = |x0: &[f64], y0: &[f64], x1: &[f64], y1: &[f64]| {
+        sum(&product(x0, y1), &negate(&product(x1, y0)))
+    };
+    let a = product(&lift(&adx, &ady), &cross(&bdx, &bdy, &cdx, &cdy));
+    let b = product(&lift(&bdx, &bdy), &cross(&cdx, &cdy, &adx, &ady));
+    let c = product(&lift(&cdx, &cdy), &cross(&adx, &ady, &bdx, &bdy));
+    estimate(&sum(&sum(&a, &b), &c))
+}
+
+// Expansions are exact sums of non-overlapping doubles in increasing magnitude, without zeros except for
+// a lone zero standing for an exact zero.
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &component in e {
+        let (sum, error) = two_sum(q, component);
+        if error != 0.0 {
+            h.push(error);
+        }
+        q = sum;
+    }
+    if q != 0.0 || h.is_empty() {
+        h.push(q);
+    }
+    h
+}
+
+fn difference(a: f64, b: f64) -> Vec<f64> {
+    grow(&[a], -b)
+}
+
+fn sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter()
+        .fold(e.to_vec(), |h, &component| grow(&h, component))
+}
+
+fn negate(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|component| -component).collect()
+}
+
+fn product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut h = vec![0.0];
+    for &a in e {
+        for &b in f {
+            let (x, error) = two_product(a, b);
+            h = grow(&grow(&h, error), x);
+        }
+    }
+    h
+}
+
+// The largest component carries the sign of the whole expansion.
+fn estimate(e: &[f64]) -> f64 {
+    e.last().copied().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Xorshift, so failures reproduce.
+    struct Random(u32);
+
+    impl Random {
+        fn next(&mut self) -> u32 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 17;
+            self.0 ^= self.0 << 5;
+            self.0
+        }
+
+        // At most 18 significant bits, so small integer multiples are still exact as `f32`, scaled by
+        // 2^-40 to 2^20 so that differences between coordinates round in `f64`.
+        fn coordinate(&mut self) -> f32 {
+            let mantissa = (self.next() >> 14) | 1;
+            let exponent = (self.next() % 61) as i32 - 58;
+            mantissa as f32 * 2f32.powi(exponent)
+        }
+    }
+
+    fn nudge(v: f32, steps: i32) -> f32 {
+        f32::from_bits((v.to_bits() as i32 + steps) as u32)
+    }
+
+    #[test]
+    fn orient2d_signs() {
+        assert!(orient2d(&[0.0, 0.0], &[1.0, 0.0], &[0.0, 1.0]) > 0.0);
+        assert!(orient2d(&[0.0, 0.0], &[0.0, 1.0], &[1.0, 0.0]) < 0.0);
+        assert_eq!(orient2d(&[0.0, 0.0], &[1.0, 1.0], &[2.0, 2.0]), 0.0);
+    }
+
+    // Points on a line through the origin with slope `p / q`, far apart in magnitude, then the last one
+    // moved off the line by an ulp. Raising `c` turns counter-clockwise exactly when `b` is right of `a`.
+    #[test]
+    fn orient2d_near_collinear() {
+        let mut random = Random(0x9e37_79b9);
+        for _ in 0..10_000 {
+            let (p, q) = (
+                (random.next() % 15 + 1) as f32,
+                (random.next() % 15 + 1) as f32,
+            );
+            let [a, b, c] = [0; 3].map(|_| {
+                let t = random.coordinate();
+                [q * t, p * t]
+            });
+            assert_eq!(orient2d(&a, &b, &c), 0.0, "{a:?} {b:?} {c:?}");
+            if a[0] == b[0] {
+                continue;
+            }
+            let raised = [c[0], nudge(c[1], 1)];
+            let lowered = [c[0], nudge(c[1], -1)];
+            let turn = (b[0] - a[0]).signum() as f64;
+            assert_eq!(
+                orient2d(&a, &b, &raised).signum(),
+                turn,
+                "{a:?} {b:?} {c:?}"
+            );
+            assert_eq!(
+                orient2d(&a, &b, &lowered).signum(),
+                -turn,
+                "{a:?} {b:?} {c:?}"
+            );
+            assert_eq!(orient2d(&a, &b, &raised), -orient2d(&b, &a, &raised));
+        }
+    }
+
+    #[test]
+    fn incircle_signs() {
+        let (a, b, c) = ([1.75, 1.25], [1.25, 1.75], [0.75, 1.25]);
+        assert!(incircle(&a, &b, &c, &[1.25, 1.25]) > 0.0);
+        assert!(incircle(&a, &b, &c, &[1.9, 1.9]) < 0.0);
+        assert_eq!(incircle(&a, &b, &c, &[1.25, 0.75]), 0.0);
+    }
+
+    // Corners of isosceles trapezoids symmetric about the y axis lie on a circle centred on it, so moving
+    // the last corner away from the axis takes it outside and towards the axis inside.
+    #[test]
+    fn incircle_near_cocircular() {
+        let mut random = Random(0x2545_f491);
+        for _ in 0..10_000 {
+            let [u, v, y0, y1] = [0; 4].map(|_| random.coordinate());
+            let (y0, y1) = (y0.min(y1), y0.max(y1));
+            if y0 == y1 || v == 0.0 {
+                continue;
+            }
+            let (a, b, c, d) = ([-u, y0], [u, y0], [v, y1], [-v, y1]);
+            assert_eq!(incircle(&a, &b, &c, &d), 0.0, "{a:?} {b:?} {c:?} {d:?}");
+            let outside = [nudge(d[0], 1), d[1]];
+            let inside = [nudge(d[0], -1), d[1]];
+            assert!(
+                incircle(&a, &b, &c, &outside) < 0.0,
+                "{a:?} {b:?} {c:?} {d:?}"
+            );
+            assert!(
+                incircle(&a, &b, &c, &inside) > 0.0,
+                "{a:?} {b:?} {c:?} {d:?}"
+            );
+            assert_eq!(
+                incircle(&a, &b, &c, &inside),
+                -incircle(&b, &a, &c, &inside)
+            );
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, RwLock,
+    },
+    time::Duration,
+};
+
+use web_time::Instant;
+
+lazy_static::lazy_static! {
+    static ref METRICS: RwLock<VecDeque<ComputeMetrics>> = RwLock::new(VecDeque::new());
+    // Stages of the request being handled, filled in by the `gpu` helpers while profiling is on.
+    static ref STAGES: Mutex<Option<StageTimings>> = Mutex::new(None);
+}
+
+static PROFILING: AtomicBool = AtomicBool::new(false);
+
+const MAX_METRICS: usize = 256;
+
+/// Timings of a single request handled by the compute thread. Durations are in microseconds.
+#[derive(Clone, Debug)]
+pub struct ComputeMetrics {
+    pub request_id: u64,
+    pub kernel: String,
+    pub point_count: u64,
+    pub on_gpu: bool,
+    /// Creating and filling the device buffers.
+    pub upload_micros: f64,
+    /// Submission until the device is idle, as seen from the CPU.
+    pub dispatch_micros: f64,
+    /// Time spent inside the compute pass, from `TIMESTAMP_QUERY`. `None` when the adapter lacks the feature.
+    pub gpu_dispatch_micros: Option<f64>,
+    /// Mapping the staging buffers and copying the results out.
+    pub readback_micros: f64,
+    pub total_micros: f64,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct StageTimings {
+    pub upload: Duration,
+    pub dispatch: Duration,
+    pub gpu_dispatch: Option<Duration>,
+    pub readback: Duration,
+}
+
+impl StageTimings {
+    pub(crate) fn into_metrics(
+        self,
+        request_id: u64,
+        kernel: &str,
+        point_count: usize,
+        on_gpu: bool,
+        total: Duration,
+    ) -> ComputeMetrics {
+        ComputeMetrics {
+            request_id,
+            kernel: kernel.to_string(),
+            point_count: point_count as u64,
+            on_gpu,
+            upload_micros: micros(self.upload),
+            dispatch_micros: micros(self.dispatch),
+            gpu_dispatch_micros: self.gpu_dispatch.map(micros),
+            readback_micros: micros(self.readback),
+            total_micros: micros(total),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Stage {
+    Upload,
+    Dispatch,
+    Readback,
+}
+
+// Starts collecting the stages of a request. Requests are handled one at a time, so a single recorder
+// is enough.
+pub(crate) fn begin_stages() {
+    *STAGES.lock().unwrap() = is_enabled().then(StageTimings::default);
+}
+
+pub(crate) fn end_stages() -> StageTimings {
+    STAGES.lock().unwrap().take().unwrap_or_default()
+}
+
+pub(crate) fn is_recording() -> bool {
+    STAGES.lock().unwrap().is_some()
+}
+
+pub(crate) fn add_stage(stage: Stage, duration: Duration) {
+    if let Some(timings) = STAGES.lock().unwrap().as_mut() {
+        match stage {
+            Stage::Upload => timings.upload += duration,
+            Stage::Dispatch => timings.dispatch += duration,
+            Stage::Readback => timings.readback += duration,
+        }
+    }
+}
+
+pub(crate) fn time_stage<T>(stage: Stage, run: impl FnOnce() -> T) -> T {
+    if !is_recording() {
+        return run();
+    }
+    let start = Instant::now();
+    let result = run();
+    add_stage(stage, start.elapsed());
+    result
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000.0
+}
+
+pub(crate) fn is_enabled() -> bool {
+    PROFILING.load(Ordering::Relaxed)
+}
+
+pub(crate) fn record(metrics: ComputeMetrics) {
+    let mut buffer = METRICS.write().unwrap();
+    if buffer.len() == MAX_METRICS {
+        buffer.pop_front();
+    }
+    buffer.push_back(metrics);
+}
+
+// Measures the duration of a compute pass with a pair of timestamp queries.
+pub(crate) struct GpuTimer {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    read_buffer: wgpu::Buffer,
+    period: f32,
+}
+
+impl GpuTimer {
+    pub(crate) fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Option<GpuTimer> {
+        if !device.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+            return None;
+        }
+        let size = 2 * std::mem::size_of::<u64>() as wgpu::BufferAddress;
+        Some(GpuTimer {
+            query_set: device.create_query_set(&wgpu::QuerySetDescriptor {
+                label: Some("Dispatch Timestamps"),
+                ty: wgpu::QueryType::Timestamp,
+                count: 2,
+            }),
+            resolve_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Timestamp Resolve Buffer"),
+                size,
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            }),
+            read_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Timestamp Staging Buffer"),
+                size,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+            period: queue.get_timestamp_period(),
+        })
+    }
+
+    pub(crate) fn timestamp_writes(&self) -> wgpu::ComputePassTimestampWrites<'_> {
+        wgpu::ComputePassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(0),
+            end_of_pass_write_index: Some(1),
+        }
+    }
+
+    // Must be recorded after the timed pass, in the same encoder.
+    pub(crate) fn resolve(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.resolve_query_set(&self.query_set, 0..2, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &self.read_buffer,
+            0,
+            self.resolve_buffer.size(),
+        );
+    }
+
+    // Must be called after the encoder passed to `resolve` has been submitted.
+    pub(crate) async fn read(&self, device: &wgpu::Device) -> Option<Duration> {
+        let slice = self.read_buffer.slice(..);
+        let (sender, receiver) = flume::bounded(1);
+        slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+        device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+
+        if let Ok(Ok(())) = receiver.recv_async().await {
+            let data = slice.get_mapped_range();
+            let timestamps: Vec<u64> = bytemuck::cast_slice(&data).to_vec();
+            drop(data);
+            self.read_buffer.unmap();
+            let ticks = timestamps[1].saturating_sub(timestamps[0]);
+            Some(Duration::from_nanos(
+                (ticks as f64 * self.period as f64) as u64,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Turns per request timing on or off. Profiling adds a timestamp query and extra synchronisation, keep it to debug builds.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_profiling_enabled(enabled: bool) {
+    PROFILING.store(enabled, Ordering::Relaxed);
+    if !enabled {
+        METRICS.write().unwrap().clear();
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_profiling_enabled() -> bool {
+    is_enabled()
+}
+
+/// Returns the metrics recorded since the last call, oldest first. At most the last 256 requests are kept.
+#[flutter_rust_bridge::frb(sync)]
+pub fn take_compute_metrics() -> Vec<ComputeMetrics> {
+    METRICS.write().unwrap().drain(..).collect()
+}
@@ -0,0 +1,331 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::PointSet,
+    simple::{
+        flatten_points, send_command, ComputeCommand, ComputePoint, ComputeRect, ComputeResponse,
+        Vec2, WgpuCompute,
+    },
+    transform::AffineTransform,
+};
+
+const PROJECTION_SHADER: &str = include_str!("projection.wgsl");
+
+/// How world space points end up on the screen.
+#[derive(Clone, Copy)]
+pub struct Camera {
+    /// World to screen transform, for example the pan and zoom of the view.
+    pub view: AffineTransform,
+    /// Visible part of the screen, in the space `view` maps to.
+    pub viewport: ComputeRect,
+    /// Distance around the viewport within which points are still kept, usually the radius they are
+    /// drawn with, so they do not pop in and out at the edges.
+    pub margin: f32,
+}
+
+/// Visible points of a set, in index order.
+#[derive(Clone, Debug)]
+pub struct ScreenPoints {
+    /// Screen position of every visible point.
+    pub positions: Vec<ComputePoint>,
+    /// Index in the point set of every visible point.
+    pub indices: Vec<u32>,
+}
+
+/// `ScreenPoints` with the positions one after another as `x0, y0, x1, y1, ...`. Both lists reach Dart as
+/// typed data without wrapping every point, so `positions` goes straight to `Canvas.drawRawPoints`.
+#[derive(Clone, Debug)]
+pub struct FlatScreenPoints {
+    pub positions: Vec<f32>,
+    pub indices: Vec<u32>,
+}
+
+// Uniforms of `projection.wgsl`, also the culling of the CPU path.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub(crate) struct CameraParams {
+    view: AffineTransform,
+    min: Vec2,
+    max: Vec2,
+    point_count: u32,
+    _padding: u32,
+}
+
+impl CameraParams {
+    // `None` when the camera is not finite or the margin is negative.
+    pub(crate) fn new(camera: &Camera) -> Option<CameraParams> {
+        let values = [camera.viewport.min, camera.viewport.max, [camera.margin; 2]];
+        if !camera.view.is_finite()
+            || !values.iter().flatten().all(|value| value.is_finite())
+            || camera.margin < 0.0
+        {
+            return None;
+        }
+        Some(CameraParams {
+            view: camera.view,
+            min: camera.viewport.min.map(|value| value - camera.margin),
+            max: camera.viewport.max.map(|value| value + camera.margin),
+            point_count: 0,
+            _padding: 0,
+        })
+    }
+
+    // Same as `onScreen` in `projection.wgsl`.
+    fn on_screen(&self, point: &Vec2) -> bool {
+        point[0] >= self.min[0]
+            && point[1] >= self.min[1]
+            && point[0] <= self.max[0]
+            && point[1] <= self.max[1]
+    }
+}
+
+pub(crate) fn project_cpu(points: &[Vec2], params: &CameraParams) -> ScreenPoints {
+    let mut screen = ScreenPoints {
+        positions: Vec::new(),
+        indices: Vec::new(),
+    };
+    for (i, point) in points.iter().enumerate() {
+        let position = params.view.apply(point);
+        if params.on_screen(&position) {
+            screen.positions.push(position.into());
+            screen.indices.push(i as u32);
+        }
+    }
+    screen
+}
+
+pub(crate) async fn project_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    params: &CameraParams,
+) -> ScreenPoints {
+    let device = &gpu.device;
+    let point_count = set.points.len() as u32;
+    if point_count == 0 {
+        return project_cpu(&[], params);
+    }
+    let params = CameraParams {
+        point_count,
+        ..*params
+    };
+    let params_buffer = gpu::uniform_buffer(device, "Camera Params Buffer", &params);
+    let visible_buffer = gpu::empty_storage_buffer(
+        device,
+        "Visible Flags Buffer",
+        point_count as u64 * 4,
+        wgpu::BufferUsages::empty(),
+    );
+    let slots_buffer = gpu::empty_storage_buffer(
+        device,
+        "Visible Slots Buffer",
+        (point_count as u64 + 1) * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let positions_buffer = gpu::empty_storage_buffer(
+        device,
+        "Screen Positions Buffer",
+        point_count as u64 * 8,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let indices_buffer = gpu::empty_storage_buffer(
+        device,
+        "Screen Indices Buffer",
+        point_count as u64 * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let mark_pipeline = gpu.pipeline(PROJECTION_SHADER, "mark_visible");
+    let compact_pipeline = gpu.pipeline(PROJECTION_SHADER, "compact_visible");
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    let mark_bind_group = gpu::bind_group(
+        device,
+        &mark_pipeline,
+        &[&set.buffer, &params_buffer, &visible_buffer],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &mark_pipeline,
+        &mark_bind_group,
+        point_count,
+    );
+    gpu::exclusive_scan(gpu, &mut encoder, &visible_buffer, &slots_buffer);
+    let compact_bind_group = gpu::bind_group(
+        device,
+        &compact_pipeline,
+        &[
+            &set.buffer,
+            &params_buffer,
+            &visible_buffer,
+            &slots_buffer,
+            &positions_buffer,
+            &indices_buffer,
+        ],
+    );
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &compact_pipeline,
+        &compact_bind_group,
+        point_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let count = gpu::read_buffer::<u32>(device, &gpu.queue, &slots_buffer, point_count as usize, 1)
+        .await[0] as usize;
+    ScreenPoints {
+        positions: gpu::read_buffer(device, &gpu.queue, &positions_buffer, 0, count).await,
+        indices: gpu::read_buffer(device, &gpu.queue, &indices_buffer, 0, count).await,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Maps the world space points of `point_set` through `camera` and keeps those inside its viewport, all on
+/// the device in a single request: the positions and indices to draw this frame. `None` if the point set
+/// does not exist, the camera is not finite or its margin is negative.
+pub async fn project_point_set(point_set: u32, camera: Camera) -> Option<ScreenPoints> {
+    match send_command(ComputeCommand::Project(point_set, camera)).await {
+        ComputeResponse::Screen(screen) => screen,
+        _ => None,
+    }
+}
+
+/// Same as `project_point_set`, with the positions flattened for `Canvas.drawRawPoints`.
+pub async fn project_point_set_flat(point_set: u32, camera: Camera) -> Option<FlatScreenPoints> {
+    project_point_set(point_set, camera)
+        .await
+        .map(|screen| FlatScreenPoints {
+            positions: flatten_points(bytemuck::cast_slice(&screen.positions)),
+            indices: screen.indices,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Xorshift points in `[0, 1000)`, about one in ten repeating an earlier one, so failures reproduce.
+    fn random_points(count: usize) -> Vec<Vec2> {
+        let mut state = 0x1b87_3593u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1 << 24) as f32 * 1000.0
+        };
+        let mut points: Vec<Vec2> = Vec::with_capacity(count);
+        while points.len() < count {
+            let point = [next(), next()];
+            if next() < 100.0 && !points.is_empty() {
+                points.push(points[points.len() / 2]);
+            } else {
+                points.push(point);
+            }
+        }
+        points
+    }
+
+    fn camera(view: AffineTransform, margin: f32) -> Camera {
+        Camera {
+            view,
+            viewport: ComputeRect {
+                min: [0.0, 0.0],
+                max: [800.0, 600.0],
+            },
+            margin,
+        }
+    }
+
+    // Transforms every point and keeps those within `margin` of the viewport.
+    fn brute_force(points: &[Vec2], camera: &Camera) -> Vec<(u32, Vec2)> {
+        let bounds = ComputeRect {
+            min: camera.viewport.min.map(|value| value - camera.margin),
+            max: camera.viewport.max.map(|value| value + camera.margin),
+        };
+        points
+            .iter()
+            .map(|point| camera.view.apply(point))
+            .enumerate()
+            .filter(|(_, position)| crate::api::cpu::is_point_inside_rect(position, &bounds))
+            .map(|(i, position)| (i as u32, position))
+            .collect()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut points = random_points(2000);
+        points[3] = [f32::NAN, 100.0];
+        points[4] = [100.0, f32::INFINITY];
+        let views = [
+            AffineTransform::identity(),
+            AffineTransform::translation(-300.0, 150.0),
+            AffineTransform::scaling(2.5, 2.5).then(AffineTransform::translation(-900.0, -700.0)),
+            AffineTransform::rotation(0.4).then(AffineTransform::translation(200.0, -100.0)),
+        ];
+        for (v, view) in views.iter().enumerate() {
+            for margin in [0.0, 12.0] {
+                let camera = camera(*view, margin);
+                let screen = project_cpu(&points, &CameraParams::new(&camera).unwrap());
+                let expected = brute_force(&points, &camera);
+                assert!(!expected.is_empty() && expected.len() < points.len());
+                let indices: Vec<u32> = expected.iter().map(|&(i, _)| i).collect();
+                let positions: Vec<Vec2> = screen.positions.iter().map(|&p| p.into()).collect();
+                assert_eq!(screen.indices, indices, "view {v}, margin {margin}");
+                assert_eq!(
+                    positions,
+                    expected.iter().map(|&(_, p)| p).collect::<Vec<_>>()
+                );
+                assert!(!screen.indices.contains(&3) && !screen.indices.contains(&4));
+            }
+        }
+    }
+
+    #[test]
+    fn edges_margins_and_duplicates() {
+        let points = [
+            [0.0, 0.0],
+            [800.0, 600.0],
+            [-5.0, 300.0],
+            [805.0, 300.0],
+            [-5.1, 300.0],
+            [400.0, 300.0],
+            [400.0, 300.0],
+        ];
+        let project = |margin| {
+            project_cpu(
+                &points,
+                &CameraParams::new(&camera(AffineTransform::identity(), margin)).unwrap(),
+            )
+            .indices
+        };
+        // The viewport edges are inside, the margin widens it on every side.
+        assert_eq!(project(0.0), [0, 1, 5, 6]);
+        assert_eq!(project(5.0), [0, 1, 2, 3, 5, 6]);
+
+        let empty = project_cpu(
+            &[],
+            &CameraParams::new(&camera(AffineTransform::identity(), 1.0)).unwrap(),
+        );
+        assert!(empty.positions.is_empty() && empty.indices.is_empty());
+    }
+
+    #[test]
+    fn unusable_cameras() {
+        let identity = AffineTransform::identity();
+        assert!(CameraParams::new(&camera(identity, -1.0)).is_none());
+        assert!(CameraParams::new(&camera(identity, f32::NAN)).is_none());
+        assert!(CameraParams::new(&camera(identity, f32::INFINITY)).is_none());
+        assert!(CameraParams::new(&camera(AffineTransform::scaling(f32::NAN, 1.0), 0.0)).is_none());
+        let mut unbounded = camera(identity, 0.0);
+        unbounded.viewport.max[0] = f32::INFINITY;
+        assert!(CameraParams::new(&unbounded).is_none());
+        // An inverted viewport shows nothing.
+        let mut inverted = camera(identity, 0.0);
+        inverted.viewport.min = [900.0, 700.0];
+        let screen = project_cpu(&random_points(100), &CameraParams::new(&inverted).unwrap());
+        assert!(screen.indices.is_empty());
+    }
+}
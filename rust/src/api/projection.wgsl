@@ -0,0 +1,58 @@
+// Projection of resident world space points to the screen. `mark_visible` maps every point through the
+// view transform and flags those inside the viewport, `scan.wgsl` turns the flags into output slots and
+// `compact_visible` writes the screen position and index of every visible point, in index order.
+struct Camera {
+    // View transform, see `transform.wgsl`. Spelled out so the uniform needs no 16 byte struct alignment.
+    xx: f32,
+    xy: f32,
+    yx: f32,
+    yy: f32,
+    tx: f32,
+    ty: f32,
+    // Viewport grown by the margin.
+    min: vec2<f32>,
+    max: vec2<f32>,
+    point_count: u32,
+};
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> camera: Camera;
+@group(0) @binding(2) var<storage, read_write> visible: array<u32>;
+@group(0) @binding(3) var<storage, read> slots: array<u32>;
+@group(0) @binding(4) var<storage, read_write> positions: array<vec2<f32>>;
+@group(0) @binding(5) var<storage, read_write> indices: array<u32>;
+
+fn toScreen(p: vec2<f32>) -> vec2<f32> {
+    return vec2<f32>(
+        camera.xx * p.x + camera.xy * p.y + camera.tx,
+        camera.yx * p.x + camera.yy * p.y + camera.ty,
+    );
+}
+
+// Also false for NaN.
+fn onScreen(p: vec2<f32>) -> bool {
+    return all(p >= camera.min) && all(p <= camera.max);
+}
+
+@compute
+@workgroup_size(64)
+fn mark_visible(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= camera.point_count) {
+        return;
+    }
+    visible[i] = u32(onScreen(toScreen(points[i])));
+}
+
+@compute
+@workgroup_size(64)
+fn compact_visible(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= camera.point_count) {
+        return;
+    }
+    if (visible[i] != 0u) {
+        positions[slots[i]] = toScreen(points[i]);
+        indices[slots[i]] = i;
+    }
+}
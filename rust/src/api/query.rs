@@ -0,0 +1,302 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    attributes::AttributeFilter,
+    cpu,
+    expression::Predicate,
+    gpu,
+    point_set::{PointSet, PointSetIndex},
+    simple::{send_command, ComputeCommand, ComputeRect, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const QUERY_SHADER: &str = include_str!("query.wgsl");
+
+const KIND_RECT: u32 = 0;
+const KIND_CIRCLE: u32 = 1;
+const KIND_POLYGON: u32 = 2;
+
+// Invocations splitting a BVH traversal between them, a power of two.
+const BVH_ROOTS: u32 = 1024;
+
+// Shapes understood by the query kernels. Every query reports matching point indices in ascending order.
+#[derive(Clone)]
+pub(crate) enum QueryShape {
+    Rect(ComputeRect),
+    Circle { center: Vec2, radius: f32 },
+    Polygon(Vec<Vec2>),
+}
+
+impl QueryShape {
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            QueryShape::Rect(_) => "query_rect",
+            QueryShape::Circle { .. } => "query_circle",
+            QueryShape::Polygon(_) => "query_polygon",
+        }
+    }
+
+    pub(crate) fn bounds(&self) -> ComputeRect {
+        match self {
+            QueryShape::Rect(rect) => *rect,
+            QueryShape::Circle { center, radius } => ComputeRect {
+                min: [center[0] - radius, center[1] - radius],
+                max: [center[0] + radius, center[1] + radius],
+            },
+            QueryShape::Polygon(vertices) => {
+                let mut bounds = ComputeRect {
+                    min: [f32::MAX; 2],
+                    max: [f32::MIN; 2],
+                };
+                for vertex in vertices {
+                    for (axis, value) in vertex.iter().enumerate() {
+                        bounds.min[axis] = bounds.min[axis].min(*value);
+                        bounds.max[axis] = bounds.max[axis].max(*value);
+                    }
+                }
+                bounds
+            }
+        }
+    }
+
+    pub(crate) fn predicate(&self) -> Predicate {
+        match self {
+            QueryShape::Rect(rect) => Predicate::InRect(*rect),
+            QueryShape::Circle { center, radius } => Predicate::InCircle {
+                center: *center,
+                radius: *radius,
+            },
+            QueryShape::Polygon(vertices) => {
+                Predicate::InPolygon(vertices.iter().map(|&vertex| vertex.into()).collect())
+            }
+        }
+    }
+
+    pub(crate) fn contains(&self, point: &Vec2) -> bool {
+        match self {
+            QueryShape::Rect(rect) => cpu::is_point_inside_rect(point, rect),
+            QueryShape::Circle { center, radius } => {
+                cpu::is_point_inside_circle(point, center, *radius)
+            }
+            QueryShape::Polygon(vertices) => {
+                cpu::is_point_inside_rect(point, &self.bounds())
+                    && cpu::is_point_inside_polygon(point, vertices)
+            }
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct QueryParams {
+    kind: u32,
+    vertex_count: u32,
+    radius_squared: f32,
+    item_count: u32,
+    min: Vec2,
+    max: Vec2,
+    center: Vec2,
+    cell_min: [u32; 2],
+    cell_span: [u32; 2],
+    grid_dims: [u32; 2],
+    _padding: [u32; 2],
+}
+
+pub(crate) fn query_cpu(set: &PointSet, shape: &QueryShape) -> Vec<u32> {
+    match &set.index {
+        PointSetIndex::RTree(tree) => tree.query(shape),
+        _ => cpu::points_in_shape(&set.points, shape),
+    }
+}
+
+pub(crate) async fn query_gpu(gpu: &WgpuCompute, set: &PointSet, shape: &QueryShape) -> Vec<u32> {
+    let device = &gpu.device;
+    let bounds = shape.bounds();
+    let (kind, center, radius, vertices) = match shape {
+        QueryShape::Rect(_) => (KIND_RECT, [0.0; 2], 0.0, &[][..]),
+        QueryShape::Circle { center, radius } => (KIND_CIRCLE, *center, *radius, &[][..]),
+        QueryShape::Polygon(vertices) => (KIND_POLYGON, [0.0; 2], 0.0, &vertices[..]),
+    };
+    if set.points.is_empty() || (kind == KIND_POLYGON && vertices.len() < 3) {
+        return Vec::new();
+    }
+
+    let mut params = QueryParams {
+        kind,
+        vertex_count: vertices.len() as u32,
+        radius_squared: radius * radius,
+        item_count: set.points.len() as u32,
+        min: bounds.min,
+        max: bounds.max,
+        center,
+        cell_min: [0; 2],
+        cell_span: [0; 2],
+        grid_dims: [0; 2],
+        _padding: [0; 2],
+    };
+    match &set.index {
+        PointSetIndex::None | PointSetIndex::RTree(_) => {}
+        PointSetIndex::Grid(grid) => {
+            let Some((cell_min, cell_span)) = grid.cell_range(&bounds) else {
+                return Vec::new();
+            };
+            params.cell_min = cell_min;
+            params.cell_span = cell_span;
+            params.grid_dims = grid.dims();
+            params.item_count = cell_span[0] * cell_span[1];
+        }
+        PointSetIndex::Bvh(_) => params.item_count = BVH_ROOTS,
+    }
+
+    let params_buffer = gpu::uniform_buffer(device, "Query Params Buffer", &params);
+    let vertices_buffer = gpu::storage_buffer(
+        device,
+        "Query Vertices Buffer",
+        vertices,
+        wgpu::BufferUsages::empty(),
+    );
+    let output_buffer = gpu::empty_storage_buffer(
+        device,
+        "Query Output Buffer",
+        set.points.len() as u64 * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let count_buffer = gpu::empty_storage_buffer(
+        device,
+        "Query Count Buffer",
+        4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+
+    let (pipeline, bind_group) = match &set.index {
+        PointSetIndex::Grid(grid) => {
+            let pipeline = gpu.pipeline(QUERY_SHADER, "query_grid");
+            let bind_group = gpu::bind_group_at(
+                device,
+                &pipeline,
+                &[
+                    (0, &set.buffer),
+                    (1, &params_buffer),
+                    (2, &vertices_buffer),
+                    (3, &output_buffer),
+                    (4, &count_buffer),
+                    (5, &grid.cell_start),
+                    (6, &grid.sorted_indices),
+                ],
+            );
+            (pipeline, bind_group)
+        }
+        PointSetIndex::Bvh(bvh) => {
+            let pipeline = gpu.pipeline(QUERY_SHADER, "query_bvh");
+            let bind_group = gpu::bind_group_at(
+                device,
+                &pipeline,
+                &[
+                    (0, &set.buffer),
+                    (1, &params_buffer),
+                    (2, &vertices_buffer),
+                    (3, &output_buffer),
+                    (4, &count_buffer),
+                    (6, &bvh.sorted_indices),
+                    (7, &bvh.nodes),
+                ],
+            );
+            (pipeline, bind_group)
+        }
+        PointSetIndex::None | PointSetIndex::RTree(_) => {
+            let pipeline = gpu.pipeline(QUERY_SHADER, "query_linear");
+            let bind_group = gpu::bind_group_at(
+                device,
+                &pipeline,
+                &[
+                    (0, &set.buffer),
+                    (1, &params_buffer),
+                    (2, &vertices_buffer),
+                    (3, &output_buffer),
+                    (4, &count_buffer),
+                ],
+            );
+            (pipeline, bind_group)
+        }
+    };
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &pipeline,
+        &bind_group,
+        params.item_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let count = gpu::read_buffer::<u32>(device, &gpu.queue, &count_buffer, 0, 1).await[0];
+    let mut indices =
+        gpu::read_buffer::<u32>(device, &gpu.queue, &output_buffer, 0, count as usize).await;
+    indices.sort_unstable();
+    indices
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Indices of the points of `point_set` inside `rect`, in ascending order. `None` if the point set does not exist.
+pub async fn query_rect(point_set: u32, rect: ComputeRect) -> Option<Vec<u32>> {
+    query(point_set, QueryShape::Rect(rect)).await
+}
+
+/// Indices of the points of `point_set` within `radius` of `center`, in ascending order. `None` if the
+/// point set does not exist or `radius` is negative or not finite.
+pub async fn query_circle(point_set: u32, center: Vec2, radius: f32) -> Option<Vec<u32>> {
+    query(point_set, QueryShape::Circle { center, radius }).await
+}
+
+/// Indices of the points of `point_set` inside `polygon` (even-odd rule), in ascending order.
+pub async fn query_polygon(point_set: u32, polygon: Vec<Vec2>) -> Option<Vec<u32>> {
+    query(point_set, QueryShape::Polygon(polygon)).await
+}
+
+/// `query_rect` keeping only the points that pass all of `filters`, the same as `query_predicate` with the
+/// rect and the filters under an `And`. `None` if the point set does not exist, a filter names a missing
+/// attribute, or a bitmask filter is used on an `F32` attribute.
+pub async fn query_rect_filtered(
+    point_set: u32,
+    rect: ComputeRect,
+    filters: Vec<AttributeFilter>,
+) -> Option<Vec<u32>> {
+    query_filtered(point_set, QueryShape::Rect(rect), filters).await
+}
+
+/// `query_circle` keeping only the points that pass all of `filters`, like `query_rect_filtered`. `None` as
+/// well for a negative or not finite `radius`.
+pub async fn query_circle_filtered(
+    point_set: u32,
+    center: Vec2,
+    radius: f32,
+    filters: Vec<AttributeFilter>,
+) -> Option<Vec<u32>> {
+    query_filtered(point_set, QueryShape::Circle { center, radius }, filters).await
+}
+
+/// `query_polygon` keeping only the points that pass all of `filters`, like `query_rect_filtered`.
+pub async fn query_polygon_filtered(
+    point_set: u32,
+    polygon: Vec<Vec2>,
+    filters: Vec<AttributeFilter>,
+) -> Option<Vec<u32>> {
+    query_filtered(point_set, QueryShape::Polygon(polygon), filters).await
+}
+
+async fn query(point_set: u32, shape: QueryShape) -> Option<Vec<u32>> {
+    query_filtered(point_set, shape, Vec::new()).await
+}
+
+async fn query_filtered(
+    point_set: u32,
+    shape: QueryShape,
+    filters: Vec<AttributeFilter>,
+) -> Option<Vec<u32>> {
+    match send_command(ComputeCommand::Query(point_set, shape, filters)).await {
+        ComputeResponse::Indices(indices) => indices,
+        _ => None,
+    }
+}
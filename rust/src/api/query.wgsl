@@ -0,0 +1,184 @@
+// Rect, circle and polygon queries over a resident point set, emitting matching point indices.
+// `query_linear` tests every point, `query_grid` only visits the cells overlapping the query bounds and
+// `query_bvh` walks the BVH from `bvh.wgsl`, skipping subtrees whose bounds miss the shape. Queries with
+// attribute filters run as compiled predicates instead, see `expression.wgsl`.
+struct Query {
+    kind: u32,
+    vertex_count: u32,
+    radius_squared: f32,
+    item_count: u32,
+    min: vec2<f32>,
+    max: vec2<f32>,
+    center: vec2<f32>,
+    cell_min: vec2<u32>,
+    cell_span: vec2<u32>,
+    grid_dims: vec2<u32>,
+};
+
+const KIND_RECT: u32 = 0u;
+const KIND_CIRCLE: u32 = 1u;
+const KIND_POLYGON: u32 = 2u;
+
+struct Node {
+    min: vec2<f32>,
+    max: vec2<f32>,
+    left: u32,
+    right: u32,
+    first: u32,
+    last: u32,
+};
+
+const LEAF: u32 = 0x80000000u;
+// Deeper than any tree `build_hierarchy` can produce from 32-bit codes plus 32-bit index tie breaks.
+const STACK_SIZE: u32 = 96u;
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> query: Query;
+@group(0) @binding(2) var<storage, read> vertices: array<vec2<f32>>;
+@group(0) @binding(3) var<storage, read_write> outputIndices: array<u32>;
+@group(0) @binding(4) var<storage, read_write> outputCount: atomic<u32>;
+@group(0) @binding(5) var<storage, read> cellStart: array<u32>;
+@group(0) @binding(6) var<storage, read> sortedIndices: array<u32>;
+@group(0) @binding(7) var<storage, read> nodes: array<Node>;
+
+// Even-odd rule, matching `cpu::is_point_inside_polygon`.
+fn isPointInsidePolygon(point: vec2<f32>) -> bool {
+    var inside = false;
+    var j = query.vertex_count - 1u;
+    for (var i = 0u; i < query.vertex_count; i++) {
+        let a = vertices[i];
+        let b = vertices[j];
+        if ((a.y > point.y) != (b.y > point.y)) {
+            let x = (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x;
+            if (point.x < x) {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    return inside;
+}
+
+fn matches(point: vec2<f32>) -> bool {
+    // `min` and `max` bound every kind of shape.
+    if (point.x < query.min.x || point.y < query.min.y || point.x > query.max.x || point.y > query.max.y) {
+        return false;
+    }
+    switch query.kind {
+        case KIND_CIRCLE: {
+            let d = point - query.center;
+            return dot(d, d) <= query.radius_squared;
+        }
+        case KIND_POLYGON: {
+            return isPointInsidePolygon(point);
+        }
+        default: {
+            return true;
+        }
+    }
+}
+
+fn emit(index: u32) {
+    outputIndices[atomicAdd(&outputCount, 1u)] = index;
+}
+
+@compute
+@workgroup_size(64)
+fn query_linear(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= query.item_count) {
+        return;
+    }
+    if (matches(points[i])) {
+        emit(i);
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn query_grid(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= query.item_count) {
+        return;
+    }
+    let cell = (query.cell_min.y + i / query.cell_span.x) * query.grid_dims.x + query.cell_min.x + i % query.cell_span.x;
+    for (var k = cellStart[cell]; k < cellStart[cell + 1u]; k++) {
+        let index = sortedIndices[k];
+        if (matches(points[index])) {
+            emit(index);
+        }
+    }
+}
+
+// Whether the shape can contain anything inside the box from `low` to `high`.
+fn overlaps(low: vec2<f32>, high: vec2<f32>) -> bool {
+    if (high.x < query.min.x || high.y < query.min.y || low.x > query.max.x || low.y > query.max.y) {
+        return false;
+    }
+    if (query.kind == KIND_CIRCLE) {
+        let d = query.center - clamp(query.center, low, high);
+        return dot(d, d) <= query.radius_squared;
+    }
+    return true;
+}
+
+fn visitLeaf(child: u32) {
+    let index = sortedIndices[child & ~LEAF];
+    if (matches(points[index])) {
+        emit(index);
+    }
+}
+
+// `item_count` is a power of two. Invocation `i` first descends from the root following the low bits of
+// `i`, one per level, then traverses the subtree it lands in, so the tree is split between invocations.
+@compute
+@workgroup_size(64)
+fn query_bvh(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= query.item_count) {
+        return;
+    }
+    // A single point has no internal nodes.
+    if (arrayLength(&sortedIndices) == 1u) {
+        if (i == 0u) {
+            visitLeaf(LEAF);
+        }
+        return;
+    }
+
+    var current = 0u;
+    let levels = countTrailingZeros(query.item_count);
+    for (var level = 0u; level < levels; level++) {
+        let node = nodes[current];
+        if (!overlaps(node.min, node.max)) {
+            return;
+        }
+        let child = select(node.left, node.right, ((i >> level) & 1u) == 1u);
+        if ((child & LEAF) != 0u) {
+            // Only the invocation with no bits left claims an early leaf.
+            if ((i >> (level + 1u)) == 0u) {
+                visitLeaf(child);
+            }
+            return;
+        }
+        current = child;
+    }
+
+    var stack: array<u32, STACK_SIZE>;
+    stack[0] = current;
+    var top = 1u;
+    while (top > 0u) {
+        top--;
+        let reference = stack[top];
+        if ((reference & LEAF) != 0u) {
+            visitLeaf(reference);
+            continue;
+        }
+        let node = nodes[reference];
+        if (overlaps(node.min, node.max)) {
+            stack[top] = node.right;
+            stack[top + 1u] = node.left;
+            top += 2u;
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    grid::GridIndex,
+    point_set::{PointSet, PointSetIndex},
+    query::QueryShape,
+    rtree::RTreeIndex,
+    simple::{send_command, ComputeCommand, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const RADIUS_GRAPH_SHADER: &str = include_str!("radius_graph.wgsl");
+
+/// Proximity graph of a point set in compressed sparse row form. The neighbours of point `i` are
+/// `neighbours[offsets[i]..offsets[i + 1]]`, in ascending order. A point is never its own neighbour,
+/// so every edge appears once in each direction.
+#[derive(Clone, Debug, Default)]
+pub struct ProximityGraph {
+    /// One entry per point plus a final one holding the edge count.
+    pub offsets: Vec<u32>,
+    pub neighbours: Vec<u32>,
+}
+
+// Why the GPU did not build a proximity graph.
+pub(crate) enum GraphOverflow {
+    // The edge list does not fit in a storage buffer binding, the CPU can still build it.
+    Binding,
+    // More edges than `u32` offsets can count, on any backend.
+    Offsets,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct GraphParams {
+    origin: Vec2,
+    inv_cell_size: Vec2,
+    dims: [u32; 2],
+    point_count: u32,
+    radius_squared: f32,
+}
+
+// `None` when there are more edges than `u32` offsets can count.
+pub(crate) fn radius_graph_cpu(
+    points: &[Vec2],
+    index: &PointSetIndex,
+    radius: f32,
+) -> Option<ProximityGraph> {
+    let built;
+    let tree = match index {
+        PointSetIndex::RTree(tree) => tree,
+        _ => {
+            built = RTreeIndex::build(points);
+            &built
+        }
+    };
+    let mut graph = ProximityGraph {
+        offsets: Vec::with_capacity(points.len() + 1),
+        neighbours: Vec::new(),
+    };
+    graph.offsets.push(0);
+    for (i, point) in points.iter().enumerate() {
+        let shape = QueryShape::Circle {
+            center: *point,
+            radius,
+        };
+        graph
+            .neighbours
+            .extend(tree.query(&shape).into_iter().filter(|&j| j != i as u32));
+        graph
+            .offsets
+            .push(u32::try_from(graph.neighbours.len()).ok()?);
+    }
+    Some(graph)
+}
+
+// A proximity graph left on the device for kernels building on it, with a CPU copy of the offsets.
+pub(crate) struct GraphBuffers {
+    pub(crate) row_offsets: Vec<u32>,
+    pub(crate) offsets: wgpu::Buffer,
+    pub(crate) neighbours: wgpu::Buffer,
+}
+
+impl GraphBuffers {
+    pub(crate) fn edge_count(&self) -> usize {
+        *self.row_offsets.last().unwrap() as usize
+    }
+}
+
+pub(crate) async fn radius_graph_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    radius: f32,
+) -> Result<ProximityGraph, GraphOverflow> {
+    if set.points.is_empty() {
+        return Ok(ProximityGraph {
+            offsets: vec![0],
+            neighbours: Vec::new(),
+        });
+    }
+    let graph = build_graph_buffers(gpu, set, radius).await?;
+    let edge_count = graph.edge_count();
+    Ok(ProximityGraph {
+        neighbours: gpu::read_buffer(&gpu.device, &gpu.queue, &graph.neighbours, 0, edge_count)
+            .await,
+        offsets: graph.row_offsets,
+    })
+}
+
+// Expects a non-empty point set.
+pub(crate) async fn build_graph_buffers(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    radius: f32,
+) -> Result<GraphBuffers, GraphOverflow> {
+    let device = &gpu.device;
+    let point_count = set.points.len() as u32;
+
+    // The slack keeps rounding in the grid's cell size from dropping pairs exactly `radius` apart.
+    let grid = GridIndex::build_with_cell_size(gpu, &set.points, &set.buffer, radius * 1.001);
+    let params = GraphParams {
+        origin: grid.origin(),
+        inv_cell_size: grid.inv_cell_size(),
+        dims: grid.dims(),
+        point_count,
+        radius_squared: radius * radius,
+    };
+    let params_buffer = gpu::uniform_buffer(device, "Graph Params Buffer", &params);
+    let row_sizes = gpu::empty_storage_buffer(
+        device,
+        "Graph Row Sizes Buffer",
+        point_count as u64 * 4,
+        wgpu::BufferUsages::empty(),
+    );
+    let offsets = gpu::empty_storage_buffer(
+        device,
+        "Graph Offsets Buffer",
+        (point_count as u64 + 1) * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+
+    let count_pipeline = gpu.pipeline(RADIUS_GRAPH_SHADER, "count_neighbours");
+    let count_bind_group = gpu::bind_group(
+        device,
+        &count_pipeline,
+        &[
+            &set.buffer,
+            &params_buffer,
+            &grid.cell_start,
+            &grid.sorted_indices,
+            &row_sizes,
+        ],
+    );
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &count_pipeline,
+        &count_bind_group,
+        point_count,
+    );
+    gpu::exclusive_scan(gpu, &mut encoder, &row_sizes, &offsets);
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let row_offsets =
+        gpu::read_buffer::<u32>(device, &gpu.queue, &offsets, 0, point_count as usize + 1).await;
+    // Every row holds fewer than `u32::MAX` edges, so a scan that wrapped around shows as an offset
+    // smaller than the one before it.
+    if row_offsets.windows(2).any(|pair| pair[1] < pair[0]) {
+        return Err(GraphOverflow::Offsets);
+    }
+    let edge_count = row_offsets[point_count as usize] as u64;
+    if edge_count * 4 > device.limits().max_storage_buffer_binding_size as u64 {
+        return Err(GraphOverflow::Binding);
+    }
+
+    let neighbours = gpu::empty_storage_buffer(
+        device,
+        "Graph Neighbours Buffer",
+        edge_count * 4,
+        wgpu::BufferUsages::COPY_SRC,
+    );
+    let fill_pipeline = gpu.pipeline(RADIUS_GRAPH_SHADER, "fill_neighbours");
+    let fill_bind_group = gpu::bind_group(
+        device,
+        &fill_pipeline,
+        &[
+            &set.buffer,
+            &params_buffer,
+            &grid.cell_start,
+            &grid.sorted_indices,
+            &offsets,
+            &neighbours,
+        ],
+    );
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &fill_pipeline,
+        &fill_bind_group,
+        point_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    Ok(GraphBuffers {
+        row_offsets,
+        offsets,
+        neighbours,
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Links every point of `point_set` to all other points within `radius` of it. `None` if the point set
+/// does not exist, `radius` is negative or not finite, or the graph has more than `u32::MAX` edges.
+pub async fn radius_graph(point_set: u32, radius: f32) -> Option<ProximityGraph> {
+    match send_command(ComputeCommand::RadiusGraph(point_set, radius)).await {
+        ComputeResponse::Graph(graph) => graph,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cpu;
+
+    // Xorshift points on a 20 x 20 lattice, so some coincide and many lie exactly `radius` apart.
+    fn lattice_points(count: usize) -> Vec<Vec2> {
+        let mut state = 0x2545_f491u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            ((state >> 8) % 20) as f32
+        };
+        (0..count).map(|_| [next(), next()]).collect()
+    }
+
+    fn brute_force(points: &[Vec2], radius: f32) -> ProximityGraph {
+        let mut graph = ProximityGraph {
+            offsets: vec![0],
+            neighbours: Vec::new(),
+        };
+        for (i, point) in points.iter().enumerate() {
+            graph.neighbours.extend(
+                (0..points.len() as u32)
+                    .filter(|&j| j != i as u32)
+                    .filter(|&j| cpu::is_point_inside_circle(&points[j as usize], point, radius)),
+            );
+            graph.offsets.push(graph.neighbours.len() as u32);
+        }
+        graph
+    }
+
+    fn assert_same(a: &ProximityGraph, b: &ProximityGraph) {
+        assert_eq!(a.offsets, b.offsets);
+        assert_eq!(a.neighbours, b.neighbours);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let points = lattice_points(300);
+        let tree = PointSetIndex::RTree(Box::new(RTreeIndex::build(&points)));
+        for radius in [0.0, 1.0, 2.5, 30.0] {
+            let expected = brute_force(&points, radius);
+            assert_same(
+                &radius_graph_cpu(&points, &PointSetIndex::None, radius).unwrap(),
+                &expected,
+            );
+            assert_same(
+                &radius_graph_cpu(&points, &tree, radius).unwrap(),
+                &expected,
+            );
+        }
+    }
+
+    #[test]
+    fn duplicates_and_non_finite_points() {
+        let points = [
+            [1.0, 1.0],
+            [f32::NAN, 1.0],
+            [1.0, 1.0],
+            [2.0, 1.0],
+            [f32::INFINITY, 0.0],
+        ];
+        let graph = radius_graph_cpu(&points, &PointSetIndex::None, 0.0).unwrap();
+        assert_eq!(graph.offsets, [0, 1, 1, 2, 2, 2]);
+        assert_eq!(graph.neighbours, [2, 0]);
+        let graph = radius_graph_cpu(&points, &PointSetIndex::None, 1.0).unwrap();
+        assert_eq!(graph.offsets, [0, 2, 2, 4, 6, 6]);
+        assert_eq!(graph.neighbours, [2, 3, 0, 3, 0, 2]);
+
+        let empty = radius_graph_cpu(&[], &PointSetIndex::None, 1.0).unwrap();
+        assert_eq!(empty.offsets, [0]);
+        assert!(empty.neighbours.is_empty());
+    }
+}
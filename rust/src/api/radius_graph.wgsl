@@ -0,0 +1,87 @@
+// Proximity graph of a resident point set in CSR form: point `i` is linked to every other point within
+// `radius`, its neighbours being `neighbours[offsets[i]..offsets[i + 1]]` in ascending order.
+// The grid from `grid.wgsl` has cells at least `radius` wide, so only the 3x3 cells around a point are
+// searched. `count_neighbours` sizes each row, `scan.wgsl` turns the counts into offsets and
+// `fill_neighbours` writes and sorts the rows.
+struct Graph {
+    origin: vec2<f32>,
+    inv_cell_size: vec2<f32>,
+    dims: vec2<u32>,
+    point_count: u32,
+    radius_squared: f32,
+};
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> graph: Graph;
+@group(0) @binding(2) var<storage, read> cellStart: array<u32>;
+@group(0) @binding(3) var<storage, read> sortedIndices: array<u32>;
+@group(0) @binding(4) var<storage, read_write> rowSizes: array<u32>;
+@group(0) @binding(5) var<storage, read_write> neighbours: array<u32>;
+
+fn cellOf(point: vec2<f32>) -> vec2<i32> {
+    return clamp(
+        vec2<i32>(floor((point - graph.origin) * graph.inv_cell_size)),
+        vec2<i32>(0, 0),
+        vec2<i32>(graph.dims) - vec2<i32>(1, 1),
+    );
+}
+
+fn isNeighbour(i: u32, j: u32) -> bool {
+    let d = points[j] - points[i];
+    return i != j && dot(d, d) <= graph.radius_squared;
+}
+
+@compute
+@workgroup_size(64)
+fn count_neighbours(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= graph.point_count) {
+        return;
+    }
+    let cell = cellOf(points[i]);
+    let low = max(cell - vec2<i32>(1, 1), vec2<i32>(0, 0));
+    let high = min(cell + vec2<i32>(1, 1), vec2<i32>(graph.dims) - vec2<i32>(1, 1));
+    var count = 0u;
+    for (var y = low.y; y <= high.y; y++) {
+        for (var x = low.x; x <= high.x; x++) {
+            let c = u32(y) * graph.dims.x + u32(x);
+            for (var k = cellStart[c]; k < cellStart[c + 1u]; k++) {
+                count += u32(isNeighbour(i, sortedIndices[k]));
+            }
+        }
+    }
+    rowSizes[i] = count;
+}
+
+// `rowSizes` holds the row offsets from here on.
+@compute
+@workgroup_size(64)
+fn fill_neighbours(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= graph.point_count) {
+        return;
+    }
+    let cell = cellOf(points[i]);
+    let low = max(cell - vec2<i32>(1, 1), vec2<i32>(0, 0));
+    let high = min(cell + vec2<i32>(1, 1), vec2<i32>(graph.dims) - vec2<i32>(1, 1));
+    let begin = rowSizes[i];
+    var end = begin;
+    for (var y = low.y; y <= high.y; y++) {
+        for (var x = low.x; x <= high.x; x++) {
+            let c = u32(y) * graph.dims.x + u32(x);
+            for (var k = cellStart[c]; k < cellStart[c + 1u]; k++) {
+                let j = sortedIndices[k];
+                if (isNeighbour(i, j)) {
+                    // Insertion sort, rows are short.
+                    var slot = end;
+                    while (slot > begin && neighbours[slot - 1u] > j) {
+                        neighbours[slot] = neighbours[slot - 1u];
+                        slot--;
+                    }
+                    neighbours[slot] = j;
+                    end++;
+                }
+            }
+        }
+    }
+}
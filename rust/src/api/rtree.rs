@@ -0,0 +1,152 @@
+use rstar::{primitives::GeomWithData, RTree, AABB};
+
+use super::{query::QueryShape, simple::Vec2};
+
+type Entry = GeomWithData<Vec2, u32>;
+
+// Updates touching more than this share of the points bulk load a fresh tree instead.
+const MAX_INCREMENTAL_SHARE: f32 = 0.25;
+
+// R-tree over a point set kept in CPU memory. Queries never touch the GPU, which suits the CPU
+// backend and sets too small to be worth a dispatch.
+pub(crate) struct RTreeIndex {
+    tree: RTree<Entry>,
+}
+
+impl RTreeIndex {
+    pub(crate) fn build(points: &[Vec2]) -> RTreeIndex {
+        RTreeIndex {
+            tree: RTree::bulk_load(entries(points)),
+        }
+    }
+
+    // Moves the tree from `old` to `new` by removing and inserting only the points that changed.
+    // Returns false, leaving the tree untouched, when that would touch too many points to beat a bulk load.
+    pub(crate) fn update(&mut self, old: &[Vec2], new: &[Vec2]) -> bool {
+        let changed = (0..old.len().max(new.len()))
+            .filter(|&i| old.get(i) != new.get(i))
+            .count();
+        if changed as f32 > new.len() as f32 * MAX_INCREMENTAL_SHARE {
+            return false;
+        }
+        for (i, (a, b)) in old.iter().zip(new).enumerate() {
+            if a != b {
+                self.remove(a, i);
+                self.insert(b, i);
+            }
+        }
+        for (i, point) in old.iter().enumerate().skip(new.len()) {
+            self.remove(point, i);
+        }
+        for (i, point) in new.iter().enumerate().skip(old.len()) {
+            self.insert(point, i);
+        }
+        true
+    }
+
+    // Moves the point at every `indices[k]` from `old[k]` to `new[k]`, in order. Same limit as `update`.
+    pub(crate) fn move_points(&mut self, indices: &[u32], old: &[Vec2], new: &[Vec2]) -> bool {
+        if indices.len() as f32 > self.tree.size() as f32 * MAX_INCREMENTAL_SHARE {
+            return false;
+        }
+        for ((&i, a), b) in indices.iter().zip(old).zip(new) {
+            self.remove(a, i as usize);
+            self.insert(b, i as usize);
+        }
+        true
+    }
+
+    fn insert(&mut self, point: &Vec2, i: usize) {
+        if is_finite(point) {
+            self.tree.insert(Entry::new(*point, i as u32));
+        }
+    }
+
+    fn remove(&mut self, point: &Vec2, i: usize) {
+        if is_finite(point) {
+            self.tree.remove(&Entry::new(*point, i as u32));
+        }
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        self.tree.size()
+    }
+
+    // Appends the `k` points nearest to `point` as (squared distance, index), plus any further points
+    // tied with the last of them so the caller can break ties by index.
+    pub(crate) fn nearest(&self, point: &Vec2, k: usize, candidates: &mut Vec<(f32, u32)>) {
+        for (entry, distance) in self.tree.nearest_neighbor_iter_with_distance_2(point) {
+            if candidates.len() >= k && candidates.last().is_some_and(|last| distance > last.0) {
+                break;
+            }
+            candidates.push((distance, entry.data));
+        }
+    }
+
+    // Same results as `cpu::points_in_shape`.
+    pub(crate) fn query(&self, shape: &QueryShape) -> Vec<u32> {
+        let bounds = shape.bounds();
+        let mut indices: Vec<u32> = self
+            .tree
+            .locate_in_envelope(&AABB::from_corners(bounds.min, bounds.max))
+            .filter(|entry| shape.contains(entry.geom()))
+            .map(|entry| entry.data)
+            .collect();
+        indices.sort_unstable();
+        indices
+    }
+}
+
+// Points that are not finite are left out, like `delaunay::unique` does: no query can match them and rstar
+// panics comparing NaN coordinates.
+fn entries(points: &[Vec2]) -> Vec<Entry> {
+    points
+        .iter()
+        .enumerate()
+        .filter(|(_, point)| is_finite(point))
+        .map(|(i, point)| Entry::new(*point, i as u32))
+        .collect()
+}
+
+fn is_finite(point: &Vec2) -> bool {
+    point.iter().all(|v| v.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::simple::ComputeRect;
+
+    fn everything(tree: &RTreeIndex) -> Vec<u32> {
+        tree.query(&QueryShape::Rect(ComputeRect {
+            min: [-100.0; 2],
+            max: [100.0; 2],
+        }))
+    }
+
+    // Removing a point moves it to NaN and inserting fills its slot again, so the other points keep
+    // their indices.
+    #[test]
+    fn vacant_slots_keep_the_other_indices() {
+        let points: Vec<Vec2> = (0..8).map(|i| [i as f32, 0.0]).collect();
+        let mut tree = RTreeIndex::build(&points);
+        assert!(tree.move_points(&[2, 5], &[points[2], points[5]], &[[f32::NAN; 2]; 2]));
+        assert_eq!(tree.size(), 6);
+        assert_eq!(everything(&tree), [0, 1, 3, 4, 6, 7]);
+
+        let mut candidates = Vec::new();
+        tree.nearest(&[5.75, 0.0], 1, &mut candidates);
+        assert_eq!(candidates, [(0.0625, 6)]);
+
+        assert!(tree.move_points(&[2], &[[f32::NAN; 2]], &[[9.0, 0.0]]));
+        assert_eq!(everything(&tree), [0, 1, 2, 3, 4, 6, 7]);
+
+        let mut grown = points.clone();
+        grown[2] = [9.0, 0.0];
+        grown[5] = [f32::NAN; 2];
+        let old = grown.clone();
+        grown.push([-1.0, 0.0]);
+        assert!(tree.update(&old, &grown));
+        assert_eq!(everything(&tree), [0, 1, 2, 3, 4, 6, 7, 8]);
+    }
+}
@@ -0,0 +1,44 @@
+// Exclusive prefix sum of `values` into `offsets`, with the total written to `offsets[n]`.
+// Runs as a single workgroup: each invocation sums a contiguous chunk, the chunk sums are scanned
+// in workgroup memory, then each invocation writes the offsets of its chunk.
+@group(0) @binding(0) var<storage, read> values: array<u32>;
+@group(0) @binding(1) var<storage, read_write> offsets: array<u32>;
+
+const THREADS: u32 = 256u;
+
+var<workgroup> partials: array<u32, 256>;
+
+@compute
+@workgroup_size(256)
+fn exclusive_scan(@builtin(local_invocation_index) t: u32) {
+    let n = arrayLength(&offsets) - 1u;
+    let chunk = (n + THREADS - 1u) / THREADS;
+    let begin = min(t * chunk, n);
+    let end = min(begin + chunk, n);
+
+    var sum = 0u;
+    for (var i = begin; i < end; i++) {
+        sum += values[i];
+    }
+    partials[t] = sum;
+    workgroupBarrier();
+
+    for (var offset = 1u; offset < THREADS; offset *= 2u) {
+        var previous = 0u;
+        if (t >= offset) {
+            previous = partials[t - offset];
+        }
+        workgroupBarrier();
+        partials[t] += previous;
+        workgroupBarrier();
+    }
+
+    var running = partials[t] - sum;
+    for (var i = begin; i < end; i++) {
+        offsets[i] = running;
+        running += values[i];
+    }
+    if (t == THREADS - 1u) {
+        offsets[n] = partials[t];
+    }
+}
@@ -1,20 +1,53 @@
 use core::panic;
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread;
 use std::{
     borrow::Cow,
     collections::HashMap,
     io::Cursor,
     num::NonZeroU64,
     sync::{
-        mpsc::{self, Receiver, Sender},
-        RwLock,
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, RwLock,
     },
-    thread,
 };
 
 use bytemuck::{Pod, Zeroable};
+#[cfg(not(target_arch = "wasm32"))]
 use tokio::runtime::Runtime;
+use tracing::Instrument;
+use web_time::Instant;
 use wgpu::util::DeviceExt;
 
+use super::{
+    adapter::{self, AdapterReport},
+    alpha_shape::{self, ShapePolygon},
+    attributes::{AttributeFilter, AttributeValues},
+    cpu,
+    dbscan::{self, Clustering},
+    delaunay::{self, DelaunayTriangulation},
+    dispatch::{
+        Dispatch, DispatchCalibration, DispatchScheduler, DispatchTarget, MAX_CPU_WORK,
+        MAX_OUTPUT_BYTES,
+    },
+    expression::{self, CompiledPredicate, Predicate},
+    heatmap::{self, DensityGrid, KernelShape},
+    histogram::{self, Histogram2d, HistogramParams},
+    hit_test, hull,
+    jump_flood::{self, VoronoiRaster},
+    kmeans::{self, KMeans},
+    knn::{self, Neighbours},
+    logging,
+    marker_cluster::MarkerCluster,
+    point_set::{IndexStats, PointSet, PointSets, SpatialIndexKind},
+    profiling::{self, GpuTimer, StageTimings},
+    projection::{self, Camera, CameraParams, ScreenPoints},
+    query::{self, QueryShape},
+    radius_graph::{self, GraphOverflow, ProximityGraph},
+    transform::{self, AffineTransform, PointTransform},
+    voronoi::{self, VoronoiDiagram},
+};
+
 lazy_static::lazy_static! {
     static ref COMPUTES: RwLock<HashMap<String, WgpuContext>> = {
         RwLock::new(HashMap::new())
@@ -25,6 +58,8 @@ const COMPUTE_KEY: &str = "0";
 
 static INITIALIZED: std::sync::Once = std::sync::Once::new();
 
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
 #[flutter_rust_bridge::frb(init)]
 pub async fn init_app() {
     // Default utilities - feel free to customize
@@ -33,14 +68,14 @@ pub async fn init_app() {
     #[cfg(not(target_arch = "wasm32"))]
     {
         INITIALIZED.call_once(|| {
-            env_logger::init();
+            logging::init();
         });
     }
     #[cfg(target_arch = "wasm32")]
     {
         INITIALIZED.call_once(|| {
             std::panic::set_hook(Box::new(console_error_panic_hook::hook));
-            console_log::init().expect("could not initialize logger");
+            logging::init();
         });
     }
     run_compute_thread().await
@@ -50,23 +85,15 @@ async fn run_compute_thread() {
     {
         let map = COMPUTES.write().unwrap();
         if map.contains_key(COMPUTE_KEY) {
-            println!("Compute thread already running");
+            tracing::warn!("Compute thread already running");
         }
     }
 
-    let (compute_request_tx, compute_request_rx): (
-        Sender<ComputeRequest>,
-        Receiver<ComputeRequest>,
-    ) = mpsc::channel();
-    let (compute_response_tx, compute_response_rx): (
-        Sender<ComputeResponse>,
-        Receiver<ComputeResponse>,
-    ) = mpsc::channel();
+    let (compute_request_tx, compute_request_rx) = flume::unbounded::<ComputeRequest>();
 
     let instance = WgpuCompute::new().await;
     let context = WgpuContext {
         request_tx: compute_request_tx,
-        response_rx: compute_response_rx,
     };
 
     {
@@ -74,64 +101,992 @@ async fn run_compute_thread() {
         map.insert(COMPUTE_KEY.to_string(), context);
     }
 
+    // Native platforms get a dedicated thread that blocks on the device.
+    #[cfg(not(target_arch = "wasm32"))]
     thread::spawn(move || {
         let rt = Runtime::new().unwrap();
-        loop {
-            if let Ok(request) = compute_request_rx.recv() {
-                let response = match &request.command {
-                    ComputeCommand::Compute(points, rect) => {
-                        rt.block_on(_compute(&instance, &points, &rect))
-                    }
-                    ComputeCommand::Dispose => ComputeResponse { points: Vec::new() },
-                };
-                match compute_response_tx.send(response) {
-                    Ok(result) => result,
-                    Err(e) => panic!("Compute thread lost. {}", e),
-                }
-
-                if let ComputeCommand::Dispose = &request.command {
-                    break;
-                }
+        let mut worker = rt.block_on(ComputeWorker::new(instance));
+        while let Ok(request) = compute_request_rx.recv() {
+            if rt.block_on(worker.handle(request)) {
+                break;
+            }
+        }
+    });
+
+    // The browser has no threads to spare and resolves buffer mapping on its own event loop,
+    // so the worker runs as a task on the page's single threaded executor instead.
+    #[cfg(target_arch = "wasm32")]
+    wasm_bindgen_futures::spawn_local(async move {
+        let mut worker = ComputeWorker::new(instance).await;
+        while let Ok(request) = compute_request_rx.recv_async().await {
+            if worker.handle(request).await {
+                break;
             }
         }
     });
 }
 
-async fn _compute(instance: &WgpuCompute, points: &[Vec2], rect: &ComputeRect) -> ComputeResponse {
-    ComputeResponse {
-        points: instance.execute(points, rect).await.unwrap(),
+// State owned by the compute thread. Requests are handled one at a time, in order.
+struct ComputeWorker {
+    instance: WgpuCompute,
+    scheduler: DispatchScheduler,
+    point_sets: PointSets,
+}
+
+impl ComputeWorker {
+    async fn new(instance: WgpuCompute) -> ComputeWorker {
+        let mut scheduler = DispatchScheduler::new(instance.supports_compute());
+        if instance.supports_compute() {
+            scheduler.calibrate(&instance).await;
+        } else {
+            tracing::warn!("Adapter has no compute shader support, running every query on the CPU");
+        }
+        ComputeWorker {
+            instance,
+            scheduler,
+            point_sets: PointSets::default(),
+        }
+    }
+
+    // Returns true once the worker should stop.
+    async fn handle(&mut self, request: ComputeRequest) -> bool {
+        // Instrumenting the future instead of entering the span keeps it from leaking into other tasks
+        // while the request waits on the device, which matters on the single threaded wasm executor.
+        let span = tracing::info_span!(
+            "compute_request",
+            request_id = request.id,
+            command = request.command.name()
+        );
+        self.respond(request).instrument(span).await
+    }
+
+    async fn respond(&mut self, request: ComputeRequest) -> bool {
+        tracing::debug!(
+            queue_micros = request.queued_at.elapsed().as_micros() as u64,
+            "dequeued"
+        );
+
+        let instance = &self.instance;
+        let dispose = matches!(request.command, ComputeCommand::Dispose);
+        let response = match request.command {
+            ComputeCommand::Compute(points, rect) => {
+                _compute(instance, &self.scheduler, request.id, &points, &rect).await
+            }
+            // Without compute shaders there is nothing to measure and every query stays on the CPU.
+            ComputeCommand::Calibrate if !instance.supports_compute() => {
+                ComputeResponse::Calibration(None)
+            }
+            ComputeCommand::Calibrate => {
+                ComputeResponse::Calibration(Some(self.scheduler.calibrate(instance).await))
+            }
+            ComputeCommand::DescribeAdapter => ComputeResponse::Adapter(Box::new(adapter::report(
+                &instance.adapter,
+                &instance.device,
+            ))),
+            ComputeCommand::CreatePointSet(points) => {
+                ComputeResponse::PointSet(self.point_sets.create(instance, points))
+            }
+            ComputeCommand::UpdatePointSet(id, points) => {
+                ComputeResponse::Done(self.point_sets.update(instance, id, points))
+            }
+            ComputeCommand::MovePoints(id, indices, points) => {
+                ComputeResponse::Done(self.point_sets.move_points(instance, id, &indices, &points))
+            }
+            ComputeCommand::InsertPoints(id, points) => {
+                ComputeResponse::Indices(self.point_sets.insert_points(instance, id, &points))
+            }
+            ComputeCommand::RemovePoints(id, indices) => {
+                ComputeResponse::Done(self.point_sets.remove_points(instance, id, &indices))
+            }
+            ComputeCommand::DisposePointSet(id) => {
+                ComputeResponse::Done(self.point_sets.dispose(id))
+            }
+            ComputeCommand::Transform(id, transform, into_new_set) => ComputeResponse::Transformed(
+                _transform(
+                    instance,
+                    &self.scheduler,
+                    &mut self.point_sets,
+                    request.id,
+                    id,
+                    &transform,
+                    into_new_set,
+                )
+                .await,
+            ),
+            ComputeCommand::SetAttribute(id, name, values) => {
+                ComputeResponse::Done(self.point_sets.set_attribute(instance, id, name, values))
+            }
+            ComputeCommand::RemoveAttribute(id, name) => {
+                ComputeResponse::Done(self.point_sets.remove_attribute(instance, id, &name))
+            }
+            ComputeCommand::SetPointSetIndex(id, kind) => {
+                ComputeResponse::Index(self.point_sets.set_index(instance, id, kind))
+            }
+            ComputeCommand::GetIndexStats(id) => {
+                ComputeResponse::Index(self.point_sets.index_stats(id))
+            }
+            ComputeCommand::Knn(id, queries, k) => ComputeResponse::Neighbours(
+                _knn(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    &queries,
+                    k,
+                )
+                .await,
+            ),
+            ComputeCommand::Query(id, shape, filters) => ComputeResponse::Indices(
+                _query(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    &shape,
+                    &filters,
+                )
+                .await,
+            ),
+            ComputeCommand::QueryPredicate(id, predicate) => ComputeResponse::Indices(
+                _query_predicate(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    &predicate,
+                )
+                .await,
+            ),
+            ComputeCommand::Project(id, camera) => ComputeResponse::Screen(
+                _project(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    &camera,
+                )
+                .await,
+            ),
+            ComputeCommand::HitTest(id, position, tolerance) => ComputeResponse::Hit(
+                _hit_test(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    &position,
+                    tolerance,
+                )
+                .await,
+            ),
+            ComputeCommand::RadiusGraph(id, radius) => ComputeResponse::Graph(
+                _radius_graph(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    radius,
+                )
+                .await,
+            ),
+            ComputeCommand::Dbscan(id, eps, min_points) => ComputeResponse::Clusters(
+                _dbscan(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    eps,
+                    min_points,
+                )
+                .await,
+            ),
+            ComputeCommand::KMeans(id, k, max_iterations, tolerance, seed) => {
+                ComputeResponse::KMeans(
+                    _kmeans(
+                        instance,
+                        &self.scheduler,
+                        &self.point_sets,
+                        request.id,
+                        id,
+                        k,
+                        max_iterations,
+                        tolerance,
+                        seed,
+                    )
+                    .await,
+                )
+            }
+            ComputeCommand::BuildMarkerClusters(id, radius, min_zoom, max_zoom) => {
+                ComputeResponse::Done(
+                    self.point_sets
+                        .build_markers(id, radius, min_zoom, max_zoom),
+                )
+            }
+            ComputeCommand::MarkerClusters(id, viewport, zoom) => ComputeResponse::MarkerClusters(
+                self.point_sets
+                    .get(id)
+                    .and_then(|set| set.markers.as_ref())
+                    .map(|markers| markers.query(&viewport, zoom)),
+            ),
+            ComputeCommand::ConvexHull(id, selection) => ComputeResponse::Indices(
+                _convex_hull(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    selection.as_ref(),
+                )
+                .await,
+            ),
+            ComputeCommand::AlphaShape(id, alpha, selection) => ComputeResponse::Polygons(
+                _alpha_shape(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    alpha,
+                    selection.as_ref(),
+                )
+                .await,
+            ),
+            ComputeCommand::Delaunay(id) => {
+                ComputeResponse::Triangulation(_delaunay(&self.point_sets, request.id, id))
+            }
+            ComputeCommand::Voronoi(id, bounds) => {
+                ComputeResponse::Voronoi(_voronoi(&self.point_sets, request.id, id, &bounds))
+            }
+            ComputeCommand::RasterizeVoronoi(id, bounds, width, height) => ComputeResponse::Raster(
+                _rasterize_voronoi(
+                    instance,
+                    &self.scheduler,
+                    &self.point_sets,
+                    request.id,
+                    id,
+                    &bounds,
+                    width,
+                    height,
+                )
+                .await,
+            ),
+            ComputeCommand::Density(id, viewport, width, height, bandwidth, shape, weights) => {
+                ComputeResponse::Density(
+                    _density(
+                        instance,
+                        &self.scheduler,
+                        &self.point_sets,
+                        request.id,
+                        id,
+                        &viewport,
+                        width,
+                        height,
+                        bandwidth,
+                        shape,
+                        weights.as_deref(),
+                    )
+                    .await,
+                )
+            }
+            ComputeCommand::Histogram(id, extent, bins_x, bins_y, values) => {
+                ComputeResponse::Histogram(
+                    _histogram(
+                        instance,
+                        &self.scheduler,
+                        &self.point_sets,
+                        request.id,
+                        id,
+                        &extent,
+                        bins_x,
+                        bins_y,
+                        values.as_deref(),
+                    )
+                    .await,
+                )
+            }
+            ComputeCommand::Dispose => ComputeResponse::Disposed,
+        };
+        tracing::debug!(
+            elapsed_micros = request.queued_at.elapsed().as_micros() as u64,
+            "responding"
+        );
+        match request.reply.send(response) {
+            Ok(result) => result,
+            Err(e) => panic!("Compute thread lost. {}", e),
+        }
+
+        dispose
+    }
+}
+
+async fn _compute(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    request_id: u64,
+    points: &[Vec2],
+    rect: &ComputeRect,
+) -> ComputeResponse {
+    let start = Instant::now();
+    let mut timings = profiling::is_enabled().then(StageTimings::default);
+    let target = scheduler.choose(points.len(), false);
+    tracing::debug!(
+        points = points.len(),
+        on_gpu = target == DispatchTarget::Gpu,
+        "rect query"
+    );
+    let result = match target {
+        DispatchTarget::Cpu => cpu::points_in_rect(points, rect),
+        DispatchTarget::Gpu => instance
+            .execute_profiled(points, rect, timings.as_mut())
+            .await
+            .unwrap(),
+    };
+    if let Some(timings) = timings {
+        profiling::record(timings.into_metrics(
+            request_id,
+            "rect",
+            points.len(),
+            target == DispatchTarget::Gpu,
+            start.elapsed(),
+        ));
+    }
+    ComputeResponse::Points(result)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn _transform(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &mut PointSets,
+    request_id: u64,
+    point_set: u32,
+    transform: &PointTransform,
+    into_new_set: bool,
+) -> Option<u32> {
+    let set = point_sets.get(point_set)?;
+    let (transforms, groups) = match transform {
+        PointTransform::All(transform) => (std::slice::from_ref(transform), None),
+        PointTransform::Groups(attribute, transforms) => (
+            transforms.as_slice(),
+            Some(set.attributes.u32_channel(attribute)?),
+        ),
+    };
+    if !transforms.iter().all(AffineTransform::is_finite) {
+        return None;
+    }
+    let kernel = if into_new_set {
+        "create_transformed_point_set"
+    } else {
+        "transform_point_set"
+    };
+    let dispatch = Dispatch::for_set(scheduler, request_id, kernel, set);
+    let (points, buffer) = if dispatch.on_gpu() {
+        let (points, buffer) =
+            transform::transform_gpu(instance, set, transforms, groups.map(|(offset, _)| offset))
+                .await;
+        (points, Some(buffer))
+    } else {
+        (
+            transform::transform_cpu(&set.points, transforms, groups.map(|(_, values)| values)),
+            None,
+        )
+    };
+    let id = if into_new_set {
+        point_sets.create_derived(instance, point_set, points, buffer)?
+    } else {
+        point_sets.replace(instance, point_set, points, buffer);
+        point_set
+    };
+    dispatch.finish();
+    Some(id)
+}
+
+async fn _query(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    shape: &QueryShape,
+    filters: &[AttributeFilter],
+) -> Option<Vec<u32>> {
+    if let QueryShape::Circle { radius, .. } = shape {
+        if !(radius.is_finite() && *radius >= 0.0) {
+            return None;
+        }
+    }
+    let set = point_sets.get(point_set)?;
+    if filters.is_empty() {
+        let dispatch = Dispatch::for_set(scheduler, request_id, shape.name(), set);
+        let result = if dispatch.on_gpu() {
+            query::query_gpu(instance, set, shape).await
+        } else {
+            query::query_cpu(set, shape)
+        };
+        dispatch.finish();
+        return Some(result);
+    }
+    // Attribute filters are only ever tested by compiled predicates. The CPU path still narrows the points
+    // down with the index first.
+    let predicate = Predicate::And(
+        std::iter::once(shape.predicate())
+            .chain(filters.iter().cloned().map(Predicate::Attribute))
+            .collect(),
+    );
+    let predicate = CompiledPredicate::new(&predicate, set.attributes.channels())?;
+    let dispatch = Dispatch::for_set(scheduler, request_id, shape.name(), set);
+    let result = if dispatch.on_gpu() {
+        expression::query_predicate_gpu(instance, set, &predicate).await
+    } else {
+        query::query_cpu(set, shape)
+            .into_iter()
+            .filter(|&i| predicate.holds(&set.points, set.attributes.channels(), i))
+            .collect()
+    };
+    dispatch.finish();
+    Some(result)
+}
+
+async fn _query_predicate(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    predicate: &Predicate,
+) -> Option<Vec<u32>> {
+    let set = point_sets.get(point_set)?;
+    let predicate = CompiledPredicate::new(predicate, set.attributes.channels())?;
+    let dispatch = Dispatch::for_set(scheduler, request_id, "query_predicate", set);
+    let result = if dispatch.on_gpu() {
+        expression::query_predicate_gpu(instance, set, &predicate).await
+    } else {
+        expression::query_predicate_cpu(&set.points, set.attributes.channels(), &predicate)
+    };
+    dispatch.finish();
+    Some(result)
+}
+
+async fn _project(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    camera: &Camera,
+) -> Option<ScreenPoints> {
+    let set = point_sets.get(point_set)?;
+    let params = CameraParams::new(camera)?;
+    let dispatch = Dispatch::for_set(scheduler, request_id, "project_point_set", set);
+    let screen = if dispatch.on_gpu() {
+        projection::project_gpu(instance, set, &params).await
+    } else {
+        projection::project_cpu(&set.points, &params)
+    };
+    dispatch.finish();
+    Some(screen)
+}
+
+async fn _knn(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    queries: &[Vec2],
+    k: u32,
+) -> Option<Neighbours> {
+    let set = point_sets.get(point_set)?;
+    if !queries.iter().flatten().all(|v| v.is_finite()) {
+        return None;
+    }
+    let mut dispatch = Dispatch::for_set(scheduler, request_id, "knn", set);
+    if k > knn::MAX_GPU_K {
+        dispatch.force_cpu();
+    }
+    let result = if dispatch.on_gpu() {
+        knn::knn_gpu(instance, set, queries, k).await
+    } else {
+        knn::knn_cpu(&set.points, &set.index, queries, k)
+    };
+    dispatch.finish();
+    Some(result)
+}
+
+async fn _hit_test(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    position: &Vec2,
+    tolerance: f32,
+) -> Option<u32> {
+    if !(tolerance.is_finite() && tolerance >= 0.0) {
+        return None;
+    }
+    let set = point_sets.get(point_set)?;
+    let dispatch = Dispatch::for_set(scheduler, request_id, "hit_test", set);
+    let result = if dispatch.on_gpu() {
+        hit_test::hit_test_gpu(instance, set, position, tolerance).await
+    } else {
+        hit_test::hit_test_cpu(set, position, tolerance)
+    };
+    dispatch.finish();
+    result
+}
+
+async fn _radius_graph(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    radius: f32,
+) -> Option<ProximityGraph> {
+    let set = point_sets.get(point_set)?;
+    if !(radius.is_finite() && radius >= 0.0) {
+        return None;
+    }
+    let mut dispatch = Dispatch::for_set(scheduler, request_id, "radius_graph", set);
+    let mut result = None;
+    if dispatch.on_gpu() {
+        match radius_graph::radius_graph_gpu(instance, set, radius).await {
+            Ok(graph) => result = Some(graph),
+            Err(GraphOverflow::Binding) => dispatch.fall_back("edge list too large for the GPU"),
+            Err(GraphOverflow::Offsets) => return None,
+        }
+    }
+    let result = match result {
+        Some(graph) => graph,
+        None => radius_graph::radius_graph_cpu(&set.points, &set.index, radius)?,
+    };
+    dispatch.finish();
+    Some(result)
+}
+
+async fn _dbscan(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    eps: f32,
+    min_points: u32,
+) -> Option<Clustering> {
+    let set = point_sets.get(point_set)?;
+    if !(eps.is_finite() && eps >= 0.0) {
+        return None;
+    }
+    let mut dispatch = Dispatch::for_set(scheduler, request_id, "dbscan", set);
+    let mut result = None;
+    if dispatch.on_gpu() {
+        match dbscan::dbscan_gpu(instance, set, eps, min_points).await {
+            Ok(clustering) => result = Some(clustering),
+            Err(GraphOverflow::Binding) => {
+                dispatch.fall_back("proximity graph too large for the GPU")
+            }
+            Err(GraphOverflow::Offsets) => return None,
+        }
+    }
+    let result = match result {
+        Some(clustering) => clustering,
+        None => dbscan::dbscan_cpu(&set.points, &set.index, eps, min_points)?,
+    };
+    dispatch.finish();
+    Some(result)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn _kmeans(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    k: u32,
+    max_iterations: u32,
+    tolerance: f32,
+    seed: u64,
+) -> Option<KMeans> {
+    let set = point_sets.get(point_set)?;
+    let dispatch = Dispatch::for_set(scheduler, request_id, "kmeans", set);
+    let result = if dispatch.on_gpu() {
+        kmeans::kmeans_gpu(instance, set, k, max_iterations, tolerance, seed).await
+    } else {
+        kmeans::kmeans_cpu(&set.points, k, max_iterations, tolerance, seed)
+    };
+    dispatch.finish();
+    Some(result)
+}
+
+async fn _convex_hull(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    selection: Option<&QueryShape>,
+) -> Option<Vec<u32>> {
+    let set = point_sets.get(point_set)?;
+    let dispatch = Dispatch::for_set(scheduler, request_id, "convex_hull", set);
+    // Selections are read back from the query anyway, so their hull is always finished on the CPU.
+    let result = match (selection, dispatch.target()) {
+        (None, DispatchTarget::Gpu) => hull::convex_hull_gpu(instance, set).await,
+        (selection, target) => hull::convex_hull_cpu(
+            &set.points,
+            &_select(instance, set, target, selection).await,
+        ),
+    };
+    dispatch.finish();
+    Some(result)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn _alpha_shape(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    alpha: f32,
+    selection: Option<&QueryShape>,
+) -> Option<Vec<ShapePolygon>> {
+    let set = point_sets.get(point_set)?;
+    let dispatch = Dispatch::for_set(scheduler, request_id, "alpha_shape", set);
+    let candidates = _select(instance, set, dispatch.target(), selection).await;
+    let result = alpha_shape::alpha_shape_cpu(&set.points, &candidates, alpha);
+    dispatch.finish();
+    Some(result)
+}
+
+fn _delaunay(
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+) -> Option<DelaunayTriangulation> {
+    let set = point_sets.get(point_set)?;
+    let dispatch = Dispatch::new(
+        request_id,
+        "delaunay",
+        set.points.len(),
+        DispatchTarget::Cpu,
+    );
+    let all: Vec<u32> = (0..set.points.len() as u32).collect();
+    let triangulation = delaunay::triangulate(&set.points, &all);
+    dispatch.finish();
+    Some(DelaunayTriangulation {
+        triangles: triangulation.triangles,
+        hull: triangulation.hull,
+    })
+}
+
+fn _voronoi(
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    bounds: &ComputeRect,
+) -> Option<VoronoiDiagram> {
+    let set = point_sets.get(point_set)?;
+    let dispatch = Dispatch::new(request_id, "voronoi", set.points.len(), DispatchTarget::Cpu);
+    let diagram = voronoi::voronoi_cpu(&set.points, bounds);
+    dispatch.finish();
+    Some(diagram)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn _rasterize_voronoi(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    bounds: &ComputeRect,
+    width: u32,
+    height: u32,
+) -> Option<VoronoiRaster> {
+    let set = point_sets.get(point_set)?;
+    // Labels and distances, 4 bytes each per pixel.
+    let pixel_count = width as u64 * height as u64;
+    if pixel_count == 0 || pixel_count * 8 > MAX_OUTPUT_BYTES {
+        return None;
+    }
+    let mut dispatch = Dispatch::for_output(
+        scheduler,
+        request_id,
+        "rasterize_voronoi",
+        set,
+        pixel_count + set.points.len() as u64,
+    );
+    let mut raster = None;
+    if dispatch.on_gpu() {
+        raster = jump_flood::rasterize_gpu(instance, set, bounds, width, height).await;
+        if raster.is_none() {
+            dispatch.fall_back(&format!(
+                "{width}x{height} voronoi raster too large for the GPU"
+            ));
+        }
+    }
+    let raster = raster.unwrap_or_else(|| jump_flood::rasterize_cpu(set, bounds, width, height));
+    dispatch.finish();
+    Some(raster)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn _density(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    viewport: &ComputeRect,
+    width: u32,
+    height: u32,
+    bandwidth: f32,
+    shape: KernelShape,
+    weights: Option<&[f32]>,
+) -> Option<DensityGrid> {
+    let set = point_sets.get(point_set)?;
+    if weights.is_some_and(|weights| {
+        weights.len() != set.points.len() || !weights.iter().all(|w| w.is_finite())
+    }) {
+        return None;
+    }
+    let layout = heatmap::Layout::new(viewport, width, height, bandwidth, shape)?;
+    if layout.size() > MAX_OUTPUT_BYTES {
+        return None;
+    }
+    let mut dispatch = Dispatch::for_output(
+        scheduler,
+        request_id,
+        "density",
+        set,
+        layout.taps() + set.points.len() as u64,
+    );
+    let mut grid = None;
+    if dispatch.on_gpu() {
+        grid = heatmap::density_gpu(instance, set, weights, &layout).await;
+        if grid.is_none() && layout.taps() <= MAX_CPU_WORK {
+            dispatch.fall_back(&format!(
+                "{width}x{height} density grid too large for the GPU"
+            ));
+        }
+    }
+    if grid.is_none() && layout.taps() > MAX_CPU_WORK {
+        tracing::warn!("{width}x{height} density grid too much work for the CPU");
+        dispatch.finish();
+        return None;
+    }
+    let grid = grid.unwrap_or_else(|| heatmap::density_cpu(&set.points, weights, &layout));
+    dispatch.finish();
+    Some(grid)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn _histogram(
+    instance: &WgpuCompute,
+    scheduler: &DispatchScheduler,
+    point_sets: &PointSets,
+    request_id: u64,
+    point_set: u32,
+    extent: &ComputeRect,
+    bins_x: u32,
+    bins_y: u32,
+    values: Option<&[f32]>,
+) -> Option<Histogram2d> {
+    let set = point_sets.get(point_set)?;
+    if values.is_some_and(|values| {
+        values.len() != set.points.len() || !values.iter().all(|v| v.is_finite())
+    }) {
+        return None;
+    }
+    let params = HistogramParams::new(extent, bins_x, bins_y)?;
+    // Counts, and sums and means with values, 4 bytes each per bin.
+    let channels = if values.is_some() { 3 } else { 1 };
+    if bins_x as u64 * bins_y as u64 * channels * 4 > MAX_OUTPUT_BYTES {
+        return None;
+    }
+    let mut dispatch = Dispatch::for_set(scheduler, request_id, "histogram_2d", set);
+    let mut histogram = None;
+    if dispatch.on_gpu() {
+        histogram = histogram::histogram_gpu(instance, set, values, &params).await;
+        if histogram.is_none() {
+            dispatch.fall_back(&format!(
+                "{bins_x}x{bins_y} histogram too large for the GPU"
+            ));
+        }
+    }
+    let histogram =
+        histogram.unwrap_or_else(|| histogram::histogram_cpu(&set.points, values, &params));
+    dispatch.finish();
+    Some(histogram)
+}
+
+// Indices of the points of `set` inside `selection`, or all of them without one.
+async fn _select(
+    instance: &WgpuCompute,
+    set: &PointSet,
+    target: DispatchTarget,
+    selection: Option<&QueryShape>,
+) -> Vec<u32> {
+    match (selection, target) {
+        (None, _) => (0..set.points.len() as u32).collect(),
+        (Some(shape), DispatchTarget::Gpu) => query::query_gpu(instance, set, shape).await,
+        (Some(shape), DispatchTarget::Cpu) => query::query_cpu(set, shape),
     }
 }
 
-struct ComputeRequest {
+pub(crate) struct ComputeRequest {
+    id: u64,
+    queued_at: Instant,
     context: Option<WgpuContext>,
     command: ComputeCommand,
+    reply: flume::Sender<ComputeResponse>,
 }
 
-struct ComputeResponse {
-    pub points: Vec<Vec2>,
+pub(crate) enum ComputeResponse {
+    Points(Vec<Vec2>),
+    Calibration(Option<DispatchCalibration>),
+    Adapter(Box<AdapterReport>),
+    PointSet(u32),
+    Transformed(Option<u32>),
+    Screen(Option<ScreenPoints>),
+    Index(Option<IndexStats>),
+    Indices(Option<Vec<u32>>),
+    Neighbours(Option<Neighbours>),
+    Hit(Option<u32>),
+    Graph(Option<ProximityGraph>),
+    Clusters(Option<Clustering>),
+    KMeans(Option<KMeans>),
+    MarkerClusters(Option<Vec<MarkerCluster>>),
+    Polygons(Option<Vec<ShapePolygon>>),
+    Triangulation(Option<DelaunayTriangulation>),
+    Voronoi(Option<VoronoiDiagram>),
+    Raster(Option<VoronoiRaster>),
+    Density(Option<DensityGrid>),
+    Histogram(Option<Histogram2d>),
+    Done(bool),
+    Disposed,
 }
 
 #[flutter_rust_bridge::frb(ignore)]
 pub struct WgpuContext {
-    request_tx: Sender<ComputeRequest>,
-    response_rx: Receiver<ComputeResponse>,
+    request_tx: flume::Sender<ComputeRequest>,
 }
 
 unsafe impl Send for WgpuContext {}
 unsafe impl Sync for WgpuContext {}
 
-enum ComputeCommand {
+pub(crate) enum ComputeCommand {
     Compute(Vec<Vec2>, ComputeRect),
+    Calibrate,
+    DescribeAdapter,
+    CreatePointSet(Vec<Vec2>),
+    UpdatePointSet(u32, Vec<Vec2>),
+    MovePoints(u32, Vec<u32>, Vec<Vec2>),
+    InsertPoints(u32, Vec<Vec2>),
+    RemovePoints(u32, Vec<u32>),
+    DisposePointSet(u32),
+    SetAttribute(u32, String, AttributeValues),
+    RemoveAttribute(u32, String),
+    Transform(u32, PointTransform, bool),
+    SetPointSetIndex(u32, SpatialIndexKind),
+    GetIndexStats(u32),
+    Knn(u32, Vec<Vec2>, u32),
+    HitTest(u32, Vec2, f32),
+    RadiusGraph(u32, f32),
+    Dbscan(u32, f32, u32),
+    KMeans(u32, u32, u32, f32, u64),
+    BuildMarkerClusters(u32, f32, u32, u32),
+    ConvexHull(u32, Option<QueryShape>),
+    AlphaShape(u32, f32, Option<QueryShape>),
+    Delaunay(u32),
+    Voronoi(u32, ComputeRect),
+    RasterizeVoronoi(u32, ComputeRect, u32, u32),
+    Density(
+        u32,
+        ComputeRect,
+        u32,
+        u32,
+        f32,
+        KernelShape,
+        Option<Vec<f32>>,
+    ),
+    Histogram(u32, ComputeRect, u32, u32, Option<Vec<f32>>),
+    MarkerClusters(u32, ComputeRect, u32),
+    Query(u32, QueryShape, Vec<AttributeFilter>),
+    QueryPredicate(u32, Predicate),
+    Project(u32, Camera),
     // Reset,
     Dispose,
 }
 
+impl ComputeCommand {
+    fn name(&self) -> &'static str {
+        match self {
+            ComputeCommand::Compute(..) => "rect",
+            ComputeCommand::Calibrate => "calibrate",
+            ComputeCommand::DescribeAdapter => "describe_adapter",
+            ComputeCommand::CreatePointSet(..) => "create_point_set",
+            ComputeCommand::UpdatePointSet(..) => "update_point_set",
+            ComputeCommand::MovePoints(..) => "move_points",
+            ComputeCommand::InsertPoints(..) => "insert_points",
+            ComputeCommand::RemovePoints(..) => "remove_points",
+            ComputeCommand::DisposePointSet(..) => "dispose_point_set",
+            ComputeCommand::SetAttribute(..) => "set_point_attribute",
+            ComputeCommand::RemoveAttribute(..) => "remove_point_attribute",
+            ComputeCommand::Transform(_, _, false) => "transform_point_set",
+            ComputeCommand::Transform(_, _, true) => "create_transformed_point_set",
+            ComputeCommand::SetPointSetIndex(..) => "set_point_set_index",
+            ComputeCommand::GetIndexStats(_) => "get_point_set_index_stats",
+            ComputeCommand::Knn(..) => "knn",
+            ComputeCommand::HitTest(..) => "hit_test",
+            ComputeCommand::RadiusGraph(..) => "radius_graph",
+            ComputeCommand::Dbscan(..) => "dbscan",
+            ComputeCommand::KMeans(..) => "kmeans",
+            ComputeCommand::BuildMarkerClusters(..) => "build_marker_clusters",
+            ComputeCommand::MarkerClusters(..) => "get_marker_clusters",
+            ComputeCommand::ConvexHull(..) => "convex_hull",
+            ComputeCommand::AlphaShape(..) => "alpha_shape",
+            ComputeCommand::Delaunay(_) => "delaunay",
+            ComputeCommand::Voronoi(..) => "voronoi",
+            ComputeCommand::RasterizeVoronoi(..) => "rasterize_voronoi",
+            ComputeCommand::Density(..) => "density_grid",
+            ComputeCommand::Histogram(..) => "histogram_2d",
+            ComputeCommand::Query(_, shape, _) => shape.name(),
+            ComputeCommand::QueryPredicate(..) => "query_predicate",
+            ComputeCommand::Project(..) => "project_point_set",
+            ComputeCommand::Dispose => "dispose",
+        }
+    }
+}
+
+const GENERATED_PIPELINE_CAPACITY: usize = 64;
+
+// Kernels generated at runtime by key and entry point, along with when they were last used. Sources whose
+// structure keeps changing, like an `Or` over more and more rects, would otherwise add kernels for good,
+// so past `GENERATED_PIPELINE_CAPACITY` the least recently used one is dropped.
+#[derive(Default)]
+struct GeneratedPipelines {
+    uses: u64,
+    pipelines: HashMap<(u64, &'static str), (u64, Arc<wgpu::ComputePipeline>)>,
+}
+
 #[flutter_rust_bridge::frb(ignore)]
 pub struct WgpuCompute {
-    device: wgpu::Device,
-    queue: wgpu::Queue,
+    adapter: wgpu::Adapter,
+    pub(crate) device: wgpu::Device,
+    pub(crate) queue: wgpu::Queue,
     cs_module: wgpu::ShaderModule,
+    // Keyed by shader source address and entry point.
+    pipelines: Mutex<HashMap<(usize, &'static str), Arc<wgpu::ComputePipeline>>>,
+    generated_pipelines: Mutex<GeneratedPipelines>,
     // pipeline: wgpu::ComputePipeline,
     // bind_group: wgpu::BindGroup,
     // points_buffer: wgpu::Buffer,
@@ -143,17 +1098,44 @@ pub struct WgpuCompute {
 impl WgpuCompute {
     #[flutter_rust_bridge::frb(ignore)]
     pub async fn new() -> WgpuCompute {
-        let instance = wgpu::Instance::default();
-        let adapter = instance
+        let adapter = match wgpu::Instance::default()
             .request_adapter(&wgpu::RequestAdapterOptions::default())
             .await
-            .unwrap();
+        {
+            Some(adapter) => adapter,
+            // Browsers without WebGPU still have WebGL2, which can host the CPU fallback.
+            #[cfg(target_arch = "wasm32")]
+            None => wgpu::Instance::new(wgpu::InstanceDescriptor {
+                backends: wgpu::Backends::GL,
+                ..Default::default()
+            })
+            .request_adapter(&wgpu::RequestAdapterOptions::default())
+            .await
+            .unwrap(),
+            #[cfg(not(target_arch = "wasm32"))]
+            None => panic!("No GPU adapter found!"),
+        };
+        let supports_compute = adapter
+            .get_downlevel_capabilities()
+            .flags
+            .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS);
         let (device, queue) = adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
                     label: None,
-                    required_features: wgpu::Features::empty(),
-                    required_limits: wgpu::Limits::downlevel_defaults(),
+                    // Only used when profiling is enabled, but has to be requested up front.
+                    required_features: adapter.features() & wgpu::Features::TIMESTAMP_QUERY,
+                    required_limits: if supports_compute {
+                        // The indexed query kernels bind more storage buffers than the downlevel default of 4.
+                        wgpu::Limits {
+                            max_storage_buffers_per_shader_stage: adapter
+                                .limits()
+                                .max_storage_buffers_per_shader_stage,
+                            ..wgpu::Limits::downlevel_defaults()
+                        }
+                    } else {
+                        wgpu::Limits::downlevel_webgl2_defaults()
+                    },
                 },
                 None,
             )
@@ -174,20 +1156,125 @@ impl WgpuCompute {
         });
 
         WgpuCompute {
+            adapter,
             device,
             queue,
             cs_module,
+            pipelines: Mutex::new(HashMap::new()),
+            generated_pipelines: Mutex::new(GeneratedPipelines::default()),
+        }
+    }
+
+    // Compiles `entry_point` of the WGSL `source` on first use, with a layout derived from the shader.
+    pub(crate) fn pipeline(
+        &self,
+        source: &'static str,
+        entry_point: &'static str,
+    ) -> Arc<wgpu::ComputePipeline> {
+        let mut pipelines = self.pipelines.lock().unwrap();
+        pipelines
+            .entry((source.as_ptr() as usize, entry_point))
+            .or_insert_with(|| self.create_pipeline(source, entry_point))
+            .clone()
+    }
+
+    // Same as `pipeline` for WGSL generated at runtime. `key` is a hash of whatever the source is generated
+    // from, and `source` is only called to compile the kernel when no kernel with that key is cached.
+    pub(crate) fn generated_pipeline(
+        &self,
+        key: u64,
+        source: impl FnOnce() -> String,
+        entry_point: &'static str,
+    ) -> Arc<wgpu::ComputePipeline> {
+        let mut cache = self.generated_pipelines.lock().unwrap();
+        cache.uses += 1;
+        let uses = cache.uses;
+        if let Some((last_use, pipeline)) = cache.pipelines.get_mut(&(key, entry_point)) {
+            *last_use = uses;
+            return pipeline.clone();
+        }
+        if cache.pipelines.len() >= GENERATED_PIPELINE_CAPACITY {
+            let oldest = cache
+                .pipelines
+                .iter()
+                .min_by_key(|(_, (last_use, _))| *last_use)
+                .map(|(key, _)| *key)
+                .unwrap();
+            cache.pipelines.remove(&oldest);
         }
+        tracing::debug!(
+            entry_point,
+            cached = cache.pipelines.len(),
+            "compiling generated kernel"
+        );
+        let pipeline = self.create_pipeline(&source(), entry_point);
+        cache
+            .pipelines
+            .insert((key, entry_point), (uses, pipeline.clone()));
+        pipeline
+    }
+
+    fn create_pipeline(&self, source: &str, entry_point: &str) -> Arc<wgpu::ComputePipeline> {
+        let module = self
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(entry_point),
+                source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(source)),
+            });
+        Arc::new(
+            self.device
+                .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                    label: Some(entry_point),
+                    layout: None,
+                    module: &module,
+                    entry_point,
+                }),
+        )
+    }
+
+    pub(crate) fn supports_compute(&self) -> bool {
+        self.adapter
+            .get_downlevel_capabilities()
+            .flags
+            .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+    }
+
+    // Time it takes to get `points` onto the device, used to tell resident and non resident queries apart.
+    pub(crate) fn measure_upload(&self, points: &[Vec2]) -> std::time::Duration {
+        let start = Instant::now();
+        let points_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Upload Calibration Buffer"),
+                contents: bytemuck::cast_slice(points),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+        self.queue.submit(None);
+        self.device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+        drop(points_buffer);
+        start.elapsed()
     }
 
     #[flutter_rust_bridge::frb(ignore)]
     pub async fn execute(&self, points: &[Vec2], rect: &ComputeRect) -> Option<Vec<Vec2>> {
+        self.execute_profiled(points, rect, None).await
+    }
+
+    pub(crate) async fn execute_profiled(
+        &self,
+        points: &[Vec2],
+        rect: &ComputeRect,
+        mut timings: Option<&mut StageTimings>,
+    ) -> Option<Vec<Vec2>> {
         let device = &self.device;
         let queue = &self.queue;
         let cs_module = &self.cs_module;
+        let gpu_timer = timings.as_ref().and_then(|_| GpuTimer::new(device, queue));
+        let mut stage_start = Instant::now();
 
         // Create the storage buffer for points
         let points_size = std::mem::size_of_val(points) as wgpu::BufferAddress;
+        let upload_span = tracing::debug_span!("upload", bytes = points_size).entered();
         let points_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Points Buffer"),
             contents: bytemuck::cast_slice(points),
@@ -257,13 +1344,21 @@ impl WgpuCompute {
             ],
         });
 
+        if let Some(timings) = timings.as_deref_mut() {
+            timings.upload = stage_start.elapsed();
+            stage_start = Instant::now();
+        }
+        drop(upload_span);
+        let dispatch_span = tracing::debug_span!("dispatch", workgroups = points.len());
+        let dispatch_guard = dispatch_span.enter();
+
         // Create the command encoder and begin the compute pass
         let mut encoder =
             device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
         {
             let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: None,
-                timestamp_writes: None,
+                timestamp_writes: gpu_timer.as_ref().map(GpuTimer::timestamp_writes),
             });
             cpass.set_pipeline(&compute_pipeline);
             cpass.set_bind_group(0, &bind_group, &[]);
@@ -302,9 +1397,25 @@ impl WgpuCompute {
             points_size,
         );
 
+        if let Some(gpu_timer) = &gpu_timer {
+            gpu_timer.resolve(&mut encoder);
+        }
+
         // Submit the commands
         queue.submit(Some(encoder.finish()));
 
+        drop(dispatch_guard);
+        if let Some(timings) = timings.as_deref_mut() {
+            dispatch_span.in_scope(|| device.poll(wgpu::Maintain::wait()).panic_on_timeout());
+            timings.dispatch = stage_start.elapsed();
+            if let Some(gpu_timer) = &gpu_timer {
+                timings.gpu_dispatch = gpu_timer.read(device).instrument(dispatch_span).await;
+            }
+            stage_start = Instant::now();
+        }
+        let map_span = tracing::debug_span!("map", bytes = 2 * points_size);
+        let map_guard = map_span.enter();
+
         // Map the staging buffers and await the results
         let buffer_slice_output = staging_buffer_output.slice(..);
         let buffer_slice_empty = staging_buffer_empty.slice(..);
@@ -318,11 +1429,16 @@ impl WgpuCompute {
         // In an actual application, `device.poll(...)` should
         // be called in an event loop or on another thread.
         device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+        drop(map_guard);
 
         let result_output: Vec<Vec2>;
 
         // Receive the results and convert them back to Vec<Vec2>
-        if let Ok(Ok(())) = receiver_output.recv_async().await {
+        if let Ok(Ok(())) = receiver_output
+            .recv_async()
+            .instrument(map_span.clone())
+            .await
+        {
             let data_output = buffer_slice_output.get_mapped_range();
             result_output = bytemuck::cast_slice(&data_output).to_vec();
             drop(data_output);
@@ -331,17 +1447,15 @@ impl WgpuCompute {
             panic!("Failed to read output points from GPU!");
         }
 
-        if let Ok(Ok(())) = receiver_empty.recv_async().await {
+        if let Ok(Ok(())) = receiver_empty.recv_async().instrument(map_span).await {
             let data_empty = buffer_slice_empty.get_mapped_range();
             let result_empty: Vec<i32> = bytemuck::cast_slice(&data_empty).to_vec();
             drop(data_empty);
             staging_buffer_empty.unmap();
-            let mut result: Vec<Vec2> = Vec::new();
+            let result = flagged_points(&result_output, &result_empty);
 
-            for i in 0..result_empty.len() - 1 {
-                if result_empty[i] == -1 {
-                    result.push(result_output[i]);
-                }
+            if let Some(timings) = timings {
+                timings.readback = stage_start.elapsed();
             }
 
             return Some(result);
@@ -351,24 +1465,60 @@ impl WgpuCompute {
     }
 }
 
+// Output points the rect kernel flagged with -1, the ones inside the rect.
+fn flagged_points(points: &[Vec2], flags: &[i32]) -> Vec<Vec2> {
+    points
+        .iter()
+        .zip(flags)
+        .filter(|(_, &flag)| flag == -1)
+        .map(|(&point, _)| point)
+        .collect()
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 pub async fn run_compute(points: Vec<Vec2>, rect: ComputeRect) -> Option<Vec<Vec2>> {
-    let map = COMPUTES.read().unwrap();
-    if !map.contains_key(COMPUTE_KEY) {
-        panic!("Compute instance not found!");
+    match send_command(ComputeCommand::Compute(points, rect)).await {
+        ComputeResponse::Points(points) => Some(points),
+        _ => None,
     }
+}
+
+/// Same as `run_compute`, with the coordinates of the points one after another as `x0, y0, x1, y1, ...`
+/// both ways. Crosses the bridge as a `Float32List` without wrapping every point, and the result is ready
+/// for `Canvas.drawRawPoints`. `None` if `points` has an odd length.
+pub async fn run_compute_flat(points: Vec<f32>, rect: ComputeRect) -> Option<Vec<f32>> {
+    run_compute(unflatten_points(&points)?, rect)
+        .await
+        .map(|points| flatten_points(&points))
+}
+
+pub(crate) async fn send_command(command: ComputeCommand) -> ComputeResponse {
+    let request_tx = {
+        let map = COMPUTES.read().unwrap();
+        if !map.contains_key(COMPUTE_KEY) {
+            panic!("Compute instance not found!");
+        }
 
-    let context = map.get(COMPUTE_KEY).unwrap();
+        map.get(COMPUTE_KEY).unwrap().request_tx.clone()
+    };
+
+    let id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+    tracing::trace!(
+        request_id = id,
+        command = command.name(),
+        "request received"
+    );
 
-    match context.request_tx.send(ComputeRequest {
+    let (reply_tx, reply_rx) = flume::bounded(1);
+    match request_tx.send(ComputeRequest {
+        id,
+        queued_at: Instant::now(),
         context: None,
-        command: ComputeCommand::Compute(points, rect),
+        command,
+        reply: reply_tx,
     }) {
-        Ok(_) => {
-            let response = context.response_rx.recv().unwrap();
-            return Some(response.points);
-        }
+        Ok(_) => reply_rx.recv_async().await.unwrap(),
         Err(_) => {
             panic!("Failed to send command to GPU!");
         }
@@ -384,4 +1534,54 @@ pub struct ComputeRect {
     pub max: Vec2,
 }
 
+/// A point inside a result struct or enum. The bridge encodes `[f32; 2]` only as a parameter or return
+/// value, so points nested in other types cross it as `ComputePoint`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ComputePoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl From<Vec2> for ComputePoint {
+    fn from([x, y]: Vec2) -> Self {
+        ComputePoint { x, y }
+    }
+}
+
+impl From<ComputePoint> for Vec2 {
+    fn from(point: ComputePoint) -> Self {
+        [point.x, point.y]
+    }
+}
+
 pub type Vec2 = [f32; 2];
+
+// Coordinates of `points` one after another, for the flat variants of the results.
+pub(crate) fn flatten_points(points: &[Vec2]) -> Vec<f32> {
+    bytemuck::cast_slice(points).to_vec()
+}
+
+// Points of flat coordinates, `None` if a coordinate is left over.
+pub(crate) fn unflatten_points(points: &[f32]) -> Option<Vec<Vec2>> {
+    points
+        .len()
+        .is_multiple_of(2)
+        .then(|| bytemuck::cast_slice(points).to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flagged_points_keeps_the_last_point() {
+        let points = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]];
+        assert_eq!(
+            flagged_points(&points, &[-1, 0, -1]),
+            vec![[0.0, 0.0], [2.0, 2.0]]
+        );
+        assert_eq!(flagged_points(&points[..1], &[-1]), vec![[0.0, 0.0]]);
+        assert!(flagged_points(&[], &[]).is_empty());
+    }
+}
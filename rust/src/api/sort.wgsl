@@ -0,0 +1,82 @@
+// One 4-bit pass of a stable least-significant-digit radix sort of `u32` keys carrying `u32` values.
+// `radix_count` writes per block digit counts laid out digit major, `scan.wgsl` turns them into
+// global offsets, and `radix_scatter` moves every element to its offset plus its rank within the block.
+struct Pass {
+    shift: u32,
+    count: u32,
+    block_count: u32,
+    _padding: u32,
+};
+
+const RADIX: u32 = 16u;
+const BLOCK: u32 = 64u;
+
+@group(0) @binding(0) var<uniform> pass_: Pass;
+@group(0) @binding(1) var<storage, read> keysIn: array<u32>;
+@group(0) @binding(2) var<storage, read_write> blockOffsets: array<u32>;
+@group(0) @binding(3) var<storage, read> valuesIn: array<u32>;
+@group(0) @binding(4) var<storage, read_write> keysOut: array<u32>;
+@group(0) @binding(5) var<storage, read_write> valuesOut: array<u32>;
+
+var<workgroup> histogram: array<atomic<u32>, 16>;
+var<workgroup> digits: array<u32, 64>;
+
+// Out of range elements get digit `RADIX`, which no real element has.
+fn digitOf(i: u32) -> u32 {
+    if (i >= pass_.count) {
+        return RADIX;
+    }
+    return (keysIn[i] >> pass_.shift) & (RADIX - 1u);
+}
+
+@compute
+@workgroup_size(64)
+fn radix_count(
+    @builtin(workgroup_id) wid: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+    @builtin(local_invocation_index) t: u32,
+) {
+    let block = wid.x + wid.y * groups.x;
+    if (block >= pass_.block_count) {
+        return;
+    }
+    if (t < RADIX) {
+        atomicStore(&histogram[t], 0u);
+    }
+    workgroupBarrier();
+    let digit = digitOf(block * BLOCK + t);
+    if (digit < RADIX) {
+        atomicAdd(&histogram[digit], 1u);
+    }
+    workgroupBarrier();
+    if (t < RADIX) {
+        blockOffsets[t * pass_.block_count + block] = atomicLoad(&histogram[t]);
+    }
+}
+
+@compute
+@workgroup_size(64)
+fn radix_scatter(
+    @builtin(workgroup_id) wid: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+    @builtin(local_invocation_index) t: u32,
+) {
+    let block = wid.x + wid.y * groups.x;
+    if (block >= pass_.block_count) {
+        return;
+    }
+    let i = block * BLOCK + t;
+    let digit = digitOf(i);
+    digits[t] = digit;
+    workgroupBarrier();
+    if (digit == RADIX) {
+        return;
+    }
+    var rank = 0u;
+    for (var j = 0u; j < t; j++) {
+        rank += u32(digits[j] == digit);
+    }
+    let destination = blockOffsets[digit * pass_.block_count + block] + rank;
+    keysOut[destination] = keysIn[i];
+    valuesOut[destination] = valuesIn[i];
+}
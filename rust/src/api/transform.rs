@@ -0,0 +1,386 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::{
+    gpu,
+    point_set::{self, PointSet},
+    simple::{send_command, ComputeCommand, ComputeResponse, Vec2, WgpuCompute},
+};
+
+const TRANSFORM_SHADER: &str = include_str!("transform.wgsl");
+
+/// 2D affine transform mapping `(x, y)` to `(xx * x + xy * y + tx, yx * x + yy * y + ty)`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct AffineTransform {
+    pub xx: f32,
+    pub xy: f32,
+    pub yx: f32,
+    pub yy: f32,
+    pub tx: f32,
+    pub ty: f32,
+}
+
+impl AffineTransform {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn identity() -> AffineTransform {
+        AffineTransform::scaling(1.0, 1.0)
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn translation(x: f32, y: f32) -> AffineTransform {
+        AffineTransform {
+            tx: x,
+            ty: y,
+            ..AffineTransform::identity()
+        }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn scaling(x: f32, y: f32) -> AffineTransform {
+        AffineTransform {
+            xx: x,
+            xy: 0.0,
+            yx: 0.0,
+            yy: y,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    /// Rotation about the origin, from the x axis towards the y axis, so clockwise on screen.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn rotation(radians: f32) -> AffineTransform {
+        let (sin, cos) = radians.sin_cos();
+        AffineTransform {
+            xx: cos,
+            xy: -sin,
+            yx: sin,
+            yy: cos,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    /// Shifts x by `tan(x_radians) * y` and y by `tan(y_radians) * x`, like `Canvas.skew` with tangents.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn skew(x_radians: f32, y_radians: f32) -> AffineTransform {
+        AffineTransform {
+            xy: x_radians.tan(),
+            yx: y_radians.tan(),
+            ..AffineTransform::identity()
+        }
+    }
+
+    /// This transform followed by `next`.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn then(&self, next: AffineTransform) -> AffineTransform {
+        AffineTransform {
+            xx: next.xx * self.xx + next.xy * self.yx,
+            xy: next.xx * self.xy + next.xy * self.yy,
+            yx: next.yx * self.xx + next.yy * self.yx,
+            yy: next.yx * self.xy + next.yy * self.yy,
+            tx: next.xx * self.tx + next.xy * self.ty + next.tx,
+            ty: next.yx * self.tx + next.yy * self.ty + next.ty,
+        }
+    }
+
+    // Same as `transform_points` in `transform.wgsl`.
+    pub(crate) fn apply(&self, point: &Vec2) -> Vec2 {
+        [
+            self.xx * point[0] + self.xy * point[1] + self.tx,
+            self.yx * point[0] + self.yy * point[1] + self.ty,
+        ]
+    }
+
+    pub(crate) fn is_finite(&self) -> bool {
+        [self.xx, self.xy, self.yx, self.yy, self.tx, self.ty]
+            .iter()
+            .all(|value| value.is_finite())
+    }
+}
+
+pub(crate) enum PointTransform {
+    All(AffineTransform),
+    // Transform of every group, picked by the named `U32` attribute channel.
+    Groups(String, Vec<AffineTransform>),
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct TransformParams {
+    point_count: u32,
+    transform_count: u32,
+    group_offset: u32,
+    grouped: u32,
+}
+
+// Points of groups without a transform stay where they are.
+pub(crate) fn transform_cpu(
+    points: &[Vec2],
+    transforms: &[AffineTransform],
+    groups: Option<&[u32]>,
+) -> Vec<Vec2> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, point)| {
+            let group = groups.map_or(0, |groups| groups[i]) as usize;
+            transforms
+                .get(group)
+                .map_or(*point, |transform| transform.apply(point))
+        })
+        .collect()
+}
+
+// Transformed points in a new buffer that can replace the points buffer of a set, along with the CPU copy
+// read back from it so both backends keep seeing the same coordinates. `group_offset` is the start of the
+// group channel in the attributes buffer.
+pub(crate) async fn transform_gpu(
+    gpu: &WgpuCompute,
+    set: &PointSet,
+    transforms: &[AffineTransform],
+    group_offset: Option<u32>,
+) -> (Vec<Vec2>, wgpu::Buffer) {
+    let device = &gpu.device;
+    if set.points.is_empty() {
+        return (Vec::new(), point_set::points_buffer(gpu, &[]));
+    }
+    let params = TransformParams {
+        point_count: set.points.len() as u32,
+        transform_count: transforms.len() as u32,
+        group_offset: group_offset.unwrap_or(0),
+        grouped: group_offset.is_some() as u32,
+    };
+    let params_buffer = gpu::uniform_buffer(device, "Transform Params Buffer", &params);
+    let transforms_buffer = gpu::storage_buffer(
+        device,
+        "Transforms Buffer",
+        transforms,
+        wgpu::BufferUsages::empty(),
+    );
+    let transformed_buffer = gpu::empty_storage_buffer(
+        device,
+        "Resident Points Buffer",
+        set.points.len() as u64 * 8,
+        wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::COPY_SRC,
+    );
+    let pipeline = gpu.pipeline(TRANSFORM_SHADER, "transform_points");
+    let bind_group = gpu::bind_group(
+        device,
+        &pipeline,
+        &[
+            &set.buffer,
+            &params_buffer,
+            &transforms_buffer,
+            &set.attributes.buffer,
+            &transformed_buffer,
+        ],
+    );
+
+    let mut encoder =
+        device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+    gpu::dispatch(
+        device,
+        &mut encoder,
+        &pipeline,
+        &bind_group,
+        params.point_count,
+    );
+    gpu.queue.submit(Some(encoder.finish()));
+
+    let points =
+        gpu::read_buffer::<Vec2>(device, &gpu.queue, &transformed_buffer, 0, set.points.len())
+            .await;
+    (points, transformed_buffer)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Applies `transform` to every point of `point_set` where it lives, so pan, zoom and layout changes do not
+/// send the points again. The spatial index and marker clusters follow as with `update_point_set`, and
+/// attribute channels are kept. Returns false if the set does not exist or the transform is not finite.
+pub async fn transform_point_set(point_set: u32, transform: AffineTransform) -> bool {
+    run_transform(point_set, PointTransform::All(transform), false)
+        .await
+        .is_some()
+}
+
+/// Applies `transforms[g]` to the points of `point_set` whose `group_attribute` is `g`, in one pass. Points
+/// of groups without a transform stay where they are. Returns false if the set does not exist,
+/// `group_attribute` is not a `U32` channel of it or a transform is not finite.
+pub async fn transform_point_set_groups(
+    point_set: u32,
+    group_attribute: String,
+    transforms: Vec<AffineTransform>,
+) -> bool {
+    run_transform(
+        point_set,
+        PointTransform::Groups(group_attribute, transforms),
+        false,
+    )
+    .await
+    .is_some()
+}
+
+/// Same as `transform_point_set`, but leaves `point_set` as is and returns the id of a new set holding the
+/// transformed points and copies of its attribute channels, without a spatial index.
+pub async fn create_transformed_point_set(
+    point_set: u32,
+    transform: AffineTransform,
+) -> Option<u32> {
+    run_transform(point_set, PointTransform::All(transform), true).await
+}
+
+/// Same as `transform_point_set_groups`, but into a new set like `create_transformed_point_set`.
+pub async fn create_transformed_point_set_groups(
+    point_set: u32,
+    group_attribute: String,
+    transforms: Vec<AffineTransform>,
+) -> Option<u32> {
+    run_transform(
+        point_set,
+        PointTransform::Groups(group_attribute, transforms),
+        true,
+    )
+    .await
+}
+
+async fn run_transform(
+    point_set: u32,
+    transform: PointTransform,
+    into_new_set: bool,
+) -> Option<u32> {
+    match send_command(ComputeCommand::Transform(
+        point_set,
+        transform,
+        into_new_set,
+    ))
+    .await
+    {
+        ComputeResponse::Transformed(id) => id,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Xorshift points in `[-100, 100)`, so failures reproduce.
+    fn random_points(count: usize) -> Vec<Vec2> {
+        let mut state = 0x7f4a_7c15u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1 << 24) as f32 * 200.0 - 100.0
+        };
+        (0..count).map(|_| [next(), next()]).collect()
+    }
+
+    // The transform as a matrix in homogeneous coordinates, applied in `f64`.
+    fn brute_force(transform: &AffineTransform, point: &Vec2) -> [f64; 2] {
+        let matrix = [
+            [transform.xx, transform.xy, transform.tx],
+            [transform.yx, transform.yy, transform.ty],
+        ];
+        let column = [point[0] as f64, point[1] as f64, 1.0];
+        matrix.map(|row| row.iter().zip(column).map(|(&m, v)| m as f64 * v).sum())
+    }
+
+    fn assert_close(actual: &Vec2, expected: [f64; 2]) {
+        for axis in 0..2 {
+            let error = (actual[axis] as f64 - expected[axis]).abs();
+            assert!(
+                error <= 1e-4 * expected[axis].abs().max(1.0),
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    fn transforms() -> Vec<AffineTransform> {
+        vec![
+            AffineTransform::identity(),
+            AffineTransform::translation(3.5, -12.0),
+            AffineTransform::scaling(2.0, -0.5),
+            AffineTransform::rotation(0.7),
+            AffineTransform::skew(0.3, -0.2),
+            AffineTransform::rotation(1.2)
+                .then(AffineTransform::scaling(3.0, 0.25))
+                .then(AffineTransform::translation(-7.0, 40.0)),
+        ]
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let points = random_points(300);
+        for transform in transforms() {
+            let transformed = transform_cpu(&points, &[transform], None);
+            assert_eq!(transformed.len(), points.len());
+            for (point, actual) in points.iter().zip(&transformed) {
+                assert_close(actual, brute_force(&transform, point));
+            }
+        }
+        // A quarter turn towards the y axis.
+        let turned = AffineTransform::rotation(std::f32::consts::FRAC_PI_2).apply(&[1.0, 0.0]);
+        assert_close(&turned, [0.0, 1.0]);
+    }
+
+    #[test]
+    fn then_applies_in_order() {
+        let points = random_points(50);
+        let transforms = transforms();
+        for first in &transforms {
+            for next in &transforms {
+                let composed = first.then(*next);
+                for point in &points {
+                    let expected = brute_force(next, &first.apply(point));
+                    assert_close(&composed.apply(point), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn groups_pick_their_transform() {
+        let points = random_points(200);
+        let transforms = transforms();
+        // Group 6 and up have no transform.
+        let groups: Vec<u32> = (0..points.len() as u32).map(|i| i % 9).collect();
+        let transformed = transform_cpu(&points, &transforms, Some(&groups));
+        for ((point, actual), &group) in points.iter().zip(&transformed).zip(&groups) {
+            match transforms.get(group as usize) {
+                Some(transform) => assert_close(actual, brute_force(transform, point)),
+                None => assert_eq!(actual, point),
+            }
+        }
+        assert_eq!(transform_cpu(&points, &[], None), points);
+    }
+
+    #[test]
+    fn empty_and_non_finite_points() {
+        let translation = AffineTransform::translation(1.0, 2.0);
+        assert!(transform_cpu(&[], &[translation], None).is_empty());
+        assert!(transform_cpu(&[], &[translation], Some(&[])).is_empty());
+
+        let points = [
+            [f32::NAN, 1.0],
+            [f32::INFINITY, 0.0],
+            [1.0, 1.0],
+            [1.0, 1.0],
+        ];
+        let transformed = transform_cpu(&points, &[translation], None);
+        assert_eq!(transformed.len(), 4);
+        // Points that are not finite keep their place in the set and stay not finite, the zero terms of
+        // the matrix spreading NaN to the other coordinate.
+        assert!(transformed[0].iter().all(|v| v.is_nan()));
+        assert!(transformed[1][0] == f32::INFINITY && transformed[1][1].is_nan());
+        // Duplicates stay duplicates.
+        assert_eq!(transformed[2], [2.0, 3.0]);
+        assert_eq!(transformed[3], transformed[2]);
+
+        assert!(translation.is_finite());
+        assert!(!AffineTransform::translation(f32::NAN, 0.0).is_finite());
+        assert!(!AffineTransform::scaling(f32::INFINITY, 1.0).is_finite());
+    }
+}
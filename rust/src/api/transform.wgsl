@@ -0,0 +1,45 @@
+// Affine transforms of resident points. Without groups every point goes through `transforms[0]`. With
+// groups, the `group` attribute channel starting at `group_offset` picks the transform of every point,
+// and points whose group has no transform are copied unchanged.
+struct Params {
+    point_count: u32,
+    transform_count: u32,
+    group_offset: u32,
+    grouped: u32,
+};
+
+// Maps `p` to `(xx * p.x + xy * p.y + tx, yx * p.x + yy * p.y + ty)`.
+struct Affine {
+    xx: f32,
+    xy: f32,
+    yx: f32,
+    yy: f32,
+    tx: f32,
+    ty: f32,
+};
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<uniform> params: Params;
+@group(0) @binding(2) var<storage, read> transforms: array<Affine>;
+@group(0) @binding(3) var<storage, read> attributes: array<u32>;
+@group(0) @binding(4) var<storage, read_write> transformed: array<vec2<f32>>;
+
+@compute
+@workgroup_size(64)
+fn transform_points(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = gid.x + gid.y * groups.x * 64u;
+    if (i >= params.point_count) {
+        return;
+    }
+    let p = points[i];
+    var group = 0u;
+    if (params.grouped != 0u) {
+        group = attributes[params.group_offset + i];
+    }
+    if (group >= params.transform_count) {
+        transformed[i] = p;
+        return;
+    }
+    let m = transforms[group];
+    transformed[i] = vec2<f32>(m.xx * p.x + m.xy * p.y + m.tx, m.yx * p.x + m.yy * p.y + m.ty);
+}
@@ -0,0 +1,250 @@
+use super::{
+    delaunay::{self, next_halfedge, Triangulation, EMPTY},
+    simple::{send_command, ComputeCommand, ComputePoint, ComputeRect, ComputeResponse, Vec2},
+};
+
+/// Voronoi cells of a point set clipped to a rectangle, laid out like `ProximityGraph`.
+#[derive(Clone, Debug)]
+pub struct VoronoiDiagram {
+    /// The cell of point `i` is `vertices[offsets[i]..offsets[i + 1]]`, so there is one more offset than
+    /// points.
+    pub offsets: Vec<u32>,
+    /// Cell polygons, counter-clockwise with y pointing up (clockwise on screen).
+    pub vertices: Vec<ComputePoint>,
+}
+
+// Delaunay neighbours of every point. Without triangles the points are collinear and the hull lists them
+// in order along their line.
+fn neighbours(triangulation: &Triangulation, point_count: usize) -> Vec<Vec<u32>> {
+    let mut neighbours = vec![Vec::new(); point_count];
+    if triangulation.triangles.is_empty() {
+        for pair in triangulation.hull.windows(2) {
+            neighbours[pair[0] as usize].push(pair[1]);
+            neighbours[pair[1] as usize].push(pair[0]);
+        }
+        return neighbours;
+    }
+    for (e, &from) in triangulation.triangles.iter().enumerate() {
+        let to = triangulation.triangles[next_halfedge(e as u32) as usize];
+        neighbours[from as usize].push(to);
+        // Hull edges have no twin to add the other direction.
+        if triangulation.halfedges[e] == EMPTY {
+            neighbours[to as usize].push(from);
+        }
+    }
+    neighbours
+}
+
+// Keeps the part of `polygon` at least as close to `site` as to `other` (Sutherland–Hodgman).
+fn clip(polygon: &[[f64; 2]], site: &Vec2, other: &Vec2) -> Vec<[f64; 2]> {
+    let normal = [
+        other[0] as f64 - site[0] as f64,
+        other[1] as f64 - site[1] as f64,
+    ];
+    let middle = [
+        (other[0] as f64 + site[0] as f64) / 2.0,
+        (other[1] as f64 + site[1] as f64) / 2.0,
+    ];
+    let side = |p: &[f64; 2]| (p[0] - middle[0]) * normal[0] + (p[1] - middle[1]) * normal[1];
+    let crossing = |a: &[f64; 2], b: &[f64; 2], fa: f64, fb: f64| {
+        let t = fa / (fa - fb);
+        [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+    };
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (k, current) in polygon.iter().enumerate() {
+        let previous = &polygon[(k + polygon.len() - 1) % polygon.len()];
+        let (fc, fp) = (side(current), side(previous));
+        if fc <= 0.0 {
+            if fp > 0.0 && fc < 0.0 {
+                clipped.push(crossing(previous, current, fp, fc));
+            }
+            clipped.push(*current);
+        } else if fp < 0.0 {
+            clipped.push(crossing(previous, current, fp, fc));
+        }
+    }
+    clipped
+}
+
+// Each cell starts as `bounds` and is cut by the bisector with every Delaunay neighbour, which covers
+// cells on the hull and collinear input without special cases. Coincident duplicates and non-finite
+// points get empty cells.
+pub(crate) fn voronoi_cpu(points: &[Vec2], bounds: &ComputeRect) -> VoronoiDiagram {
+    let all: Vec<u32> = (0..points.len() as u32).collect();
+    let triangulation = delaunay::triangulate(points, &all);
+    let neighbours = neighbours(&triangulation, points.len());
+    let mut present = vec![false; points.len()];
+    for &i in triangulation.hull.iter().chain(&triangulation.triangles) {
+        present[i as usize] = true;
+    }
+    let [min, max] = [bounds.min, bounds.max].map(|corner| corner.map(f64::from));
+    let rect = [
+        [min[0], min[1]],
+        [max[0], min[1]],
+        [max[0], max[1]],
+        [min[0], max[1]],
+    ];
+    let valid = min[0] < max[0] && min[1] < max[1];
+
+    let mut offsets = Vec::with_capacity(points.len() + 1);
+    let mut vertices = Vec::new();
+    offsets.push(0);
+    for (i, site) in points.iter().enumerate() {
+        if valid && present[i] {
+            let mut cell = rect.to_vec();
+            for &other in &neighbours[i] {
+                cell = clip(&cell, site, &points[other as usize]);
+                if cell.is_empty() {
+                    break;
+                }
+            }
+            vertices.extend(cell.iter().map(|v| ComputePoint {
+                x: v[0] as f32,
+                y: v[1] as f32,
+            }));
+        }
+        offsets.push(vertices.len() as u32);
+    }
+    VoronoiDiagram { offsets, vertices }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Voronoi diagram of `point_set`, the dual of its Delaunay triangulation, with every cell clipped to
+/// `bounds`. Cells of points outside `bounds` may still reach into it. Of coincident points only the lowest
+/// index gets a cell, the others get an empty one. `None` if the point set does not exist.
+pub async fn voronoi(point_set: u32, bounds: ComputeRect) -> Option<VoronoiDiagram> {
+    match send_command(ComputeCommand::Voronoi(point_set, bounds)).await {
+        ComputeResponse::Voronoi(diagram) => diagram,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: ComputeRect = ComputeRect {
+        min: [-10.0, -10.0],
+        max: [110.0, 60.0],
+    };
+
+    // Xorshift points in `[0, 100) x [0, 50)`, so failures reproduce.
+    fn random_points(count: usize) -> Vec<Vec2> {
+        let mut state = 0x9e37_79b9u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1 << 24) as f32
+        };
+        (0..count)
+            .map(|_| [next() * 100.0, next() * 50.0])
+            .collect()
+    }
+
+    fn cell(diagram: &VoronoiDiagram, i: usize) -> &[Vec2] {
+        bytemuck::cast_slice(
+            &diagram.vertices[diagram.offsets[i] as usize..diagram.offsets[i + 1] as usize],
+        )
+    }
+
+    fn area(cell: &[Vec2]) -> f64 {
+        let mut area = 0.0;
+        for (k, a) in cell.iter().enumerate() {
+            let b = cell[(k + 1) % cell.len()];
+            area += a[0] as f64 * b[1] as f64 - b[0] as f64 * a[1] as f64;
+        }
+        area / 2.0
+    }
+
+    // Cells are counter-clockwise, hold their site, have every vertex as close to their site as to any
+    // other and together tile the bounds.
+    fn check(points: &[Vec2]) -> VoronoiDiagram {
+        let diagram = voronoi_cpu(points, &BOUNDS);
+        assert_eq!(diagram.offsets.len(), points.len() + 1);
+        let distance = |a: &Vec2, b: &Vec2| (a[0] - b[0]).hypot(a[1] - b[1]);
+        let mut total = 0.0;
+        for (i, site) in points.iter().enumerate() {
+            let cell = cell(&diagram, i);
+            if cell.is_empty() {
+                continue;
+            }
+            assert!(area(cell) >= 0.0, "cell {i} is clockwise");
+            total += area(cell);
+            for (k, a) in cell.iter().enumerate() {
+                let b = cell[(k + 1) % cell.len()];
+                let edge = [b[0] - a[0], b[1] - a[1]];
+                let cross = edge[0] * (site[1] - a[1]) - edge[1] * (site[0] - a[0]);
+                assert!(cross >= -1e-3, "site {i} is outside its cell");
+            }
+            for vertex in cell {
+                let nearest = points
+                    .iter()
+                    .map(|other| distance(vertex, other))
+                    .fold(f32::INFINITY, f32::min);
+                assert!(
+                    distance(vertex, site) <= nearest + 1e-3,
+                    "{vertex:?} of cell {i}"
+                );
+            }
+        }
+        let bounds =
+            (BOUNDS.max[0] - BOUNDS.min[0]) as f64 * (BOUNDS.max[1] - BOUNDS.min[1]) as f64;
+        assert!(
+            (total - bounds).abs() < 1e-2 * bounds.sqrt(),
+            "{total} of {bounds}"
+        );
+        diagram
+    }
+
+    #[test]
+    fn random() {
+        check(&random_points(1000));
+    }
+
+    // Four cocircular sites around every vertex.
+    #[test]
+    fn grid() {
+        let points: Vec<Vec2> = (0..200)
+            .map(|i| [(i % 20) as f32 * 5.0, (i / 20) as f32 * 5.0])
+            .collect();
+        check(&points);
+    }
+
+    #[test]
+    fn collinear() {
+        let points: Vec<Vec2> = (0..20).map(|i| [i as f32 * 5.0, i as f32 * 2.0]).collect();
+        let diagram = check(&points);
+        assert!((0..20).all(|i| !cell(&diagram, i).is_empty()));
+    }
+
+    #[test]
+    fn single_point() {
+        let diagram = check(&[[5.0, 5.0]]);
+        assert_eq!(area(cell(&diagram, 0)), 120.0 * 70.0);
+    }
+
+    // Only the lowest index of coincident points gets a cell, and non-finite points get none.
+    #[test]
+    fn duplicates_and_non_finite() {
+        let mut points = random_points(100);
+        points.push(points[3]);
+        points.push([f32::NAN, 0.0]);
+        points.push([0.0, f32::NEG_INFINITY]);
+        let diagram = check(&points);
+        assert!(!cell(&diagram, 3).is_empty());
+        assert!((100..103).all(|i| cell(&diagram, i).is_empty()));
+    }
+
+    #[test]
+    fn empty_bounds() {
+        let bounds = ComputeRect {
+            min: [10.0, 0.0],
+            max: [0.0, 10.0],
+        };
+        let diagram = voronoi_cpu(&random_points(10), &bounds);
+        assert!(diagram.vertices.is_empty());
+        assert_eq!(diagram.offsets, vec![0; 11]);
+    }
+}
@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire_init_app_impl(
+fn wire_get_adapter_report_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -46,7 +46,7 @@ fn wire_init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "get_adapter_report",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -62,14 +62,17 @@ fn wire_init_app_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| async move {
-                transform_result_sse((move || async move {
-                         Result::<_,()>::Ok(crate::api::simple::init_app().await)
-                    })().await)
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(crate::api::adapter::get_adapter_report().await)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire_run_compute_impl(
+fn wire_alpha_shape_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -77,7 +80,7 @@ fn wire_run_compute_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "run_compute",
+            debug_name: "alpha_shape",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -91,14 +94,272 @@ fn wire_run_compute_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_points = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_alpha = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::alpha_shape::alpha_shape(api_point_set, api_alpha).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_alpha_shape_in_circle_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "alpha_shape_in_circle",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_alpha = <f32>::sse_decode(&mut deserializer);
+            let api_center = <[f32; 2]>::sse_decode(&mut deserializer);
+            let api_radius = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::alpha_shape::alpha_shape_in_circle(
+                                api_point_set,
+                                api_alpha,
+                                api_center,
+                                api_radius,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_alpha_shape_in_polygon_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "alpha_shape_in_polygon",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_alpha = <f32>::sse_decode(&mut deserializer);
+            let api_polygon = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::alpha_shape::alpha_shape_in_polygon(
+                                api_point_set,
+                                api_alpha,
+                                api_polygon,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_alpha_shape_in_rect_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "alpha_shape_in_rect",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_alpha = <f32>::sse_decode(&mut deserializer);
             let api_rect = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse(
                     (move || async move {
                         Result::<_, ()>::Ok(
-                            crate::api::simple::run_compute(api_points, api_rect).await,
+                            crate::api::alpha_shape::alpha_shape_in_rect(
+                                api_point_set,
+                                api_alpha,
+                                api_rect,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_remove_point_attribute_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_point_attribute",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::attributes::remove_point_attribute(api_point_set, api_name)
+                                .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_set_point_attribute_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_point_attribute",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_values =
+                <crate::api::attributes::AttributeValues>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::attributes::set_point_attribute(
+                                api_point_set,
+                                api_name,
+                                api_values,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_dbscan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dbscan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_eps = <f32>::sse_decode(&mut deserializer);
+            let api_min_points = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::dbscan::dbscan(api_point_set, api_eps, api_min_points)
+                                .await,
                         )
                     })()
                     .await,
@@ -107,204 +368,5080 @@ fn wire_run_compute_impl(
         },
     )
 }
+fn wire_delaunay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delaunay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(crate::api::delaunay::delaunay(api_point_set).await)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_DispatchCalibration_crossover_points_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DispatchCalibration_crossover_points",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dispatch::DispatchCalibration>::sse_decode(&mut deserializer);
+            let api_resident = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::dispatch::DispatchCalibration::crossover_points(
+                    &api_that,
+                    api_resident,
+                ))
+            })())
+        },
+    )
+}
+fn wire_calibrate_dispatch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "calibrate_dispatch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(crate::api::dispatch::calibrate_dispatch().await)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_get_compute_backend_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_compute_backend",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::dispatch::get_compute_backend())
+            })())
+        },
+    )
+}
+fn wire_set_compute_backend_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_compute_backend",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_backend = <crate::api::dispatch::ComputeBackend>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::dispatch::set_compute_backend(api_backend))
+            })())
+        },
+    )
+}
+fn wire_query_predicate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_predicate",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_predicate = <crate::api::expression::Predicate>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::expression::query_predicate(api_point_set, api_predicate)
+                                .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_density_grid_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "density_grid",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_viewport = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_bandwidth = <f32>::sse_decode(&mut deserializer);
+            let api_shape = <crate::api::heatmap::KernelShape>::sse_decode(&mut deserializer);
+            let api_weights = <Option<Vec<f32>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::heatmap::density_grid(
+                                api_point_set,
+                                api_viewport,
+                                api_width,
+                                api_height,
+                                api_bandwidth,
+                                api_shape,
+                                api_weights,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_density_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "density_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_viewport = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_bandwidth = <f32>::sse_decode(&mut deserializer);
+            let api_shape = <crate::api::heatmap::KernelShape>::sse_decode(&mut deserializer);
+            let api_weights = <Option<Vec<f32>>>::sse_decode(&mut deserializer);
+            let api_colormap = <crate::api::heatmap::Colormap>::sse_decode(&mut deserializer);
+            let api_max = <Option<f32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::heatmap::density_image(
+                                api_point_set,
+                                api_viewport,
+                                api_width,
+                                api_height,
+                                api_bandwidth,
+                                api_shape,
+                                api_weights,
+                                api_colormap,
+                                api_max,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_histogram_2d_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "histogram_2d",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_extent = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            let api_bins_x = <u32>::sse_decode(&mut deserializer);
+            let api_bins_y = <u32>::sse_decode(&mut deserializer);
+            let api_values = <Option<Vec<f32>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::histogram::histogram_2d(
+                                api_point_set,
+                                api_extent,
+                                api_bins_x,
+                                api_bins_y,
+                                api_values,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_hit_test_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hit_test",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_position = <[f32; 2]>::sse_decode(&mut deserializer);
+            let api_tolerance = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::hit_test::hit_test(
+                                api_point_set,
+                                api_position,
+                                api_tolerance,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_convex_hull_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "convex_hull",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(crate::api::hull::convex_hull(api_point_set).await)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_convex_hull_in_circle_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "convex_hull_in_circle",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_center = <[f32; 2]>::sse_decode(&mut deserializer);
+            let api_radius = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::hull::convex_hull_in_circle(
+                                api_point_set,
+                                api_center,
+                                api_radius,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_convex_hull_in_polygon_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "convex_hull_in_polygon",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_polygon = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::hull::convex_hull_in_polygon(api_point_set, api_polygon)
+                                .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_convex_hull_in_rect_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "convex_hull_in_rect",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_rect = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::hull::convex_hull_in_rect(api_point_set, api_rect).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_rasterize_voronoi_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rasterize_voronoi",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_bounds = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::jump_flood::rasterize_voronoi(
+                                api_point_set,
+                                api_bounds,
+                                api_width,
+                                api_height,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_kmeans_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "kmeans",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_k = <u32>::sse_decode(&mut deserializer);
+            let api_max_iterations = <u32>::sse_decode(&mut deserializer);
+            let api_tolerance = <f32>::sse_decode(&mut deserializer);
+            let api_seed = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::kmeans::kmeans(
+                                api_point_set,
+                                api_k,
+                                api_max_iterations,
+                                api_tolerance,
+                                api_seed,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_knn_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "knn",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_query_points = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            let api_k = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::knn::knn(api_point_set, api_query_points, api_k).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_close_log_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "close_log_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::logging::close_log_stream())
+            })())
+        },
+    )
+}
+fn wire_create_log_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_log_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Stream,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_level = <crate::api::logging::LogLevel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    Result::<_, ()>::Ok(crate::api::logging::create_log_stream(
+                        StreamSink::new(
+                            context
+                                .rust2dart_context()
+                                .stream_sink::<_, crate::api::logging::LogEntry>(),
+                        ),
+                        api_max_level,
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_build_marker_clusters_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "build_marker_clusters",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_radius = <f32>::sse_decode(&mut deserializer);
+            let api_min_zoom = <u32>::sse_decode(&mut deserializer);
+            let api_max_zoom = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::marker_cluster::build_marker_clusters(
+                                api_point_set,
+                                api_radius,
+                                api_min_zoom,
+                                api_max_zoom,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_get_marker_clusters_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_marker_clusters",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_viewport = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            let api_zoom = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::marker_cluster::get_marker_clusters(
+                                api_point_set,
+                                api_viewport,
+                                api_zoom,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_create_point_set_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_point_set",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_points = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::point_set::create_point_set(api_points).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_create_point_set_flat_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_point_set_flat",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_points = <Vec<f32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::point_set::create_point_set_flat(api_points).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_dispose_point_set_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dispose_point_set",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::point_set::dispose_point_set(api_point_set).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_get_point_set_index_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_point_set_index_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::point_set::get_point_set_index_stats(api_point_set).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_insert_points_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "insert_points",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_points = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::point_set::insert_points(api_point_set, api_points).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_move_points_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_points",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_indices = <Vec<u32>>::sse_decode(&mut deserializer);
+            let api_points = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::point_set::move_points(
+                                api_point_set,
+                                api_indices,
+                                api_points,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_remove_points_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_points",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_ids = <Vec<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::point_set::remove_points(api_point_set, api_ids).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_set_point_set_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_point_set_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_kind = <crate::api::point_set::SpatialIndexKind>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::point_set::set_point_set_index(api_point_set, api_kind)
+                                .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_update_point_set_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_point_set",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_points = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::point_set::update_point_set(api_point_set, api_points)
+                                .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_is_profiling_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_profiling_enabled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::profiling::is_profiling_enabled())
+            })())
+        },
+    )
+}
+fn wire_set_profiling_enabled_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_profiling_enabled",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::profiling::set_profiling_enabled(api_enabled))
+            })())
+        },
+    )
+}
+fn wire_take_compute_metrics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_compute_metrics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::profiling::take_compute_metrics())
+            })())
+        },
+    )
+}
+fn wire_project_point_set_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "project_point_set",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_camera = <crate::api::projection::Camera>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::projection::project_point_set(api_point_set, api_camera)
+                                .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_project_point_set_flat_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "project_point_set_flat",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_camera = <crate::api::projection::Camera>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::projection::project_point_set_flat(
+                                api_point_set,
+                                api_camera,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_query_circle_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_circle",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_center = <[f32; 2]>::sse_decode(&mut deserializer);
+            let api_radius = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::query::query_circle(api_point_set, api_center, api_radius)
+                                .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_query_circle_filtered_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_circle_filtered",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_center = <[f32; 2]>::sse_decode(&mut deserializer);
+            let api_radius = <f32>::sse_decode(&mut deserializer);
+            let api_filters =
+                <Vec<crate::api::attributes::AttributeFilter>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::query::query_circle_filtered(
+                                api_point_set,
+                                api_center,
+                                api_radius,
+                                api_filters,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_query_polygon_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_polygon",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_polygon = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::query::query_polygon(api_point_set, api_polygon).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_query_polygon_filtered_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_polygon_filtered",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_polygon = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            let api_filters =
+                <Vec<crate::api::attributes::AttributeFilter>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::query::query_polygon_filtered(
+                                api_point_set,
+                                api_polygon,
+                                api_filters,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_query_rect_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_rect",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_rect = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::query::query_rect(api_point_set, api_rect).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_query_rect_filtered_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_rect_filtered",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_rect = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            let api_filters =
+                <Vec<crate::api::attributes::AttributeFilter>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::query::query_rect_filtered(
+                                api_point_set,
+                                api_rect,
+                                api_filters,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_radius_graph_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "radius_graph",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_radius = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::radius_graph::radius_graph(api_point_set, api_radius).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse((move || async move {
+                         Result::<_,()>::Ok(crate::api::simple::init_app().await)
+                    })().await)
+            }
+        },
+    )
+}
+fn wire_run_compute_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_compute",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_points = <Vec<[f32; 2]>>::sse_decode(&mut deserializer);
+            let api_rect = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::simple::run_compute(api_points, api_rect).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_run_compute_flat_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_compute_flat",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_points = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_rect = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::simple::run_compute_flat(api_points, api_rect).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_AffineTransform_identity_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AffineTransform_identity",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::transform::AffineTransform::identity())
+            })())
+        },
+    )
+}
+fn wire_AffineTransform_rotation_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AffineTransform_rotation",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_radians = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::transform::AffineTransform::rotation(
+                    api_radians,
+                ))
+            })())
+        },
+    )
+}
+fn wire_AffineTransform_scaling_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AffineTransform_scaling",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_x = <f32>::sse_decode(&mut deserializer);
+            let api_y = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::transform::AffineTransform::scaling(
+                    api_x, api_y,
+                ))
+            })())
+        },
+    )
+}
+fn wire_AffineTransform_skew_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AffineTransform_skew",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_x_radians = <f32>::sse_decode(&mut deserializer);
+            let api_y_radians = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::transform::AffineTransform::skew(
+                    api_x_radians,
+                    api_y_radians,
+                ))
+            })())
+        },
+    )
+}
+fn wire_AffineTransform_then_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AffineTransform_then",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::transform::AffineTransform>::sse_decode(&mut deserializer);
+            let api_next = <crate::api::transform::AffineTransform>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::transform::AffineTransform::then(
+                    &api_that, api_next,
+                ))
+            })())
+        },
+    )
+}
+fn wire_AffineTransform_translation_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "AffineTransform_translation",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_x = <f32>::sse_decode(&mut deserializer);
+            let api_y = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::transform::AffineTransform::translation(
+                    api_x, api_y,
+                ))
+            })())
+        },
+    )
+}
+fn wire_create_transformed_point_set_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_transformed_point_set",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_transform =
+                <crate::api::transform::AffineTransform>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::transform::create_transformed_point_set(
+                                api_point_set,
+                                api_transform,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_create_transformed_point_set_groups_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_transformed_point_set_groups",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_group_attribute = <String>::sse_decode(&mut deserializer);
+            let api_transforms =
+                <Vec<crate::api::transform::AffineTransform>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::transform::create_transformed_point_set_groups(
+                                api_point_set,
+                                api_group_attribute,
+                                api_transforms,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_transform_point_set_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "transform_point_set",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_transform =
+                <crate::api::transform::AffineTransform>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::transform::transform_point_set(
+                                api_point_set,
+                                api_transform,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_transform_point_set_groups_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "transform_point_set_groups",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_group_attribute = <String>::sse_decode(&mut deserializer);
+            let api_transforms =
+                <Vec<crate::api::transform::AffineTransform>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::transform::transform_point_set_groups(
+                                api_point_set,
+                                api_group_attribute,
+                                api_transforms,
+                            )
+                            .await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire_voronoi_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "voronoi",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_point_set = <u32>::sse_decode(&mut deserializer);
+            let api_bounds = <crate::api::simple::ComputeRect>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse(
+                    (move || async move {
+                        Result::<_, ()>::Ok(
+                            crate::api::voronoi::voronoi(api_point_set, api_bounds).await,
+                        )
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::adapter::AdapterReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_vendor = <u32>::sse_decode(deserializer);
+        let mut var_device = <u32>::sse_decode(deserializer);
+        let mut var_deviceType = <String>::sse_decode(deserializer);
+        let mut var_backend = <String>::sse_decode(deserializer);
+        let mut var_driver = <String>::sse_decode(deserializer);
+        let mut var_driverInfo = <String>::sse_decode(deserializer);
+        let mut var_computeShaders = <bool>::sse_decode(deserializer);
+        let mut var_adapterFeatures = <Vec<String>>::sse_decode(deserializer);
+        let mut var_deviceFeatures = <Vec<String>>::sse_decode(deserializer);
+        let mut var_adapterLimits = <crate::api::adapter::ComputeLimits>::sse_decode(deserializer);
+        let mut var_deviceLimits = <crate::api::adapter::ComputeLimits>::sse_decode(deserializer);
+        let mut var_kernels = <Vec<crate::api::adapter::KernelSupport>>::sse_decode(deserializer);
+        return crate::api::adapter::AdapterReport {
+            name: var_name,
+            vendor: var_vendor,
+            device: var_device,
+            device_type: var_deviceType,
+            backend: var_backend,
+            driver: var_driver,
+            driver_info: var_driverInfo,
+            compute_shaders: var_computeShaders,
+            adapter_features: var_adapterFeatures,
+            device_features: var_deviceFeatures,
+            adapter_limits: var_adapterLimits,
+            device_limits: var_deviceLimits,
+            kernels: var_kernels,
+        };
+    }
+}
+
+impl SseDecode for crate::api::transform::AffineTransform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_xx = <f32>::sse_decode(deserializer);
+        let mut var_xy = <f32>::sse_decode(deserializer);
+        let mut var_yx = <f32>::sse_decode(deserializer);
+        let mut var_yy = <f32>::sse_decode(deserializer);
+        let mut var_tx = <f32>::sse_decode(deserializer);
+        let mut var_ty = <f32>::sse_decode(deserializer);
+        return crate::api::transform::AffineTransform {
+            xx: var_xx,
+            xy: var_xy,
+            yx: var_yx,
+            yy: var_yy,
+            tx: var_tx,
+            ty: var_ty,
+        };
+    }
+}
+
+impl SseDecode for crate::api::attributes::AttributeFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_attribute = <String>::sse_decode(deserializer);
+                let mut var_min = <f64>::sse_decode(deserializer);
+                let mut var_max = <f64>::sse_decode(deserializer);
+                return crate::api::attributes::AttributeFilter::Range {
+                    attribute: var_attribute,
+                    min: var_min,
+                    max: var_max,
+                };
+            }
+            1 => {
+                let mut var_attribute = <String>::sse_decode(deserializer);
+                let mut var_values = <Vec<f64>>::sse_decode(deserializer);
+                return crate::api::attributes::AttributeFilter::OneOf {
+                    attribute: var_attribute,
+                    values: var_values,
+                };
+            }
+            2 => {
+                let mut var_attribute = <String>::sse_decode(deserializer);
+                let mut var_mask = <u32>::sse_decode(deserializer);
+                let mut var_all = <bool>::sse_decode(deserializer);
+                return crate::api::attributes::AttributeFilter::Bitmask {
+                    attribute: var_attribute,
+                    mask: var_mask,
+                    all: var_all,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::attributes::AttributeValues {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <Vec<f32>>::sse_decode(deserializer);
+                return crate::api::attributes::AttributeValues::F32(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <Vec<u32>>::sse_decode(deserializer);
+                return crate::api::attributes::AttributeValues::U32(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for Box<crate::api::expression::Predicate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <crate::api::expression::Predicate>::sse_decode(deserializer);
+        return Box::new(inner);
+    }
+}
+
+impl SseDecode for crate::api::projection::Camera {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_view = <crate::api::transform::AffineTransform>::sse_decode(deserializer);
+        let mut var_viewport = <crate::api::simple::ComputeRect>::sse_decode(deserializer);
+        let mut var_margin = <f32>::sse_decode(deserializer);
+        return crate::api::projection::Camera {
+            view: var_view,
+            viewport: var_viewport,
+            margin: var_margin,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dbscan::Clustering {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_labels = <Vec<i32>>::sse_decode(deserializer);
+        let mut var_clusterCount = <u32>::sse_decode(deserializer);
+        return crate::api::dbscan::Clustering {
+            labels: var_labels,
+            cluster_count: var_clusterCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::heatmap::Colormap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::heatmap::Colormap::Viridis,
+            1 => crate::api::heatmap::Colormap::Magma,
+            2 => crate::api::heatmap::Colormap::Inferno,
+            3 => crate::api::heatmap::Colormap::Grayscale,
+            _ => unreachable!("Invalid variant for Colormap: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::dispatch::ComputeBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::dispatch::ComputeBackend::Auto,
+            1 => crate::api::dispatch::ComputeBackend::Cpu,
+            2 => crate::api::dispatch::ComputeBackend::Gpu,
+            _ => unreachable!("Invalid variant for ComputeBackend: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::adapter::ComputeLimits {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxBufferSize = <u64>::sse_decode(deserializer);
+        let mut var_maxStorageBufferBindingSize = <u32>::sse_decode(deserializer);
+        let mut var_maxStorageBuffersPerShaderStage = <u32>::sse_decode(deserializer);
+        let mut var_maxComputeWorkgroupStorageSize = <u32>::sse_decode(deserializer);
+        let mut var_maxComputeInvocationsPerWorkgroup = <u32>::sse_decode(deserializer);
+        let mut var_maxComputeWorkgroupSizeX = <u32>::sse_decode(deserializer);
+        let mut var_maxComputeWorkgroupsPerDimension = <u32>::sse_decode(deserializer);
+        return crate::api::adapter::ComputeLimits {
+            max_buffer_size: var_maxBufferSize,
+            max_storage_buffer_binding_size: var_maxStorageBufferBindingSize,
+            max_storage_buffers_per_shader_stage: var_maxStorageBuffersPerShaderStage,
+            max_compute_workgroup_storage_size: var_maxComputeWorkgroupStorageSize,
+            max_compute_invocations_per_workgroup: var_maxComputeInvocationsPerWorkgroup,
+            max_compute_workgroup_size_x: var_maxComputeWorkgroupSizeX,
+            max_compute_workgroups_per_dimension: var_maxComputeWorkgroupsPerDimension,
+        };
+    }
+}
+
+impl SseDecode for crate::api::profiling::ComputeMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_requestId = <u64>::sse_decode(deserializer);
+        let mut var_kernel = <String>::sse_decode(deserializer);
+        let mut var_pointCount = <u64>::sse_decode(deserializer);
+        let mut var_onGpu = <bool>::sse_decode(deserializer);
+        let mut var_uploadMicros = <f64>::sse_decode(deserializer);
+        let mut var_dispatchMicros = <f64>::sse_decode(deserializer);
+        let mut var_gpuDispatchMicros = <Option<f64>>::sse_decode(deserializer);
+        let mut var_readbackMicros = <f64>::sse_decode(deserializer);
+        let mut var_totalMicros = <f64>::sse_decode(deserializer);
+        return crate::api::profiling::ComputeMetrics {
+            request_id: var_requestId,
+            kernel: var_kernel,
+            point_count: var_pointCount,
+            on_gpu: var_onGpu,
+            upload_micros: var_uploadMicros,
+            dispatch_micros: var_dispatchMicros,
+            gpu_dispatch_micros: var_gpuDispatchMicros,
+            readback_micros: var_readbackMicros,
+            total_micros: var_totalMicros,
+        };
+    }
+}
+
+impl SseDecode for crate::api::simple::ComputePoint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <f32>::sse_decode(deserializer);
+        let mut var_y = <f32>::sse_decode(deserializer);
+        return crate::api::simple::ComputePoint { x: var_x, y: var_y };
+    }
+}
+
+impl SseDecode for crate::api::simple::ComputeRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_min = <[f32; 2]>::sse_decode(deserializer);
+        let mut var_max = <[f32; 2]>::sse_decode(deserializer);
+        return crate::api::simple::ComputeRect {
+            min: var_min,
+            max: var_max,
+        };
+    }
+}
+
+impl SseDecode for crate::api::delaunay::DelaunayTriangulation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_triangles = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_hull = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::delaunay::DelaunayTriangulation {
+            triangles: var_triangles,
+            hull: var_hull,
+        };
+    }
+}
+
+impl SseDecode for crate::api::heatmap::DensityGrid {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_values = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_max = <f32>::sse_decode(deserializer);
+        return crate::api::heatmap::DensityGrid {
+            width: var_width,
+            height: var_height,
+            values: var_values,
+            max: var_max,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dispatch::DispatchCalibration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_gpuFixedMicros = <f64>::sse_decode(deserializer);
+        let mut var_gpuPerPointNanos = <f64>::sse_decode(deserializer);
+        let mut var_uploadPerPointNanos = <f64>::sse_decode(deserializer);
+        let mut var_cpuPerPointNanos = <f64>::sse_decode(deserializer);
+        return crate::api::dispatch::DispatchCalibration {
+            gpu_fixed_micros: var_gpuFixedMicros,
+            gpu_per_point_nanos: var_gpuPerPointNanos,
+            upload_per_point_nanos: var_uploadPerPointNanos,
+            cpu_per_point_nanos: var_cpuPerPointNanos,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for [f32; 2] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<f32>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::projection::FlatScreenPoints {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_positions = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_indices = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::projection::FlatScreenPoints {
+            positions: var_positions,
+            indices: var_indices,
+        };
+    }
+}
+
+impl SseDecode for crate::api::heatmap::HeatmapImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_pixels = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_max = <f32>::sse_decode(deserializer);
+        return crate::api::heatmap::HeatmapImage {
+            width: var_width,
+            height: var_height,
+            pixels: var_pixels,
+            max: var_max,
+        };
+    }
+}
+
+impl SseDecode for crate::api::histogram::Histogram2d {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_binsX = <u32>::sse_decode(deserializer);
+        let mut var_binsY = <u32>::sse_decode(deserializer);
+        let mut var_counts = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_sums = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_means = <Vec<f32>>::sse_decode(deserializer);
+        return crate::api::histogram::Histogram2d {
+            bins_x: var_binsX,
+            bins_y: var_binsY,
+            counts: var_counts,
+            sums: var_sums,
+            means: var_means,
+        };
+    }
+}
+
+impl SseDecode for crate::api::point_set::IndexStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::point_set::SpatialIndexKind>::sse_decode(deserializer);
+        let mut var_buildMicros = <f64>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        let mut var_refitted = <bool>::sse_decode(deserializer);
+        return crate::api::point_set::IndexStats {
+            kind: var_kind,
+            build_micros: var_buildMicros,
+            size: var_size,
+            refitted: var_refitted,
+        };
+    }
+}
+
+impl SseDecode for crate::api::kmeans::KMeans {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_assignments = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_centroids = <Vec<crate::api::simple::ComputePoint>>::sse_decode(deserializer);
+        let mut var_iterations = <u32>::sse_decode(deserializer);
+        return crate::api::kmeans::KMeans {
+            assignments: var_assignments,
+            centroids: var_centroids,
+            iterations: var_iterations,
+        };
+    }
+}
+
+impl SseDecode for crate::api::heatmap::KernelShape {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::heatmap::KernelShape::Gaussian,
+            1 => crate::api::heatmap::KernelShape::Epanechnikov,
+            2 => crate::api::heatmap::KernelShape::Quartic,
+            3 => crate::api::heatmap::KernelShape::Uniform,
+            _ => unreachable!("Invalid variant for KernelShape: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::adapter::KernelSupport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_available = <bool>::sse_decode(deserializer);
+        let mut var_missingFeatures = <Vec<String>>::sse_decode(deserializer);
+        let mut var_storageBuffers = <u32>::sse_decode(deserializer);
+        return crate::api::adapter::KernelSupport {
+            name: var_name,
+            available: var_available,
+            missing_features: var_missingFeatures,
+            storage_buffers: var_storageBuffers,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::transform::AffineTransform> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::transform::AffineTransform>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::attributes::AttributeFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::attributes::AttributeFilter>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::profiling::ComputeMetrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::profiling::ComputeMetrics>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::simple::ComputePoint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::simple::ComputePoint>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<[f32; 2]> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<[f32; 2]>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::adapter::KernelSupport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::adapter::KernelSupport>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<u32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<u32>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::marker_cluster::MarkerCluster> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::marker_cluster::MarkerCluster>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::expression::Predicate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::expression::Predicate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::alpha_shape::ShapePolygon> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::alpha_shape::ShapePolygon>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::logging::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_level = <crate::api::logging::LogLevel>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_fields = <String>::sse_decode(deserializer);
+        let mut var_spans = <String>::sse_decode(deserializer);
+        return crate::api::logging::LogEntry {
+            level: var_level,
+            target: var_target,
+            message: var_message,
+            fields: var_fields,
+            spans: var_spans,
+        };
+    }
+}
+
+impl SseDecode for crate::api::logging::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::logging::LogLevel::Trace,
+            1 => crate::api::logging::LogLevel::Debug,
+            2 => crate::api::logging::LogLevel::Info,
+            3 => crate::api::logging::LogLevel::Warn,
+            4 => crate::api::logging::LogLevel::Error,
+            _ => unreachable!("Invalid variant for LogLevel: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::marker_cluster::MarkerCluster {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_position = <[f32; 2]>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        let mut var_representative = <u32>::sse_decode(deserializer);
+        return crate::api::marker_cluster::MarkerCluster {
+            position: var_position,
+            count: var_count,
+            representative: var_representative,
+        };
+    }
+}
+
+impl SseDecode for crate::api::knn::Neighbours {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_k = <u32>::sse_decode(deserializer);
+        let mut var_indices = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_distances = <Vec<f32>>::sse_decode(deserializer);
+        return crate::api::knn::Neighbours {
+            k: var_k,
+            indices: var_indices,
+            distances: var_distances,
+        };
+    }
+}
+
+impl SseDecode for Option<crate::api::adapter::AdapterReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::adapter::AdapterReport>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::dbscan::Clustering> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::dbscan::Clustering>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::delaunay::DelaunayTriangulation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::delaunay::DelaunayTriangulation>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::heatmap::DensityGrid> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::heatmap::DensityGrid>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::dispatch::DispatchCalibration> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::dispatch::DispatchCalibration>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::projection::FlatScreenPoints> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::projection::FlatScreenPoints>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::heatmap::HeatmapImage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::heatmap::HeatmapImage>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::histogram::Histogram2d> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::histogram::Histogram2d>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::point_set::IndexStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::point_set::IndexStats>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::kmeans::KMeans> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::kmeans::KMeans>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::knn::Neighbours> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::knn::Neighbours>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::radius_graph::ProximityGraph> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::radius_graph::ProximityGraph>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::projection::ScreenPoints> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::projection::ScreenPoints>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::voronoi::VoronoiDiagram> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::voronoi::VoronoiDiagram>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::jump_flood::VoronoiRaster> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::jump_flood::VoronoiRaster>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<[f32; 2]>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<[f32; 2]>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::api::marker_cluster::MarkerCluster>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <Vec<crate::api::marker_cluster::MarkerCluster>>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<f32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<f32>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u32>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::api::alpha_shape::ShapePolygon>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::api::alpha_shape::ShapePolygon>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::expression::Predicate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <crate::api::simple::ComputeRect>::sse_decode(deserializer);
+                return crate::api::expression::Predicate::InRect(var_field0);
+            }
+            1 => {
+                let mut var_center = <[f32; 2]>::sse_decode(deserializer);
+                let mut var_radius = <f32>::sse_decode(deserializer);
+                return crate::api::expression::Predicate::InCircle {
+                    center: var_center,
+                    radius: var_radius,
+                };
+            }
+            2 => {
+                let mut var_field0 =
+                    <Vec<crate::api::simple::ComputePoint>>::sse_decode(deserializer);
+                return crate::api::expression::Predicate::InPolygon(var_field0);
+            }
+            3 => {
+                let mut var_field0 =
+                    <crate::api::attributes::AttributeFilter>::sse_decode(deserializer);
+                return crate::api::expression::Predicate::Attribute(var_field0);
+            }
+            4 => {
+                let mut var_field0 =
+                    <Vec<crate::api::expression::Predicate>>::sse_decode(deserializer);
+                return crate::api::expression::Predicate::And(var_field0);
+            }
+            5 => {
+                let mut var_field0 =
+                    <Vec<crate::api::expression::Predicate>>::sse_decode(deserializer);
+                return crate::api::expression::Predicate::Or(var_field0);
+            }
+            6 => {
+                let mut var_field0 =
+                    <Box<crate::api::expression::Predicate>>::sse_decode(deserializer);
+                return crate::api::expression::Predicate::Not(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::radius_graph::ProximityGraph {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_offsets = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_neighbours = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::radius_graph::ProximityGraph {
+            offsets: var_offsets,
+            neighbours: var_neighbours,
+        };
+    }
+}
+
+impl SseDecode for crate::api::projection::ScreenPoints {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_positions = <Vec<crate::api::simple::ComputePoint>>::sse_decode(deserializer);
+        let mut var_indices = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::projection::ScreenPoints {
+            positions: var_positions,
+            indices: var_indices,
+        };
+    }
+}
+
+impl SseDecode for crate::api::alpha_shape::ShapePolygon {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_exterior = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_holes = <Vec<Vec<u32>>>::sse_decode(deserializer);
+        return crate::api::alpha_shape::ShapePolygon {
+            exterior: var_exterior,
+            holes: var_holes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::point_set::SpatialIndexKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::point_set::SpatialIndexKind::None,
+            1 => crate::api::point_set::SpatialIndexKind::Grid,
+            2 => crate::api::point_set::SpatialIndexKind::Bvh,
+            3 => crate::api::point_set::SpatialIndexKind::RTree,
+            _ => unreachable!("Invalid variant for SpatialIndexKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::voronoi::VoronoiDiagram {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_offsets = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_vertices = <Vec<crate::api::simple::ComputePoint>>::sse_decode(deserializer);
+        return crate::api::voronoi::VoronoiDiagram {
+            offsets: var_offsets,
+            vertices: var_vertices,
+        };
+    }
+}
+
+impl SseDecode for crate::api::jump_flood::VoronoiRaster {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_labels = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_distances = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::jump_flood::VoronoiRaster {
+            width: var_width,
+            height: var_height,
+            labels: var_labels,
+            distances: var_distances,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire_get_adapter_report_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire_alpha_shape_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire_alpha_shape_in_circle_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire_alpha_shape_in_polygon_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire_alpha_shape_in_rect_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire_remove_point_attribute_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire_set_point_attribute_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire_dbscan_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire_delaunay_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire_calibrate_dispatch_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire_query_predicate_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire_density_grid_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire_density_image_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire_histogram_2d_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire_hit_test_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire_convex_hull_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire_convex_hull_in_circle_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire_convex_hull_in_polygon_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire_convex_hull_in_rect_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire_rasterize_voronoi_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire_kmeans_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire_knn_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire_create_log_stream_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire_build_marker_clusters_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire_get_marker_clusters_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire_create_point_set_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire_create_point_set_flat_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire_dispose_point_set_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire_get_point_set_index_stats_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire_insert_points_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire_move_points_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire_remove_points_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire_set_point_set_index_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire_update_point_set_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire_project_point_set_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire_project_point_set_flat_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire_query_circle_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire_query_circle_filtered_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire_query_polygon_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire_query_polygon_filtered_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire_query_rect_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire_query_rect_filtered_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire_radius_graph_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire_init_app_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire_run_compute_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire_run_compute_flat_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire_create_transformed_point_set_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire_create_transformed_point_set_groups_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire_transform_point_set_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire_transform_point_set_groups_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire_voronoi_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        10 => wire_DispatchCalibration_crossover_points_impl(ptr, rust_vec_len, data_len),
+        12 => wire_get_compute_backend_impl(ptr, rust_vec_len, data_len),
+        13 => wire_set_compute_backend_impl(ptr, rust_vec_len, data_len),
+        26 => wire_close_log_stream_impl(ptr, rust_vec_len, data_len),
+        39 => wire_is_profiling_enabled_impl(ptr, rust_vec_len, data_len),
+        40 => wire_set_profiling_enabled_impl(ptr, rust_vec_len, data_len),
+        41 => wire_take_compute_metrics_impl(ptr, rust_vec_len, data_len),
+        54 => wire_AffineTransform_identity_impl(ptr, rust_vec_len, data_len),
+        55 => wire_AffineTransform_rotation_impl(ptr, rust_vec_len, data_len),
+        56 => wire_AffineTransform_scaling_impl(ptr, rust_vec_len, data_len),
+        57 => wire_AffineTransform_skew_impl(ptr, rust_vec_len, data_len),
+        58 => wire_AffineTransform_then_impl(ptr, rust_vec_len, data_len),
+        59 => wire_AffineTransform_translation_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::adapter::AdapterReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.vendor.into_into_dart().into_dart(),
+            self.device.into_into_dart().into_dart(),
+            self.device_type.into_into_dart().into_dart(),
+            self.backend.into_into_dart().into_dart(),
+            self.driver.into_into_dart().into_dart(),
+            self.driver_info.into_into_dart().into_dart(),
+            self.compute_shaders.into_into_dart().into_dart(),
+            self.adapter_features.into_into_dart().into_dart(),
+            self.device_features.into_into_dart().into_dart(),
+            self.adapter_limits.into_into_dart().into_dart(),
+            self.device_limits.into_into_dart().into_dart(),
+            self.kernels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::adapter::AdapterReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::adapter::AdapterReport>
+    for crate::api::adapter::AdapterReport
+{
+    fn into_into_dart(self) -> crate::api::adapter::AdapterReport {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::transform::AffineTransform {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.xx.into_into_dart().into_dart(),
+            self.xy.into_into_dart().into_dart(),
+            self.yx.into_into_dart().into_dart(),
+            self.yy.into_into_dart().into_dart(),
+            self.tx.into_into_dart().into_dart(),
+            self.ty.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::transform::AffineTransform
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::transform::AffineTransform>
+    for crate::api::transform::AffineTransform
+{
+    fn into_into_dart(self) -> crate::api::transform::AffineTransform {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::attributes::AttributeFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::attributes::AttributeFilter::Range {
+                attribute,
+                min,
+                max,
+            } => [
+                0.into_dart(),
+                attribute.into_into_dart().into_dart(),
+                min.into_into_dart().into_dart(),
+                max.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::attributes::AttributeFilter::OneOf { attribute, values } => [
+                1.into_dart(),
+                attribute.into_into_dart().into_dart(),
+                values.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::attributes::AttributeFilter::Bitmask {
+                attribute,
+                mask,
+                all,
+            } => [
+                2.into_dart(),
+                attribute.into_into_dart().into_dart(),
+                mask.into_into_dart().into_dart(),
+                all.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::attributes::AttributeFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::attributes::AttributeFilter>
+    for crate::api::attributes::AttributeFilter
+{
+    fn into_into_dart(self) -> crate::api::attributes::AttributeFilter {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::attributes::AttributeValues {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::attributes::AttributeValues::F32(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::attributes::AttributeValues::U32(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::attributes::AttributeValues
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::attributes::AttributeValues>
+    for crate::api::attributes::AttributeValues
+{
+    fn into_into_dart(self) -> crate::api::attributes::AttributeValues {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::projection::Camera {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.view.into_into_dart().into_dart(),
+            self.viewport.into_into_dart().into_dart(),
+            self.margin.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::projection::Camera
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::projection::Camera>
+    for crate::api::projection::Camera
+{
+    fn into_into_dart(self) -> crate::api::projection::Camera {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::dbscan::Clustering {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.labels.into_into_dart().into_dart(),
+            self.cluster_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dbscan::Clustering
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dbscan::Clustering>
+    for crate::api::dbscan::Clustering
+{
+    fn into_into_dart(self) -> crate::api::dbscan::Clustering {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::heatmap::Colormap {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Viridis => 0.into_dart(),
+            Self::Magma => 1.into_dart(),
+            Self::Inferno => 2.into_dart(),
+            Self::Grayscale => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::heatmap::Colormap {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::heatmap::Colormap>
+    for crate::api::heatmap::Colormap
+{
+    fn into_into_dart(self) -> crate::api::heatmap::Colormap {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::dispatch::ComputeBackend {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Auto => 0.into_dart(),
+            Self::Cpu => 1.into_dart(),
+            Self::Gpu => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dispatch::ComputeBackend
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dispatch::ComputeBackend>
+    for crate::api::dispatch::ComputeBackend
+{
+    fn into_into_dart(self) -> crate::api::dispatch::ComputeBackend {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::adapter::ComputeLimits {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_buffer_size.into_into_dart().into_dart(),
+            self.max_storage_buffer_binding_size
+                .into_into_dart()
+                .into_dart(),
+            self.max_storage_buffers_per_shader_stage
+                .into_into_dart()
+                .into_dart(),
+            self.max_compute_workgroup_storage_size
+                .into_into_dart()
+                .into_dart(),
+            self.max_compute_invocations_per_workgroup
+                .into_into_dart()
+                .into_dart(),
+            self.max_compute_workgroup_size_x
+                .into_into_dart()
+                .into_dart(),
+            self.max_compute_workgroups_per_dimension
+                .into_into_dart()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::adapter::ComputeLimits
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::adapter::ComputeLimits>
+    for crate::api::adapter::ComputeLimits
+{
+    fn into_into_dart(self) -> crate::api::adapter::ComputeLimits {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::profiling::ComputeMetrics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.request_id.into_into_dart().into_dart(),
+            self.kernel.into_into_dart().into_dart(),
+            self.point_count.into_into_dart().into_dart(),
+            self.on_gpu.into_into_dart().into_dart(),
+            self.upload_micros.into_into_dart().into_dart(),
+            self.dispatch_micros.into_into_dart().into_dart(),
+            self.gpu_dispatch_micros.into_into_dart().into_dart(),
+            self.readback_micros.into_into_dart().into_dart(),
+            self.total_micros.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::profiling::ComputeMetrics
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::profiling::ComputeMetrics>
+    for crate::api::profiling::ComputeMetrics
+{
+    fn into_into_dart(self) -> crate::api::profiling::ComputeMetrics {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ComputePoint {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ComputePoint
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ComputePoint>
+    for crate::api::simple::ComputePoint
+{
+    fn into_into_dart(self) -> crate::api::simple::ComputePoint {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::simple::ComputeRect {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.min.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::simple::ComputeRect
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ComputeRect>
+    for crate::api::simple::ComputeRect
+{
+    fn into_into_dart(self) -> crate::api::simple::ComputeRect {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::delaunay::DelaunayTriangulation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.triangles.into_into_dart().into_dart(),
+            self.hull.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::delaunay::DelaunayTriangulation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::delaunay::DelaunayTriangulation>
+    for crate::api::delaunay::DelaunayTriangulation
+{
+    fn into_into_dart(self) -> crate::api::delaunay::DelaunayTriangulation {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::heatmap::DensityGrid {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.values.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::heatmap::DensityGrid
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::heatmap::DensityGrid>
+    for crate::api::heatmap::DensityGrid
+{
+    fn into_into_dart(self) -> crate::api::heatmap::DensityGrid {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::dispatch::DispatchCalibration {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.gpu_fixed_micros.into_into_dart().into_dart(),
+            self.gpu_per_point_nanos.into_into_dart().into_dart(),
+            self.upload_per_point_nanos.into_into_dart().into_dart(),
+            self.cpu_per_point_nanos.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dispatch::DispatchCalibration
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dispatch::DispatchCalibration>
+    for crate::api::dispatch::DispatchCalibration
+{
+    fn into_into_dart(self) -> crate::api::dispatch::DispatchCalibration {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::projection::FlatScreenPoints {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.positions.into_into_dart().into_dart(),
+            self.indices.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::projection::FlatScreenPoints
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::projection::FlatScreenPoints>
+    for crate::api::projection::FlatScreenPoints
+{
+    fn into_into_dart(self) -> crate::api::projection::FlatScreenPoints {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::heatmap::HeatmapImage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.pixels.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::heatmap::HeatmapImage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::heatmap::HeatmapImage>
+    for crate::api::heatmap::HeatmapImage
+{
+    fn into_into_dart(self) -> crate::api::heatmap::HeatmapImage {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::histogram::Histogram2d {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bins_x.into_into_dart().into_dart(),
+            self.bins_y.into_into_dart().into_dart(),
+            self.counts.into_into_dart().into_dart(),
+            self.sums.into_into_dart().into_dart(),
+            self.means.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::histogram::Histogram2d
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::histogram::Histogram2d>
+    for crate::api::histogram::Histogram2d
+{
+    fn into_into_dart(self) -> crate::api::histogram::Histogram2d {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::point_set::IndexStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.build_micros.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.refitted.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::point_set::IndexStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::point_set::IndexStats>
+    for crate::api::point_set::IndexStats
+{
+    fn into_into_dart(self) -> crate::api::point_set::IndexStats {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::kmeans::KMeans {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.assignments.into_into_dart().into_dart(),
+            self.centroids.into_into_dart().into_dart(),
+            self.iterations.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::kmeans::KMeans {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::kmeans::KMeans> for crate::api::kmeans::KMeans {
+    fn into_into_dart(self) -> crate::api::kmeans::KMeans {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::heatmap::KernelShape {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Gaussian => 0.into_dart(),
+            Self::Epanechnikov => 1.into_dart(),
+            Self::Quartic => 2.into_dart(),
+            Self::Uniform => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::heatmap::KernelShape
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::heatmap::KernelShape>
+    for crate::api::heatmap::KernelShape
+{
+    fn into_into_dart(self) -> crate::api::heatmap::KernelShape {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::adapter::KernelSupport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+            self.missing_features.into_into_dart().into_dart(),
+            self.storage_buffers.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::adapter::KernelSupport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::adapter::KernelSupport>
+    for crate::api::adapter::KernelSupport
+{
+    fn into_into_dart(self) -> crate::api::adapter::KernelSupport {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::logging::LogEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.level.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
+            self.spans.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::logging::LogEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logging::LogEntry>
+    for crate::api::logging::LogEntry
+{
+    fn into_into_dart(self) -> crate::api::logging::LogEntry {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::logging::LogLevel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Trace => 0.into_dart(),
+            Self::Debug => 1.into_dart(),
+            Self::Info => 2.into_dart(),
+            Self::Warn => 3.into_dart(),
+            Self::Error => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::logging::LogLevel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logging::LogLevel>
+    for crate::api::logging::LogLevel
+{
+    fn into_into_dart(self) -> crate::api::logging::LogLevel {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::marker_cluster::MarkerCluster {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.position.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.representative.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::marker_cluster::MarkerCluster
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::marker_cluster::MarkerCluster>
+    for crate::api::marker_cluster::MarkerCluster
+{
+    fn into_into_dart(self) -> crate::api::marker_cluster::MarkerCluster {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::knn::Neighbours {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.k.into_into_dart().into_dart(),
+            self.indices.into_into_dart().into_dart(),
+            self.distances.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::knn::Neighbours {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::knn::Neighbours>
+    for crate::api::knn::Neighbours
+{
+    fn into_into_dart(self) -> crate::api::knn::Neighbours {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::expression::Predicate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::expression::Predicate::InRect(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::expression::Predicate::InCircle { center, radius } => [
+                1.into_dart(),
+                center.into_into_dart().into_dart(),
+                radius.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::expression::Predicate::InPolygon(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::expression::Predicate::Attribute(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::expression::Predicate::And(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::expression::Predicate::Or(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::expression::Predicate::Not(field0) => {
+                [6.into_dart(), (*field0).into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::expression::Predicate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::expression::Predicate>
+    for crate::api::expression::Predicate
+{
+    fn into_into_dart(self) -> crate::api::expression::Predicate {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::radius_graph::ProximityGraph {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.offsets.into_into_dart().into_dart(),
+            self.neighbours.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::radius_graph::ProximityGraph
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::radius_graph::ProximityGraph>
+    for crate::api::radius_graph::ProximityGraph
+{
+    fn into_into_dart(self) -> crate::api::radius_graph::ProximityGraph {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::projection::ScreenPoints {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.positions.into_into_dart().into_dart(),
+            self.indices.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::projection::ScreenPoints
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::projection::ScreenPoints>
+    for crate::api::projection::ScreenPoints
+{
+    fn into_into_dart(self) -> crate::api::projection::ScreenPoints {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::alpha_shape::ShapePolygon {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.exterior.into_into_dart().into_dart(),
+            self.holes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::alpha_shape::ShapePolygon
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::alpha_shape::ShapePolygon>
+    for crate::api::alpha_shape::ShapePolygon
+{
+    fn into_into_dart(self) -> crate::api::alpha_shape::ShapePolygon {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::point_set::SpatialIndexKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Grid => 1.into_dart(),
+            Self::Bvh => 2.into_dart(),
+            Self::RTree => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::point_set::SpatialIndexKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::point_set::SpatialIndexKind>
+    for crate::api::point_set::SpatialIndexKind
+{
+    fn into_into_dart(self) -> crate::api::point_set::SpatialIndexKind {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::voronoi::VoronoiDiagram {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.offsets.into_into_dart().into_dart(),
+            self.vertices.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::voronoi::VoronoiDiagram
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::voronoi::VoronoiDiagram>
+    for crate::api::voronoi::VoronoiDiagram
+{
+    fn into_into_dart(self) -> crate::api::voronoi::VoronoiDiagram {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::jump_flood::VoronoiRaster {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.labels.into_into_dart().into_dart(),
+            self.distances.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::jump_flood::VoronoiRaster
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::jump_flood::VoronoiRaster>
+    for crate::api::jump_flood::VoronoiRaster
+{
+    fn into_into_dart(self) -> crate::api::jump_flood::VoronoiRaster {
+        self
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::adapter::AdapterReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <u32>::sse_encode(self.vendor, serializer);
+        <u32>::sse_encode(self.device, serializer);
+        <String>::sse_encode(self.device_type, serializer);
+        <String>::sse_encode(self.backend, serializer);
+        <String>::sse_encode(self.driver, serializer);
+        <String>::sse_encode(self.driver_info, serializer);
+        <bool>::sse_encode(self.compute_shaders, serializer);
+        <Vec<String>>::sse_encode(self.adapter_features, serializer);
+        <Vec<String>>::sse_encode(self.device_features, serializer);
+        <crate::api::adapter::ComputeLimits>::sse_encode(self.adapter_limits, serializer);
+        <crate::api::adapter::ComputeLimits>::sse_encode(self.device_limits, serializer);
+        <Vec<crate::api::adapter::KernelSupport>>::sse_encode(self.kernels, serializer);
+    }
+}
+
+impl SseEncode for crate::api::transform::AffineTransform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.xx, serializer);
+        <f32>::sse_encode(self.xy, serializer);
+        <f32>::sse_encode(self.yx, serializer);
+        <f32>::sse_encode(self.yy, serializer);
+        <f32>::sse_encode(self.tx, serializer);
+        <f32>::sse_encode(self.ty, serializer);
+    }
+}
+
+impl SseEncode for crate::api::attributes::AttributeFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::attributes::AttributeFilter::Range {
+                attribute,
+                min,
+                max,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(attribute, serializer);
+                <f64>::sse_encode(min, serializer);
+                <f64>::sse_encode(max, serializer);
+            }
+            crate::api::attributes::AttributeFilter::OneOf { attribute, values } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(attribute, serializer);
+                <Vec<f64>>::sse_encode(values, serializer);
+            }
+            crate::api::attributes::AttributeFilter::Bitmask {
+                attribute,
+                mask,
+                all,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(attribute, serializer);
+                <u32>::sse_encode(mask, serializer);
+                <bool>::sse_encode(all, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::attributes::AttributeValues {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::attributes::AttributeValues::F32(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<f32>>::sse_encode(field0, serializer);
+            }
+            crate::api::attributes::AttributeValues::U32(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<u32>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for Box<crate::api::expression::Predicate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::expression::Predicate>::sse_encode(*self, serializer);
+    }
+}
+
+impl SseEncode for crate::api::projection::Camera {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::transform::AffineTransform>::sse_encode(self.view, serializer);
+        <crate::api::simple::ComputeRect>::sse_encode(self.viewport, serializer);
+        <f32>::sse_encode(self.margin, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dbscan::Clustering {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<i32>>::sse_encode(self.labels, serializer);
+        <u32>::sse_encode(self.cluster_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::heatmap::Colormap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::heatmap::Colormap::Viridis => 0,
+                crate::api::heatmap::Colormap::Magma => 1,
+                crate::api::heatmap::Colormap::Inferno => 2,
+                crate::api::heatmap::Colormap::Grayscale => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::dispatch::ComputeBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::dispatch::ComputeBackend::Auto => 0,
+                crate::api::dispatch::ComputeBackend::Cpu => 1,
+                crate::api::dispatch::ComputeBackend::Gpu => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::adapter::ComputeLimits {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.max_buffer_size, serializer);
+        <u32>::sse_encode(self.max_storage_buffer_binding_size, serializer);
+        <u32>::sse_encode(self.max_storage_buffers_per_shader_stage, serializer);
+        <u32>::sse_encode(self.max_compute_workgroup_storage_size, serializer);
+        <u32>::sse_encode(self.max_compute_invocations_per_workgroup, serializer);
+        <u32>::sse_encode(self.max_compute_workgroup_size_x, serializer);
+        <u32>::sse_encode(self.max_compute_workgroups_per_dimension, serializer);
+    }
+}
+
+impl SseEncode for crate::api::profiling::ComputeMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.request_id, serializer);
+        <String>::sse_encode(self.kernel, serializer);
+        <u64>::sse_encode(self.point_count, serializer);
+        <bool>::sse_encode(self.on_gpu, serializer);
+        <f64>::sse_encode(self.upload_micros, serializer);
+        <f64>::sse_encode(self.dispatch_micros, serializer);
+        <Option<f64>>::sse_encode(self.gpu_dispatch_micros, serializer);
+        <f64>::sse_encode(self.readback_micros, serializer);
+        <f64>::sse_encode(self.total_micros, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::ComputePoint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.x, serializer);
+        <f32>::sse_encode(self.y, serializer);
+    }
+}
+
+impl SseEncode for crate::api::simple::ComputeRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <[f32; 2]>::sse_encode(self.min, serializer);
+        <[f32; 2]>::sse_encode(self.max, serializer);
+    }
+}
+
+impl SseEncode for crate::api::delaunay::DelaunayTriangulation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u32>>::sse_encode(self.triangles, serializer);
+        <Vec<u32>>::sse_encode(self.hull, serializer);
+    }
+}
+
+impl SseEncode for crate::api::heatmap::DensityGrid {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <Vec<f32>>::sse_encode(self.values, serializer);
+        <f32>::sse_encode(self.max, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dispatch::DispatchCalibration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.gpu_fixed_micros, serializer);
+        <f64>::sse_encode(self.gpu_per_point_nanos, serializer);
+        <f64>::sse_encode(self.upload_per_point_nanos, serializer);
+        <f64>::sse_encode(self.cpu_per_point_nanos, serializer);
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for [f32; 2] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<f32>>::sse_encode(
+            {
+                let boxed: Box<[_]> = Box::new(self);
+                boxed.into_vec()
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::projection::FlatScreenPoints {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<f32>>::sse_encode(self.positions, serializer);
+        <Vec<u32>>::sse_encode(self.indices, serializer);
+    }
+}
+
+impl SseEncode for crate::api::heatmap::HeatmapImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <Vec<u8>>::sse_encode(self.pixels, serializer);
+        <f32>::sse_encode(self.max, serializer);
+    }
+}
+
+impl SseEncode for crate::api::histogram::Histogram2d {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.bins_x, serializer);
+        <u32>::sse_encode(self.bins_y, serializer);
+        <Vec<u32>>::sse_encode(self.counts, serializer);
+        <Vec<f32>>::sse_encode(self.sums, serializer);
+        <Vec<f32>>::sse_encode(self.means, serializer);
+    }
+}
+
+impl SseEncode for crate::api::point_set::IndexStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::point_set::SpatialIndexKind>::sse_encode(self.kind, serializer);
+        <f64>::sse_encode(self.build_micros, serializer);
+        <u64>::sse_encode(self.size, serializer);
+        <bool>::sse_encode(self.refitted, serializer);
+    }
+}
+
+impl SseEncode for crate::api::kmeans::KMeans {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u32>>::sse_encode(self.assignments, serializer);
+        <Vec<crate::api::simple::ComputePoint>>::sse_encode(self.centroids, serializer);
+        <u32>::sse_encode(self.iterations, serializer);
+    }
+}
+
+impl SseEncode for crate::api::heatmap::KernelShape {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::heatmap::KernelShape::Gaussian => 0,
+                crate::api::heatmap::KernelShape::Epanechnikov => 1,
+                crate::api::heatmap::KernelShape::Quartic => 2,
+                crate::api::heatmap::KernelShape::Uniform => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::adapter::KernelSupport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <bool>::sse_encode(self.available, serializer);
+        <Vec<String>>::sse_encode(self.missing_features, serializer);
+        <u32>::sse_encode(self.storage_buffers, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::transform::AffineTransform> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::transform::AffineTransform>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::attributes::AttributeFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::attributes::AttributeFilter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::profiling::ComputeMetrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::profiling::ComputeMetrics>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::simple::ComputePoint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::simple::ComputePoint>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<[f32; 2]> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <[f32; 2]>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::adapter::KernelSupport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::adapter::KernelSupport>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<Vec<u32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<u32>>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::marker_cluster::MarkerCluster> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::marker_cluster::MarkerCluster>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::expression::Predicate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::expression::Predicate>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::alpha_shape::ShapePolygon> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::alpha_shape::ShapePolygon>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::logging::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::logging::LogLevel>::sse_encode(self.level, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <String>::sse_encode(self.fields, serializer);
+        <String>::sse_encode(self.spans, serializer);
+    }
+}
+
+impl SseEncode for crate::api::logging::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::logging::LogLevel::Trace => 0,
+                crate::api::logging::LogLevel::Debug => 1,
+                crate::api::logging::LogLevel::Info => 2,
+                crate::api::logging::LogLevel::Warn => 3,
+                crate::api::logging::LogLevel::Error => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::marker_cluster::MarkerCluster {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <[f32; 2]>::sse_encode(self.position, serializer);
+        <u32>::sse_encode(self.count, serializer);
+        <u32>::sse_encode(self.representative, serializer);
+    }
+}
+
+impl SseEncode for crate::api::knn::Neighbours {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.k, serializer);
+        <Vec<u32>>::sse_encode(self.indices, serializer);
+        <Vec<f32>>::sse_encode(self.distances, serializer);
+    }
+}
+
+impl SseEncode for Option<crate::api::adapter::AdapterReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::adapter::AdapterReport>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::dbscan::Clustering> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dbscan::Clustering>::sse_encode(value, serializer);
+        }
+    }
+}
 
-// Section: dart2rust
+impl SseEncode for Option<crate::api::delaunay::DelaunayTriangulation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::delaunay::DelaunayTriangulation>::sse_encode(value, serializer);
+        }
+    }
+}
 
-impl SseDecode for crate::api::simple::ComputeRect {
+impl SseEncode for Option<crate::api::heatmap::DensityGrid> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_min = <[f32; 2]>::sse_decode(deserializer);
-        let mut var_max = <[f32; 2]>::sse_decode(deserializer);
-        return crate::api::simple::ComputeRect {
-            min: var_min,
-            max: var_max,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::heatmap::DensityGrid>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for f32 {
+impl SseEncode for Option<crate::api::dispatch::DispatchCalibration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dispatch::DispatchCalibration>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for [f32; 2] {
+impl SseEncode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<f32>>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for Vec<[f32; 2]> {
+impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<[f32; 2]>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<f32> {
+impl SseEncode for Option<crate::api::projection::FlatScreenPoints> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f32>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::projection::FlatScreenPoints>::sse_encode(value, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Option<Vec<[f32; 2]>> {
+impl SseEncode for Option<crate::api::heatmap::HeatmapImage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<[f32; 2]>>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::heatmap::HeatmapImage>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Option<crate::api::histogram::Histogram2d> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::histogram::Histogram2d>::sse_encode(value, serializer);
+        }
+    }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Option<crate::api::point_set::IndexStats> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::point_set::IndexStats>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for Option<crate::api::kmeans::KMeans> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::kmeans::KMeans>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire_init_app_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire_run_compute_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Option<crate::api::knn::Neighbours> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::knn::Neighbours>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for Option<crate::api::radius_graph::ProximityGraph> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::radius_graph::ProximityGraph>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
+impl SseEncode for Option<crate::api::projection::ScreenPoints> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::projection::ScreenPoints>::sse_encode(value, serializer);
+        }
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::simple::ComputeRect {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.min.into_into_dart().into_dart(),
-            self.max.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::simple::ComputeRect
-{
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::simple::ComputeRect>
-    for crate::api::simple::ComputeRect
-{
-    fn into_into_dart(self) -> crate::api::simple::ComputeRect {
-        self
+
+impl SseEncode for Option<crate::api::voronoi::VoronoiDiagram> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::voronoi::VoronoiDiagram>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::simple::ComputeRect {
+impl SseEncode for Option<crate::api::jump_flood::VoronoiRaster> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <[f32; 2]>::sse_encode(self.min, serializer);
-        <[f32; 2]>::sse_encode(self.max, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::jump_flood::VoronoiRaster>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for Option<Vec<[f32; 2]>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<[f32; 2]>>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for [f32; 2] {
+impl SseEncode for Option<Vec<crate::api::marker_cluster::MarkerCluster>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<f32>>::sse_encode(
-            {
-                let boxed: Box<[_]> = Box::new(self);
-                boxed.into_vec()
-            },
-            serializer,
-        );
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::api::marker_cluster::MarkerCluster>>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<[f32; 2]> {
+impl SseEncode for Option<Vec<f32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <[f32; 2]>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<f32>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<f32> {
+impl SseEncode for Option<Vec<u32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f32>::sse_encode(item, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u32>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<Vec<[f32; 2]>> {
+impl SseEncode for Option<Vec<crate::api::alpha_shape::ShapePolygon>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <Vec<[f32; 2]>>::sse_encode(value, serializer);
+            <Vec<crate::api::alpha_shape::ShapePolygon>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::expression::Predicate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::expression::Predicate::InRect(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::simple::ComputeRect>::sse_encode(field0, serializer);
+            }
+            crate::api::expression::Predicate::InCircle { center, radius } => {
+                <i32>::sse_encode(1, serializer);
+                <[f32; 2]>::sse_encode(center, serializer);
+                <f32>::sse_encode(radius, serializer);
+            }
+            crate::api::expression::Predicate::InPolygon(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <Vec<crate::api::simple::ComputePoint>>::sse_encode(field0, serializer);
+            }
+            crate::api::expression::Predicate::Attribute(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <crate::api::attributes::AttributeFilter>::sse_encode(field0, serializer);
+            }
+            crate::api::expression::Predicate::And(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <Vec<crate::api::expression::Predicate>>::sse_encode(field0, serializer);
+            }
+            crate::api::expression::Predicate::Or(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <Vec<crate::api::expression::Predicate>>::sse_encode(field0, serializer);
+            }
+            crate::api::expression::Predicate::Not(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <Box<crate::api::expression::Predicate>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
+impl SseEncode for crate::api::radius_graph::ProximityGraph {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u32>>::sse_encode(self.offsets, serializer);
+        <Vec<u32>>::sse_encode(self.neighbours, serializer);
+    }
+}
+
+impl SseEncode for crate::api::projection::ScreenPoints {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::simple::ComputePoint>>::sse_encode(self.positions, serializer);
+        <Vec<u32>>::sse_encode(self.indices, serializer);
+    }
+}
+
+impl SseEncode for crate::api::alpha_shape::ShapePolygon {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u32>>::sse_encode(self.exterior, serializer);
+        <Vec<Vec<u32>>>::sse_encode(self.holes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::point_set::SpatialIndexKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::point_set::SpatialIndexKind::None => 0,
+                crate::api::point_set::SpatialIndexKind::Grid => 1,
+                crate::api::point_set::SpatialIndexKind::Bvh => 2,
+                crate::api::point_set::SpatialIndexKind::RTree => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self).unwrap();
+    }
+}
+
 impl SseEncode for () {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::voronoi::VoronoiDiagram {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u32>>::sse_encode(self.offsets, serializer);
+        <Vec<crate::api::simple::ComputePoint>>::sse_encode(self.vertices, serializer);
+    }
+}
+
+impl SseEncode for crate::api::jump_flood::VoronoiRaster {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <Vec<u8>>::sse_encode(self.labels, serializer);
+        <Vec<u8>>::sse_encode(self.distances, serializer);
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {